@@ -34,6 +34,10 @@ use {
         },
     }
 };
+use solana_defi_core::{
+    sink::{sink_from_spec, DEFAULT_BUFFER},
+    EventSink,
+};
 
 type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
@@ -47,6 +51,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 
@@ -153,10 +164,12 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -173,7 +186,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -190,6 +203,7 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -229,9 +243,9 @@ async fn geyser_subscribe(
                             }
                         };
                 
-                        println!("\nAccount Info: {:#?}", account_json);
+                        sink.send(&account_json)?;
                     } else {
-                        println!("Account data is None for slot: {}", slot);
+                        error!("Account data is None for slot: {}", slot);
                     }
 
                 }
@@ -7,6 +7,12 @@ use {
         stream::StreamExt,
     },
     log::{error, info},
+    serde::Serialize,
+    solana_defi_core::{
+        models::serialize::serialization::serialize_token_balances,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        EventSink,
+    },
     solana_sdk::{
         hash::Hash,
         message::{v0::LoadedAddresses, MessageHeader},
@@ -46,6 +52,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -94,12 +107,12 @@ impl Args {
 
 
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 struct ParsedEventTransaction {
     parsed_transaction: ParsedConfirmedTransactionWithStatusMeta,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedConfirmedTransactionWithStatusMeta {
     pub slot: u64,
     pub transaction: ParsedTransaction,
@@ -107,13 +120,13 @@ pub struct ParsedConfirmedTransactionWithStatusMeta {
     pub block_time: Option<i64>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedTransaction {
     pub signatures: Vec<Signature>,
     pub message: ParsedMessage,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedMessage {
     pub header: MessageHeader,
     pub account_keys: Vec<Pubkey>,
@@ -122,7 +135,7 @@ pub struct ParsedMessage {
     pub address_table_lookups: Vec<MessageAddressTableLookup>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedTransactionStatusMeta {
     pub status: TransactionResult<()>,
     pub fee: u64,
@@ -130,7 +143,9 @@ pub struct ParsedTransactionStatusMeta {
     pub post_balances: Vec<u64>,
     pub inner_instructions: Vec<DecodedInstruction>, 
     pub log_messages: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub pre_token_balances: Option<Vec<TransactionTokenBalance>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub post_token_balances: Option<Vec<TransactionTokenBalance>>,
     pub rewards: Option<Rewards>,
     pub loaded_addresses: LoadedAddresses,
@@ -147,10 +162,12 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -167,7 +184,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -182,6 +199,7 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
     info!("stream opened");
@@ -193,7 +211,9 @@ async fn geyser_subscribe(
             Ok(msg) => match msg.update_oneof {
                Some(UpdateOneof::Transaction(update)) => {
                  match processor.process_transaction_update(update) {
-                  Ok(Some(meteora_txn)) => println!("Meteora DAMM BUY SELL EVENT:\n{:#?}", meteora_txn),
+                  Ok(Some(meteora_txn)) => {
+                      sink.send(&meteora_txn)?;
+                  }
                   Ok(None) => (),
                   Err(e) => error!("Failed to process txn (skipping): {e}"),
                 }
@@ -32,7 +32,7 @@ pub struct ExtractedInstructions {
     pub compiled: Vec<TransactionInstructionWithParent>,
     pub inner: Vec<TransactionInstructionWithParent>,
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TransactionEvent {
    pub event_type: String,
    pub user: Option<String>,
@@ -7,6 +7,10 @@ use {
         stream::StreamExt,
     },
     log::{error, info},
+    solana_defi_core::{
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        EventSink,
+    },
     serde::Serialize,
     std::{
         collections::HashMap, env, sync::Arc, time::Duration
@@ -64,6 +68,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -165,10 +176,12 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -185,7 +198,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -200,6 +213,7 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -236,9 +250,9 @@ async fn geyser_subscribe(
                             }
                         };
 
-                        println!("\nAccount Info: {:#?}", account_json);
+                        sink.send(&account_json)?;
                     } else {
-                        println!("Account data is None for slot: {}", slot);
+                        error!("Account data is None for slot: {}", slot);
                     }
                 }
                 Some(UpdateOneof::Ping(_)) => {
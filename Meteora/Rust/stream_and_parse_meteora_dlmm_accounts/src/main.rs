@@ -17,6 +17,10 @@ use {
         },
     }
 };
+use solana_defi_core::{
+    sink::{sink_from_spec, DEFAULT_BUFFER},
+    EventSink,
+};
 
 type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
@@ -30,6 +34,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 
@@ -115,6 +126,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -122,6 +134,7 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -138,7 +151,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -155,6 +168,7 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -192,9 +206,9 @@ async fn geyser_subscribe(
                             "decoded_data": decoded_account
                         });
                 
-                        println!("\nAccount Info: {}", account_info);
+                        sink.send(&account_info)?;
                     } else {
-                        println!("Account data is None for slot: {}", slot);
+                        error!("Account data is None for slot: {}", slot);
                     }
 
                 }
@@ -240,7 +254,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize BinArrayExtAccout: {}", e),
                 })?;
-            println!("\nDecoded Bin Array Ext Structure: {:#?}", data);
             Ok(DecodedAccount::BinArrayBitmapExtension(data.0)) // Unwrapping the inner BondingCurve struct
         }
         BIN_ARRAY_ACCOUNT_DISCM => {
@@ -249,7 +262,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize BondingCurveAccount: {}", e),
                 })?;
-            println!("\nDecoded Bonding Curve Structure: {:#?}", data);
             Ok(DecodedAccount::BinArray(data.0))
         }
         LB_PAIR_ACCOUNT_DISCM => {
@@ -258,7 +270,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize BondingCurveAccount: {}", e),
                 })?;
-            println!("\nDecoded Bonding Curve Structure: {:#?}", data);
             Ok(DecodedAccount::LbPair(data.0))
         }
         ORACLE_ACCOUNT_DISCM => {
@@ -267,7 +278,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize BondingCurveAccount: {}", e),
                 })?;
-            println!("\nDecoded Bonding Curve Structure: {:#?}", data);
             Ok(DecodedAccount::Oracle(data.0))
         }
         POSITION_ACCOUNT_DISCM => {
@@ -276,7 +286,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize BondingCurveAccount: {}", e),
                 })?;
-            println!("\nDecoded Bonding Curve Structure: {:#?}", data);
             Ok(DecodedAccount::Position(data.0))
         }
         _ => Err(AccountDecodeError {
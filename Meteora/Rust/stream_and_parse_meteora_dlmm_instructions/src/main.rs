@@ -11,6 +11,11 @@ use {
     serialization::{serialize_pubkey, serialize_option_pubkey},
     instruction_account_mapper::{AccountMetadata, Idl, InstructionAccountMapper},
      log::{error, info},
+    solana_defi_core::{
+        models::serialize::serialization::serialize_token_balances,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        EventSink,
+    },
       serde::Serialize, solana_account_decoder_client_types::token::UiTokenAmount, solana_sdk::{
         hash::Hash, instruction::{AccountMeta, CompiledInstruction, Instruction}, message::{v0::{LoadedAddresses,
              Message, MessageAddressTableLookup},
@@ -56,6 +61,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -127,13 +139,13 @@ pub struct TransactionWithActions {
     pub actions: Vec<DecodedInstruction>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedTransaction {
     pub signatures: Vec<Signature>,
     pub message: ParsedMessage,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedMessage {
     pub header: MessageHeader,
     pub account_keys: Vec<Pubkey>,
@@ -142,7 +154,7 @@ pub struct ParsedMessage {
     pub address_table_lookups: Vec<MessageAddressTableLookup>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedTransactionStatusMeta {
     pub status: TransactionResult<()>,
     pub fee: u64,
@@ -150,7 +162,9 @@ pub struct ParsedTransactionStatusMeta {
     pub post_balances: Vec<u64>,
     pub inner_instructions: Vec<DecodedInstruction>, // Replacing inner_instructions
     pub log_messages: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub pre_token_balances: Option<Vec<TransactionTokenBalance>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub post_token_balances: Option<Vec<TransactionTokenBalance>>,
     pub rewards: Option<Rewards>,
     pub loaded_addresses: LoadedAddresses,
@@ -158,14 +172,14 @@ pub struct ParsedTransactionStatusMeta {
     pub compute_units_consumed: Option<u64>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedConfirmedTransaction {
     pub slot: u64,
     pub transaction: ParsedTransaction,
     pub meta: ParsedTransactionStatusMeta,
     pub block_time: Option<i64>,
 }
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ParsedConfirmedTransactionWithStatusMeta {
     pub slot: u64,
     pub transaction: ParsedTransaction,
@@ -182,6 +196,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -189,6 +204,7 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -205,7 +221,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -241,6 +257,7 @@ fn get_instruction_name_with_typename(instruction: &TokenInstruction) -> String
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -743,7 +760,7 @@ async fn geyser_subscribe(
                         block_time: confirmed_txn_with_meta.block_time,
                     };
 
-                    println!("Decoded Transaction:\n{:#?}", parsed_confirmed_txn_with_meta);
+                    sink.send(&parsed_confirmed_txn_with_meta)?;
                     
                     }
                 }
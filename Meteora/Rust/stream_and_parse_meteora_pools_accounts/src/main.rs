@@ -18,6 +18,10 @@ use {
         },
     }
 };
+use solana_defi_core::{
+    sink::{sink_from_spec, DEFAULT_BUFFER},
+    EventSink,
+};
 
 type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
@@ -31,6 +35,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 
@@ -112,6 +123,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -119,6 +131,7 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -135,7 +148,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -152,6 +165,7 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -189,9 +203,9 @@ async fn geyser_subscribe(
                             "decoded_data": decoded_account
                         });
                 
-                        println!("\nAccount Info: {}", account_info);
+                        sink.send(&account_info)?;
                     } else {
-                        println!("Account data is None for slot: {}", slot);
+                        error!("Account data is None for slot: {}", slot);
                     }
 
                 }
@@ -237,7 +251,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize Config: {}", e),
                 })?;
-            println!("\nDecoded Config Structure: {:#?}", data);
             Ok(DecodedAccount::Config(data.0)) // Unwrapping the inner BondingCurve struct
         }
         LOCK_ESCROW_ACCOUNT_DISCM => {
@@ -246,7 +259,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize Lock Escrow Account: {}", e),
                 })?;
-            println!("\nDecoded Lock Escrow Structure: {:#?}", data);
             Ok(DecodedAccount::LockEscrow(data.0))
         }
         POOL_ACCOUNT_DISCM => {
@@ -255,7 +267,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize Pool Account: {}", e),
                 })?;
-            println!("\nDecoded Pool Structure: {:#?}", data);
             Ok(DecodedAccount::Pool(data.0))
         }
         _ => Err(AccountDecodeError {
@@ -14,6 +14,7 @@ use {
         ConfirmedTransactionWithStatusMeta, TransactionTokenBalance, TransactionWithStatusMeta, VersionedTransactionWithStatusMeta
     },
     solana_sdk::{pubkey::Pubkey, hash::Hash},
+    solana_defi_core::token_registry::TokenRegistry,
 };
 use crate::processor::models::mapper::instruction::Idl;
 use crate::METEORA_DAMM_V2_PROGRAM_ID;
@@ -27,10 +28,12 @@ pub struct TransactionProcessor {
     pub token_idl: Idl,
     pub meteora_damm_v2_program_id: Pubkey,
     pub token_program_id: Pubkey,
+    /// Symbols and decimals of the mints seen, shared through its cache file.
+    pub tokens: TokenRegistry,
 }
 
 impl TransactionProcessor {
-    pub fn new() -> anyhow::Result<Self> {
+    pub fn new(tokens: TokenRegistry) -> anyhow::Result<Self> {
         let token_idl_json = fs::read_to_string("idls/token_program_idl.json")
             .context("Unable to read Token IDL JSON file")?;
         let meteora_damm_v2_idl = fs::read_to_string("idls/meteora_damm.json")
@@ -41,11 +44,12 @@ impl TransactionProcessor {
             token_idl: serde_json::from_str(&token_idl_json)?,
             meteora_damm_v2_program_id: Pubkey::from_str(METEORA_DAMM_V2_PROGRAM_ID)?,
             token_program_id: Pubkey::from_str(TOKEN_PROGRAM_ID)?,
+            tokens,
         })
     }
 
     pub fn process_transaction_update(
-    &self,
+    &mut self,
     update: SubscribeUpdateTransaction,
     ) -> anyhow::Result<Option<PriceData>> {
       let slot = update.slot;
@@ -86,6 +90,8 @@ impl TransactionProcessor {
                 decoded_inner,
                 block_time,
             )?;
+            self.tokens.observe_token_balances(parsed_txn.meta.pre_token_balances.as_deref().unwrap_or_default());
+            self.tokens.observe_token_balances(parsed_txn.meta.post_token_balances.as_deref().unwrap_or_default());
 
             Ok(self.meteoradamm_transaction_output(parsed_txn))
         } else {
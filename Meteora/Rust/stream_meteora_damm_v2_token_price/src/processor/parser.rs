@@ -9,10 +9,8 @@ use {
     std::vec::Vec,
 };
 use crate::ParsedConfirmedTransactionWithStatusMeta;
-use num_bigint::BigInt;
-use num_traits::identities::Zero;
 use crate::processor::models::mapper::event::DecodedEvent;
-use num_traits::cast::ToPrimitive;
+use solana_defi_core::price::Price;
 use crate::processor::types::PriceData;
 
 #[derive(Debug, Clone)]
@@ -108,36 +106,27 @@ impl TransactionProcessor {
           })
           .collect();
 
-      let base_mint = swap_txn.accounts.iter().find(|a| a.name == "token_a_mint")?.pubkey.to_string().clone();
-      let quote_mint = swap_txn.accounts.iter().find(|a| a.name == "token_b_mint")?.pubkey.to_string().clone();
+      let base_key = swap_txn.accounts.iter().find(|a| a.name == "token_a_mint")?.pubkey;
+      let quote_key = swap_txn.accounts.iter().find(|a| a.name == "token_b_mint")?.pubkey;
+      let (base_mint, quote_mint) = (base_key.to_string(), quote_key.to_string());
 
-      let base_decimal = transfers.iter().find(|t| t.mint.as_ref() == Some(&base_mint)).map(|t| t.decimal)?;
-      let quote_decimal = transfers.iter().find(|t| t.mint.as_ref() == Some(&quote_mint)).map(|t| t.decimal)?;
+      // A mint moved without `transferChecked` takes its decimals from the token registry
+      let base_decimal = transfers.iter().find(|t| t.mint.as_ref() == Some(&base_mint)).map(|t| t.decimal as u8)
+          .or_else(|| self.tokens.get(&base_key)?.decimals)?;
+      let quote_decimal = transfers.iter().find(|t| t.mint.as_ref() == Some(&quote_mint)).map(|t| t.decimal as u8)
+          .or_else(|| self.tokens.get(&quote_key)?.decimals)?;
 
-       let sqrt_price_str = match &swap_event {
-            Some(DecodedEvent::Swap(event)) => event.swap_result.next_sqrt_price.to_string(),
+       let sqrt_price = match &swap_event {
+            Some(DecodedEvent::Swap(event)) => event.swap_result.next_sqrt_price,
             _ => return None,
          };
-      let calculated_price = sqrt_price_x64_to_price(&sqrt_price_str, base_decimal, quote_decimal);
-      let formatted_price = format!("{:.13} SOL", calculated_price) ;
+      let price = Price::from_sqrt_price_x64(sqrt_price, base_decimal, quote_decimal);
     Some(PriceData {
         token_a: base_mint,
         token_b: quote_mint,
-        price: formatted_price, 
+        token_a_symbol: self.tokens.symbol(&base_key),
+        token_b_symbol: self.tokens.symbol(&quote_key),
+        price,
         })
     }
 }
-fn sqrt_price_x64_to_price(next_sqrt_price_str: &str, decimals_a: u64, decimals_b: u64) -> f64 {
-    let sqrt_price_x64 = BigInt::parse_bytes(next_sqrt_price_str.as_bytes(), 10)
-        .unwrap_or_else(|| BigInt::zero());
-
-    let two_pow_64 = BigInt::from(1u128 << 64);
-    let sqrt_price = sqrt_price_x64.to_f64().unwrap() / two_pow_64.to_f64().unwrap();
-
-    let mut price = sqrt_price * sqrt_price;
-
-    let decimal_adjustment = 10f64.powi((decimals_a as i32) - (decimals_b as i32));
-    price *= decimal_adjustment;
-    price
-}
-
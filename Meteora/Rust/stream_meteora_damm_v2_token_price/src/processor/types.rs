@@ -1,5 +1,6 @@
 use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
 use serde::Serialize;
+use solana_defi_core::price::Price;
 
 use crate::processor::models::mapper::event::DecodedEvent;
 use crate::processor::models::mapper::instruction::AccountMetadata;
@@ -32,9 +33,12 @@ pub struct ExtractedInstructions {
     pub compiled: Vec<TransactionInstructionWithParent>,
     pub inner: Vec<TransactionInstructionWithParent>,
 }
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct PriceData {
     pub token_a: String,
     pub token_b: String,
-    pub price: String,
+    pub token_a_symbol: String,
+    pub token_b_symbol: String,
+    /// Token B per token A.
+    pub price: Price,
 }
\ No newline at end of file
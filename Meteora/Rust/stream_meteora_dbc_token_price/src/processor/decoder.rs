@@ -55,9 +55,10 @@ impl TransactionProcessor{
     ) -> anyhow::Result<Option<DecodedInstruction>> {
         match MeteoraDBCProgramIx::deserialize(&instruction.instruction.data) {
             Ok(decoded_ix) => {
+                // The IDL names instructions in snake_case, matched in PascalCase.
                 let mapped_accounts = self.meteora_dbc_idl.map_accounts(
                     &instruction.instruction.accounts,
-                    &decoded_ix.name().to_string(),
+                    &decoded_ix.to_string(),
                 )?;
 
                 let data = serde_json::to_value(&decoded_ix)
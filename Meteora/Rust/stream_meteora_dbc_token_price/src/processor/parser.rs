@@ -4,16 +4,15 @@ use {
          ParsedConfirmedTransactionWithStatusMeta,
          processor::types::PriceData
     },
+    solana_defi_core::token_registry::{TokenInfo, TokenProgram},
     solana_sdk::{
         instruction::AccountMeta,
         message::{v0::LoadedAddresses, VersionedMessage},
     },
     std::vec::Vec,
 };
-use num_bigint::BigInt;
-use num_traits::cast::ToPrimitive;
 use crate::processor::models::mapper::event::DecodedEvent;
-use num_traits::identities::Zero;
+use solana_defi_core::price::Price;
 
 #[derive(Debug, Clone)]
 pub struct TransferInfo {
@@ -68,6 +67,37 @@ impl TransactionProcessor {
         parsed_accounts
     }
 
+    /// Learns the name, symbol and program of the mints launched in `txn`,
+    /// and the decimals of every mint in its token balances.
+    pub fn observe_dbc_txn(&mut self, txn: &ParsedConfirmedTransactionWithStatusMeta) {
+        let meta = &txn.meta;
+        self.tokens.observe_token_balances(meta.pre_token_balances.as_deref().unwrap_or_default());
+        self.tokens.observe_token_balances(meta.post_token_balances.as_deref().unwrap_or_default());
+
+        for instr in txn.transaction.message.instructions.iter().chain(meta.inner_instructions.iter()) {
+            let Some(params) = ["InitializeVirtualPoolWithSplToken", "InitializeVirtualPoolWithToken2022"]
+                .into_iter()
+                .find_map(|variant| instr.data.get(variant))
+                .map(|args| &args["params"])
+            else {
+                continue;
+            };
+            let account = |name: &str| instr.accounts.iter().find(|acc| acc.name == name).map(|acc| acc.pubkey);
+            let Some(base_mint) = account("base_mint") else {
+                continue;
+            };
+            let param = |key: &str| params[key].as_str().filter(|value| !value.is_empty()).map(str::to_owned);
+            self.tokens.learn(
+                base_mint,
+                TokenInfo {
+                    symbol: param("symbol"),
+                    name: param("name"),
+                    decimals: None,
+                    program: account("token_program").and_then(|program| TokenProgram::from_program_id(&program)),
+                },
+            );
+        }
+    }
 
    pub fn meteoradbc_transaction_output(&self, parsed_instruction: ParsedConfirmedTransactionWithStatusMeta) -> Option<PriceData> {
      let meta = &parsed_instruction.meta;
@@ -110,36 +140,28 @@ impl TransactionProcessor {
           })
           .collect();
 
-      let base_mint = swap_txn.accounts.iter().find(|a| a.name == "base_mint")?.pubkey.to_string().clone();
-      let quote_mint = swap_txn.accounts.iter().find(|a| a.name == "quote_mint")?.pubkey.to_string().clone();
+      let base_key = swap_txn.accounts.iter().find(|a| a.name == "base_mint")?.pubkey;
+      let quote_key = swap_txn.accounts.iter().find(|a| a.name == "quote_mint")?.pubkey;
+      let (base_mint, quote_mint) = (base_key.to_string(), quote_key.to_string());
 
-      let base_decimal = transfers.iter().find(|t| t.mint.as_ref() == Some(&base_mint)).map(|t| t.decimal)?;
-      let quote_decimal = transfers.iter().find(|t| t.mint.as_ref() == Some(&quote_mint)).map(|t| t.decimal)?;
+      // A mint moved without `transferChecked` takes its decimals from the token registry
+      let base_decimal = transfers.iter().find(|t| t.mint.as_ref() == Some(&base_mint)).map(|t| t.decimal as u8)
+          .or_else(|| self.tokens.get(&base_key)?.decimals)?;
+      let quote_decimal = transfers.iter().find(|t| t.mint.as_ref() == Some(&quote_mint)).map(|t| t.decimal as u8)
+          .or_else(|| self.tokens.get(&quote_key)?.decimals)?;
 
-       let sqrt_price_str = match &swap_event {
-            Some(DecodedEvent::Swap(event)) => event.swap_result.next_sqrt_price.to_string(),
+       let sqrt_price = match &swap_event {
+            Some(DecodedEvent::Swap(event)) => event.swap_result.next_sqrt_price,
             _ => return None,
          };
-      let calculated_price = sqrt_price_x64_to_price(&sqrt_price_str, base_decimal, quote_decimal);
-      let formatted_price = format!("{:.13} SOL", calculated_price) ;
+      let price = Price::from_sqrt_price_x64(sqrt_price, base_decimal, quote_decimal);
 
     Some(PriceData {
         token_a: base_mint,
         token_b: quote_mint,
-        price: formatted_price, 
+        token_a_symbol: self.tokens.symbol(&base_key),
+        token_b_symbol: self.tokens.symbol(&quote_key),
+        price,
         })
     }
 }
-fn sqrt_price_x64_to_price(next_sqrt_price_str: &str, decimals_a: u64, decimals_b: u64) -> f64 {
-    let sqrt_price_x64 = BigInt::parse_bytes(next_sqrt_price_str.as_bytes(), 10)
-        .unwrap_or_else(|| BigInt::zero());
-
-    let two_pow_64 = BigInt::from(1u128 << 64);
-    let sqrt_price = sqrt_price_x64.to_f64().unwrap() / two_pow_64.to_f64().unwrap();
-
-    let mut price = sqrt_price * sqrt_price;
-
-    let decimal_adjustment = 10f64.powi((decimals_a as i32) - (decimals_b as i32));
-    price *= decimal_adjustment;
-    price
-}
\ No newline at end of file
@@ -14,6 +14,10 @@ use {
         },
     }
 };
+use solana_defi_core::{
+    sink::{sink_from_spec, DEFAULT_BUFFER},
+    EventSink,
+};
 
 type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
@@ -27,6 +31,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 
@@ -111,6 +122,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -118,6 +130,7 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -134,7 +147,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -151,6 +164,7 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -187,9 +201,9 @@ async fn geyser_subscribe(
                             "decoded_data": decoded_account
                         });
                 
-                        println!("\nAccount Info: {}", account_info);
+                        sink.send(&account_info)?;
                     } else {
-                        println!("Account data is None for slot: {}", slot);
+                        error!("Account data is None for slot: {}", slot);
                     }
 
                 }
@@ -235,7 +249,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize WhirlpoolsConfigAccount: {}", e),
                 })?;
-            println!("\nDecoded Whirlpool Config Account Structure: {:#?}", data);
             Ok(DecodedAccount::WhirlpoolsConfig(data.0)) // Unwrapping the inner BondingCurve struct
         }
         WHIRLPOOLS_CONFIG_EXTENSION_ACCOUNT_DISCM => {
@@ -244,7 +257,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                     message: format!("Failed to deserialize WhirlpoolsConfigExtAccount: {}", e),
                 })?;
 
-            println!("\nDecoded Whirlpool Config Ext Account Structure: {:#?}", data);
             Ok(DecodedAccount::WhirlpoolsConfigExtension(data.0)) // Unwrapping the inner BondingCurve struct
         }
         FEE_TIER_ACCOUNT_DISCM => {
@@ -253,7 +265,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                     message: format!("Failed to deserialize FreeTier Account: {}", e),
                 })?;
 
-            println!("\nDecoded Fee Tier Account Structure: {:#?}", data);
             Ok(DecodedAccount::FeeTier(data.0)) // Unwrapping the inner BondingCurve struct
         }
         POSITION_BUNDLE_ACCOUNT_DISCM => {
@@ -261,7 +272,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize PositionBundleAccount: {}", e),
                 })?;
-            println!("\nDecoded Position Bundle Account Structure: {:#?}", data);
             Ok(DecodedAccount::PositionBundle(data.0)) // Unwrapping the inner BondingCurve struct
         }
         POSITION_ACCOUNT_DISCM => {
@@ -269,7 +279,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize PositionAccount: {}", e),
                 })?;
-            println!("\nDecoded Position Account Structure: {:#?}", data);
             Ok(DecodedAccount::Position(data.0)) // Unwrapping the inner BondingCurve struct
         }
         TICK_ARRAY_ACCOUNT_DISCM => {
@@ -277,7 +286,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize TickArrayAccount: {}", e),
                 })?;
-            println!("\nDecoded Tick Array Account Structure: {:#?}", data);
             Ok(DecodedAccount::TickArray(data.0)) // Unwrapping the inner BondingCurve struct
         }
         TOKEN_BADGE_ACCOUNT_DISCM => {
@@ -285,7 +293,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize TokenBadgeAccount: {}", e),
                 })?;
-            println!("\nDecoded Token Badge Account Structure: {:#?}", data);
             Ok(DecodedAccount::TokenBadge(data.0)) // Unwrapping the inner BondingCurve struct
         }
         WHIRLPOOL_ACCOUNT_DISCM => {
@@ -293,7 +300,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize WhirlpoolAccount: {}", e),
                 })?;
-            println!("\nDecoded Whirlpool Account Structure: {:#?}", data);
             Ok(DecodedAccount::Whirlpool(data.0)) // Unwrapping the inner BondingCurve struct
         }
         _ => Err(AccountDecodeError {
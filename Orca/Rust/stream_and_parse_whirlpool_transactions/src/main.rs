@@ -25,6 +25,11 @@ use {
     spl_token::instruction::TokenInstruction
 };
 use crate::token_serializable::convert_to_serializable;
+use solana_defi_core::{
+    models::serialize::serialization::serialize_token_balances,
+    sink::{sink_from_spec, DEFAULT_BUFFER},
+    EventSink,
+};
 use solana_transaction_status::Rewards;
 use::solana_sdk::transaction::Result as TransactionResult;
 type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
@@ -43,6 +48,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -106,7 +118,7 @@ pub struct DecodedInstruction {
     #[serde(serialize_with = "serialize_option_pubkey")]
     pub parent_program_id: Option<Pubkey>,
 }
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug, Serialize)]
 struct TransactionEvent {
     event_type: String,
     user: Option<String>,
@@ -126,13 +138,13 @@ pub struct TransactionWithActions {
     pub version: Option<TransactionVersion>
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedTransaction {
     pub signatures: Vec<Signature>,
     pub message: ParsedMessage,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedMessage {
     pub header: MessageHeader,
     pub account_keys: Vec<Pubkey>,
@@ -141,7 +153,7 @@ pub struct ParsedMessage {
     pub address_table_lookups: Vec<MessageAddressTableLookup>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedTransactionStatusMeta {
     pub status: TransactionResult<()>,
     pub fee: u64,
@@ -149,7 +161,9 @@ pub struct ParsedTransactionStatusMeta {
     pub post_balances: Vec<u64>,
     pub inner_instructions: Vec<DecodedInstruction>, // Replacing inner_instructions
     pub log_messages: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub pre_token_balances: Option<Vec<TransactionTokenBalance>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub post_token_balances: Option<Vec<TransactionTokenBalance>>,
     pub rewards: Option<Rewards>,
     pub loaded_addresses: LoadedAddresses,
@@ -157,21 +171,21 @@ pub struct ParsedTransactionStatusMeta {
     pub compute_units_consumed: Option<u64>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedConfirmedTransaction {
     pub slot: u64,
     pub transaction: ParsedTransaction,
     pub meta: ParsedTransactionStatusMeta,
     pub block_time: Option<i64>,
 }
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug, Serialize)]
  struct ParsedConfirmedTransactionWithStatusMeta {
     pub slot: u64,
     pub transaction: ParsedTransaction,
     pub meta: ParsedTransactionStatusMeta,
     pub block_time: Option<i64>,
 }
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug, Serialize)]
 struct ParsedEventTransaction {
     pub parsed_transaction: ParsedConfirmedTransactionWithStatusMeta,
     pub event: TransactionEvent
@@ -186,6 +200,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -193,6 +208,7 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -209,7 +225,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -244,6 +260,7 @@ fn get_instruction_name_with_typename(instruction: &TokenInstruction) -> String
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -772,7 +789,7 @@ async fn geyser_subscribe(
                         },
                         block_time: confirmed_txn_with_meta.block_time,
                     };
-                    println!("Decoded Inner Instructions:\n{:#?}\n", parsed_confirmed_txn_with_meta);                     
+                    sink.send(&parsed_confirmed_txn_with_meta)?;
                   }
                 }
                 Some(UpdateOneof::Ping(_)) => {
@@ -1,88 +0,0 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
-pub const GLOBAL_ACCOUNT_DISCM: [u8; 8] = [167, 232, 232, 177, 200, 108, 114, 127];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Global {
-    pub initialized: bool,
-    pub authority: Pubkey,
-    pub fee_recipient: Pubkey,
-    pub initial_virtual_token_reserves: u64,
-    pub initial_virtual_sol_reserves: u64,
-    pub initial_real_token_reserves: u64,
-    pub token_total_supply: u64,
-    pub fee_basis_points: u64,
-}
-#[derive(Clone, Debug, PartialEq)]
-pub struct GlobalAccount(pub Global);
-impl GlobalAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
-        let mut reader = buf;
-        let mut maybe_discm = [0u8; 8];
-        reader.read_exact(&mut maybe_discm)?;
-        if maybe_discm != GLOBAL_ACCOUNT_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        GLOBAL_ACCOUNT_DISCM, maybe_discm
-                    ),
-                ),
-            );
-        }
-        Ok(Self(Global::deserialize(&mut reader)?))
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&GLOBAL_ACCOUNT_DISCM)?;
-        self.0.serialize(&mut writer)
-    }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
-        let mut data = Vec::new();
-        self.serialize(&mut data)?;
-        Ok(data)
-    }
-}
-pub const BONDING_CURVE_ACCOUNT_DISCM: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct BondingCurve {
-    pub virtual_token_reserves: u64,
-    pub virtual_sol_reserves: u64,
-    pub real_token_reserves: u64,
-    pub real_sol_reserves: u64,
-    pub token_total_supply: u64,
-    pub complete: bool,
-}
-#[derive(Clone, Debug, PartialEq)]
-pub struct BondingCurveAccount(pub BondingCurve);
-impl BondingCurveAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
-        let mut reader = buf;
-        let mut maybe_discm = [0u8; 8];
-        reader.read_exact(&mut maybe_discm)?;
-        if maybe_discm != BONDING_CURVE_ACCOUNT_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        BONDING_CURVE_ACCOUNT_DISCM, maybe_discm
-                    ),
-                ),
-            );
-        }
-        Ok(Self(BondingCurve::deserialize(&mut reader)?))
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&BONDING_CURVE_ACCOUNT_DISCM)?;
-        self.0.serialize(&mut writer)
-    }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
-        let mut data = Vec::new();
-        self.serialize(&mut data)?;
-        Ok(data)
-    }
-}
@@ -30,7 +30,8 @@ use {
     yellowstone_grpc_client::{GeyserGrpcClient, Interceptor},
     yellowstone_grpc_proto::prelude::{
         subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest, SubscribeRequestPing,UnixTimestamp,
-        SubscribeRequestFilterTransactions,SubscribeUpdateTransactionInfo,SubscribeRequestFilterBlocks
+        SubscribeRequestFilterTransactions,SubscribeUpdateTransactionInfo,SubscribeRequestFilterBlocks,
+        SubscribeRequestFilterAccounts
     },
     pump_interface::instructions::PumpProgramIx,
     yellowstone_grpc_proto::convert_from
@@ -38,13 +39,14 @@ use {
   use spl_token::instruction::TokenInstruction;
 
   use chrono::{DateTime, NaiveDateTime, Utc};
+  use pump_curve::{CurveParams, GLOBAL_ACCOUNT};
   //type TransactionsFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
   type BlocksFilterMap = HashMap<String, SubscribeRequestFilterBlocks>;
+  type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
   
   mod serialization;
   mod instruction_account_mapper;
   mod token_serializable;
-  mod get_balance;
   const PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
   
   
@@ -84,10 +86,22 @@ use {
                 include_entries: None,
             },
         );
+
+      // The curve parameters only change when the program admin updates them.
+      let mut accounts: AccountFilterMap = HashMap::new();
+      accounts.insert(
+            "global".to_owned(),
+            SubscribeRequestFilterAccounts {
+                account: vec![GLOBAL_ACCOUNT.to_string()],
+                owner: vec![],
+                filters: vec![],
+                nonempty_txn_signature: None,
+            },
+        );
   
         Ok(SubscribeRequest {
             slots: HashMap::default(),
-            accounts: HashMap::default(),
+            accounts,
             transactions : HashMap::default(),
             transactions_status: HashMap::default(),
             entry: HashMap::default(),
@@ -190,6 +204,7 @@ use {
     let (mut subscribe_tx, mut stream) = _client.subscribe_with_request(Some(request)).await?;
   
     info!("stream opened");
+    let mut curve_params = CurveParams::default();
     while let Some(message) = stream.next().await {
         match message {
             Ok(msg) => {
@@ -213,6 +228,7 @@ use {
                       let raw_signature_array: [u8; 64] = raw_signature.try_into().expect("Failed to convert to [u8; 64]");
                       let signature = Signature::from(raw_signature_array);
                       let meta = txn.meta.expect("Meta empty");
+                      let trade_events = pump_curve::trade_events(&meta.log_messages);
                       let raw_transaction = txn.transaction.expect("transaction empty");
                       let raw_message = raw_transaction.message.expect("message empty").clone();
                       let header = raw_message.header.expect("header empty");
@@ -435,6 +451,7 @@ use {
                     let signature = signature.to_string();
                     let idl_json = idl_json.clone();
                     let token_idl_json = token_idl_json.clone();
+                    let trade_events = trade_events.clone();
               task::spawn(async move {   
                   if instruction.instruction.program_id
                       == Pubkey::from_str(PROGRAM_ID)
@@ -473,16 +490,21 @@ use {
                                             let mint = data.iter().find(|obj| obj["name"]== "mint").unwrap();
                                             let mint_pubkey = mint["pubkey"].as_str().unwrap();
                                             let bonding_curve_pubkey = bonding_curve["pubkey"].as_str().unwrap();
-                                            let balance = get_balance::get_sol_balance(bonding_curve_pubkey) as f64;
-                                            let progress = (balance / 84.0) * 100.0;
+                                            let Some(trade) = trade_events.iter().find(|event| event.mint.to_string() == mint_pubkey) else {
+                                              return;
+                                            };
+                                            let curve = curve_params.curve_from_trade(trade);
+                                            let progress = curve_params.progress(&curve);
                                             info!("
                                             Signature: {:?}
                                             Mint: {:?}
                                             Bonding curve: {:?}
-                                            Balance: {:?} sol
-                                            Progress: {:?}% to completion
+                                            SOL raised: {:.4} sol
+                                            Tokens left: {:.2}
+                                            Progress: {:.2}% to completion
+                                            Graduated: {}
                                             ",
-                                            signature,mint_pubkey,bonding_curve_pubkey,balance,progress);
+                                            signature,mint_pubkey,bonding_curve_pubkey,progress.sol_raised.ui_amount.unwrap_or_default(),progress.tokens_left.ui_amount.unwrap_or_default(),progress.progress_bps as f64 / 100.0,progress.complete);
                                             
                                          }
                                           Err(e) => error!("Failed to serialize ix data for instruction: {:?}", e),
@@ -498,6 +520,17 @@ use {
                    })
                    }
                   }     
+                    Some(UpdateOneof::Account(update)) => {
+                        if let Some(account) = update.account {
+                            match CurveParams::from_global_account(&account.data) {
+                                Ok(params) => {
+                                    info!("Curve parameters updated: {:?}", params);
+                                    curve_params = params;
+                                }
+                                Err(e) => error!("Failed to decode Global account: {:?}", e),
+                            }
+                        }
+                    }
                     Some(UpdateOneof::Ping(_)) => {
                         subscribe_tx
                             .send(SubscribeRequest {
@@ -3,3 +3,4 @@ pub mod accounts;
 pub use accounts::*;
 pub mod typedefs;
 pub use typedefs::*;
+pub mod quote;
@@ -25,6 +25,18 @@ pub struct BuyEvent {
     pub user_quote_token_account: Pubkey,
     pub protocol_fee_recipient: Pubkey,
     pub protocol_fee_recipient_token_account: Pubkey,
+    pub coin_creator: Pubkey,
+    pub coin_creator_fee_basis_points: u64,
+    pub coin_creator_fee: u64,
+    pub track_volume: bool,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    pub min_base_amount_out: u64,
+    pub ix_name: String,
+    pub cashback_fee_basis_points: u64,
+    pub cashback: u64
 }
 
 #[derive(Default, Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -166,6 +178,11 @@ pub struct SellEvent {
     pub user_quote_token_account: Pubkey,
     pub protocol_fee_recipient: Pubkey,
     pub protocol_fee_recipient_token_account: Pubkey,
+    pub coin_creator: Pubkey,
+    pub coin_creator_fee_basis_points: u64,
+    pub coin_creator_fee: u64,
+    pub cashback_fee_basis_points: u64,
+    pub cashback: u64,
 }
 
 #[derive(Default, Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -0,0 +1,242 @@
+use {
+    base64::{engine::general_purpose, Engine},
+    pump_interface::{BondingCurve, Global, GlobalAccount, TradeEvent, TradeEventEvent},
+    serde::Serialize,
+    solana_defi_core::schema::TokenAmount,
+};
+
+/// The pump.fun `Global` account holding the curve parameters.
+pub const GLOBAL_ACCOUNT: &str = "4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnjf";
+
+pub const PUMP_TOKEN_DECIMALS: u8 = 6;
+
+/// Progress of a curve that has sold all of its real token reserves.
+pub const COMPLETE_BPS: u16 = 10_000;
+
+/// Initial reserves every curve starts from, as set in the `Global` account.
+#[derive(Debug, Clone, Copy)]
+pub struct CurveParams {
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+}
+
+impl Default for CurveParams {
+    /// Mainnet values, used until the `Global` account is streamed.
+    fn default() -> Self {
+        Self {
+            initial_virtual_token_reserves: 1_073_000_000_000_000,
+            initial_virtual_sol_reserves: 30_000_000_000,
+            initial_real_token_reserves: 793_100_000_000_000,
+        }
+    }
+}
+
+impl From<&Global> for CurveParams {
+    fn from(global: &Global) -> Self {
+        Self {
+            initial_virtual_token_reserves: global.initial_virtual_token_reserves,
+            initial_virtual_sol_reserves: global.initial_virtual_sol_reserves,
+            initial_real_token_reserves: global.initial_real_token_reserves,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BondingCurveProgress {
+    pub sol_raised: TokenAmount,
+    pub tokens_left: TokenAmount,
+    /// Share of the initial real token reserves sold, in basis points.
+    pub progress_bps: u16,
+    pub complete: bool,
+}
+
+impl CurveParams {
+    pub fn from_global_account(data: &[u8]) -> std::io::Result<Self> {
+        GlobalAccount::deserialize(data).map(|account| Self::from(&account.0))
+    }
+
+    /// The curve state right after a trade, as reported by its `TradeEvent`.
+    pub fn curve_from_trade(&self, event: &TradeEvent) -> BondingCurve {
+        BondingCurve {
+            virtual_token_reserves: event.virtual_token_reserves,
+            virtual_sol_reserves: event.virtual_sol_reserves,
+            real_token_reserves: event.real_token_reserves,
+            real_sol_reserves: event.real_sol_reserves,
+            token_total_supply: 0,
+            complete: event.real_token_reserves == 0,
+        }
+    }
+
+    /// Tokens the curve holds on top of its real reserves, or `None` when
+    /// the parameters are inconsistent.
+    pub fn virtual_token_offset(&self) -> Option<u64> {
+        self.initial_virtual_token_reserves
+            .checked_sub(self.initial_real_token_reserves)
+    }
+
+    /// Progress is the share of the initial real token reserves sold so far.
+    pub fn progress(&self, curve: &BondingCurve) -> BondingCurveProgress {
+        let complete = curve.complete || curve.real_token_reserves == 0;
+        let sold = self
+            .initial_real_token_reserves
+            .saturating_sub(curve.real_token_reserves);
+        let progress_bps = if complete || self.initial_real_token_reserves == 0 {
+            COMPLETE_BPS
+        } else {
+            (sold as u128 * COMPLETE_BPS as u128 / self.initial_real_token_reserves as u128) as u16
+        };
+
+        BondingCurveProgress {
+            sol_raised: TokenAmount::lamports(curve.real_sol_reserves),
+            tokens_left: TokenAmount::new(curve.real_token_reserves, PUMP_TOKEN_DECIMALS),
+            progress_bps,
+            complete,
+        }
+    }
+}
+
+/// Decodes the `TradeEvent`s logged by the program as `Program data:` lines.
+pub fn trade_events(log_messages: &[String]) -> Vec<TradeEvent> {
+    log_messages
+        .iter()
+        .filter_map(|message| message.strip_prefix("Program data: "))
+        .filter_map(|encoded| general_purpose::STANDARD.decode(encoded.trim()).ok())
+        .filter_map(|data| TradeEventEvent::deserialize(&mut data.as_slice()).ok())
+        .map(|event| event.0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        borsh::BorshSerialize,
+        solana_program::pubkey::Pubkey,
+    };
+
+    fn trade(real_sol_reserves: u64, real_token_reserves: u64) -> TradeEvent {
+        let params = CurveParams::default();
+        TradeEvent {
+            mint: Pubkey::new_unique(),
+            sol_amount: 1,
+            token_amount: 1,
+            is_buy: true,
+            user: Pubkey::new_unique(),
+            timestamp: 0,
+            virtual_sol_reserves: params.initial_virtual_sol_reserves + real_sol_reserves,
+            virtual_token_reserves: params.virtual_token_offset().unwrap() + real_token_reserves,
+            real_sol_reserves,
+            real_token_reserves,
+            fee_recipient: Pubkey::new_unique(),
+            fee_basis_points: 95,
+            fee: 0,
+            creator: Pubkey::new_unique(),
+            creator_fee_basis_points: 5,
+            creator_fee: 0,
+            track_volume: false,
+            total_unclaimed_tokens: 0,
+            total_claimed_tokens: 0,
+            current_sol_volume: 0,
+            last_update_timestamp: 0,
+            ix_name: "buy".to_owned(),
+            mayhem_mode: false,
+            cashback_fee_basis_points: 0,
+            cashback: 0,
+        }
+    }
+
+    #[test]
+    fn new_curve_has_no_progress() {
+        let params = CurveParams::default();
+        let progress = params.progress(&params.curve_from_trade(&trade(0, 793_100_000_000_000)));
+        assert_eq!(progress.progress_bps, 0);
+        assert_eq!(progress.sol_raised, TokenAmount::lamports(0));
+        assert_eq!(progress.tokens_left, TokenAmount::new(793_100_000_000_000, 6));
+        assert_eq!(progress.tokens_left.ui_amount, Some(793_100_000.0));
+        assert!(!progress.complete);
+    }
+
+    #[test]
+    fn progress_is_the_share_of_real_tokens_sold() {
+        let params = CurveParams::default();
+        let curve = params.curve_from_trade(&trade(10_000_000_000, 793_100_000_000_000 / 4 * 3));
+
+        let progress = params.progress(&curve);
+        assert_eq!(progress.progress_bps, 2_500);
+        assert_eq!(progress.sol_raised.amount, 10_000_000_000);
+        assert!(!progress.complete);
+    }
+
+    #[test]
+    fn progress_rounds_down_to_whole_basis_points() {
+        let params = CurveParams {
+            initial_real_token_reserves: 3,
+            ..CurveParams::default()
+        };
+        let progress = params.progress(&params.curve_from_trade(&trade(0, 2)));
+        assert_eq!(progress.progress_bps, 3_333);
+    }
+
+    #[test]
+    fn sold_out_curve_is_complete() {
+        let params = CurveParams::default();
+        let curve = params.curve_from_trade(&trade(85_005_359_056, 0));
+        assert!(curve.complete);
+
+        let progress = params.progress(&curve);
+        assert_eq!(progress.progress_bps, COMPLETE_BPS);
+        assert_eq!(progress.tokens_left.amount, 0);
+        assert!(progress.complete);
+    }
+
+    #[test]
+    fn inconsistent_params_do_not_underflow() {
+        let params = CurveParams {
+            initial_virtual_token_reserves: 1,
+            initial_virtual_sol_reserves: 0,
+            initial_real_token_reserves: 100,
+        };
+        assert_eq!(params.virtual_token_offset(), None);
+
+        let curve = BondingCurve {
+            virtual_token_reserves: 0,
+            virtual_sol_reserves: 0,
+            real_token_reserves: 400,
+            real_sol_reserves: 0,
+            token_total_supply: 0,
+            complete: false,
+        };
+        assert_eq!(params.progress(&curve).progress_bps, 0);
+    }
+
+    #[test]
+    fn progress_uses_the_streamed_curve_params() {
+        let params = CurveParams {
+            initial_real_token_reserves: 100,
+            ..CurveParams::default()
+        };
+        let curve = BondingCurve {
+            virtual_token_reserves: 0,
+            virtual_sol_reserves: 0,
+            real_token_reserves: 40,
+            real_sol_reserves: 0,
+            token_total_supply: 0,
+            complete: false,
+        };
+        assert_eq!(params.progress(&curve).progress_bps, 6_000);
+    }
+
+    #[test]
+    fn decodes_trade_events_from_program_data_logs() {
+        let event = trade(10_000_000_000, 700_000_000_000_000);
+        let mut data = Vec::new();
+        TradeEventEvent(event.clone()).serialize(&mut data).unwrap();
+        let logs = [
+            "Program log: Instruction: Buy".to_string(),
+            format!("Program data: {}", general_purpose::STANDARD.encode(&data)),
+            format!("Program data: {}", general_purpose::STANDARD.encode([0u8; 16])),
+        ];
+        assert_eq!(trade_events(&logs), vec![event]);
+    }
+}
@@ -1,5 +1,3 @@
-use base64::engine::general_purpose;
-use base64::Engine;
 use serde::Serialize; 
 use pump_interface::events::{
     CreateEvent, CreateEventEvent, CREATE_EVENT_EVENT_DISCM,
@@ -13,6 +11,8 @@ use pump_interface::events::{
     SetMetaplexCreatorEvent, SetMetaplexCreatorEventEvent, SET_METAPLEX_CREATOR_EVENT_DISCM,
     UpdateGlobalAuthorityEvent, UpdateGlobalAuthorityEventEvent, UPDATE_GLOBAL_AUTHORITY_EVENT_DISCM,
     InitUserVolumeAccumulatorEvent,InitUserVolumeAccumulatorEventEvent,INIT_USER_VOLUME_ACCUMULATOR_EVENT_DISCM,
+    AdminUpdateTokenIncentivesEvent, AdminUpdateTokenIncentivesEventEvent, ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT_DISCM,
+    ClaimTokenIncentivesEvent, ClaimTokenIncentivesEventEvent, CLAIM_TOKEN_INCENTIVES_EVENT_DISCM,
  };
 
 #[derive(Debug, Clone, Serialize,PartialEq)]
@@ -28,6 +28,8 @@ pub enum DecodedEvent {
     SetMetaplexCreatorEvent(SetMetaplexCreatorEvent),
     UpdateGlobalAuthorityEvent(UpdateGlobalAuthorityEvent),
     InitUserVolumeAccumulatorEvent(InitUserVolumeAccumulatorEvent),
+    AdminUpdateTokenIncentivesEvent(AdminUpdateTokenIncentivesEvent),
+    ClaimTokenIncentivesEvent(ClaimTokenIncentivesEvent),
 }
 
 #[derive(Debug)]
@@ -35,19 +37,6 @@ pub struct AccountEventError {
     pub message: String,
 }
 
-pub fn convert_to_discm(base64_string: &str) -> Result<Vec<u8>, base64::DecodeError> {
-    general_purpose::STANDARD.decode(base64_string)
-}
-pub fn extract_all_log_messages(logs: &[String]) -> Vec<String> {
-    logs.iter()
-        .filter_map(|message| {
-            message
-                .strip_prefix("Program data: ")
-                .map(|s| s.trim().to_string())
-        })
-        .collect()
-}
-
 pub fn decode_event_data(buf: &[u8]) -> Result<DecodedEvent, AccountEventError> {
     if buf.len() < 8 {
         return Err(AccountEventError {
@@ -106,12 +95,6 @@ pub fn decode_event_data(buf: &[u8]) -> Result<DecodedEvent, AccountEventError>
         })?;
         Ok(DecodedEvent::SetCreatorEvent(data.0))
     }
-    INIT_USER_VOLUME_ACCUMULATOR_EVENT_DISCM => {
-        let data = InitUserVolumeAccumulatorEventEvent::deserialize(&mut &buf[..]).map_err(|e| AccountEventError {
-            message: format!("Failed to deserialize InitUserVolumeAccumulatorEvent: {}", e),
-        })?;
-        Ok(DecodedEvent::InitUserVolumeAccumulatorEvent(data.0))
-    }
     SET_METAPLEX_CREATOR_EVENT_DISCM => {
         let data = SetMetaplexCreatorEventEvent::deserialize(&mut &buf[..]).map_err(|e| AccountEventError {
             message: format!("Failed to deserialize SetMetaplexCreatorEvent: {}", e),
@@ -124,6 +107,24 @@ pub fn decode_event_data(buf: &[u8]) -> Result<DecodedEvent, AccountEventError>
         })?;
         Ok(DecodedEvent::UpdateGlobalAuthorityEvent(data.0))
     }
+    INIT_USER_VOLUME_ACCUMULATOR_EVENT_DISCM => {
+        let data = InitUserVolumeAccumulatorEventEvent::deserialize(&mut &buf[..]).map_err(|e| AccountEventError {
+            message: format!("Failed to deserialize InitUserVolumeAccumulatorEvent: {}", e),
+        })?;
+        Ok(DecodedEvent::InitUserVolumeAccumulatorEvent(data.0))
+    }
+    ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT_DISCM => {
+        let data = AdminUpdateTokenIncentivesEventEvent::deserialize(&mut &buf[..]).map_err(|e| AccountEventError {
+            message: format!("Failed to deserialize AdminUpdateTokenIncentivesEvent: {}", e),
+        })?;
+        Ok(DecodedEvent::AdminUpdateTokenIncentivesEvent(data.0))
+    }
+    CLAIM_TOKEN_INCENTIVES_EVENT_DISCM => {
+        let data = ClaimTokenIncentivesEventEvent::deserialize(&mut &buf[..]).map_err(|e| AccountEventError {
+            message: format!("Failed to deserialize ClaimTokenIncentivesEvent: {}", e),
+        })?;
+        Ok(DecodedEvent::ClaimTokenIncentivesEvent(data.0))
+    }
     _ => Err(AccountEventError {
         message: "Account discriminator not found.".to_string(),
     }),
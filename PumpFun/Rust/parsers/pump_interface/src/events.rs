@@ -9,15 +9,20 @@ pub struct CreateEvent {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub bonding_curve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
     pub timestamp: i64,
     pub virtual_token_reserves: u64,
     pub virtual_sol_reserves: u64,
     pub real_token_reserves: u64,
     pub token_total_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub token_program: Pubkey,
     pub is_mayhem_mode : bool,
     pub is_cashback_enabled: bool
@@ -49,19 +54,23 @@ impl CreateEventEvent {
 pub const TRADE_EVENT_EVENT_DISCM: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct TradeEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub mint: Pubkey,
     pub sol_amount: u64,
     pub token_amount: u64,
     pub is_buy: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub user: Pubkey,
     pub timestamp: i64,
     pub virtual_sol_reserves: u64,
     pub virtual_token_reserves: u64,
     pub real_sol_reserves: u64,
     pub real_token_reserves: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub fee_recipient: Pubkey,
     pub fee_basis_points: u64,
     pub fee: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
     pub creator_fee_basis_points: u64,
     pub creator_fee: u64,
@@ -102,8 +111,11 @@ impl TradeEventEvent {
 pub const COMPLETE_EVENT_EVENT_DISCM: [u8; 8] = [95, 114, 97, 156, 212, 46, 152, 8];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct CompleteEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub bonding_curve: Pubkey,
     pub timestamp: i64,
 }
@@ -134,6 +146,7 @@ impl CompleteEventEvent {
 pub const SET_PARAMS_EVENT_EVENT_DISCM: [u8; 8] = [223, 195, 159, 246, 62, 48, 143, 131];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct SetParamsEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub fee_recipient: Pubkey,
     pub initial_virtual_token_reserves: u64,
     pub initial_virtual_sol_reserves: u64,
@@ -141,12 +154,15 @@ pub struct SetParamsEvent {
     pub final_real_sol_reserves: u64,
     pub token_total_supply: u64,
     pub fee_basis_points: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub withdraw_authority: Pubkey,
     pub enable_migrate: bool,
     pub pool_migration_fee: u64,
     pub creator_fee_basis_points: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::array"))]
     pub fee_recipients: [Pubkey; 8],
     pub timestamp: i64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub set_creator_authority: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -176,7 +192,9 @@ impl SetParamsEventEvent {
 pub const INIT_USER_VOLUME_ACCUMULATOR_EVENT_DISCM: [u8; 8] = [134, 36, 13, 72, 232, 101, 130, 216];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct InitUserVolumeAccumulatorEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub payer: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub user: Pubkey,
     pub timestamp: i64,
 }
@@ -209,6 +227,7 @@ pub const COLLECT_CREATOR_FEE_EVENT_DISCM: [u8; 8] = [122, 2, 127, 1, 14, 191, 1
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct CollectCreatorFeeEvent {
     pub timestamp: i64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
     pub creator_fee: u64,
 }
@@ -239,13 +258,17 @@ impl CollectCreatorFeeEventEvent {
 pub const COMPLETE_PUMP_AMM_MIGRATION_EVENT_DISCM: [u8; 8] = [189, 233, 93, 185, 92, 148, 234, 148];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct CompletePumpAmmMigrationEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub mint: Pubkey,
     pub mint_amount: u64,
     pub sol_amount: u64,
     pub pool_migration_fee: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub bonding_curve: Pubkey,
     pub timestamp: i64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub pool: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -275,7 +298,9 @@ impl CompletePumpAmmMigrationEventEvent {
 pub const EXTEND_ACCOUNT_EVENT_DISCM: [u8; 8] = [97, 97, 215, 144, 93, 146, 22, 124];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct ExtendAccountEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub user: Pubkey,
     pub current_size: u64,
     pub new_size: u64,
@@ -309,8 +334,11 @@ pub const SET_CREATOR_EVENT_DISCM: [u8; 8] = [237, 52, 123, 37, 245, 251, 72, 21
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct SetCreatorEvent {
     pub timestamp: i64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub bonding_curve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -341,9 +369,13 @@ pub const SET_METAPLEX_CREATOR_EVENT_DISCM: [u8; 8] = [142, 203, 6, 32, 127, 105
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct SetMetaplexCreatorEvent {
     pub timestamp: i64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub bonding_curve: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub metadata: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -373,8 +405,11 @@ impl SetMetaplexCreatorEventEvent {
 pub const UPDATE_GLOBAL_AUTHORITY_EVENT_DISCM: [u8; 8] = [182, 195, 137, 42, 35, 206, 207, 247];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
 pub struct UpdateGlobalAuthorityEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub global: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub new_authority: Pubkey,
     pub timestamp: i64,
 }
@@ -400,4 +435,74 @@ impl UpdateGlobalAuthorityEventEvent {
         }
         Ok(Self(UpdateGlobalAuthorityEvent::deserialize(buf)?))
     }
-}
\ No newline at end of file
+}pub const ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT_DISCM: [u8; 8] = [147, 250, 108, 120, 247, 29, 67, 222];
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
+pub struct AdminUpdateTokenIncentivesEvent {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub day_number: u64,
+    pub token_supply_per_day: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub mint: Pubkey,
+    pub seconds_in_a_day: i64,
+    pub timestamp: i64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminUpdateTokenIncentivesEventEvent(pub AdminUpdateTokenIncentivesEvent);
+impl BorshSerialize for AdminUpdateTokenIncentivesEventEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl AdminUpdateTokenIncentivesEventEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AdminUpdateTokenIncentivesEvent::deserialize(buf)?))
+    }
+}
+
+pub const CLAIM_TOKEN_INCENTIVES_EVENT_DISCM: [u8; 8] = [79, 172, 246, 49, 205, 91, 206, 232];
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, serde::Serialize)]
+pub struct ClaimTokenIncentivesEvent {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub user: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClaimTokenIncentivesEventEvent(pub ClaimTokenIncentivesEvent);
+impl BorshSerialize for ClaimTokenIncentivesEventEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        CLAIM_TOKEN_INCENTIVES_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl ClaimTokenIncentivesEventEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != CLAIM_TOKEN_INCENTIVES_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CLAIM_TOKEN_INCENTIVES_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(ClaimTokenIncentivesEvent::deserialize(buf)?))
+    }
+}
@@ -303,6 +303,7 @@ pub const ADMIN_SET_CREATOR_IX_DISCM: [u8; 8] = [69, 25, 171, 142, 57, 239, 13,
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdminSetCreatorIxArgs {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -557,6 +558,7 @@ pub const ADMIN_SET_IDL_AUTHORITY_IX_DISCM: [u8; 8] = [8, 217, 96, 231, 144, 104
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdminSetIdlAuthorityIxArgs {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub idl_authority: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -1598,6 +1600,7 @@ pub const BUY_EXACT_SOL_IN_IX_DISCM: [u8; 8] = [56, 252, 116, 8, 158, 223, 205,
 pub struct BuyExactSolInIxArgs {
     pub spendable_sol_in: u64,
     pub min_tokens_out: u64,
+    pub track_volume: OptionBool,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct BuyExactSolInIxData(pub BuyExactSolInIxArgs);
@@ -2961,6 +2964,7 @@ pub struct CreateIxArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -3327,6 +3331,7 @@ pub struct CreateV2IxArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
     pub is_mayhem_mode: bool,
 }
@@ -5843,6 +5848,7 @@ pub const SET_CREATOR_IX_DISCM: [u8; 8] = [254, 148, 255, 112, 207, 142, 170, 16
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetCreatorIxArgs {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub creator: Pubkey,
 }
 
@@ -6601,10 +6607,12 @@ pub struct SetParamsIxArgs {
     pub initial_real_token_reserves: u64,
     pub token_total_supply: u64,
     pub fee_basis_points: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub withdraw_authority: Pubkey,
     pub enable_migrate: bool,
     pub pool_migration_fee: u64,
     pub creator_fee_basis_points: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub set_creator_authority: Pubkey,
 }
 
@@ -6840,6 +6848,7 @@ pub const SET_RESERVED_FEE_RECIPIENTS_IX_DISCM: [u8; 8] = [111, 172, 162, 232, 1
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetReservedFeeRecipientsIxArgs {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub whitelist_pda: Pubkey,
 }
 
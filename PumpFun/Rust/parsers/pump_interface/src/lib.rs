@@ -0,0 +1,59 @@
+solana_program::declare_id!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+/// The Anchor IDL of the Pump.fun program these bindings were generated from.
+pub const IDL: &str = include_str!("../idls/pump_0.1.0.json");
+pub mod accounts;
+pub use accounts::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
+pub use typedefs::*;
+pub mod typedefs;
+pub mod events;
+pub use events::*;
+/// Serializes pubkeys as base58 strings instead of byte arrays.
+#[cfg(feature = "serde")]
+pub mod serde_pubkey {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use solana_program::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Pubkey::from_str(&s).map_err(D::Error::custom)
+    }
+
+    pub mod array {
+        use serde::{de::Error, ser::SerializeSeq, Deserialize, Deserializer, Serializer};
+        use solana_program::pubkey::Pubkey;
+        use std::str::FromStr;
+
+        pub fn serialize<S: Serializer, const N: usize>(
+            pubkeys: &[Pubkey; N],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(N))?;
+            for pubkey in pubkeys {
+                seq.serialize_element(&pubkey.to_string())?;
+            }
+            seq.end()
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+            deserializer: D,
+        ) -> Result<[Pubkey; N], D::Error> {
+            let strings = Vec::<String>::deserialize(deserializer)?;
+            let pubkeys = strings
+                .iter()
+                .map(|s| Pubkey::from_str(s).map_err(D::Error::custom))
+                .collect::<Result<Vec<_>, _>>()?;
+            pubkeys
+                .try_into()
+                .map_err(|v: Vec<Pubkey>| D::Error::invalid_length(v.len(), &"a fixed number of pubkeys"))
+        }
+    }
+}
@@ -0,0 +1,11 @@
+solana_program::declare_id!("pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA");
+/// The Anchor IDL of the PumpSwap program these bindings were generated from.
+pub const IDL: &str = include_str!("../idls/pump_amm_0.1.0.json");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
+pub mod events;
+pub use events::*;
@@ -1,8 +0,0 @@
-mod core;
-mod decoder;
-mod builder;
-mod models;
-mod parser;
-pub mod types;
-
-pub use core::TransactionProcessor;
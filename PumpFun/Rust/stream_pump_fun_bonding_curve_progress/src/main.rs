@@ -11,6 +11,11 @@ use {
     serialization::{serialize_pubkey, serialize_option_pubkey},
     instruction_account_mapper::{AccountMetadata, Idl, InstructionAccountMapper},
      log::{error, info},
+    solana_defi_core::{
+        models::serialize::serialization::serialize_token_balances,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        EventSink,
+    },
       serde::Serialize, solana_account_decoder_client_types::token::UiTokenAmount, solana_sdk::{
         hash::Hash, instruction::{ AccountMeta,CompiledInstruction, Instruction}, message::{v0::{LoadedAddresses,
              Message, MessageAddressTableLookup},
@@ -26,7 +31,7 @@ use {
     pump_interface::instructions::PumpProgramIx,
 
     yellowstone_grpc_client::{GeyserGrpcClient, Interceptor}, yellowstone_grpc_proto::{
-        geyser::SubscribeRequestFilterTransactions,
+        geyser::{SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions},
         prelude::{
             subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest, SubscribeRequestPing,
         },
@@ -37,6 +42,7 @@ use crate::token_serializable::convert_to_serializable;
 use crate::event_account_mapper::AccountEventError;
 use crate::event_account_mapper::DecodedEvent;
 use crate::event_account_mapper::decode_event_data;
+use pump_curve::{BondingCurveProgress, CurveParams, GLOBAL_ACCOUNT};
 // use solana_account_decoder::parse_token::UiTokenAmount;
 
 use solana_transaction_status::Rewards;
@@ -44,6 +50,7 @@ use::solana_sdk::transaction::Result as TransactionResult;
 
 
 type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
+type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
 
 const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
@@ -57,6 +64,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -87,8 +101,20 @@ impl Args {
             },
         );
 
+        // The curve parameters only change when the program admin updates them.
+        let mut accounts: AccountFilterMap = HashMap::new();
+        accounts.insert(
+            "global".to_owned(),
+            SubscribeRequestFilterAccounts {
+                account: vec![GLOBAL_ACCOUNT.to_string()],
+                owner: vec![],
+                filters: vec![],
+                nonempty_txn_signature: None,
+            },
+        );
+
         Ok(SubscribeRequest {
-            accounts: HashMap::default(),
+            accounts,
             slots: HashMap::default(),
             transactions,
             transactions_status: HashMap::default(),
@@ -108,15 +134,13 @@ struct TransactionInstructionWithParent {
     instruction: Instruction,
     parent_program_id: Option<Pubkey>,
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 struct PumpFunSwapEvent {
     pub purchase_type: String,
     pub mint : String,
     pub signer: String,
     pub bonding_curve : String,
-    pub sol_pool_amount : String,
-    pub token_pool_amount: u64,
-    pub bonding_curve_progress : String
+    pub bonding_curve_progress : BondingCurveProgress,
 
 }
 
@@ -140,13 +164,13 @@ pub struct TransactionWithActions {
     pub actions: Vec<DecodedInstruction>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedTransaction {
     pub signatures: Vec<Signature>,
     pub message: ParsedMessage,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedMessage {
     pub header: MessageHeader,
     pub account_keys: Vec<Pubkey>,
@@ -155,7 +179,7 @@ pub struct ParsedMessage {
     pub address_table_lookups: Vec<MessageAddressTableLookup>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedTransactionStatusMeta {
     pub status: TransactionResult<()>,
     pub fee: u64,
@@ -163,7 +187,9 @@ pub struct ParsedTransactionStatusMeta {
     pub post_balances: Vec<u64>,
     pub inner_instructions: Vec<DecodedInstruction>, 
     pub log_messages: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub pre_token_balances: Option<Vec<TransactionTokenBalance>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub post_token_balances: Option<Vec<TransactionTokenBalance>>,
     pub rewards: Option<Rewards>,
     pub loaded_addresses: LoadedAddresses,
@@ -171,14 +197,14 @@ pub struct ParsedTransactionStatusMeta {
     pub compute_units_consumed: Option<u64>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedConfirmedTransaction {
     pub slot: u64,
     pub transaction: ParsedTransaction,
     pub meta: ParsedTransactionStatusMeta,
     pub block_time: Option<i64>,
 }
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedConfirmedTransactionWithStatusMeta {
     pub slot: u64,
     pub transaction: ParsedTransaction,
@@ -196,6 +222,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -203,6 +230,7 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -219,7 +247,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -253,10 +281,12 @@ fn get_instruction_name_with_typename(instruction: &TokenInstruction) -> String
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
     info!("stream opened");
+    let mut curve_params = CurveParams::default();
 
     while let Some(message) = stream.next().await {
         match message {
@@ -762,12 +792,23 @@ async fn geyser_subscribe(
                         },
                         block_time: confirmed_txn_with_meta.block_time,
                     };
-                        let pumpfun_txn = parsed_pump_txn(parsed_confirmed_txn_with_meta.clone());
+                        let pumpfun_txn = parsed_pump_txn(parsed_confirmed_txn_with_meta.clone(), &curve_params);
                         if let Some(pumpfun_txn) = pumpfun_txn {
-                         println!("Pump AMM:\n{:#?}", pumpfun_txn);
+                         sink.send(&pumpfun_txn)?;
                          }
                      }
                 }
+                Some(UpdateOneof::Account(update)) => {
+                    if let Some(account) = update.account {
+                        match CurveParams::from_global_account(&account.data) {
+                            Ok(params) => {
+                                info!("Curve parameters updated: {:?}", params);
+                                curve_params = params;
+                            }
+                            Err(e) => error!("Failed to decode Global account: {:?}", e),
+                        }
+                    }
+                }
                 Some(UpdateOneof::Ping(_)) => {
                     subscribe_tx
                         .send(SubscribeRequest {
@@ -999,6 +1040,7 @@ pub fn parse_transaction_accounts(
 }
 pub fn parsed_pump_txn(
     original: ParsedConfirmedTransactionWithStatusMeta,
+    curve_params: &CurveParams,
 ) -> Option<PumpFunSwapEvent> {
     let meta = &original.meta;
     let tx = &original.transaction;
@@ -1012,24 +1054,13 @@ pub fn parsed_pump_txn(
             instr.name.to_lowercase() == "sell" || instr.name.to_lowercase() == "buy"
         })?;
 
-    let sol_reserve = meta.inner_instructions.iter()
-                      .chain(tx.message.instructions.iter()).filter_map(|instr|{
+    let trade_event = meta.inner_instructions.iter()
+                      .chain(tx.message.instructions.iter()).find_map(|instr|{
                         match &instr.event {
-                            Some(DecodedEvent::TradeEvent(event)) => Some(event.real_sol_reserves),
+                            Some(DecodedEvent::TradeEvent(event)) => Some(event),
                             _ => None,
                         }
-                      })
-                      .next()
-                      .unwrap_or(0);
-    let token_reserve = meta.inner_instructions.iter()
-                        .chain(tx.message.instructions.iter()).filter_map(|instr|{
-                            match &instr.event {
-                              Some(DecodedEvent::TradeEvent(event)) => Some(event.real_token_reserves),
-                              _ => None,
-                            }
-                        }) 
-                        .next()
-                        .unwrap_or(0);                 
+                      })?;
     let signer_pubkey = amm_instruction
         .accounts
         .iter()
@@ -1047,23 +1078,15 @@ pub fn parsed_pump_txn(
         .iter()
         .find(|acc| acc.name == "bonding_curve")
         .map(|acc| acc.pubkey.to_string())?;
-    let formatted_sol_reserve = sol_reserve /1_000_000_000;
-    let curve_progress = calculate_curve_percentage(formatted_sol_reserve, 84); 
+    let curve = curve_params.curve_from_trade(trade_event);
 
     let output =  PumpFunSwapEvent {
         purchase_type: amm_instruction.name.clone(),
         mint : input_mint,
         signer: signer_pubkey?,
         bonding_curve : bonding_curve,
-        sol_pool_amount : formatted_sol_reserve.to_string() + " SOL",
-        token_pool_amount: token_reserve,
-        bonding_curve_progress : format!("{:.2}%", curve_progress)
+        bonding_curve_progress : curve_params.progress(&curve),
         };
 
     Some(output)
 }
-
-fn calculate_curve_percentage(current_value :u64, max_value : u64) -> f64 {
-    let percentage = 100;
-    (current_value as f64 / max_value as f64) * 100.0
-}
\ No newline at end of file
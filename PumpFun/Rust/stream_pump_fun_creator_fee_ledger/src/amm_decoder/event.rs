@@ -0,0 +1,53 @@
+use serde::Serialize;
+use pumpfun_amm_interface::events::{
+    BuyEvent, BuyEventEvent, BUY_EVENT_EVENT_DISCM,
+    SellEvent, SellEventEvent, SELL_EVENT_EVENT_DISCM,
+    CollectCoinCreatorFeeEvent, CollectCoinCreatorFeeEventEvent, COLLECT_COIN_CREATOR_FEE_EVENT_DISCM,
+};
+
+/// The PumpSwap events the ledger reads. PumpSwap emits many more, which
+/// are kept as `Other` so their transactions still decode.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub enum DecodedAmmEvent {
+    BuyEvent(BuyEvent),
+    SellEvent(SellEvent),
+    CollectCoinCreatorFeeEvent(CollectCoinCreatorFeeEvent),
+    Other { discriminator: [u8; 8] },
+}
+
+#[derive(Debug)]
+pub struct AmmEventError {
+    pub message: String,
+}
+
+pub fn decode_amm_event_data(buf: &[u8]) -> Result<DecodedAmmEvent, AmmEventError> {
+    if buf.len() < 8 {
+        return Err(AmmEventError {
+            message: "Buffer too short to contain a valid discriminator.".to_string(),
+        });
+    }
+
+    let discriminator: [u8; 8] = buf[..8].try_into().expect("Failed to extract first 8 bytes");
+
+    match discriminator {
+        BUY_EVENT_EVENT_DISCM => {
+            let data = BuyEventEvent::deserialize(&mut &buf[..]).map_err(|e| AmmEventError {
+                message: format!("Failed to deserialize BuyEvent: {}", e),
+            })?;
+            Ok(DecodedAmmEvent::BuyEvent(data.0))
+        }
+        SELL_EVENT_EVENT_DISCM => {
+            let data = SellEventEvent::deserialize(&mut &buf[..]).map_err(|e| AmmEventError {
+                message: format!("Failed to deserialize SellEvent: {}", e),
+            })?;
+            Ok(DecodedAmmEvent::SellEvent(data.0))
+        }
+        COLLECT_COIN_CREATOR_FEE_EVENT_DISCM => {
+            let data = CollectCoinCreatorFeeEventEvent::deserialize(&mut &buf[..]).map_err(|e| AmmEventError {
+                message: format!("Failed to deserialize CollectCoinCreatorFeeEvent: {}", e),
+            })?;
+            Ok(DecodedAmmEvent::CollectCoinCreatorFeeEvent(data.0))
+        }
+        _ => Ok(DecodedAmmEvent::Other { discriminator }),
+    }
+}
@@ -0,0 +1,53 @@
+pub mod event;
+
+use {
+    event::{decode_amm_event_data, DecodedAmmEvent},
+    pumpfun_amm_interface::instructions::PumpfunAmmProgramIx,
+    solana_defi_core::{
+        models::mapper::instruction::{AccountMetadata, Idl, InstructionAccountMapper},
+        ProgramDecoder,
+    },
+    solana_sdk::{instruction::AccountMeta, pubkey::Pubkey},
+    std::path::Path,
+};
+
+pub struct PumpAmmDecoder {
+    pub idl: Idl,
+}
+
+impl PumpAmmDecoder {
+    /// Uses the IDL embedded at build time unless `idl_override` points to another one.
+    pub fn new(idl_override: Option<&Path>) -> anyhow::Result<Self> {
+        Ok(Self {
+            idl: Idl::load(pumpfun_amm_interface::IDL, idl_override)?,
+        })
+    }
+}
+
+impl ProgramDecoder for PumpAmmDecoder {
+    type Event = DecodedAmmEvent;
+
+    fn program_id(&self) -> Pubkey {
+        pumpfun_amm_interface::ID
+    }
+
+    fn decode_instruction(&self, data: &[u8]) -> anyhow::Result<(String, serde_json::Value)> {
+        let decoded_ix = PumpfunAmmProgramIx::deserialize(data)?;
+        let data = serde_json::to_value(&decoded_ix)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize ix data: {:?}", e))?;
+
+        Ok((decoded_ix.name().to_string(), data))
+    }
+
+    fn decode_event(&self, data: &[u8]) -> anyhow::Result<DecodedAmmEvent> {
+        decode_amm_event_data(data).map_err(|e| anyhow::anyhow!(e.message))
+    }
+
+    fn map_accounts(
+        &self,
+        accounts: &[AccountMeta],
+        instruction_name: &str,
+    ) -> anyhow::Result<Vec<AccountMetadata>> {
+        Ok(self.idl.map_accounts(accounts, instruction_name)?)
+    }
+}
@@ -0,0 +1,308 @@
+use {
+    serde::Serialize,
+    serde_with::{serde_as, DisplayFromStr},
+    solana_sdk::pubkey::Pubkey,
+    std::collections::HashMap,
+};
+
+/// Pump.fun creator vault, where bonding curve creator fees are paid and
+/// from which `collect_creator_fee` claims them.
+pub fn creator_vault(creator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"creator-vault", creator.as_ref()], &pump_interface::ID).0
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Venue {
+    BondingCurve,
+    PumpSwap,
+}
+
+/// A creator fee movement seen in a transaction, in lamports.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(tag = "entry", rename_all = "snake_case")]
+pub enum FeeEntry {
+    /// Creator fee of a trade.
+    Accrued {
+        venue: Venue,
+        #[serde_as(as = "DisplayFromStr")]
+        creator: Pubkey,
+        #[serde_as(as = "DisplayFromStr")]
+        mint: Pubkey,
+        lamports: u64,
+    },
+    /// Fees withdrawn by the creator.
+    Claimed {
+        venue: Venue,
+        #[serde_as(as = "DisplayFromStr")]
+        creator: Pubkey,
+        lamports: u64,
+    },
+    /// PumpSwap fees moved into the Pump.fun creator vault by `transfer_creator_fees_to_pump`.
+    TransferredToVault {
+        #[serde_as(as = "DisplayFromStr")]
+        creator: Pubkey,
+        lamports: u64,
+    },
+}
+
+impl FeeEntry {
+    pub fn creator(&self) -> Pubkey {
+        match self {
+            FeeEntry::Accrued { creator, .. }
+            | FeeEntry::Claimed { creator, .. }
+            | FeeEntry::TransferredToVault { creator, .. } => *creator,
+        }
+    }
+
+    pub fn mint(&self) -> Option<Pubkey> {
+        match self {
+            FeeEntry::Accrued { mint, .. } => Some(*mint),
+            _ => None,
+        }
+    }
+}
+
+/// Fees of one creator since the ledger started. Claims can cover fees
+/// accrued before that, so unclaimed balances may go negative.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct CreatorFees {
+    pub curve_accrued: u64,
+    pub amm_accrued: u64,
+    pub curve_claimed: u64,
+    pub amm_claimed: u64,
+    pub amm_transferred: u64,
+    /// Vault lamports not explained by the ledger, set by the first vault check.
+    pub vault_baseline: Option<i64>,
+}
+
+impl CreatorFees {
+    /// Change of the Pump.fun creator vault balance.
+    pub fn vault_unclaimed(&self) -> i64 {
+        self.curve_accrued as i64 + self.amm_transferred as i64 - self.curve_claimed as i64
+    }
+
+    /// Change of the PumpSwap creator vault token account balance.
+    pub fn amm_unclaimed(&self) -> i64 {
+        self.amm_accrued as i64 - self.amm_claimed as i64 - self.amm_transferred as i64
+    }
+
+    pub fn unclaimed(&self) -> i64 {
+        self.vault_unclaimed() + self.amm_unclaimed()
+    }
+}
+
+/// Fees accrued by one mint. Claims are per creator, not per mint.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MintFees {
+    #[serde_as(as = "DisplayFromStr")]
+    pub creator: Pubkey,
+    pub curve_accrued: u64,
+    pub amm_accrued: u64,
+}
+
+/// Result of comparing a creator vault's lamports with the ledger.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct VaultCheck {
+    #[serde_as(as = "DisplayFromStr")]
+    pub creator: Pubkey,
+    #[serde_as(as = "DisplayFromStr")]
+    pub vault: Pubkey,
+    pub vault_lamports: u64,
+    pub expected_lamports: i64,
+    /// `vault_lamports - expected_lamports`; non-zero means a missed fee movement.
+    pub drift: i64,
+}
+
+#[derive(Default)]
+pub struct CreatorFeeLedger {
+    creators: HashMap<Pubkey, CreatorFees>,
+    mints: HashMap<Pubkey, MintFees>,
+}
+
+impl CreatorFeeLedger {
+    pub fn apply(&mut self, entry: FeeEntry) -> LedgerUpdate {
+        let fees = self.creators.entry(entry.creator()).or_default();
+        match entry {
+            FeeEntry::Accrued { venue, creator, mint, lamports } => {
+                let mint_fees = self.mints.entry(mint).or_insert(MintFees {
+                    creator,
+                    curve_accrued: 0,
+                    amm_accrued: 0,
+                });
+                // PumpSwap pools can have their coin creator changed.
+                mint_fees.creator = creator;
+                match venue {
+                    Venue::BondingCurve => {
+                        fees.curve_accrued += lamports;
+                        mint_fees.curve_accrued += lamports;
+                    }
+                    Venue::PumpSwap => {
+                        fees.amm_accrued += lamports;
+                        mint_fees.amm_accrued += lamports;
+                    }
+                }
+            }
+            FeeEntry::Claimed { venue: Venue::BondingCurve, lamports, .. } => fees.curve_claimed += lamports,
+            FeeEntry::Claimed { venue: Venue::PumpSwap, lamports, .. } => fees.amm_claimed += lamports,
+            FeeEntry::TransferredToVault { lamports, .. } => fees.amm_transferred += lamports,
+        }
+
+        LedgerUpdate {
+            creator_fees: fees.clone(),
+            mint_fees: entry.mint().and_then(|mint| self.mints.get(&mint)).cloned(),
+            entry,
+        }
+    }
+
+    pub fn creator(&self, creator: &Pubkey) -> Option<&CreatorFees> {
+        self.creators.get(creator)
+    }
+
+    pub fn mint(&self, mint: &Pubkey) -> Option<&MintFees> {
+        self.mints.get(mint)
+    }
+
+    /// Mints whose fees last accrued to `creator`.
+    pub fn mints_of<'a>(&'a self, creator: &'a Pubkey) -> impl Iterator<Item = (&'a Pubkey, &'a MintFees)> {
+        self.mints.iter().filter(move |(_, fees)| fees.creator == *creator)
+    }
+
+    /// Compares the creator vault balance with the ledger. The first check of
+    /// a creator records what the vault held before the ledger started.
+    pub fn check_vault(&mut self, creator: &Pubkey, vault_lamports: u64) -> Option<VaultCheck> {
+        let fees = self.creators.get_mut(creator)?;
+        let vault_unclaimed = fees.vault_unclaimed();
+        let baseline = *fees.vault_baseline.get_or_insert(vault_lamports as i64 - vault_unclaimed);
+        let expected_lamports = baseline + vault_unclaimed;
+
+        Some(VaultCheck {
+            creator: *creator,
+            vault: creator_vault(creator),
+            vault_lamports,
+            expected_lamports,
+            drift: vault_lamports as i64 - expected_lamports,
+        })
+    }
+}
+
+/// An entry with the totals it led to, emitted as the `creator_fee` record.
+#[derive(Debug, Clone, Serialize)]
+pub struct LedgerUpdate {
+    #[serde(flatten)]
+    pub entry: FeeEntry,
+    pub creator_fees: CreatorFees,
+    pub mint_fees: Option<MintFees>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENT: u64 = 890_880;
+
+    fn accrued(venue: Venue, creator: Pubkey, mint: Pubkey, lamports: u64) -> FeeEntry {
+        FeeEntry::Accrued { venue, creator, mint, lamports }
+    }
+
+    #[test]
+    fn tracks_accruals_claims_and_transfers_on_both_venues() {
+        let (creator, curve_mint, amm_mint) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut ledger = CreatorFeeLedger::default();
+
+        let update = ledger.apply(accrued(Venue::BondingCurve, creator, curve_mint, 1_000));
+        assert_eq!(update.mint_fees.unwrap().curve_accrued, 1_000);
+        ledger.apply(accrued(Venue::PumpSwap, creator, amm_mint, 400));
+        let update = ledger.apply(FeeEntry::Claimed {
+            venue: Venue::BondingCurve,
+            creator,
+            lamports: 300,
+        });
+        assert_eq!(update.mint_fees, None);
+        ledger.apply(FeeEntry::Claimed {
+            venue: Venue::PumpSwap,
+            creator,
+            lamports: 100,
+        });
+        let update = ledger.apply(FeeEntry::TransferredToVault { creator, lamports: 200 });
+
+        let fees = ledger.creator(&creator).unwrap();
+        assert_eq!(update.creator_fees, *fees);
+        assert_eq!(
+            *fees,
+            CreatorFees {
+                curve_accrued: 1_000,
+                amm_accrued: 400,
+                curve_claimed: 300,
+                amm_claimed: 100,
+                amm_transferred: 200,
+                vault_baseline: None,
+            }
+        );
+        assert_eq!(fees.vault_unclaimed(), 900);
+        assert_eq!(fees.amm_unclaimed(), 100);
+        assert_eq!(fees.unclaimed(), 1_000);
+
+        assert_eq!(ledger.mint(&curve_mint).unwrap().amm_accrued, 0);
+        assert_eq!(ledger.mint(&amm_mint).unwrap().amm_accrued, 400);
+        assert_eq!(ledger.mints_of(&creator).count(), 2);
+    }
+
+    #[test]
+    fn claims_of_fees_accrued_before_the_ledger_go_negative() {
+        let creator = Pubkey::new_unique();
+        let mut ledger = CreatorFeeLedger::default();
+        ledger.apply(FeeEntry::Claimed {
+            venue: Venue::PumpSwap,
+            creator,
+            lamports: 250,
+        });
+
+        assert_eq!(ledger.creator(&creator).unwrap().unclaimed(), -250);
+    }
+
+    #[test]
+    fn mints_follow_their_latest_coin_creator() {
+        let (old_creator, new_creator, mint) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut ledger = CreatorFeeLedger::default();
+        ledger.apply(accrued(Venue::PumpSwap, old_creator, mint, 100));
+        ledger.apply(accrued(Venue::PumpSwap, new_creator, mint, 50));
+
+        assert_eq!(ledger.mint(&mint).unwrap().creator, new_creator);
+        assert_eq!(ledger.mint(&mint).unwrap().amm_accrued, 150);
+        assert_eq!(ledger.mints_of(&old_creator).count(), 0);
+        assert_eq!(ledger.creator(&old_creator).unwrap().amm_accrued, 100);
+    }
+
+    #[test]
+    fn first_vault_check_sets_the_baseline_and_later_ones_report_drift() {
+        let creator = Pubkey::new_unique();
+        let mut ledger = CreatorFeeLedger::default();
+        assert_eq!(ledger.check_vault(&creator, RENT), None);
+
+        ledger.apply(accrued(Venue::BondingCurve, creator, Pubkey::new_unique(), 1_000));
+        let check = ledger.check_vault(&creator, RENT + 5_000 + 1_000).unwrap();
+        assert_eq!(check.vault, creator_vault(&creator));
+        assert_eq!(check.expected_lamports, (RENT + 6_000) as i64);
+        assert_eq!(check.drift, 0);
+        assert_eq!(ledger.creator(&creator).unwrap().vault_baseline, Some((RENT + 5_000) as i64));
+
+        ledger.apply(FeeEntry::Claimed {
+            venue: Venue::BondingCurve,
+            creator,
+            lamports: 6_000,
+        });
+        ledger.apply(FeeEntry::TransferredToVault { creator, lamports: 200 });
+        // PumpSwap fees only reach the vault once transferred.
+        ledger.apply(accrued(Venue::PumpSwap, creator, Pubkey::new_unique(), 700));
+        assert_eq!(ledger.check_vault(&creator, RENT + 200).unwrap().drift, 0);
+
+        // A missed bonding curve trade shows up as drift.
+        let check = ledger.check_vault(&creator, RENT + 200 + 30).unwrap();
+        assert_eq!(check.expected_lamports, (RENT + 200) as i64);
+        assert_eq!(check.drift, 30);
+    }
+}
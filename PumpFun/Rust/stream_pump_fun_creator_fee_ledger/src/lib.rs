@@ -0,0 +1,4 @@
+pub mod amm_decoder;
+pub mod ledger;
+pub mod parser;
+pub mod query;
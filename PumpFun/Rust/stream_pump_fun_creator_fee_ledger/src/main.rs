@@ -0,0 +1,373 @@
+use {
+    backoff::{future::retry, ExponentialBackoff},
+    clap::Parser as ClapParser,
+    futures::{future::TryFutureExt, sink::SinkExt, stream::StreamExt},
+    log::{error, info, warn},
+    pump_decoder::PumpDecoder,
+    pump_fun_creator_fee_ledger::{
+        amm_decoder::PumpAmmDecoder,
+        ledger::{creator_vault, CreatorFeeLedger, FeeEntry},
+        parser::{pump_amm_fee_entries, pump_fee_entries},
+        query,
+    },
+    solana_defi_core::{
+        processor::block_time::blocks_meta_filter,
+        schema::Record,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        types::ParsedConfirmedTransactionWithStatusMeta,
+        EventSink, TransactionProcessor,
+    },
+    solana_sdk::pubkey::Pubkey,
+    std::{
+        collections::{hash_map::Entry, HashMap, HashSet},
+        env,
+        path::Path,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+    tokio::sync::Mutex,
+    tonic::transport::channel::ClientTlsConfig,
+    yellowstone_grpc_client::{GeyserGrpcClient, Interceptor},
+    yellowstone_grpc_proto::{
+        geyser::{SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions},
+        prelude::{subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest, SubscribeRequestPing},
+    },
+};
+
+type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
+type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
+
+const PUMPFUN_PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+const PUMP_AMM_PROGRAM_ID: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
+/// New creators trade in almost every slot, so vaults are added to the subscription in batches.
+const RESUBSCRIBE_INTERVAL: Duration = Duration::from_secs(5);
+/// How often the processing error counts and dropped sink records are logged.
+const STATS_LOG_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, ClapParser)]
+#[clap(author, version, about)]
+struct Args {
+    #[clap(short, long, help = "gRPC endpoint")]
+    endpoint: String,
+
+    #[clap(long, help = "X-Token")]
+    x_token: String,
+
+    #[clap(long, default_value_t = 60, help = "Seconds between two vault checks of the same creator")]
+    check_interval: u64,
+
+    #[clap(
+        long,
+        default_value_t = 3600,
+        help = "Seconds a creator vault stays subscribed after its creator's last fee movement"
+    )]
+    vault_watch_window: u64,
+
+    #[clap(long, help = "Unix socket answering creator, mint and mints_of queries")]
+    query_socket: Option<String>,
+
+    #[clap(long, help = "Directory to dump raw updates that fail to process")]
+    dump_failed_updates: Option<String>,
+
+    #[clap(long, help = "Pump IDL to use instead of the embedded one")]
+    idl: Option<String>,
+
+    #[clap(long, help = "PumpSwap IDL to use instead of the embedded one")]
+    amm_idl: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
+}
+
+impl Args {
+    async fn connect(&self) -> anyhow::Result<GeyserGrpcClient<impl Interceptor>> {
+        GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
+            .x_token(Some(self.x_token.clone()))?
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(10))
+            .tls_config(ClientTlsConfig::new().with_native_roots())?
+            .max_decoding_message_size(1024 * 1024 * 1024)
+            .connect()
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Pump.fun and PumpSwap transactions, and the creator vaults being watched.
+    pub fn get_updates(&self, vaults: &[Pubkey]) -> SubscribeRequest {
+        let mut transactions: TxnFilterMap = HashMap::new();
+
+        transactions.insert(
+            "creatorFees".to_owned(),
+            SubscribeRequestFilterTransactions {
+                vote: Some(false),
+                failed: Some(false),
+                account_include: vec![PUMPFUN_PROGRAM_ID.to_string(), PUMP_AMM_PROGRAM_ID.to_string()],
+                account_exclude: vec![],
+                account_required: vec![],
+                signature: None,
+            },
+        );
+
+        let mut accounts: AccountFilterMap = HashMap::new();
+        // An empty `account` list would match every account.
+        if !vaults.is_empty() {
+            accounts.insert(
+                "creatorVaults".to_owned(),
+                SubscribeRequestFilterAccounts {
+                    account: vaults.iter().map(|vault| vault.to_string()).collect(),
+                    owner: vec![],
+                    filters: vec![],
+                    nonempty_txn_signature: None,
+                },
+            );
+        }
+
+        SubscribeRequest {
+            accounts,
+            slots: HashMap::default(),
+            transactions,
+            transactions_status: HashMap::default(),
+            blocks: HashMap::default(),
+            blocks_meta: blocks_meta_filter(),
+            entry: HashMap::default(),
+            commitment: Some(CommitmentLevel::Processed as i32),
+            accounts_data_slice: Vec::default(),
+            ping: None,
+            from_slot: None,
+        }
+    }
+}
+
+/// One processor per program; both see every update of the subscription.
+struct Processors {
+    pump: TransactionProcessor<PumpDecoder>,
+    pump_amm: TransactionProcessor<PumpAmmDecoder>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env::set_var(
+        env_logger::DEFAULT_FILTER_ENV,
+        env::var_os(env_logger::DEFAULT_FILTER_ENV).unwrap_or_else(|| "info".into()),
+    );
+    env_logger::init();
+
+    let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
+    // Kept across reconnects so totals are not reset.
+    let ledger = Arc::new(Mutex::new(CreatorFeeLedger::default()));
+    if let Some(path) = &args.query_socket {
+        query::serve(path, Arc::clone(&ledger))?;
+        info!("Answering ledger queries on {path}");
+    }
+    let mut pump = TransactionProcessor::new(PumpDecoder::new(args.idl.as_deref().map(Path::new))?)?;
+    let mut pump_amm =
+        TransactionProcessor::new(PumpAmmDecoder::new(args.amm_idl.as_deref().map(Path::new))?)?;
+    if let Some(dir) = &args.dump_failed_updates {
+        pump = pump.dump_failed_updates(dir);
+        pump_amm = pump_amm.dump_failed_updates(dir);
+    }
+    // Kept across reconnects so transactions waiting for their block meta survive them.
+    let processors = Arc::new(Mutex::new(Processors { pump, pump_amm }));
+    let zero_attempts = Arc::new(Mutex::new(true));
+
+    retry(ExponentialBackoff::default(), move || {
+        let args = args.clone();
+        let sink = Arc::clone(&sink);
+        let ledger = Arc::clone(&ledger);
+        let processors = Arc::clone(&processors);
+        let zero_attempts = Arc::clone(&zero_attempts);
+
+        async move {
+            let mut zero_attempts = zero_attempts.lock().await;
+            if *zero_attempts {
+                *zero_attempts = false;
+            } else {
+                info!("Retry to connect to the server");
+            }
+            drop(zero_attempts);
+
+            let client = args.connect().await.map_err(backoff::Error::transient)?;
+            info!("Connected");
+
+            let mut processors = processors.lock().await;
+            geyser_subscribe(client, &args, &sink, &ledger, &mut processors)
+                .await
+                .map_err(backoff::Error::transient)?;
+
+            Ok::<(), backoff::Error<anyhow::Error>>(())
+        }
+        .inspect_err(|error| error!("failed to connect: {error}"))
+    })
+    .await
+    .map_err(Into::into)
+}
+
+async fn geyser_subscribe(
+    mut client: GeyserGrpcClient<impl Interceptor>,
+    args: &Args,
+    sink: &Arc<dyn EventSink>,
+    ledger: &Mutex<CreatorFeeLedger>,
+    processors: &mut Processors,
+) -> anyhow::Result<()> {
+    let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(args.get_updates(&[]))).await?;
+    info!("stream opened");
+
+    let Processors { pump, pump_amm } = processors;
+
+    let check_interval = Duration::from_secs(args.check_interval);
+    let watch_window = Duration::from_secs(args.vault_watch_window);
+    // Vault -> its creator and the creator's last fee movement.
+    let mut vaults: HashMap<Pubkey, (Pubkey, Instant)> = HashMap::new();
+    let mut vaults_added = false;
+    let mut last_resubscribe = Instant::now();
+    // Latest slot and lamports of each vault, checked once the slot's block meta arrives.
+    let mut pending_checks: HashMap<Pubkey, (u64, u64)> = HashMap::new();
+    let mut last_checked: HashMap<Pubkey, Instant> = HashMap::new();
+    let mut last_stats = Instant::now();
+    let mut last_dropped = 0;
+
+    while let Some(message) = stream.next().await {
+        let mut touched = HashSet::new();
+
+        match message {
+            Ok(msg) => match msg.update_oneof {
+                Some(UpdateOneof::Transaction(update)) => {
+                    let mut ledger = ledger.lock().await;
+                    match pump.process_transaction_update(update.clone()) {
+                        Ok(ready) => {
+                            for txn in ready {
+                                apply(&mut ledger, sink, &txn, pump_fee_entries(&txn), &mut touched)?;
+                            }
+                        }
+                        Err(e) => error!("Failed to process Pump.fun txn (skipping) [{}]: {e}", e.kind()),
+                    }
+                    match pump_amm.process_transaction_update(update) {
+                        Ok(ready) => {
+                            for txn in ready {
+                                apply(&mut ledger, sink, &txn, pump_amm_fee_entries(&txn), &mut touched)?;
+                            }
+                        }
+                        Err(e) => error!("Failed to process PumpSwap txn (skipping) [{}]: {e}", e.kind()),
+                    }
+                }
+                Some(UpdateOneof::BlockMeta(update)) => {
+                    let slot = update.slot;
+                    let mut ledger = ledger.lock().await;
+                    for txn in pump.process_block_meta_update(update.clone()) {
+                        apply(&mut ledger, sink, &txn, pump_fee_entries(&txn), &mut touched)?;
+                    }
+                    for txn in pump_amm.process_block_meta_update(update) {
+                        apply(&mut ledger, sink, &txn, pump_amm_fee_entries(&txn), &mut touched)?;
+                    }
+
+                    // Every transaction of `slot` is in the ledger by now.
+                    let ready: Vec<Pubkey> = pending_checks
+                        .iter()
+                        .filter(|(_, (vault_slot, _))| *vault_slot <= slot)
+                        .map(|(vault, _)| *vault)
+                        .collect();
+                    for vault in ready {
+                        let Some((vault_slot, lamports)) = pending_checks.remove(&vault) else {
+                            continue;
+                        };
+                        let Some((creator, _)) = vaults.get(&vault) else {
+                            continue;
+                        };
+                        if last_checked.get(&vault).is_some_and(|at| at.elapsed() < check_interval) {
+                            continue;
+                        }
+                        last_checked.insert(vault, Instant::now());
+
+                        if let Some(check) = ledger.check_vault(creator, lamports) {
+                            if check.drift != 0 {
+                                warn!("Creator vault {vault} is off by {} lamports", check.drift);
+                            }
+                            sink.send(&Record::account_update("creator_vault_check", vault_slot, check))?;
+                        }
+                    }
+                }
+                Some(UpdateOneof::Account(update)) => {
+                    if let Some(account) = update.account {
+                        let vault = Pubkey::try_from(account.pubkey.as_slice()).ok();
+                        if let Some(vault) = vault.filter(|vault| vaults.contains_key(vault)) {
+                            pending_checks.insert(vault, (update.slot, account.lamports));
+                        }
+                    }
+                }
+                Some(UpdateOneof::Ping(_)) => {
+                    subscribe_tx
+                        .send(SubscribeRequest {
+                            ping: Some(SubscribeRequestPing { id: 1 }),
+                            ..Default::default()
+                        })
+                        .await?;
+                }
+                None => {
+                    error!("update not found in the message");
+                    break;
+                }
+                _ => {}
+            },
+            Err(error) => {
+                error!("error: {error:?}");
+                break;
+            }
+        }
+
+        for creator in touched {
+            match vaults.entry(creator_vault(&creator)) {
+                Entry::Occupied(mut watched) => watched.get_mut().1 = Instant::now(),
+                Entry::Vacant(vacant) => {
+                    vacant.insert((creator, Instant::now()));
+                    vaults_added = true;
+                }
+            }
+        }
+
+        if vaults_added && last_resubscribe.elapsed() >= RESUBSCRIBE_INTERVAL {
+            vaults.retain(|_, (_, last_fee)| last_fee.elapsed() < watch_window);
+            last_checked.retain(|vault, _| vaults.contains_key(vault));
+            let watched: Vec<Pubkey> = vaults.keys().copied().collect();
+            subscribe_tx.send(args.get_updates(&watched)).await?;
+            vaults_added = false;
+            last_resubscribe = Instant::now();
+        }
+
+        if last_stats.elapsed() >= STATS_LOG_INTERVAL {
+            if !pump.error_counts().is_empty() {
+                info!("Pump.fun processing errors so far: {:?}", pump.error_counts());
+            }
+            if !pump_amm.error_counts().is_empty() {
+                info!("PumpSwap processing errors so far: {:?}", pump_amm.error_counts());
+            }
+            let dropped = sink.dropped();
+            if dropped != last_dropped {
+                warn!("Sink dropped {} records so far", dropped);
+                last_dropped = dropped;
+            }
+            last_stats = Instant::now();
+        }
+    }
+
+    info!("stream closed");
+    Ok(())
+}
+
+fn apply<E>(
+    ledger: &mut CreatorFeeLedger,
+    sink: &Arc<dyn EventSink>,
+    txn: &ParsedConfirmedTransactionWithStatusMeta<E>,
+    entries: Vec<FeeEntry>,
+    touched: &mut HashSet<Pubkey>,
+) -> anyhow::Result<()> {
+    for entry in entries {
+        touched.insert(entry.creator());
+        sink.send(&Record::new("creator_fee", txn, ledger.apply(entry)))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,364 @@
+use {
+    crate::{
+        amm_decoder::event::DecodedAmmEvent,
+        ledger::{FeeEntry, Venue},
+    },
+    pump_decoder::event::DecodedEvent,
+    solana_defi_core::types::{DecodedInstruction, ParsedConfirmedTransactionWithStatusMeta},
+    solana_sdk::pubkey::Pubkey,
+    solana_transaction_status::TransactionTokenBalance,
+};
+
+/// Creator fees accrued and claimed on the bonding curve.
+pub fn pump_fee_entries(original: &ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>) -> Vec<FeeEntry> {
+    let meta = &original.meta;
+    let tx = &original.transaction;
+
+    tx.message
+        .instructions
+        .iter()
+        .chain(meta.inner_instructions.iter())
+        .flat_map(|instr| &instr.events)
+        .filter_map(|event| match event {
+            DecodedEvent::TradeEvent(event) if event.creator_fee > 0 => Some(FeeEntry::Accrued {
+                venue: Venue::BondingCurve,
+                creator: event.creator,
+                mint: event.mint,
+                lamports: event.creator_fee,
+            }),
+            DecodedEvent::CollectCreatorFeeEvent(event) if event.creator_fee > 0 => Some(FeeEntry::Claimed {
+                venue: Venue::BondingCurve,
+                creator: event.creator,
+                lamports: event.creator_fee,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Creator fees accrued, claimed and moved to the Pump.fun vault on PumpSwap.
+/// Only WSOL-quoted pools are counted, so token amounts are lamports; fees in
+/// any other quote mint would not add up with the bonding curve's.
+pub fn pump_amm_fee_entries(
+    original: &ParsedConfirmedTransactionWithStatusMeta<DecodedAmmEvent>,
+) -> Vec<FeeEntry> {
+    let meta = &original.meta;
+    let tx = &original.transaction;
+    let mut entries = Vec::new();
+
+    for instr in tx.message.instructions.iter().chain(meta.inner_instructions.iter()) {
+        if instr.name == "TransferCreatorFeesToPump" {
+            let transferred = account(instr, "coin_creator").zip(account(instr, "coin_creator_vault_ata"));
+            if let Some((creator, vault_ata)) = transferred {
+                let lamports = token_balance_decrease(original, &vault_ata);
+                if lamports > 0 {
+                    entries.push(FeeEntry::TransferredToVault { creator, lamports });
+                }
+            }
+        }
+
+        if account(instr, "quote_mint") != Some(spl_token::native_mint::ID) {
+            continue;
+        }
+
+        for event in &instr.events {
+            match event {
+                DecodedAmmEvent::BuyEvent(event) if event.coin_creator_fee > 0 => {
+                    if let Some(mint) = account(instr, "base_mint") {
+                        entries.push(FeeEntry::Accrued {
+                            venue: Venue::PumpSwap,
+                            creator: event.coin_creator,
+                            mint,
+                            lamports: event.coin_creator_fee,
+                        });
+                    }
+                }
+                DecodedAmmEvent::SellEvent(event) if event.coin_creator_fee > 0 => {
+                    if let Some(mint) = account(instr, "base_mint") {
+                        entries.push(FeeEntry::Accrued {
+                            venue: Venue::PumpSwap,
+                            creator: event.coin_creator,
+                            mint,
+                            lamports: event.coin_creator_fee,
+                        });
+                    }
+                }
+                DecodedAmmEvent::CollectCoinCreatorFeeEvent(event) if event.coin_creator_fee > 0 => {
+                    entries.push(FeeEntry::Claimed {
+                        venue: Venue::PumpSwap,
+                        creator: event.coin_creator,
+                        lamports: event.coin_creator_fee,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    entries
+}
+
+fn account<E>(instr: &DecodedInstruction<E>, name: &str) -> Option<Pubkey> {
+    instr
+        .accounts
+        .iter()
+        .find(|account| account.name == name)
+        .map(|account| account.pubkey)
+}
+
+fn token_balance_decrease<E>(
+    original: &ParsedConfirmedTransactionWithStatusMeta<E>,
+    token_account: &Pubkey,
+) -> u64 {
+    let meta = &original.meta;
+    let index = original
+        .transaction
+        .message
+        .account_keys
+        .iter()
+        .chain(&meta.loaded_addresses.writable)
+        .chain(&meta.loaded_addresses.readonly)
+        .position(|key| key == token_account);
+    let Some(index) = index else {
+        return 0;
+    };
+
+    token_amount(meta.pre_token_balances.as_deref(), index)
+        .saturating_sub(token_amount(meta.post_token_balances.as_deref(), index))
+}
+
+fn token_amount(balances: Option<&[TransactionTokenBalance]>, account_index: usize) -> u64 {
+    balances
+        .unwrap_or_default()
+        .iter()
+        .find(|balance| balance.account_index as usize == account_index)
+        .and_then(|balance| balance.ui_token_amount.amount.parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        pumpfun_amm_interface::events::{CollectCoinCreatorFeeEvent, SellEvent},
+        solana_account_decoder_client_types::token::UiTokenAmount,
+        solana_defi_core::{
+            models::mapper::instruction::AccountMetadata,
+            types::{InstructionPosition, ParsedMessage, ParsedTransaction, ParsedTransactionStatusMeta},
+        },
+        solana_sdk::{
+            hash::Hash,
+            message::{v0::LoadedAddresses, MessageHeader},
+            signature::Signature,
+        },
+    };
+
+    const USDC: Pubkey = solana_sdk::pubkey!("EPjFWdd5AufqSSqeM2qJxdbfBwvrTATzCGxrUaq9EGtS");
+
+    fn instruction(
+        name: &str,
+        accounts: &[(&str, Pubkey)],
+        events: Vec<DecodedAmmEvent>,
+    ) -> DecodedInstruction<DecodedAmmEvent> {
+        DecodedInstruction {
+            name: name.to_string(),
+            accounts: accounts
+                .iter()
+                .map(|(name, pubkey)| AccountMetadata {
+                    pubkey: *pubkey,
+                    is_writable: true,
+                    is_signer: false,
+                    declared_writable: Some(true),
+                    declared_signer: Some(false),
+                    name: name.to_string(),
+                })
+                .collect(),
+            data: serde_json::Value::Null,
+            events,
+            position: InstructionPosition {
+                outer_index: 0,
+                inner_index: None,
+            },
+            program_id: pumpfun_amm_interface::ID,
+            parent_program_id: None,
+            error: None,
+        }
+    }
+
+    fn transaction(
+        account_keys: Vec<Pubkey>,
+        loaded_addresses: LoadedAddresses,
+        instructions: Vec<DecodedInstruction<DecodedAmmEvent>>,
+    ) -> ParsedConfirmedTransactionWithStatusMeta<DecodedAmmEvent> {
+        ParsedConfirmedTransactionWithStatusMeta {
+            slot: 1,
+            transaction: ParsedTransaction {
+                signatures: vec![Signature::default()],
+                message: ParsedMessage {
+                    versioned: true,
+                    header: MessageHeader::default(),
+                    account_keys,
+                    recent_blockhash: Hash::default(),
+                    instructions,
+                    address_table_lookups: vec![],
+                },
+            },
+            meta: ParsedTransactionStatusMeta {
+                status: Ok(()),
+                fee: 5_000,
+                pre_balances: vec![],
+                post_balances: vec![],
+                inner_instructions: vec![],
+                log_messages: None,
+                pre_token_balances: None,
+                post_token_balances: None,
+                rewards: None,
+                loaded_addresses,
+                return_data: None,
+                compute_units_consumed: None,
+            },
+            block_time: None,
+            received_at: 0,
+        }
+    }
+
+    fn token_balance(account_index: u8, amount: u64) -> TransactionTokenBalance {
+        TransactionTokenBalance {
+            account_index,
+            mint: spl_token::native_mint::ID.to_string(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: None,
+                decimals: 9,
+                amount: amount.to_string(),
+                ui_amount_string: String::new(),
+            },
+            owner: String::new(),
+            program_id: spl_token::ID.to_string(),
+        }
+    }
+
+    fn sell_event(coin_creator: Pubkey, coin_creator_fee: u64) -> DecodedAmmEvent {
+        DecodedAmmEvent::SellEvent(SellEvent {
+            timestamp: 0,
+            base_amount_in: 1_000_000,
+            min_quote_amount_out: 0,
+            user_base_token_reserves: 0,
+            user_quote_token_reserves: 0,
+            pool_base_token_reserves: 0,
+            pool_quote_token_reserves: 0,
+            quote_amount_out: 0,
+            lp_fee_basis_points: 20,
+            lp_fee: 0,
+            protocol_fee_basis_points: 5,
+            protocol_fee: 0,
+            quote_amount_out_without_lp_fee: 0,
+            user_quote_amount_out: 0,
+            pool: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            user_base_token_account: Pubkey::new_unique(),
+            user_quote_token_account: Pubkey::new_unique(),
+            protocol_fee_recipient: Pubkey::new_unique(),
+            protocol_fee_recipient_token_account: Pubkey::new_unique(),
+            coin_creator,
+            coin_creator_fee_basis_points: 5,
+            coin_creator_fee,
+            cashback_fee_basis_points: 0,
+            cashback: 0,
+        })
+    }
+
+    fn collect_event(coin_creator: Pubkey, coin_creator_fee: u64) -> DecodedAmmEvent {
+        DecodedAmmEvent::CollectCoinCreatorFeeEvent(CollectCoinCreatorFeeEvent {
+            timestamp: 0,
+            coin_creator,
+            coin_creator_fee,
+            coin_creator_vault_ata: Pubkey::new_unique(),
+            coin_creator_token_account: Pubkey::new_unique(),
+        })
+    }
+
+    #[test]
+    fn only_counts_wsol_quoted_pools() {
+        let (creator, wsol_mint, usdc_mint) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let sell = |base_mint: Pubkey, quote_mint: Pubkey, fee: u64| {
+            instruction(
+                "Sell",
+                &[("base_mint", base_mint), ("quote_mint", quote_mint)],
+                vec![sell_event(creator, fee)],
+            )
+        };
+        let collect = |quote_mint: Pubkey, fee: u64| {
+            instruction("CollectCoinCreatorFee", &[("quote_mint", quote_mint)], vec![collect_event(creator, fee)])
+        };
+        let txn = transaction(
+            vec![],
+            LoadedAddresses::default(),
+            vec![
+                sell(wsol_mint, spl_token::native_mint::ID, 1_500),
+                sell(usdc_mint, USDC, 9_000),
+                sell(wsol_mint, spl_token::native_mint::ID, 0),
+                collect(USDC, 7_000),
+                collect(spl_token::native_mint::ID, 2_000),
+            ],
+        );
+
+        assert_eq!(
+            pump_amm_fee_entries(&txn),
+            vec![
+                FeeEntry::Accrued {
+                    venue: Venue::PumpSwap,
+                    creator,
+                    mint: wsol_mint,
+                    lamports: 1_500,
+                },
+                FeeEntry::Claimed {
+                    venue: Venue::PumpSwap,
+                    creator,
+                    lamports: 2_000,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_transfers_from_a_vault_ata_loaded_from_a_lookup_table() {
+        let (creator, vault_ata) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let account_keys = vec![Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let loaded_addresses = LoadedAddresses {
+            writable: vec![Pubkey::new_unique(), vault_ata],
+            readonly: vec![Pubkey::new_unique()],
+        };
+        let transfer = instruction(
+            "TransferCreatorFeesToPump",
+            &[("coin_creator", creator), ("coin_creator_vault_ata", vault_ata)],
+            vec![],
+        );
+        let mut txn = transaction(account_keys, loaded_addresses, vec![transfer]);
+        // The vault ATA comes after the three static keys and the first writable loaded address.
+        txn.meta.pre_token_balances = Some(vec![token_balance(1, 10), token_balance(4, 12_345)]);
+        txn.meta.post_token_balances = Some(vec![token_balance(1, 10), token_balance(4, 0)]);
+
+        assert_eq!(token_balance_decrease(&txn, &vault_ata), 12_345);
+        assert_eq!(token_balance_decrease(&txn, &Pubkey::new_unique()), 0);
+        assert_eq!(
+            pump_amm_fee_entries(&txn),
+            vec![FeeEntry::TransferredToVault {
+                creator,
+                lamports: 12_345,
+            }]
+        );
+    }
+
+    #[test]
+    fn empty_vault_transfers_are_skipped() {
+        let (creator, vault_ata) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let transfer = instruction(
+            "TransferCreatorFeesToPump",
+            &[("coin_creator", creator), ("coin_creator_vault_ata", vault_ata)],
+            vec![],
+        );
+        let txn = transaction(vec![vault_ata], LoadedAddresses::default(), vec![transfer]);
+
+        assert!(pump_amm_fee_entries(&txn).is_empty());
+    }
+}
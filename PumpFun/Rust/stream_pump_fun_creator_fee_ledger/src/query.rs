@@ -0,0 +1,125 @@
+use {
+    crate::ledger::CreatorFeeLedger,
+    log::{error, info},
+    serde_json::{json, Value},
+    solana_sdk::pubkey::Pubkey,
+    std::{path::Path, str::FromStr, sync::Arc},
+    tokio::{
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+        net::UnixListener,
+        sync::Mutex,
+    },
+};
+
+/// Answers one query line against the ledger:
+/// `creator <pubkey>`, `mint <pubkey>` or `mints_of <creator>`.
+pub fn answer(ledger: &CreatorFeeLedger, query: &str) -> Value {
+    let (command, key) = query.trim().split_once(' ').unwrap_or((query.trim(), ""));
+    let key = match Pubkey::from_str(key.trim()) {
+        Ok(key) => key,
+        Err(e) => return json!({ "error": format!("Invalid pubkey `{}`: {e}", key.trim()) }),
+    };
+
+    match command {
+        "creator" => json!(ledger.creator(&key)),
+        "mint" => json!(ledger.mint(&key)),
+        "mints_of" => ledger
+            .mints_of(&key)
+            .map(|(mint, fees)| (mint.to_string(), json!(fees)))
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+        _ => json!({ "error": format!("Unknown query `{command}`, expected creator, mint or mints_of") }),
+    }
+}
+
+/// Listens on a Unix domain socket and answers each query line with one JSON line.
+pub fn serve(path: impl AsRef<Path>, ledger: Arc<Mutex<CreatorFeeLedger>>) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    // A socket file left behind by a previous run would make bind fail.
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = match listener.accept().await {
+                Ok(client) => client,
+                Err(e) => {
+                    error!("Query socket stopped accepting: {e}");
+                    break;
+                }
+            };
+            info!("Query socket client connected");
+            let ledger = Arc::clone(&ledger);
+            tokio::spawn(async move {
+                let (reader, mut writer) = stream.into_split();
+                let mut lines = BufReader::new(reader).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let reply = answer(&*ledger.lock().await, &line).to_string();
+                    if writer.write_all(reply.as_bytes()).await.is_err() || writer.write_all(b"\n").await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::ledger::{FeeEntry, Venue},
+    };
+
+    fn ledger(creator: Pubkey, mint: Pubkey) -> CreatorFeeLedger {
+        let mut ledger = CreatorFeeLedger::default();
+        ledger.apply(FeeEntry::Accrued {
+            venue: Venue::BondingCurve,
+            creator,
+            mint,
+            lamports: 1_500,
+        });
+        ledger.apply(FeeEntry::Claimed {
+            venue: Venue::BondingCurve,
+            creator,
+            lamports: 500,
+        });
+        ledger
+    }
+
+    #[test]
+    fn answers_creator_mint_and_mints_of() {
+        let (creator, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let ledger = ledger(creator, mint);
+
+        let fees = answer(&ledger, &format!("creator {creator}"));
+        assert_eq!(fees["curve_accrued"], 1_500);
+        assert_eq!(fees["curve_claimed"], 500);
+
+        let fees = answer(&ledger, &format!("mint {mint}\n"));
+        assert_eq!(fees["creator"], creator.to_string());
+
+        let mints = answer(&ledger, &format!("mints_of {creator}"));
+        assert_eq!(mints[mint.to_string()]["curve_accrued"], 1_500);
+        assert_eq!(answer(&ledger, &format!("mints_of {mint}")), json!({}));
+    }
+
+    #[test]
+    fn unknown_keys_answer_null() {
+        let ledger = ledger(Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(answer(&ledger, &format!("creator {}", Pubkey::new_unique())), Value::Null);
+    }
+
+    #[test]
+    fn bad_queries_answer_an_error() {
+        let ledger = CreatorFeeLedger::default();
+        assert!(answer(&ledger, "creator nope")["error"].is_string());
+        assert!(answer(&ledger, &format!("balance {}", Pubkey::new_unique()))["error"].is_string());
+    }
+}
@@ -0,0 +1,25 @@
+use {
+    pump_decoder::event::DecodedEvent,
+    solana_defi_core::{
+        schema::Record,
+        types::{DecodedInstruction, ParsedConfirmedTransactionWithStatusMeta},
+    },
+};
+
+pub fn parsed_pumpfun_create_txn(
+    original: ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>,
+) -> Option<Record<DecodedInstruction<DecodedEvent>>> {
+    let meta = &original.meta;
+    let tx = &original.transaction;
+
+    let create_ix = tx
+        .message
+        .instructions
+        .iter()
+        .chain(meta.inner_instructions.iter())
+        .find(|instr| {
+            let name = instr.name.to_lowercase();
+            name == "create" || name == "createV2"
+        })?;
+    Some(Record::new("pump_create", &original, create_ix.clone()))
+}
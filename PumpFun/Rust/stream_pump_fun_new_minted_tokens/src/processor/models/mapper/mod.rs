@@ -1,2 +0,0 @@
-pub mod event;
-pub mod instruction;
\ No newline at end of file
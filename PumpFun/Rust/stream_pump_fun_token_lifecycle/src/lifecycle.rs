@@ -0,0 +1,470 @@
+use {
+    serde::{Deserialize, Serialize},
+    serde_with::{serde_as, DisplayFromStr},
+    solana_sdk::pubkey::Pubkey,
+    std::{
+        collections::HashMap,
+        fs::{self, File, OpenOptions},
+        io::{BufRead, BufReader, BufWriter, Write},
+        path::PathBuf,
+    },
+};
+
+/// How often, in slots, `observe` looks for idle tokens to expire.
+const EXPIRY_INTERVAL_SLOTS: u64 = 150;
+
+/// How many transitions are appended to the journal before it is compacted.
+const COMPACT_INTERVAL_APPENDS: usize = 10_000;
+
+/// Where a token is between its Pump.fun `create` and its PumpSwap pool.
+/// Stages only move forward.
+#[serde_as]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum Stage {
+    Created,
+    /// Traded on the bonding curve.
+    Trading,
+    /// The bonding curve is `complete` and no longer trades.
+    Completed,
+    /// Migrated to this PumpSwap pool, no swap on it yet.
+    Migrated {
+        #[serde_as(as = "DisplayFromStr")]
+        pool: Pubkey,
+    },
+    AmmTrading {
+        #[serde_as(as = "DisplayFromStr")]
+        pool: Pubkey,
+    },
+}
+
+impl Stage {
+    fn rank(&self) -> u8 {
+        match self {
+            Stage::Created => 0,
+            Stage::Trading => 1,
+            Stage::Completed => 2,
+            Stage::Migrated { .. } => 3,
+            Stage::AmmTrading { .. } => 4,
+        }
+    }
+
+    pub fn pool(&self) -> Option<Pubkey> {
+        match self {
+            Stage::Migrated { pool } | Stage::AmmTrading { pool } => Some(*pool),
+            _ => None,
+        }
+    }
+
+    /// Nothing is left to track once the pool has traded.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Stage::AmmTrading { .. })
+    }
+
+    /// Most tokens never complete their bonding curve, so they are dropped once idle.
+    fn expires(&self) -> bool {
+        matches!(self, Stage::Created | Stage::Trading)
+    }
+}
+
+/// What the stream saw, from a transaction or an account update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Observation {
+    Created { mint: Pubkey, bonding_curve: Pubkey },
+    Traded { mint: Pubkey },
+    Completed { mint: Pubkey, bonding_curve: Pubkey },
+    /// A bonding curve account with `complete` set. Only known curves map to a mint.
+    CurveCompleted { bonding_curve: Pubkey },
+    Migrated { mint: Pubkey, pool: Pubkey },
+    /// A swap on a PumpSwap pool.
+    AmmTraded { pool: Pubkey },
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TokenLifecycle {
+    #[serde_as(as = "DisplayFromStr")]
+    pub mint: Pubkey,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub bonding_curve: Option<Pubkey>,
+    #[serde(flatten)]
+    pub stage: Stage,
+    /// Slot of the last transition.
+    pub slot: u64,
+}
+
+/// A stage change, emitted as the `pump_lifecycle` record.
+#[derive(Debug, Clone, Serialize)]
+pub struct Transition {
+    /// `None` for tokens first seen past `Created`, e.g. created before the tracker started.
+    pub from: Option<Stage>,
+    #[serde(flatten)]
+    pub token: TokenLifecycle,
+}
+
+/// A journal line. Compaction also records when the token was last seen, so
+/// tokens that kept trading without a transition do not expire on replay.
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalEntry {
+    #[serde(flatten)]
+    token: TokenLifecycle,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_seen: Option<u64>,
+}
+
+/// Lifecycle state of every token seen, keyed by mint.
+///
+/// Transitions are appended to a JSON Lines journal. On start the journal is
+/// replayed, then rewritten with one line per token still being tracked. It
+/// is rewritten the same way every `COMPACT_INTERVAL_APPENDS` transitions and
+/// on `save`.
+///
+/// Tokens still on their bonding curve are dropped after `max_idle_slots`
+/// without a create or trade. One that trades again later re-enters as
+/// `Trading` with no `from` stage.
+pub struct LifecycleTracker {
+    tokens: HashMap<Pubkey, TokenLifecycle>,
+    by_bonding_curve: HashMap<Pubkey, Pubkey>,
+    by_pool: HashMap<Pubkey, Pubkey>,
+    /// Slot each token was last observed in, transition or not.
+    last_seen: HashMap<Pubkey, u64>,
+    max_idle_slots: u64,
+    next_expiry: u64,
+    path: PathBuf,
+    journal: Option<BufWriter<File>>,
+    /// Transitions appended since the journal was last compacted.
+    appends: usize,
+}
+
+impl LifecycleTracker {
+    pub fn open(path: impl Into<PathBuf>, max_idle_slots: u64) -> anyhow::Result<Self> {
+        let path = path.into();
+        let mut tracker = Self {
+            tokens: HashMap::new(),
+            by_bonding_curve: HashMap::new(),
+            by_pool: HashMap::new(),
+            last_seen: HashMap::new(),
+            max_idle_slots,
+            next_expiry: 0,
+            path,
+            journal: None,
+            appends: 0,
+        };
+
+        if tracker.path.exists() {
+            for line in BufReader::new(File::open(&tracker.path)?).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                // A crash can leave the last line half written.
+                match serde_json::from_str::<JournalEntry>(&line) {
+                    Ok(entry) if entry.token.stage.is_final() => tracker.remove(&entry.token.mint),
+                    Ok(JournalEntry { token, last_seen }) => {
+                        let mint = token.mint;
+                        tracker.insert(token);
+                        if let Some(last_seen) = last_seen {
+                            tracker.seen(&mint, last_seen);
+                        }
+                    }
+                    Err(e) => log::warn!("Skipping journal line in {}: {e}", tracker.path.display()),
+                }
+            }
+        }
+
+        // Tokens that went idle while the tracker was down are not rewritten.
+        if let Some(latest) = tracker.last_seen.values().copied().max() {
+            tracker.expire(latest);
+        }
+        tracker.compact()?;
+        Ok(tracker)
+    }
+
+    /// Rewrites the journal with the tokens still being tracked and when
+    /// each was last seen.
+    pub fn save(&mut self) -> anyhow::Result<()> {
+        self.compact()
+    }
+
+    /// Number of tokens not in their final stage yet.
+    pub fn tracked(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Pools migrated to but not traded yet.
+    pub fn awaiting_amm_trade(&self) -> Vec<Pubkey> {
+        self.tokens
+            .values()
+            .filter_map(|token| match token.stage {
+                Stage::Migrated { pool } => Some(pool),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Drops tokens still on their bonding curve that were last seen more than
+    /// `max_idle_slots` before `slot`, and returns how many were dropped.
+    pub fn expire(&mut self, slot: u64) -> usize {
+        let idle: Vec<Pubkey> = self
+            .tokens
+            .values()
+            .filter(|token| token.stage.expires())
+            .filter(|token| {
+                let last_seen = self.last_seen.get(&token.mint).copied().unwrap_or(token.slot);
+                slot.saturating_sub(last_seen) > self.max_idle_slots
+            })
+            .map(|token| token.mint)
+            .collect();
+        for mint in &idle {
+            self.remove(mint);
+        }
+        idle.len()
+    }
+
+    /// Applies an observation and returns the transition it caused, if any.
+    pub fn observe(&mut self, observation: Observation, slot: u64) -> anyhow::Result<Option<Transition>> {
+        if slot >= self.next_expiry {
+            let expired = self.expire(slot);
+            if expired > 0 {
+                log::info!("Expired {expired} idle tokens at slot {slot}");
+            }
+            self.next_expiry = slot + EXPIRY_INTERVAL_SLOTS;
+        }
+
+        let (mint, bonding_curve, stage) = match observation {
+            Observation::Created { mint, bonding_curve } => (mint, Some(bonding_curve), Stage::Created),
+            Observation::Traded { mint } => (mint, None, Stage::Trading),
+            Observation::Completed { mint, bonding_curve } => (mint, Some(bonding_curve), Stage::Completed),
+            Observation::CurveCompleted { bonding_curve } => match self.by_bonding_curve.get(&bonding_curve) {
+                Some(mint) => (*mint, Some(bonding_curve), Stage::Completed),
+                None => return Ok(None),
+            },
+            Observation::Migrated { mint, pool } => (mint, None, Stage::Migrated { pool }),
+            Observation::AmmTraded { pool } => match self.by_pool.get(&pool) {
+                Some(mint) => (*mint, None, Stage::AmmTrading { pool }),
+                None => return Ok(None),
+            },
+        };
+
+        let previous = self.tokens.get(&mint);
+        if previous.is_some_and(|token| token.stage.rank() >= stage.rank()) {
+            self.seen(&mint, slot);
+            return Ok(None);
+        }
+
+        let token = TokenLifecycle {
+            mint,
+            bonding_curve: bonding_curve.or_else(|| previous.and_then(|token| token.bonding_curve)),
+            stage,
+            slot,
+        };
+        let transition = Transition {
+            from: previous.map(|token| token.stage),
+            token: token.clone(),
+        };
+
+        self.append(&token)?;
+        if token.stage.is_final() {
+            self.remove(&mint);
+        } else {
+            self.insert(token);
+        }
+        Ok(Some(transition))
+    }
+
+    fn insert(&mut self, token: TokenLifecycle) {
+        if let Some(bonding_curve) = token.bonding_curve {
+            self.by_bonding_curve.insert(bonding_curve, token.mint);
+        }
+        if let Some(pool) = token.stage.pool() {
+            self.by_pool.insert(pool, token.mint);
+        }
+        self.last_seen.insert(token.mint, token.slot);
+        self.tokens.insert(token.mint, token);
+    }
+
+    fn seen(&mut self, mint: &Pubkey, slot: u64) {
+        if let Some(last_seen) = self.last_seen.get_mut(mint) {
+            *last_seen = (*last_seen).max(slot);
+        }
+    }
+
+    fn remove(&mut self, mint: &Pubkey) {
+        self.last_seen.remove(mint);
+        if let Some(token) = self.tokens.remove(mint) {
+            if let Some(bonding_curve) = token.bonding_curve {
+                self.by_bonding_curve.remove(&bonding_curve);
+            }
+            if let Some(pool) = token.stage.pool() {
+                self.by_pool.remove(&pool);
+            }
+        }
+    }
+
+    fn append(&mut self, token: &TokenLifecycle) -> anyhow::Result<()> {
+        if let Some(journal) = self.journal.as_mut() {
+            serde_json::to_writer(&mut *journal, token)?;
+            journal.write_all(b"\n")?;
+            journal.flush()?;
+            self.appends += 1;
+        }
+        if self.appends >= COMPACT_INTERVAL_APPENDS {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    fn compact(&mut self) -> anyhow::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&tmp)?);
+        for token in self.tokens.values() {
+            let entry = JournalEntry {
+                token: token.clone(),
+                last_seen: self.last_seen.get(&token.mint).copied().filter(|slot| *slot > token.slot),
+            };
+            serde_json::to_writer(&mut writer, &entry)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        fs::rename(&tmp, &self.path)?;
+        self.journal = Some(BufWriter::new(OpenOptions::new().append(true).open(&self.path)?));
+        self.appends = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_IDLE_SLOTS: u64 = 1_000;
+
+    fn state_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("pump_lifecycle_{}_{name}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn created(mint: Pubkey) -> Observation {
+        Observation::Created {
+            mint,
+            bonding_curve: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn idle_tokens_on_the_curve_expire() {
+        let path = state_file("idle_tokens_on_the_curve_expire");
+        let mut tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        let mint = Pubkey::new_unique();
+        tracker.observe(created(mint), 100).unwrap();
+        tracker.observe(Observation::Traded { mint }, 200).unwrap();
+
+        assert_eq!(tracker.expire(200 + MAX_IDLE_SLOTS), 0);
+        assert_eq!(tracker.expire(201 + MAX_IDLE_SLOTS), 1);
+        assert_eq!(tracker.tracked(), 0);
+
+        let transition = tracker
+            .observe(Observation::Traded { mint }, 5_000)
+            .unwrap()
+            .unwrap();
+        assert_eq!(transition.from, None);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn trades_keep_a_token_alive() {
+        let path = state_file("trades_keep_a_token_alive");
+        let mut tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        let mint = Pubkey::new_unique();
+        tracker.observe(created(mint), 100).unwrap();
+        tracker.observe(Observation::Traded { mint }, 200).unwrap();
+        assert!(tracker.observe(Observation::Traded { mint }, 900).unwrap().is_none());
+
+        assert_eq!(tracker.expire(900 + MAX_IDLE_SLOTS), 0);
+        assert_eq!(tracker.tracked(), 1);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn observe_expires_idle_tokens() {
+        let path = state_file("observe_expires_idle_tokens");
+        let mut tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        tracker.observe(created(Pubkey::new_unique()), 100).unwrap();
+        tracker.observe(created(Pubkey::new_unique()), 2_000).unwrap();
+
+        assert_eq!(tracker.tracked(), 1);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn migrated_tokens_do_not_expire() {
+        let path = state_file("migrated_tokens_do_not_expire");
+        let mut tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        let (mint, pool) = (Pubkey::new_unique(), Pubkey::new_unique());
+        tracker.observe(created(mint), 100).unwrap();
+        tracker.observe(Observation::Migrated { mint, pool }, 200).unwrap();
+
+        assert_eq!(tracker.expire(10 * MAX_IDLE_SLOTS), 0);
+        assert_eq!(tracker.awaiting_amm_trade(), vec![pool]);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn replay_drops_tokens_that_went_idle() {
+        let path = state_file("replay_drops_tokens_that_went_idle");
+        let mut tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        let (idle, active) = (Pubkey::new_unique(), Pubkey::new_unique());
+        tracker.observe(created(idle), 100).unwrap();
+        tracker.observe(created(active), 1_050).unwrap();
+        tracker.observe(Observation::Traded { mint: active }, 1_200).unwrap();
+        drop(tracker);
+
+        let tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        assert_eq!(tracker.tracked(), 1);
+        assert!(tracker.tokens.contains_key(&active));
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn replay_keeps_tokens_that_traded_since_their_last_transition() {
+        let path = state_file("replay_keeps_tokens_that_traded_since_their_last_transition");
+        let mut tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        let mint = Pubkey::new_unique();
+        tracker.observe(created(mint), 100).unwrap();
+        tracker.observe(Observation::Traded { mint }, 200).unwrap();
+        tracker.observe(Observation::Traded { mint }, 900).unwrap();
+        tracker.observe(created(Pubkey::new_unique()), 1_850).unwrap();
+        tracker.save().unwrap();
+        drop(tracker);
+
+        let mut tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        assert_eq!(tracker.tracked(), 2);
+        assert_eq!(tracker.expire(900 + MAX_IDLE_SLOTS), 0);
+        assert_eq!(tracker.expire(901 + MAX_IDLE_SLOTS), 1);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn journal_is_compacted_as_it_grows() {
+        let path = state_file("journal_is_compacted_as_it_grows");
+        let mut tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        let kept = Pubkey::new_unique();
+        tracker.observe(created(kept), 100).unwrap();
+        for _ in 0..COMPACT_INTERVAL_APPENDS / 2 {
+            let (mint, pool) = (Pubkey::new_unique(), Pubkey::new_unique());
+            tracker.observe(Observation::Migrated { mint, pool }, 100).unwrap();
+            tracker.observe(Observation::AmmTraded { pool }, 100).unwrap();
+        }
+
+        assert_eq!(tracker.tracked(), 1);
+        assert!(fs::read_to_string(&path).unwrap().lines().count() < 4);
+        drop(tracker);
+
+        let tracker = LifecycleTracker::open(&path, MAX_IDLE_SLOTS).unwrap();
+        assert!(tracker.tokens.contains_key(&kept));
+        assert_eq!(tracker.tracked(), 1);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,315 @@
+use {
+    backoff::{future::retry, ExponentialBackoff},
+    clap::Parser as ClapParser,
+    futures::{future::TryFutureExt, sink::SinkExt, stream::StreamExt},
+    log::{error, info, warn},
+    solana_defi_core::{
+        processor::block_time::blocks_meta_filter,
+        schema::Record,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        EventSink, TransactionProcessor,
+    },
+    solana_sdk::pubkey::Pubkey,
+    std::{collections::HashMap, env, path::Path, sync::Arc, time::{Duration, Instant}},
+    tokio::sync::Mutex,
+    tonic::transport::channel::ClientTlsConfig,
+    yellowstone_grpc_client::{GeyserGrpcClient, Interceptor},
+    yellowstone_grpc_proto::{
+        geyser::{SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions},
+        prelude::{
+            subscribe_request_filter_accounts_filter::Filter,
+            subscribe_request_filter_accounts_filter_memcmp::Data, subscribe_update::UpdateOneof,
+            CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccountsFilter,
+            SubscribeRequestFilterAccountsFilterMemcmp, SubscribeRequestPing,
+        },
+    },
+};
+
+mod lifecycle;
+mod parser;
+use pump_decoder::PumpDecoder;
+use lifecycle::{LifecycleTracker, Transition};
+use parser::{account_observation, transaction_observations};
+
+type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
+type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
+
+const PUMPFUN_PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+/// `complete` follows the discriminator and five u64 reserves.
+const COMPLETE_FIELD_OFFSET: u64 = 48;
+/// How often the processing error counts and dropped sink records are logged.
+const STATS_LOG_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, ClapParser)]
+#[clap(author, version, about)]
+struct Args {
+    #[clap(short, long, help = "gRPC endpoint")]
+    endpoint: String,
+
+    #[clap(long, help = "X-Token")]
+    x_token: String,
+
+    #[clap(long, help = "Directory to dump raw updates that fail to process")]
+    dump_failed_updates: Option<String>,
+
+    #[clap(long, help = "Pump IDL to use instead of the embedded one")]
+    idl: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
+
+    #[clap(
+        long,
+        default_value = "pump_lifecycle.jsonl",
+        help = "JSON Lines file the lifecycle state is kept in across restarts"
+    )]
+    state_file: String,
+
+    #[clap(
+        long,
+        default_value_t = 216_000,
+        help = "Slots without a create or trade after which a token still on its bonding curve is dropped (~1 day)"
+    )]
+    max_idle_slots: u64,
+}
+
+impl Args {
+    async fn connect(&self) -> anyhow::Result<GeyserGrpcClient<impl Interceptor>> {
+        GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
+            .x_token(Some(self.x_token.clone()))?
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(10))
+            .tls_config(ClientTlsConfig::new().with_native_roots())?
+            .max_decoding_message_size(1024 * 1024 * 1024)
+            .connect()
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Pump.fun transactions, completed bonding curves and, once tokens have
+    /// migrated, the transactions touching their pools.
+    pub fn get_lifecycle_updates(&self, pools: &[Pubkey]) -> SubscribeRequest {
+        let mut transactions: TxnFilterMap = HashMap::new();
+        transactions.insert(
+            "pumpfun".to_owned(),
+            SubscribeRequestFilterTransactions {
+                vote: Some(false),
+                failed: Some(false),
+                account_include: vec![PUMPFUN_PROGRAM_ID.to_string()],
+                account_exclude: vec![],
+                account_required: vec![],
+                signature: None,
+            },
+        );
+        // An empty `account_include` would match every transaction.
+        if !pools.is_empty() {
+            transactions.insert(
+                "migratedPools".to_owned(),
+                SubscribeRequestFilterTransactions {
+                    vote: Some(false),
+                    failed: Some(false),
+                    account_include: pools.iter().map(|pool| pool.to_string()).collect(),
+                    account_exclude: vec![],
+                    account_required: vec![],
+                    signature: None,
+                },
+            );
+        }
+
+        let mut accounts: AccountFilterMap = HashMap::new();
+        accounts.insert(
+            "completedCurves".to_owned(),
+            SubscribeRequestFilterAccounts {
+                account: vec![],
+                owner: vec![PUMPFUN_PROGRAM_ID.to_string()],
+                nonempty_txn_signature: None,
+                filters: vec![SubscribeRequestFilterAccountsFilter {
+                    filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                        offset: COMPLETE_FIELD_OFFSET,
+                        data: Some(Data::Bytes(vec![1])),
+                    })),
+                }],
+            },
+        );
+
+        SubscribeRequest {
+            accounts,
+            slots: HashMap::default(),
+            transactions,
+            transactions_status: HashMap::default(),
+            blocks: HashMap::default(),
+            blocks_meta: blocks_meta_filter(),
+            entry: HashMap::default(),
+            commitment: Some(CommitmentLevel::Processed as i32),
+            accounts_data_slice: Vec::default(),
+            ping: None,
+            from_slot: None,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env::set_var(
+        env_logger::DEFAULT_FILTER_ENV,
+        env::var_os(env_logger::DEFAULT_FILTER_ENV).unwrap_or_else(|| "info".into()),
+    );
+    env_logger::init();
+
+    let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
+    let tracker = LifecycleTracker::open(&args.state_file, args.max_idle_slots)?;
+    info!("Restored {} tokens from {}", tracker.tracked(), args.state_file);
+    let tracker = Arc::new(Mutex::new(tracker));
+    let decoder = PumpDecoder::new(args.idl.as_deref().map(Path::new))?;
+    let mut processor = TransactionProcessor::new(decoder)?;
+    if let Some(dir) = &args.dump_failed_updates {
+        processor = processor.dump_failed_updates(dir);
+    }
+    // Kept across reconnects so transactions waiting for their block meta survive them.
+    let processor = Arc::new(Mutex::new(processor));
+    let zero_attempts = Arc::new(Mutex::new(true));
+
+    retry(ExponentialBackoff::default(), move || {
+        let args = args.clone();
+        let sink = Arc::clone(&sink);
+        let tracker = Arc::clone(&tracker);
+        let processor = Arc::clone(&processor);
+        let zero_attempts = Arc::clone(&zero_attempts);
+
+        async move {
+            let mut zero_attempts = zero_attempts.lock().await;
+            if *zero_attempts {
+                *zero_attempts = false;
+            } else {
+                info!("Retry to connect to the server");
+            }
+            drop(zero_attempts);
+
+            let client = args.connect().await.map_err(backoff::Error::transient)?;
+            info!("Connected");
+
+            let mut tracker = tracker.lock().await;
+            let mut processor = processor.lock().await;
+            geyser_subscribe(client, &args, &sink, &mut tracker, &mut processor)
+                .await
+                .map_err(backoff::Error::transient)?;
+
+            Ok::<(), backoff::Error<anyhow::Error>>(())
+        }
+        .inspect_err(|error| error!("failed to connect: {error}"))
+    })
+    .await
+    .map_err(Into::into)
+}
+
+async fn geyser_subscribe(
+    mut client: GeyserGrpcClient<impl Interceptor>,
+    args: &Args,
+    sink: &Arc<dyn EventSink>,
+    tracker: &mut LifecycleTracker,
+    processor: &mut TransactionProcessor<PumpDecoder>,
+) -> anyhow::Result<()> {
+    let request = args.get_lifecycle_updates(&tracker.awaiting_amm_trade());
+    let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
+    info!("stream opened");
+    info!("Tracking Pump.fun tokens from create to PumpSwap");
+
+    let mut last_stats = Instant::now();
+    let mut last_dropped = 0;
+    while let Some(message) = stream.next().await {
+        let mut transitions = Vec::new();
+
+        match message {
+            Ok(msg) => match msg.update_oneof {
+                Some(UpdateOneof::Transaction(update)) => {
+                    match processor.process_transaction_update(update) {
+                        Ok(ready) => {
+                            for txn in ready {
+                                for observation in transaction_observations(&txn) {
+                                    if let Some(transition) = tracker.observe(observation, txn.slot)? {
+                                        transitions.push(Record::new("pump_lifecycle", &txn, transition));
+                                    }
+                                }
+                            }
+                        }
+                        Err(e) => error!("Failed to process txn (skipping) [{}]: {e}", e.kind()),
+                    }
+                }
+                Some(UpdateOneof::BlockMeta(update)) => {
+                    for txn in processor.process_block_meta_update(update) {
+                        for observation in transaction_observations(&txn) {
+                            if let Some(transition) = tracker.observe(observation, txn.slot)? {
+                                transitions.push(Record::new("pump_lifecycle", &txn, transition));
+                            }
+                        }
+                    }
+                }
+                Some(UpdateOneof::Account(update)) => {
+                    let observation = update
+                        .account
+                        .and_then(|account| account_observation(&account.pubkey, &account.data));
+                    if let Some(observation) = observation {
+                        if let Some(transition) = tracker.observe(observation, update.slot)? {
+                            transitions.push(Record::account_update(
+                                "pump_lifecycle",
+                                update.slot,
+                                transition,
+                            ));
+                        }
+                    }
+                }
+                Some(UpdateOneof::Ping(_)) => {
+                    subscribe_tx
+                        .send(SubscribeRequest {
+                            ping: Some(SubscribeRequestPing { id: 1 }),
+                            ..Default::default()
+                        })
+                        .await?;
+                }
+                None => {
+                    error!("update not found in the message");
+                    break;
+                }
+                _ => {}
+            },
+            Err(error) => {
+                error!("error: {error:?}");
+                break;
+            }
+        }
+
+        // The pool filter follows the tokens waiting for their first swap.
+        let pools_changed = transitions
+            .iter()
+            .any(|record: &Record<Transition>| record.data.token.stage.pool().is_some());
+        for record in &transitions {
+            sink.send(record)?;
+        }
+        if pools_changed {
+            subscribe_tx
+                .send(args.get_lifecycle_updates(&tracker.awaiting_amm_trade()))
+                .await?;
+        }
+
+        if last_stats.elapsed() >= STATS_LOG_INTERVAL {
+            if !processor.error_counts().is_empty() {
+                info!("Processing errors so far: {:?}", processor.error_counts());
+            }
+            let dropped = sink.dropped();
+            if dropped != last_dropped {
+                warn!("Sink dropped {} records so far", dropped);
+                last_dropped = dropped;
+            }
+            last_stats = Instant::now();
+        }
+    }
+
+    tracker.save()?;
+    info!("stream closed");
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use {
+    crate::lifecycle::Observation,
+    pump_decoder::event::DecodedEvent,
+    pump_interface::accounts::BondingCurveAccount,
+    solana_defi_core::types::ParsedConfirmedTransactionWithStatusMeta,
+    solana_sdk::pubkey::Pubkey,
+};
+
+const PUMP_AMM_PROGRAM_ID: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
+
+/// Everything the transaction tells about token lifecycles, in execution order.
+pub fn transaction_observations(
+    original: &ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>,
+) -> Vec<Observation> {
+    let meta = &original.meta;
+    let message = &original.transaction.message;
+
+    let mut observations: Vec<Observation> = message
+        .instructions
+        .iter()
+        .chain(meta.inner_instructions.iter())
+        .flat_map(|instr| &instr.events)
+        .filter_map(|event| match event {
+            DecodedEvent::CreateEvent(event) => Some(Observation::Created {
+                mint: event.mint,
+                bonding_curve: event.bonding_curve,
+            }),
+            DecodedEvent::TradeEvent(event) => Some(Observation::Traded { mint: event.mint }),
+            DecodedEvent::CompleteEvent(event) => Some(Observation::Completed {
+                mint: event.mint,
+                bonding_curve: event.bonding_curve,
+            }),
+            DecodedEvent::CompletePumpAmmMigrationEvent(event) => Some(Observation::Migrated {
+                mint: event.mint,
+                pool: event.pool,
+            }),
+            _ => None,
+        })
+        .collect();
+
+    // PumpSwap instructions are not decoded here, so swaps are read from the
+    // logs and matched to a pool through the accounts of the transaction.
+    if has_amm_swap(meta.log_messages.as_deref().unwrap_or_default()) {
+        observations.extend(
+            message
+                .account_keys
+                .iter()
+                .chain(&meta.loaded_addresses.writable)
+                .chain(&meta.loaded_addresses.readonly)
+                .map(|pool| Observation::AmmTraded { pool: *pool }),
+        );
+    }
+
+    observations
+}
+
+/// A Pump.fun bonding curve account with `complete` set.
+pub fn account_observation(pubkey: &[u8], data: &[u8]) -> Option<Observation> {
+    let curve = BondingCurveAccount::deserialize(data).ok()?;
+    if !curve.0.complete {
+        return None;
+    }
+    Some(Observation::CurveCompleted {
+        bonding_curve: Pubkey::try_from(pubkey).ok()?,
+    })
+}
+
+/// Whether PumpSwap ran a buy or sell, following the invoke stack in the logs.
+fn has_amm_swap(logs: &[String]) -> bool {
+    let mut stack: Vec<&str> = Vec::new();
+    for log in logs {
+        if let Some(rest) = log.strip_prefix("Program ") {
+            if let Some((program, _)) = rest.split_once(" invoke [") {
+                stack.push(program);
+                continue;
+            }
+            if rest.ends_with(" success") || rest.contains(" failed: ") {
+                stack.pop();
+                continue;
+            }
+        }
+        if stack.last() == Some(&PUMP_AMM_PROGRAM_ID) {
+            if let Some(name) = log.strip_prefix("Program log: Instruction: ") {
+                if matches!(name, "Buy" | "Sell" | "BuyExactQuoteIn") {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
@@ -0,0 +1,88 @@
+use {
+    crate::TransactionEvent,
+    pump_decoder::event::DecodedEvent,
+    solana_defi_core::{
+        schema::{Record, TokenAmount},
+        types::{DecodedInstruction, ParsedConfirmedTransactionWithStatusMeta},
+    },
+};
+
+/// Pump.fun mints are always created with 6 decimals.
+const PUMP_TOKEN_DECIMALS: u8 = 6;
+
+/// Returns one `TransactionEvent` per buy/sell instruction, so bundled trades
+/// each report their own amounts.
+pub fn parsed_pump_txn(
+    original: ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>,
+) -> Vec<Record<TransactionEvent>> {
+    let meta = &original.meta;
+    let tx = &original.transaction;
+
+    tx.message
+        .instructions
+        .iter()
+        .chain(meta.inner_instructions.iter())
+        .filter(|instr| {
+            instr.name.to_lowercase() == "sell" || instr.name.to_lowercase() == "buy"
+        })
+        .filter_map(parse_trade)
+        .map(|trade| Record::new("pump_trade", &original, trade))
+        .collect()
+}
+
+fn parse_trade(amm_instruction: &DecodedInstruction<DecodedEvent>) -> Option<TransactionEvent> {
+    let in_amount = match amm_instruction.name.as_str() {
+        "buy" => amm_instruction.data.get("Buy")?.get("amount")?.as_u64(),
+        "sell" => amm_instruction.data.get("Sell")?.get("amount")?.as_u64(),
+        _ => Some(0),
+    }?;
+
+    let sol_amount = amm_instruction
+        .events
+        .iter()
+        .find_map(|event| match event {
+            DecodedEvent::TradeEvent(event) => Some(event.sol_amount),
+            _ => None,
+        })
+        .unwrap_or(0);
+
+    let (amount_in, amount_out) = match amm_instruction.name.as_str() {
+        "buy" => (
+            TokenAmount::lamports(sol_amount),
+            TokenAmount::new(in_amount, PUMP_TOKEN_DECIMALS),
+        ),
+        _ => (
+            TokenAmount::new(in_amount, PUMP_TOKEN_DECIMALS),
+            TokenAmount::lamports(sol_amount),
+        ),
+    };
+
+    let signer_pubkey = amm_instruction
+        .accounts
+        .iter()
+        .find(|acc| acc.name == "user")
+        .map(|acc| acc.pubkey.to_string());
+
+    let input_mint = amm_instruction
+        .accounts
+        .iter()
+        .find(|acc| acc.name == "mint")
+        .map(|acc| acc.pubkey.to_string())?;
+
+    let bonding_curve = amm_instruction
+        .accounts
+        .iter()
+        .find(|acc| acc.name == "bonding_curve")
+        .map(|acc| acc.pubkey.to_string())?;
+
+    let output =  TransactionEvent {
+            event_type: Some(amm_instruction.name.to_string()),
+            user: signer_pubkey,
+            mint: Some(input_mint),
+            bonding_curve: Some(bonding_curve),
+            amount_in: Some(amount_in),
+            amount_out: Some(amount_out),
+    };
+
+    Some(output)
+}
@@ -1,2 +0,0 @@
-pub mod serialization;
-pub mod token_serializable;
\ No newline at end of file
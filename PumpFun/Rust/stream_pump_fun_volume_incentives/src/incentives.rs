@@ -0,0 +1,445 @@
+use {
+    pump_decoder::event::DecodedEvent,
+    pump_interface::{
+        accounts::{GlobalVolumeAccumulator, UserVolumeAccumulator},
+        events::{AdminUpdateTokenIncentivesEvent, ClaimTokenIncentivesEvent, TradeEvent},
+    },
+    serde::Serialize,
+    serde_with::{serde_as, DisplayFromStr},
+    solana_sdk::pubkey::Pubkey,
+    std::collections::{BTreeMap, HashMap},
+};
+
+/// Days `GlobalVolumeAccumulator` keeps a supply and a volume for.
+pub const INCENTIVE_DAYS: usize = 30;
+
+/// Global volume accumulator PDA of the Pump.fun program.
+pub fn global_volume_accumulator() -> Pubkey {
+    Pubkey::find_program_address(&[b"global_volume_accumulator"], &pump_interface::ID).0
+}
+
+/// The daily token schedule and the tracked SOL volume of every day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncentiveSchedule {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub mint: Pubkey,
+    pub total_token_supply: [u64; INCENTIVE_DAYS],
+    pub sol_volumes: [u64; INCENTIVE_DAYS],
+}
+
+impl IncentiveSchedule {
+    /// Incentive day of `timestamp`, `None` outside the schedule.
+    pub fn day(&self, timestamp: i64) -> Option<u64> {
+        if self.seconds_in_a_day <= 0 || timestamp < self.start_time || timestamp >= self.end_time {
+            return None;
+        }
+        let day = ((timestamp - self.start_time) / self.seconds_in_a_day) as u64;
+        ((day as usize) < INCENTIVE_DAYS).then_some(day)
+    }
+
+    /// Share of the day's supply earned by `sol_volume`, rounded down as the
+    /// program does. Before the day ends the day's total volume still grows,
+    /// so this is an upper bound.
+    pub fn tokens_for(&self, day: u64, sol_volume: u64) -> u64 {
+        let day = day as usize;
+        let total_volume = self.sol_volumes.get(day).copied().unwrap_or(0);
+        if total_volume == 0 {
+            return 0;
+        }
+        (sol_volume as u128 * self.total_token_supply[day] as u128 / total_volume as u128) as u64
+    }
+}
+
+impl From<GlobalVolumeAccumulator> for IncentiveSchedule {
+    fn from(account: GlobalVolumeAccumulator) -> Self {
+        Self {
+            start_time: account.start_time,
+            end_time: account.end_time,
+            seconds_in_a_day: account.seconds_in_a_day,
+            mint: account.mint,
+            total_token_supply: account.total_token_supply,
+            sol_volumes: account.sol_volumes,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UserVolume {
+    /// Tracked SOL volume per incentive day, as last reported by the program.
+    pub day_volumes: BTreeMap<u64, u64>,
+    /// Day of `current_sol_volume`, not yet converted into unclaimed tokens.
+    pub current_day: Option<u64>,
+    pub current_sol_volume: u64,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    /// Time of the last trade, claim or account update applied.
+    pub last_update_timestamp: i64,
+}
+
+/// A user's volume and projected incentives after a tracked trade,
+/// emitted as the `pump_volume` record.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct VolumeUpdate {
+    #[serde_as(as = "DisplayFromStr")]
+    pub user: Pubkey,
+    #[serde_as(as = "DisplayFromStr")]
+    pub mint: Pubkey,
+    pub sol_amount: u64,
+    pub day: Option<u64>,
+    pub day_sol_volume: u64,
+    pub total_unclaimed_tokens: u64,
+    /// `total_unclaimed_tokens` plus the tokens the current day's volume earns.
+    pub projected_claimable_tokens: Option<u64>,
+}
+
+/// A `claim_token_incentives`, emitted as the `pump_incentive_claim` record.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct IncentiveClaim {
+    #[serde_as(as = "DisplayFromStr")]
+    pub user: Pubkey,
+    #[serde_as(as = "DisplayFromStr")]
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub total_claimed_tokens: u64,
+    /// What the tracker projected just before the claim, if it had seen the user.
+    pub projected_claimable_tokens: Option<u64>,
+}
+
+/// A day of the schedule set by `admin_update_token_incentives`, emitted as
+/// the `pump_incentive_schedule` record.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ScheduleUpdate {
+    pub day_number: u64,
+    pub token_supply_per_day: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub mint: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum IncentiveOutput {
+    Volume(VolumeUpdate),
+    Claim(IncentiveClaim),
+    Schedule(ScheduleUpdate),
+}
+
+impl IncentiveOutput {
+    pub fn record_kind(&self) -> &'static str {
+        match self {
+            IncentiveOutput::Volume(_) => "pump_volume",
+            IncentiveOutput::Claim(_) => "pump_incentive_claim",
+            IncentiveOutput::Schedule(_) => "pump_incentive_schedule",
+        }
+    }
+}
+
+/// Tracked SOL volume of every user seen, per incentive day.
+#[derive(Default)]
+pub struct VolumeTracker {
+    schedule: Option<IncentiveSchedule>,
+    users: HashMap<Pubkey, UserVolume>,
+}
+
+impl VolumeTracker {
+    pub fn user(&self, user: &Pubkey) -> Option<&UserVolume> {
+        self.users.get(user)
+    }
+
+    /// Replaces the schedule with the global volume accumulator's current state.
+    pub fn on_global_accumulator(&mut self, account: GlobalVolumeAccumulator) {
+        self.schedule = Some(account.into());
+    }
+
+    /// Syncs a user with their volume accumulator, which also changes on
+    /// `sync_user_volume_accumulator` and on trades this stream did not see.
+    /// Updates older than what the user's events already reported are ignored.
+    pub fn on_user_accumulator(&mut self, account: UserVolumeAccumulator) {
+        let day = self.schedule.as_ref().and_then(|schedule| schedule.day(account.last_update_timestamp));
+        let volume = self.users.entry(account.user).or_default();
+        if account.last_update_timestamp < volume.last_update_timestamp {
+            return;
+        }
+
+        volume.last_update_timestamp = account.last_update_timestamp;
+        volume.current_day = day.filter(|_| account.current_sol_volume > 0);
+        volume.current_sol_volume = account.current_sol_volume;
+        volume.total_unclaimed_tokens = account.total_unclaimed_tokens;
+        volume.total_claimed_tokens = account.total_claimed_tokens;
+        if let Some(day) = volume.current_day {
+            volume.day_volumes.insert(day, account.current_sol_volume);
+        }
+    }
+
+    pub fn on_event(&mut self, event: &DecodedEvent) -> Option<IncentiveOutput> {
+        match event {
+            DecodedEvent::TradeEvent(event) if event.track_volume => {
+                Some(IncentiveOutput::Volume(self.on_trade(event)))
+            }
+            DecodedEvent::ClaimTokenIncentivesEvent(event) => Some(IncentiveOutput::Claim(self.on_claim(event))),
+            DecodedEvent::AdminUpdateTokenIncentivesEvent(event) => {
+                Some(IncentiveOutput::Schedule(self.on_schedule_update(event)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Claimable tokens: the unclaimed total plus what the volume of the day
+    /// not yet converted by the program earns.
+    pub fn projected_claimable(&self, user: &Pubkey) -> Option<u64> {
+        let schedule = self.schedule.as_ref()?;
+        let volume = self.users.get(user)?;
+        let pending = volume
+            .current_day
+            .map_or(0, |day| schedule.tokens_for(day, volume.current_sol_volume));
+        Some(volume.total_unclaimed_tokens + pending)
+    }
+
+    fn on_trade(&mut self, event: &TradeEvent) -> VolumeUpdate {
+        let day = self.schedule.as_ref().and_then(|schedule| schedule.day(event.last_update_timestamp));
+        if let (Some(schedule), Some(day)) = (self.schedule.as_mut(), day) {
+            // The day's total is at least this user's volume, even before the
+            // global accumulator update for this trade arrives.
+            let index = day as usize;
+            schedule.sol_volumes[index] = schedule.sol_volumes[index].max(event.current_sol_volume);
+        }
+
+        let volume = self.users.entry(event.user).or_default();
+        volume.current_day = day;
+        volume.current_sol_volume = event.current_sol_volume;
+        volume.total_unclaimed_tokens = event.total_unclaimed_tokens;
+        volume.total_claimed_tokens = event.total_claimed_tokens;
+        volume.last_update_timestamp = volume.last_update_timestamp.max(event.last_update_timestamp);
+        if let Some(day) = day {
+            volume.day_volumes.insert(day, event.current_sol_volume);
+        }
+
+        VolumeUpdate {
+            user: event.user,
+            mint: event.mint,
+            sol_amount: event.sol_amount,
+            day,
+            day_sol_volume: event.current_sol_volume,
+            total_unclaimed_tokens: event.total_unclaimed_tokens,
+            projected_claimable_tokens: self.projected_claimable(&event.user),
+        }
+    }
+
+    fn on_claim(&mut self, event: &ClaimTokenIncentivesEvent) -> IncentiveClaim {
+        let projected_claimable_tokens = self.projected_claimable(&event.user);
+
+        // The claim converts the volume of past days and pays out everything;
+        // only the ongoing day's volume is left.
+        let day = self.schedule.as_ref().and_then(|schedule| schedule.day(event.timestamp));
+        let volume = self.users.entry(event.user).or_default();
+        volume.current_day = day.filter(|_| event.current_sol_volume > 0);
+        volume.current_sol_volume = event.current_sol_volume;
+        volume.total_unclaimed_tokens = 0;
+        volume.total_claimed_tokens = event.total_claimed_tokens;
+        volume.last_update_timestamp = volume.last_update_timestamp.max(event.timestamp);
+
+        IncentiveClaim {
+            user: event.user,
+            mint: event.mint,
+            amount: event.amount,
+            total_claimed_tokens: event.total_claimed_tokens,
+            projected_claimable_tokens,
+        }
+    }
+
+    fn on_schedule_update(&mut self, event: &AdminUpdateTokenIncentivesEvent) -> ScheduleUpdate {
+        let schedule = self.schedule.get_or_insert(IncentiveSchedule {
+            start_time: event.start_time,
+            end_time: event.end_time,
+            seconds_in_a_day: event.seconds_in_a_day,
+            mint: event.mint,
+            total_token_supply: [0; INCENTIVE_DAYS],
+            sol_volumes: [0; INCENTIVE_DAYS],
+        });
+        schedule.start_time = event.start_time;
+        schedule.end_time = event.end_time;
+        schedule.seconds_in_a_day = event.seconds_in_a_day;
+        schedule.mint = event.mint;
+        if let Some(supply) = schedule.total_token_supply.get_mut(event.day_number as usize) {
+            *supply = event.token_supply_per_day;
+        }
+
+        ScheduleUpdate {
+            day_number: event.day_number,
+            token_supply_per_day: event.token_supply_per_day,
+            mint: event.mint,
+            start_time: event.start_time,
+            end_time: event.end_time,
+            seconds_in_a_day: event.seconds_in_a_day,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: i64 = 1_000;
+    const DAY: i64 = 100;
+
+    fn accumulator() -> GlobalVolumeAccumulator {
+        let mut total_token_supply = [0; INCENTIVE_DAYS];
+        total_token_supply[0] = 1_000_000;
+        total_token_supply[1] = 2_000_000;
+        let mut sol_volumes = [0; INCENTIVE_DAYS];
+        sol_volumes[0] = 3;
+        GlobalVolumeAccumulator {
+            start_time: START,
+            end_time: START + INCENTIVE_DAYS as i64 * DAY,
+            seconds_in_a_day: DAY,
+            mint: Pubkey::new_unique(),
+            total_token_supply,
+            sol_volumes,
+        }
+    }
+
+    fn tracker() -> VolumeTracker {
+        let mut tracker = VolumeTracker::default();
+        tracker.on_global_accumulator(accumulator());
+        tracker
+    }
+
+    fn trade(user: Pubkey, timestamp: i64, current_sol_volume: u64, total_unclaimed_tokens: u64) -> DecodedEvent {
+        DecodedEvent::TradeEvent(TradeEvent {
+            mint: Pubkey::new_unique(),
+            sol_amount: current_sol_volume,
+            token_amount: 1,
+            is_buy: true,
+            user,
+            timestamp,
+            virtual_sol_reserves: 0,
+            virtual_token_reserves: 0,
+            real_sol_reserves: 0,
+            real_token_reserves: 0,
+            fee_recipient: Pubkey::new_unique(),
+            fee_basis_points: 0,
+            fee: 0,
+            creator: Pubkey::new_unique(),
+            creator_fee_basis_points: 0,
+            creator_fee: 0,
+            track_volume: true,
+            total_unclaimed_tokens,
+            total_claimed_tokens: 0,
+            current_sol_volume,
+            last_update_timestamp: timestamp,
+            ix_name: "buy".to_owned(),
+            mayhem_mode: false,
+            cashback_fee_basis_points: 0,
+            cashback: 0,
+        })
+    }
+
+    fn claim(user: Pubkey, timestamp: i64, amount: u64, current_sol_volume: u64) -> DecodedEvent {
+        DecodedEvent::ClaimTokenIncentivesEvent(ClaimTokenIncentivesEvent {
+            user,
+            mint: Pubkey::new_unique(),
+            amount,
+            timestamp,
+            total_claimed_tokens: amount,
+            current_sol_volume,
+        })
+    }
+
+    #[test]
+    fn days_count_from_the_start_time() {
+        let schedule = IncentiveSchedule::from(accumulator());
+        assert_eq!(schedule.day(START - 1), None);
+        assert_eq!(schedule.day(START), Some(0));
+        assert_eq!(schedule.day(START + DAY - 1), Some(0));
+        assert_eq!(schedule.day(START + DAY), Some(1));
+        assert_eq!(schedule.day(schedule.end_time - 1), Some(INCENTIVE_DAYS as u64 - 1));
+        assert_eq!(schedule.day(schedule.end_time), None);
+    }
+
+    #[test]
+    fn no_day_past_the_last_one_or_without_a_day_length() {
+        let mut schedule = IncentiveSchedule::from(accumulator());
+        schedule.end_time += 10 * DAY;
+        assert_eq!(schedule.day(START + INCENTIVE_DAYS as i64 * DAY), None);
+
+        schedule.seconds_in_a_day = 0;
+        assert_eq!(schedule.day(START), None);
+    }
+
+    #[test]
+    fn tokens_are_the_share_of_the_day_rounded_down() {
+        let schedule = IncentiveSchedule::from(accumulator());
+        assert_eq!(schedule.tokens_for(0, 1), 333_333);
+        assert_eq!(schedule.tokens_for(0, 3), 1_000_000);
+        // No volume on the day, or no such day.
+        assert_eq!(schedule.tokens_for(1, 1), 0);
+        assert_eq!(schedule.tokens_for(INCENTIVE_DAYS as u64, 1), 0);
+    }
+
+    #[test]
+    fn claims_reset_the_unclaimed_tokens() {
+        let mut tracker = tracker();
+        let user = Pubkey::new_unique();
+        tracker.on_event(&trade(user, START + 50, 1, 500));
+        assert_eq!(tracker.projected_claimable(&user), Some(500 + 333_333));
+
+        let Some(IncentiveOutput::Claim(output)) = tracker.on_event(&claim(user, START + DAY + 10, 333_833, 0)) else {
+            panic!("expected a claim");
+        };
+        assert_eq!(output.projected_claimable_tokens, Some(333_833));
+
+        let volume = tracker.user(&user).unwrap();
+        assert_eq!(volume.total_unclaimed_tokens, 0);
+        assert_eq!(volume.total_claimed_tokens, 333_833);
+        assert_eq!(volume.current_day, None);
+        assert_eq!(tracker.projected_claimable(&user), Some(0));
+    }
+
+    #[test]
+    fn claims_keep_the_ongoing_days_volume() {
+        let mut tracker = tracker();
+        let user = Pubkey::new_unique();
+        tracker.on_event(&trade(user, START + 50, 1, 0));
+        tracker.on_event(&claim(user, START + 60, 0, 1));
+
+        let volume = tracker.user(&user).unwrap();
+        assert_eq!(volume.current_day, Some(0));
+        assert_eq!(volume.current_sol_volume, 1);
+        assert_eq!(tracker.projected_claimable(&user), Some(333_333));
+    }
+
+    #[test]
+    fn user_accumulator_updates_sync_the_user() {
+        let mut tracker = tracker();
+        let user = Pubkey::new_unique();
+        tracker.on_event(&trade(user, START + 50, 1, 0));
+
+        let mut account = UserVolumeAccumulator {
+            user,
+            needs_claim: true,
+            total_unclaimed_tokens: 333_333,
+            total_claimed_tokens: 0,
+            current_sol_volume: 2,
+            last_update_timestamp: START + DAY + 5,
+            has_total_claimed_tokens: true,
+        };
+        tracker.on_user_accumulator(account.clone());
+        let volume = tracker.user(&user).unwrap();
+        assert_eq!(volume.current_day, Some(1));
+        assert_eq!(volume.day_volumes.get(&1), Some(&2));
+        assert_eq!(volume.total_unclaimed_tokens, 333_333);
+
+        // An update older than what the user last reported changes nothing.
+        account.last_update_timestamp = START + DAY;
+        account.total_unclaimed_tokens = 0;
+        tracker.on_user_accumulator(account);
+        assert_eq!(tracker.user(&user).unwrap().total_unclaimed_tokens, 333_333);
+    }
+}
@@ -0,0 +1,296 @@
+use {
+    backoff::{future::retry, ExponentialBackoff},
+    clap::Parser as ClapParser,
+    futures::{future::TryFutureExt, sink::SinkExt, stream::StreamExt},
+    log::{error, info, warn},
+    pump_decoder::{event::DecodedEvent, PumpDecoder},
+    pump_fun_volume_incentives::incentives::{global_volume_accumulator, IncentiveOutput, VolumeTracker},
+    pump_interface::accounts::{
+        GlobalVolumeAccumulatorAccount, UserVolumeAccumulatorAccount, USER_VOLUME_ACCUMULATOR_ACCOUNT_DISCM,
+    },
+    solana_defi_core::{
+        processor::block_time::blocks_meta_filter,
+        schema::Record,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        types::ParsedConfirmedTransactionWithStatusMeta,
+        EventSink, TransactionProcessor,
+    },
+    std::{collections::HashMap, env, path::Path, sync::Arc, time::{Duration, Instant}},
+    tokio::sync::Mutex,
+    tonic::transport::channel::ClientTlsConfig,
+    yellowstone_grpc_client::{GeyserGrpcClient, Interceptor},
+    yellowstone_grpc_proto::{
+        geyser::{SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions},
+        prelude::{
+            subscribe_request_filter_accounts_filter::Filter,
+            subscribe_request_filter_accounts_filter_memcmp::Data, subscribe_update::UpdateOneof,
+            CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccountsFilter,
+            SubscribeRequestFilterAccountsFilterMemcmp, SubscribeRequestPing,
+        },
+    },
+};
+
+type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
+type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
+
+const PUMPFUN_PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+/// How often the processing error counts and dropped sink records are logged.
+const STATS_LOG_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, ClapParser)]
+#[clap(author, version, about)]
+struct Args {
+    #[clap(short, long, help = "gRPC endpoint")]
+    endpoint: String,
+
+    #[clap(long, help = "X-Token")]
+    x_token: String,
+
+    #[clap(long, help = "Directory to dump raw updates that fail to process")]
+    dump_failed_updates: Option<String>,
+
+    #[clap(long, help = "Pump IDL to use instead of the embedded one")]
+    idl: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
+}
+
+impl Args {
+    async fn connect(&self) -> anyhow::Result<GeyserGrpcClient<impl Interceptor>> {
+        GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
+            .x_token(Some(self.x_token.clone()))?
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(10))
+            .tls_config(ClientTlsConfig::new().with_native_roots())?
+            .max_decoding_message_size(1024 * 1024 * 1024)
+            .connect()
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Pump.fun transactions, the global volume accumulator, which holds the
+    /// daily schedule and the volume of every day, and every user's volume
+    /// accumulator.
+    pub fn get_volume_updates(&self) -> anyhow::Result<SubscribeRequest> {
+        let mut transactions: TxnFilterMap = HashMap::new();
+        transactions.insert(
+            "pumpfun".to_owned(),
+            SubscribeRequestFilterTransactions {
+                vote: Some(false),
+                failed: Some(false),
+                account_include: vec![PUMPFUN_PROGRAM_ID.to_string()],
+                account_exclude: vec![],
+                account_required: vec![],
+                signature: None,
+            },
+        );
+
+        let mut accounts: AccountFilterMap = HashMap::new();
+        accounts.insert(
+            "globalVolumeAccumulator".to_owned(),
+            SubscribeRequestFilterAccounts {
+                account: vec![global_volume_accumulator().to_string()],
+                owner: vec![],
+                nonempty_txn_signature: None,
+                filters: vec![],
+            },
+        );
+        accounts.insert(
+            "userVolumeAccumulators".to_owned(),
+            SubscribeRequestFilterAccounts {
+                account: vec![],
+                owner: vec![PUMPFUN_PROGRAM_ID.to_string()],
+                nonempty_txn_signature: None,
+                filters: vec![SubscribeRequestFilterAccountsFilter {
+                    filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                        offset: 0,
+                        data: Some(Data::Bytes(USER_VOLUME_ACCUMULATOR_ACCOUNT_DISCM.to_vec())),
+                    })),
+                }],
+            },
+        );
+
+        Ok(SubscribeRequest {
+            accounts,
+            slots: HashMap::default(),
+            transactions,
+            transactions_status: HashMap::default(),
+            blocks: HashMap::default(),
+            blocks_meta: blocks_meta_filter(),
+            entry: HashMap::default(),
+            commitment: Some(CommitmentLevel::Processed as i32),
+            accounts_data_slice: Vec::default(),
+            ping: None,
+            from_slot: None,
+        })
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env::set_var(
+        env_logger::DEFAULT_FILTER_ENV,
+        env::var_os(env_logger::DEFAULT_FILTER_ENV).unwrap_or_else(|| "info".into()),
+    );
+    env_logger::init();
+
+    let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
+    // Kept across reconnects so per-day volumes are not lost.
+    let tracker = Arc::new(Mutex::new(VolumeTracker::default()));
+    let decoder = PumpDecoder::new(args.idl.as_deref().map(Path::new))?;
+    let mut processor = TransactionProcessor::new(decoder)?;
+    if let Some(dir) = &args.dump_failed_updates {
+        processor = processor.dump_failed_updates(dir);
+    }
+    // Kept across reconnects so transactions waiting for their block meta survive them.
+    let processor = Arc::new(Mutex::new(processor));
+    let zero_attempts = Arc::new(Mutex::new(true));
+
+    retry(ExponentialBackoff::default(), move || {
+        let args = args.clone();
+        let sink = Arc::clone(&sink);
+        let tracker = Arc::clone(&tracker);
+        let processor = Arc::clone(&processor);
+        let zero_attempts = Arc::clone(&zero_attempts);
+
+        async move {
+            let mut zero_attempts = zero_attempts.lock().await;
+            if *zero_attempts {
+                *zero_attempts = false;
+            } else {
+                info!("Retry to connect to the server");
+            }
+            drop(zero_attempts);
+
+            let client = args.connect().await.map_err(backoff::Error::transient)?;
+            info!("Connected");
+
+            let request = args.get_volume_updates().map_err(backoff::Error::Permanent)?;
+            let mut tracker = tracker.lock().await;
+            let mut processor = processor.lock().await;
+            geyser_subscribe(client, request, &sink, &mut tracker, &mut processor)
+                .await
+                .map_err(backoff::Error::transient)?;
+
+            Ok::<(), backoff::Error<anyhow::Error>>(())
+        }
+        .inspect_err(|error| error!("failed to connect: {error}"))
+    })
+    .await
+    .map_err(Into::into)
+}
+
+async fn geyser_subscribe(
+    mut client: GeyserGrpcClient<impl Interceptor>,
+    request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
+    tracker: &mut VolumeTracker,
+    processor: &mut TransactionProcessor<PumpDecoder>,
+) -> anyhow::Result<()> {
+    let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
+    info!("stream opened");
+
+    let mut last_stats = Instant::now();
+    let mut last_dropped = 0;
+    while let Some(message) = stream.next().await {
+        match message {
+            Ok(msg) => match msg.update_oneof {
+                Some(UpdateOneof::Transaction(update)) => match processor.process_transaction_update(update) {
+                    Ok(ready) => {
+                        for txn in ready {
+                            track_transaction(tracker, sink, &txn)?;
+                        }
+                    }
+                    Err(e) => error!("Failed to process txn (skipping) [{}]: {e}", e.kind()),
+                },
+                Some(UpdateOneof::BlockMeta(update)) => {
+                    for txn in processor.process_block_meta_update(update) {
+                        track_transaction(tracker, sink, &txn)?;
+                    }
+                }
+                Some(UpdateOneof::Account(update)) => {
+                    let Some(account) = update.account else {
+                        continue;
+                    };
+                    if account.data.starts_with(&USER_VOLUME_ACCUMULATOR_ACCOUNT_DISCM) {
+                        match UserVolumeAccumulatorAccount::deserialize(&account.data) {
+                            Ok(user) => tracker.on_user_accumulator(user.0),
+                            Err(e) => error!("Failed to decode a user volume accumulator: {e}"),
+                        }
+                        continue;
+                    }
+                    match GlobalVolumeAccumulatorAccount::deserialize(&account.data) {
+                        Ok(global) => tracker.on_global_accumulator(global.0),
+                        Err(e) => error!("Failed to decode the global volume accumulator: {e}"),
+                    }
+                }
+                Some(UpdateOneof::Ping(_)) => {
+                    subscribe_tx
+                        .send(SubscribeRequest {
+                            ping: Some(SubscribeRequestPing { id: 1 }),
+                            ..Default::default()
+                        })
+                        .await?;
+                }
+                None => {
+                    error!("update not found in the message");
+                    break;
+                }
+                _ => {}
+            },
+            Err(error) => {
+                error!("error: {error:?}");
+                break;
+            }
+        }
+
+        if last_stats.elapsed() >= STATS_LOG_INTERVAL {
+            if !processor.error_counts().is_empty() {
+                info!("Processing errors so far: {:?}", processor.error_counts());
+            }
+            let dropped = sink.dropped();
+            if dropped != last_dropped {
+                warn!("Sink dropped {} records so far", dropped);
+                last_dropped = dropped;
+            }
+            last_stats = Instant::now();
+        }
+    }
+
+    info!("stream closed");
+    Ok(())
+}
+
+fn track_transaction(
+    tracker: &mut VolumeTracker,
+    sink: &Arc<dyn EventSink>,
+    txn: &ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>,
+) -> anyhow::Result<()> {
+    let events = txn
+        .transaction
+        .message
+        .instructions
+        .iter()
+        .chain(txn.meta.inner_instructions.iter())
+        .flat_map(|instr| &instr.events);
+
+    for event in events {
+        let Some(output) = tracker.on_event(event) else {
+            continue;
+        };
+        if let IncentiveOutput::Claim(claim) = &output {
+            info!(
+                "claim_token_incentives: {} claimed {} (projected {:?})",
+                claim.user, claim.amount, claim.projected_claimable_tokens
+            );
+        }
+        sink.send(&Record::new(output.record_kind(), txn, output))?;
+    }
+    Ok(())
+}
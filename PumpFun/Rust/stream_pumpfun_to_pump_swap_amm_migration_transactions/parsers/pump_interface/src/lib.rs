@@ -1,11 +0,0 @@
-solana_program::declare_id!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
-pub mod accounts;
-pub use accounts::*;
-pub mod instructions;
-pub use instructions::*;
-pub mod errors;
-pub use errors::*;
-pub use typedefs::*;
-pub mod typedefs;
-pub mod events;
-pub use events::*;
\ No newline at end of file
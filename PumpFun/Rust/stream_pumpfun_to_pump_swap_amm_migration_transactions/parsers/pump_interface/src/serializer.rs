@@ -1,37 +0,0 @@
-// src/serializer.rs
-#[cfg(feature = "serde")]
-use serde::Deserialize;
-
-#[cfg(feature = "serde")]
-pub fn serialize_u128_as_string<S>(value: &u128, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    serializer.serialize_str(&value.to_string())
-}
-
-#[cfg(feature = "serde")]
-pub fn deserialize_u128_as_string<'de, D>(deserializer: D) -> Result<u128, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let s = String::deserialize(deserializer)?;
-    s.parse::<u128>().map_err(serde::de::Error::custom)
-}
-
-#[cfg(feature = "serde")]
-pub fn serialize_i128_as_string<S>(value: &i128, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    serializer.serialize_str(&value.to_string())
-}
-
-#[cfg(feature = "serde")]
-pub fn deserialize_i128_as_string<'de, D>(deserializer: D) -> Result<i128, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let s = String::deserialize(deserializer)?;
-    s.parse::<i128>().map_err(serde::de::Error::custom)
-}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use {
+    pump_decoder::event::DecodedEvent,
+    solana_defi_core::{
+        schema::Record,
+        types::{DecodedInstruction, ParsedConfirmedTransactionWithStatusMeta},
+    },
+};
+
+pub fn pump_amm_formatter(
+    original: ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>,
+) -> Option<Record<DecodedInstruction<DecodedEvent>>> {
+    let meta = &original.meta;
+    let tx = &original.transaction;
+
+    let migrated_transaction = tx.message.instructions
+        .iter()
+        .chain(meta.inner_instructions.iter())
+        .find(|instr| instr.name == "migrate")?;
+    Some(Record::new("pump_migration", &original, migrated_transaction.clone()))
+}
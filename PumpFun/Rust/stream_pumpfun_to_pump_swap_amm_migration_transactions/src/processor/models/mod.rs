@@ -1,2 +0,0 @@
-pub mod mapper;
-pub mod serialize;
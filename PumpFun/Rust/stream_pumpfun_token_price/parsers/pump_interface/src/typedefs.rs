@@ -1,80 +0,0 @@
-#[cfg(feature = "serde")]
-
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
-#[derive(Default, Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct OpenPositionBumps {
-    pub position_bump: u8,
-}
-#[derive(Default, Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct OpenPositionWithMetadataBumps {
-    pub position_bump: u8,
-    pub metadata_bump: u8,
-}
-#[derive(Default, Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct OptionBool {
-    pub value: u8,
-}
-
-impl OptionBool {
-    pub fn none() -> Self {
-        Self { value: 0 } 
-    }
-    
-    pub fn some(value: bool) -> Self {
-        Self { 
-            value: if value { 1 } else { 2 }  
-        }
-    }
-}
-#[derive(Default, Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct WhirlpoolBumps {
-    pub whirlpool_bump: u8,
-}
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct RemainingAccountsSlice {
-    pub accounts_type: AccountsType,
-    pub length: u8,
-}
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct RemainingAccountsInfo {
-    pub slices: Vec<RemainingAccountsSlice>,
-}
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum CurrIndex {
-    Below,
-    Inside,
-    Above,
-}
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum TickLabel {
-    Upper,
-    Lower,
-}
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum Direction {
-    Left,
-    Right,
-}
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum AccountsType {
-    TransferHookA,
-    TransferHookB,
-    TransferHookReward,
-    TransferHookInput,
-    TransferHookIntermediate,
-    TransferHookOutput,
-    SupplementalTickArrays,
-    SupplementalTickArraysOne,
-    SupplementalTickArraysTwo,
-}
\ No newline at end of file
@@ -6,20 +6,25 @@ use {
         sink::SinkExt,         
         stream::StreamExt,
     },
-    log::{error, info},
-    solana_sdk::{
-        hash::Hash,
-        message::{v0::LoadedAddresses, MessageHeader},
-        pubkey::Pubkey,
-        signature::Signature,
-        transaction::{Result as TransactionResult}, 
-        message::v0::MessageAddressTableLookup,
-        transaction_context::TransactionReturnData,
+    log::{error, info, warn},
+    solana_defi_core::{
+        metadata_lookup::MetadataLookup,
+        processor::block_time::blocks_meta_filter,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        token_registry::TokenRegistry,
+        EventSink, TransactionProcessor,
+    },
+    std::{
+        collections::HashMap,
+        env,
+        path::Path,
+        str::FromStr,
+        sync::Arc,
+        time::{Duration, Instant},
     },
-    solana_transaction_status::{TransactionTokenBalance, Rewards},
-    std::{collections::HashMap, env, sync::Arc, time::Duration},
     tokio::sync::Mutex,
     tonic::transport::channel::ClientTlsConfig,
+    solana_sdk::pubkey::Pubkey,
     yellowstone_grpc_client::{GeyserGrpcClient, Interceptor},
     yellowstone_grpc_proto::{
         geyser::SubscribeRequestFilterTransactions,
@@ -28,14 +33,16 @@ use {
 };
 
 
-mod processor;
-use processor::TransactionProcessor;
-use processor::types::DecodedInstruction;
+mod parser;
+use pump_decoder::PumpDecoder;
+use parser::parsed_pump_txn;
 
 type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
 
 const PUMPFUN_PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
-const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const TOKEN_CACHE_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+/// How often the processing error counts and dropped sink records are logged.
+const STATS_LOG_INTERVAL: Duration = Duration::from_secs(60);
 
 
 #[derive(Debug, Clone, ClapParser)]
@@ -46,6 +53,25 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(long, help = "Directory to dump raw updates that fail to process")]
+    dump_failed_updates: Option<String>,
+
+    #[clap(long, help = "Pump IDL to use instead of the embedded one")]
+    idl: Option<String>,
+
+    #[clap(long, default_value = "token_registry.json", help = "Token metadata cache, can be shared with other streamers")]
+    token_cache: String,
+
+    #[clap(long, help = "RPC endpoint to look up the metadata of mints not in the token cache")]
+    rpc_url: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -82,7 +108,7 @@ impl Args {
             transactions,
             transactions_status: HashMap::default(),
             blocks: HashMap::default(),
-            blocks_meta: HashMap::default(),
+            blocks_meta: blocks_meta_filter(),
             entry: HashMap::default(),
             commitment: Some(CommitmentLevel::Processed as i32),
             accounts_data_slice: Vec::default(),
@@ -92,61 +118,6 @@ impl Args {
     }
 }
 
-#[derive(Clone, Debug)]
-struct TransactionEvent {
-    event_type: Option<String>,
-    user: Option<String>,
-    mint: Option<String>,
-    bonding_curve: Option<String>,
-    amount_in: Option<u64>,
-    amount_out: Option<u64>,
-}
-
-#[derive(Clone, Debug)]
-struct ParsedEventTransaction {
-    parsed_transaction: ParsedConfirmedTransactionWithStatusMeta,
-    market_details: TransactionEvent
-}
-
-#[derive(Clone, Debug)]
-pub struct ParsedConfirmedTransactionWithStatusMeta {
-    pub slot: u64,
-    pub transaction: ParsedTransaction,
-    pub meta: ParsedTransactionStatusMeta,
-    pub block_time: Option<i64>,
-}
-
-#[derive(Clone, Debug)]
-pub struct ParsedTransaction {
-    pub signatures: Vec<Signature>,
-    pub message: ParsedMessage,
-}
-
-#[derive(Clone, Debug)]
-pub struct ParsedMessage {
-    pub header: MessageHeader,
-    pub account_keys: Vec<Pubkey>,
-    pub recent_blockhash: Hash,
-    pub instructions: Vec<DecodedInstruction>,
-    pub address_table_lookups: Vec<MessageAddressTableLookup>,
-}
-
-#[derive(Clone, Debug)]
-pub struct ParsedTransactionStatusMeta {
-    pub status: TransactionResult<()>,
-    pub fee: u64,
-    pub pre_balances: Vec<u64>,
-    pub post_balances: Vec<u64>,
-    pub inner_instructions: Vec<DecodedInstruction>, 
-    pub log_messages: Option<Vec<String>>,
-    pub pre_token_balances: Option<Vec<TransactionTokenBalance>>,
-    pub post_token_balances: Option<Vec<TransactionTokenBalance>>,
-    pub rewards: Option<Rewards>,
-    pub loaded_addresses: LoadedAddresses,
-    pub return_data: Option<TransactionReturnData>,
-    pub compute_units_consumed: Option<u64>,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     env::set_var(
@@ -156,10 +127,23 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
+    let decoder = PumpDecoder::new(args.idl.as_deref().map(Path::new))?;
+    let mut processor = TransactionProcessor::new(decoder)?;
+    if let Some(dir) = &args.dump_failed_updates {
+        processor = processor.dump_failed_updates(dir);
+    }
+    // Kept across reconnects so transactions waiting for their block meta survive them.
+    let processor = Arc::new(Mutex::new(processor));
+    // Kept across reconnects so tokens learned since the last save survive them.
+    let tokens = Arc::new(Mutex::new(TokenRegistry::open(&args.token_cache)?));
     let zero_attempts = Arc::new(Mutex::new(true));
 
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
+        let processor = Arc::clone(&processor);
+        let tokens = Arc::clone(&tokens);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -176,7 +160,9 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            let mut processor = processor.lock().await;
+            let mut tokens = tokens.lock().await;
+            geyser_subscribe(client, request, &args, &sink, &mut processor, &mut tokens)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -191,22 +177,50 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    args: &Args,
+    sink: &Arc<dyn EventSink>,
+    processor: &mut TransactionProcessor<PumpDecoder>,
+    tokens: &mut TokenRegistry,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
     info!("stream opened");
 
-    let processor = TransactionProcessor::new()?;
+    let mut lookup = args.rpc_url.clone().map(MetadataLookup::spawn);
+    let mut last_saved = Instant::now();
+    let mut last_stats = Instant::now();
+    let mut last_dropped = 0;
 
     while let Some(message) = stream.next().await {
         match message {
             Ok(msg) => match msg.update_oneof {
                Some(UpdateOneof::Transaction(update)) => {
                  match processor.process_transaction_update(update) {
-                  Ok(Some(pumpfun_txn)) => println!("Pump Fun:\n{:#?}", pumpfun_txn),
-                  Ok(None) => (),
-                  Err(e) => (),
+                  Ok(ready) => {
+                      for txn in ready {
+                          let Some(pumpfun_txn) = parsed_pump_txn(txn, tokens) else {
+                              continue;
+                          };
+                          if let Some(lookup) = &mut lookup {
+                              lookup.request(tokens, Pubkey::from_str(&pumpfun_txn.mint));
+                          }
+                          sink.send(&pumpfun_txn)?;
+                      }
+                  }
+                  Err(e) => error!("Failed to process txn (skipping) [{}]: {e}", e.kind()),
                 }
             } 
+                Some(UpdateOneof::BlockMeta(update)) => {
+                    let ready = processor.process_block_meta_update(update);
+                    for txn in ready {
+                        let Some(pumpfun_txn) = parsed_pump_txn(txn, tokens) else {
+                            continue;
+                        };
+                        if let Some(lookup) = &mut lookup {
+                            lookup.request(tokens, Pubkey::from_str(&pumpfun_txn.mint));
+                        }
+                        sink.send(&pumpfun_txn)?;
+                    }
+                }
                 Some(UpdateOneof::Ping(_)) => {
                     subscribe_tx
                         .send(SubscribeRequest {
@@ -226,8 +240,32 @@ async fn geyser_subscribe(
                 break;
             }
         }
+
+        if let Some(lookup) = &mut lookup {
+            lookup.apply(tokens);
+        }
+
+        if last_saved.elapsed() >= TOKEN_CACHE_SAVE_INTERVAL {
+            if let Err(e) = tokens.save() {
+                error!("Failed to save the token cache: {e}");
+            }
+            last_saved = Instant::now();
+        }
+
+        if last_stats.elapsed() >= STATS_LOG_INTERVAL {
+            if !processor.error_counts().is_empty() {
+                info!("Processing errors so far: {:?}", processor.error_counts());
+            }
+            let dropped = sink.dropped();
+            if dropped != last_dropped {
+                warn!("Sink dropped {} records so far", dropped);
+                last_dropped = dropped;
+            }
+            last_stats = Instant::now();
+        }
     }
 
+    tokens.save()?;
     info!("stream closed");
     Ok(())
 }
@@ -0,0 +1,135 @@
+use {
+    pump_decoder::event::DecodedEvent,
+    serde::Serialize,
+    solana_defi_core::{
+        price::Price,
+        schema::{Record, SOL_DECIMALS, TokenAmount},
+        token_registry::{TokenInfo, TokenProgram, TokenRegistry},
+        types::ParsedConfirmedTransactionWithStatusMeta,
+    },
+    solana_sdk::pubkey::Pubkey,
+    std::str::FromStr,
+};
+
+/// Pump.fun mints all have 6 decimals.
+const PUMP_TOKEN_DECIMALS: u8 = 6;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PumpAmmSwapOutput  {
+   pub bonding_curve: String,
+   pub virtual_sol_reserves: TokenAmount,
+   pub virtual_token_reserves: TokenAmount,
+   pub real_sol_reserves : TokenAmount,
+   pub real_token_reserves : TokenAmount,
+   pub mint : String,
+   #[serde(skip_serializing_if = "Option::is_none")]
+   pub symbol : Option<String>,
+   #[serde(skip_serializing_if = "Option::is_none")]
+   pub name : Option<String>,
+   pub creator : String,
+   /// SOL per token.
+   pub price : Price,
+}
+
+/// Learns the name, symbol and program of the mints created in `txn`, and
+/// the decimals of every mint in its token balances.
+pub fn observe_pump_txn(
+    tokens: &mut TokenRegistry,
+    txn: &ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>,
+) {
+    let meta = &txn.meta;
+    tokens.observe_token_balances(meta.pre_token_balances.as_deref().unwrap_or_default());
+    tokens.observe_token_balances(meta.post_token_balances.as_deref().unwrap_or_default());
+
+    let events = txn
+        .transaction
+        .message
+        .instructions
+        .iter()
+        .chain(meta.inner_instructions.iter())
+        .flat_map(|instr| &instr.events);
+    for event in events {
+        if let DecodedEvent::CreateEvent(event) = event {
+            tokens.learn(
+                event.mint,
+                TokenInfo {
+                    symbol: Some(event.symbol.clone()).filter(|symbol| !symbol.is_empty()),
+                    name: Some(event.name.clone()).filter(|name| !name.is_empty()),
+                    decimals: Some(PUMP_TOKEN_DECIMALS),
+                    program: TokenProgram::from_program_id(&event.token_program),
+                },
+            );
+        }
+    }
+}
+
+pub fn parsed_pump_txn(
+    original: ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>,
+    tokens: &mut TokenRegistry,
+) -> Option<Record<PumpAmmSwapOutput>> {
+    const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+    observe_pump_txn(tokens, &original);
+    let meta = &original.meta;
+    let tx = &original.transaction;
+    let decimal = meta.pre_token_balances.as_ref()?
+        .iter()
+        .find(|balance| balance.mint != SOL_MINT)
+        .map(|balance| balance.ui_token_amount.decimals)
+        .unwrap_or(PUMP_TOKEN_DECIMALS);
+    let amm_instruction = tx
+        .message
+        .instructions
+        .iter()
+        .chain(meta.inner_instructions.iter())
+        .find(|instr| {
+            instr.name.to_lowercase() == "sell" || instr.name.to_lowercase() == "buy"
+        })?;
+
+    let (virtual_sol_reserve, virtual_token_reserve, real_sol_reserve, real_token_reserve, creator) = amm_instruction
+        .events
+        .iter()
+        .find_map(|event| match event {
+            DecodedEvent::TradeEvent(event) => Some((
+                event.virtual_sol_reserves,
+                event.virtual_token_reserves,
+                event.real_sol_reserves,
+                event.real_token_reserves,
+                event.creator.to_string(),
+            )),
+            _ => None,
+        })
+        .unwrap_or((0, 0, 0, 0, String::default()));
+
+    // Extract signer
+    let mint = amm_instruction
+        .accounts
+        .iter()
+        .find(|acc| acc.name == "mint")
+        .map(|acc| acc.pubkey.to_string())?;
+
+    let bonding_curve = amm_instruction
+        .accounts
+        .iter()
+        .find(|acc| acc.name == "bonding_curve")
+        .map(|acc| acc.pubkey.to_string())?;
+
+    let token = Pubkey::from_str(&mint).ok().and_then(|mint| tokens.get(&mint));
+    let symbol = token.and_then(|token| token.symbol.clone());
+    let name = token.and_then(|token| token.name.clone());
+
+     let price = Price::from_amounts(virtual_sol_reserve, SOL_DECIMALS, virtual_token_reserve, decimal)
+        .unwrap_or_default();
+    let output = PumpAmmSwapOutput  {
+                     bonding_curve: bonding_curve,
+                     virtual_sol_reserves: TokenAmount::lamports(virtual_sol_reserve),
+                     virtual_token_reserves: TokenAmount::new(virtual_token_reserve, decimal),
+                     real_sol_reserves : TokenAmount::lamports(real_sol_reserve),
+                     real_token_reserves : TokenAmount::new(real_token_reserve, decimal),
+                     mint : mint,
+                     symbol : symbol,
+                     name : name,
+                     creator : creator,
+                     price : price 
+                  };
+    Some(Record::new("pump_price", &original, output))
+}
@@ -7,6 +7,14 @@ use {
         stream::StreamExt,
     },
     log::{error, info},
+    serde::Serialize,
+    solana_defi_core::{
+        metadata_lookup::MetadataLookup,
+        models::serialize::serialization::serialize_token_balances,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        token_registry::TokenRegistry,
+        EventSink,
+    },
     solana_sdk::{
         hash::Hash,
         message::{v0::LoadedAddresses, MessageHeader},
@@ -17,7 +25,13 @@ use {
         transaction_context::TransactionReturnData,
     },
     solana_transaction_status::{TransactionTokenBalance, Rewards},
-    std::{collections::HashMap, env, sync::Arc, time::Duration},
+    std::{
+        collections::HashMap,
+        env,
+        str::FromStr,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
     tokio::sync::Mutex,
     tonic::transport::channel::ClientTlsConfig,
     yellowstone_grpc_client::{GeyserGrpcClient, Interceptor},
@@ -36,6 +50,7 @@ type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
 
 const PUMPFUN_AMM_PROGRAM_ID: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
 const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const TOKEN_CACHE_SAVE_INTERVAL: Duration = Duration::from_secs(30);
 
 
 #[derive(Debug, Clone, ClapParser)]
@@ -46,6 +61,19 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(long, default_value = "token_registry.json", help = "Token metadata cache, can be shared with other streamers")]
+    token_cache: String,
+
+    #[clap(long, help = "RPC endpoint to look up the metadata of mints not in the token cache")]
+    rpc_url: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -94,13 +122,13 @@ impl Args {
 
 
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 struct ParsedEventTransaction {
     parsed_transaction: ParsedConfirmedTransactionWithStatusMeta,
 }
 
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedConfirmedTransactionWithStatusMeta {
     pub slot: u64,
     pub transaction: ParsedTransaction,
@@ -108,13 +136,13 @@ pub struct ParsedConfirmedTransactionWithStatusMeta {
     pub block_time: Option<i64>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedTransaction {
     pub signatures: Vec<Signature>,
     pub message: ParsedMessage,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedMessage {
     pub header: MessageHeader,
     pub account_keys: Vec<Pubkey>,
@@ -123,7 +151,7 @@ pub struct ParsedMessage {
     pub address_table_lookups: Vec<MessageAddressTableLookup>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ParsedTransactionStatusMeta {
     pub status: TransactionResult<()>,
     pub fee: u64,
@@ -131,7 +159,9 @@ pub struct ParsedTransactionStatusMeta {
     pub post_balances: Vec<u64>,
     pub inner_instructions: Vec<DecodedInstruction>, 
     pub log_messages: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub pre_token_balances: Option<Vec<TransactionTokenBalance>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub post_token_balances: Option<Vec<TransactionTokenBalance>>,
     pub rewards: Option<Rewards>,
     pub loaded_addresses: LoadedAddresses,
@@ -148,10 +178,15 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
+    // Kept across reconnects so tokens learned since the last save survive them.
+    let processor = Arc::new(Mutex::new(TransactionProcessor::new(TokenRegistry::open(&args.token_cache)?)?));
     let zero_attempts = Arc::new(Mutex::new(true));
 
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
+        let processor = Arc::clone(&processor);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -168,7 +203,8 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            let mut processor = processor.lock().await;
+            geyser_subscribe(client, request, &args, &sink, &mut processor)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -183,18 +219,30 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    args: &Args,
+    sink: &Arc<dyn EventSink>,
+    processor: &mut TransactionProcessor,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
     info!("stream opened");
 
-    let processor = TransactionProcessor::new()?;
+    let mut lookup = args.rpc_url.clone().map(MetadataLookup::spawn);
+    let mut last_saved = Instant::now();
 
     while let Some(message) = stream.next().await {
         match message {
             Ok(msg) => match msg.update_oneof {
                Some(UpdateOneof::Transaction(update)) => {
                  match processor.process_transaction_update(update) {
-                  Ok(Some(pumpfun_txn)) => println!("Pump AMM:\n{:#?}", pumpfun_txn),
+                  Ok(Some(pumpfun_txn)) => {
+                      if let Some(lookup) = &mut lookup {
+                          let mints = [&pumpfun_txn.base_mint, &pumpfun_txn.quote_mint]
+                              .into_iter()
+                              .filter_map(|mint| Pubkey::from_str(mint).ok());
+                          lookup.request(&processor.tokens, mints);
+                      }
+                      sink.send(&pumpfun_txn)?;
+                  }
                   Ok(None) => (),
                   Err(e) => (),
                 }
@@ -218,8 +266,20 @@ async fn geyser_subscribe(
                 break;
             }
         }
+
+        if let Some(lookup) = &mut lookup {
+            lookup.apply(&mut processor.tokens);
+        }
+
+        if last_saved.elapsed() >= TOKEN_CACHE_SAVE_INTERVAL {
+            if let Err(e) = processor.tokens.save() {
+                error!("Failed to save the token cache: {e}");
+            }
+            last_saved = Instant::now();
+        }
     }
 
+    processor.tokens.save()?;
     info!("stream closed");
     Ok(())
 }
\ No newline at end of file
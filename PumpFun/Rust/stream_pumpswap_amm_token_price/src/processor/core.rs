@@ -14,6 +14,7 @@ use {
         ConfirmedTransactionWithStatusMeta, TransactionTokenBalance, TransactionWithStatusMeta, VersionedTransactionWithStatusMeta
     },
     solana_sdk::{pubkey::Pubkey, hash::Hash},
+    solana_defi_core::token_registry::TokenRegistry,
 };
 use crate::processor::models::mapper::instruction::Idl;
 use crate::PUMPFUN_AMM_PROGRAM_ID;
@@ -29,10 +30,12 @@ pub struct TransactionProcessor {
     pub token_idl: Idl,
     pub pumpfun_program_id: Pubkey,
     pub token_program_id: Pubkey,
+    /// Symbols and decimals of the mints seen, shared through its cache file.
+    pub tokens: TokenRegistry,
 }
 
 impl TransactionProcessor {
-    pub fn new() -> anyhow::Result<Self> {
+    pub fn new(tokens: TokenRegistry) -> anyhow::Result<Self> {
         let token_idl_json = fs::read_to_string("idls/token_program_idl.json")
             .context("Unable to read Token IDL JSON file")?;
         let pumpfun_idl = fs::read_to_string("idls/pump_amm_0.1.0.json")
@@ -43,11 +46,12 @@ impl TransactionProcessor {
             token_idl: serde_json::from_str(&token_idl_json)?,
             pumpfun_program_id: Pubkey::from_str(PUMPFUN_AMM_PROGRAM_ID)?,
             token_program_id: Pubkey::from_str(TOKEN_PROGRAM_ID)?,
+            tokens,
         })
     }
 
     pub fn process_transaction_update(
-    &self,
+    &mut self,
     update: SubscribeUpdateTransaction,
     ) -> anyhow::Result<Option<PumpAmmSwapOutput>> {
       let slot = update.slot;
@@ -90,6 +94,8 @@ impl TransactionProcessor {
                 decoded_inner.clone(),
                 block_time,
             )?;
+            self.tokens.observe_token_balances(parsed_txn.meta.pre_token_balances.as_deref().unwrap_or_default());
+            self.tokens.observe_token_balances(parsed_txn.meta.post_token_balances.as_deref().unwrap_or_default());
             let parsed_accounts = self.parse_swap_transaction_output(parsed_txn,decoded_inner.clone(),decoded_compiled.clone());
             Ok(parsed_accounts)
         } else {
@@ -8,6 +8,7 @@ use {
         ParsedTransactionStatusMeta,
         TransactionProcessor,
     },
+    serde::Serialize,
     solana_sdk::{
         instruction::AccountMeta,
         message::{v0::LoadedAddresses, VersionedMessage},
@@ -15,10 +16,12 @@ use {
     std::vec::Vec,
 };
 use crate::DecodedInstruction;
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PumpAmmSwapOutput {
     pub base_mint: String,
     pub quote_mint: String,
+    pub base_symbol: String,
+    pub quote_symbol: String,
     pub pool_base_token_reserve: String,
     pub pool_quote_token_reserve: String,
     pub price: String
@@ -93,10 +96,13 @@ impl TransactionProcessor {
             .or_else(|| compiled_instructions.get(0).and_then(|ix| ix.event.clone()))?;
         let base_mint = parsed_instruction.accounts.iter()
             .find(|acc| acc.name == "base_mint")
-            .map(|acc| acc.pubkey.to_string())?;
+            .map(|acc| acc.pubkey)?;
         let quote_mint = parsed_instruction.accounts.iter()
             .find(|acc| acc.name == "quote_mint")
-            .map(|acc| acc.pubkey.to_string())?;
+            .map(|acc| acc.pubkey)?;
+        let base_symbol = self.tokens.symbol(&base_mint);
+        let quote_symbol = self.tokens.symbol(&quote_mint);
+        let (base_mint, quote_mint) = (base_mint.to_string(), quote_mint.to_string());
         let (base_reserve, quote_reserve) = match parsed_event {
             DecodedEvent::BuyEvent(event) => (
                 event.pool_base_token_reserves,
@@ -128,6 +134,8 @@ impl TransactionProcessor {
         Some(PumpAmmSwapOutput {
             base_mint,
             quote_mint,
+            base_symbol,
+            quote_symbol,
             pool_base_token_reserve: base_reserve_pool,
             pool_quote_token_reserve: quote_reserve_pool,
             price: price.to_string() + " SOL",
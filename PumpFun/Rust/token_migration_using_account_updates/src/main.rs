@@ -18,6 +18,10 @@ use {
 };
 
 use solana_client::{rpc_client::RpcClient, rpc_config::RpcTransactionConfig};
+use solana_defi_core::{
+    sink::{sink_from_spec, DEFAULT_BUFFER},
+    EventSink,
+};
 use tokio::task;
 use solana_transaction_status::option_serializer::OptionSerializer;
 
@@ -39,6 +43,13 @@ struct Args {
 
     #[clap(long, help = "Solana RPC URL")]
     rpc_url: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -127,6 +138,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -134,6 +146,7 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -150,7 +163,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request,args.rpc_url)
+            geyser_subscribe(client, request,args.rpc_url, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -167,6 +180,7 @@ async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
     rpc_url: String,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
@@ -192,26 +206,31 @@ async fn geyser_subscribe(
                         let pubkey_str = bs58::encode(&account_data.pubkey).into_string();
                         let owner = bs58::encode(&account_data.owner).into_string();
 
-                        println!("Account Update Received for: {}, Owner: {} at slot: {}", pubkey_str, owner, slot);
+                        info!("Account update received for: {}, owner: {} at slot: {}", pubkey_str, owner, slot);
 
 
                         match Pubkey::from_str(&pubkey_str) {
                             Ok(pubkey) => {
                                 let rpc_url_clone = rpc_url.clone(); 
+                                let sink = Arc::clone(sink);
                                 // Spawn the function in a separate task to execute concurrently
                                 task::spawn(async move {
-                                    get_tokens_in_wallet(pubkey, current_timestamp, rpc_url_clone).await;
+                                    get_tokens_in_wallet(pubkey, current_timestamp, rpc_url_clone, &sink).await;
                                 });
                             }
                             Err(e) => {
-                                println!("Failed to parse pubkey: {}", e);
+                                error!("Failed to parse pubkey: {}", e);
                             }
                         }
 
                         if let Some(decoded_structure) = decode_account_data(&account_data.data) {
-                            println!("Decoded Structure for account {}: {:#?}", pubkey_str, decoded_structure);
+                            sink.send(&serde_json::json!({
+                                "pubkey": pubkey_str,
+                                "slot": slot,
+                                "decoded_data": decoded_structure
+                            }))?;
                         } else {
-                            println!("Failed to decode structure for account {}", pubkey_str);
+                            error!("Failed to decode structure for account {}", pubkey_str);
                         }
                     }
 
@@ -245,7 +264,7 @@ async fn geyser_subscribe(
 fn decode_account_data(data: &[u8]) -> Option<Structure> {
     if data.len() < 49 {
         // 8 bytes * 6 (for u64) + 1 byte (for bool) = 49 bytes minimum
-        println!("Data length too short to decode.");
+        error!("Data length too short to decode.");
         return None;
     }
 
@@ -272,7 +291,7 @@ fn decode_account_data(data: &[u8]) -> Option<Structure> {
 }
 
 
-async fn get_tokens_in_wallet(address: Pubkey, current_timestamp: u64, rpc_url: String) {
+async fn get_tokens_in_wallet(address: Pubkey, current_timestamp: u64, rpc_url: String, sink: &Arc<dyn EventSink>) {
     //let rpc_url = "https://rpc.shyft.to?api_key=";
     let client = RpcClient::new(rpc_url.to_string());
 
@@ -323,19 +342,25 @@ async fn get_tokens_in_wallet(address: Pubkey, current_timestamp: u64, rpc_url:
                             if let OptionSerializer::Some(post_balances) = &meta.post_token_balances {
                                 for balance in post_balances.iter() {
                                     if balance.ui_token_amount.amount == "0" {
-                                        println!("Mint being transferred: {}", balance.mint);
+                                        let record = serde_json::json!({
+                                            "bonding_curve": address.to_string(),
+                                            "migrated_mint": balance.mint
+                                        });
+                                        if let Err(e) = sink.send(&record) {
+                                            error!("Failed to send migrated mint: {}", e);
+                                        }
                                     }
                                 }
                             }
                         }
                     },
-                    Err(e) => println!("Failed to fetch transaction: {}", e),
+                    Err(e) => error!("Failed to fetch transaction: {}", e),
                 }
             } else {
-                println!("No valid signature found.");
+                info!("No valid signature found.");
             }
         }
-        Err(e) => println!("Error fetching signatures: {}", e),
+        Err(e) => error!("Error fetching signatures: {}", e),
     }
 }
 
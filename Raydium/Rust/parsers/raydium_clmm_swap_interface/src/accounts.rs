@@ -4,7 +4,7 @@ use serde_with::serde_as;
 use serde_with::DisplayFromStr;
 use crate::typedefs::{TickState,Observation,RewardInfo};
 pub const AMM_CONFIG_ACCOUNT_DISCM: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[derive(Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AmmConfig {
    pub bump: u8, 
@@ -101,7 +101,7 @@ impl ObservationStateAccount {
 }
 pub const POOL_STATE_ACCOUNT_DISCM: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
 #[serde_as]
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[derive(Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
   pub struct PoolState {
     pub bump: [u8; 1],
@@ -137,7 +137,7 @@ pub const POOL_STATE_ACCOUNT_DISCM: [u8; 8] = [247, 237, 227, 245, 215, 195, 222
     #[serde_as(as = "DisplayFromStr")]
     pub swap_out_amount_token_0: u128,
     pub status: u8,
-    pub padding: Vec<u8>,
+    pub padding: [u8; 7],
     pub reward_infos: [RewardInfo; 3],
     pub tick_array_bitmap: [u64; 16],
     pub total_fees_token_0: u64,
@@ -148,8 +148,8 @@ pub const POOL_STATE_ACCOUNT_DISCM: [u8; 8] = [247, 237, 227, 245, 215, 195, 222
     pub fund_fees_token_1: u64,
     pub open_time: u64,
     pub recent_epoch: u64,
-    pub padding1: Vec<u8>,
-    pub padding2: Vec<u8>,
+    pub padding1: [u64; 24],
+    pub padding2: [u64; 32],
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct PoolStateAccount(pub PoolState);
@@ -188,18 +188,14 @@ pub const TICK_ARRAY_STATE_DISCM: [u8; 8] = [192, 155, 85, 205, 49, 249, 129, 42
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickArrayState {
-    pub tick: i32,
-    #[serde_as(as = "DisplayFromStr")]
-    pub liquidity_net: i128,
-    #[serde_as(as = "DisplayFromStr")]
-    pub liquidity_gross: u128,
-    #[serde_as(as = "DisplayFromStr")]
-    pub fee_growth_outside_0_x64: u128,
-    #[serde_as(as = "DisplayFromStr")]
-    pub fee_growth_outside_1_x64: u128,
-    #[serde_as(as = "Vec<DisplayFromStr>")]
-    pub reward_growths_outside_x64: Vec<u128>,
-    pub padding: Vec<u32>,
+    pub pool_id: Pubkey,
+    pub start_tick_index: i32,
+    #[serde_as(as = "[_; 60]")]
+    pub ticks: [TickState; 60],
+    pub initialized_tick_count: u8,
+    pub recent_epoch: u64,
+    #[serde_as(as = "[_; 107]")]
+    pub padding: [u8; 107],
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct TickArrayStateAccount(pub TickArrayState);
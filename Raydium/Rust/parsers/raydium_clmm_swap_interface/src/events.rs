@@ -357,19 +357,24 @@ impl CollectProtocolFeeEventEvent {
     }
 }
 
-// SwapEvent (already provided in the example, included for completeness)
+// SwapEvent
 pub const SWAP_EVENT_DISCM: [u8; 8] = [64, 198, 205, 232, 38, 8, 113, 226];
 
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwapEvent {
-    pub pool_id: Pubkey,
-    pub input_vault_before: u64,
-    pub output_vault_before: u64,
-    pub input_amount: u64,
-    pub output_amount: u64,
-    pub input_transfer_fee: u64,
-    pub output_transfer_fee: u64,
-    pub base_input: bool,
+    pub pool_state: Pubkey,
+    pub sender: Pubkey,
+    pub token_account_0: Pubkey,
+    pub token_account_1: Pubkey,
+    pub amount_0: u64,
+    pub transfer_fee_0: u64,
+    pub amount_1: u64,
+    pub transfer_fee_1: u64,
+    pub zero_for_one: bool,
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick: i32,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -1,4 +1,6 @@
 solana_program::declare_id!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+pub mod accounts;
+pub mod swap;
 pub mod typedefs;
 pub use typedefs::*;
 pub mod instructions;
@@ -0,0 +1,874 @@
+//! Exact-in and exact-out swap simulation over a decoded `PoolState`, its
+//! `AmmConfig` and `TickArrayState`s, walking initialized ticks and rounding
+//! the way the CLMM program does.
+
+use crate::accounts::{AmmConfig, PoolState, TickArrayState};
+use crate::typedefs::TickState;
+use solana_program::{pubkey, pubkey::Pubkey};
+use big_num::U512;
+use thiserror::Error;
+
+pub const RAYDIUM_CLMM_PROGRAM_ID: Pubkey = pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+
+pub const TICK_ARRAY_SIZE: i32 = 60;
+pub const MIN_TICK: i32 = -443636;
+pub const MAX_TICK: i32 = -MIN_TICK;
+/// `get_sqrt_price_at_tick(MIN_TICK)` and `get_sqrt_price_at_tick(MAX_TICK)`.
+pub const MIN_SQRT_PRICE_X64: u128 = 4295048016;
+pub const MAX_SQRT_PRICE_X64: u128 = 79226673521066979257578248091;
+/// Denominator of the `AmmConfig` fee rates.
+pub const FEE_RATE_DENOMINATOR: u32 = 1_000_000;
+
+mod big_num {
+    // The lints fire on the code `construct_uint!` expands to.
+    #![allow(clippy::all)]
+
+    uint::construct_uint! {
+        /// Wide enough for every intermediate product of the swap math: token 0
+        /// amounts multiply `liquidity << 64` by a sqrt price, past 256 bits.
+        pub(super) struct U512(8);
+    }
+}
+
+/// `PoolState::status` bit that disables swaps.
+const SWAP_DISABLED: u8 = 1 << 4;
+/// Tick arrays the pool's own bitmap tracks on each side of tick zero.
+const TICK_ARRAY_BITMAP_SIZE: i32 = 512;
+
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SwapError {
+    #[error("Swap amount is zero")]
+    ZeroAmount,
+    #[error("Swaps are disabled on this pool")]
+    SwapDisabled,
+    #[error("Sqrt price limit is out of range or on the wrong side of the pool price")]
+    SqrtPriceLimitOverflow,
+    #[error("Tick array {0} is needed but was not provided")]
+    MissingTickArray(i32),
+    #[error("Tick array {0} has no initialized tick")]
+    InvalidTickArray(i32),
+    #[error("Swap leaves the range of the pool's tick array bitmap")]
+    TickArrayBitmapExtensionRequired,
+    #[error("Not enough liquidity in the swap direction")]
+    InsufficientLiquidity,
+    #[error("Input or output amount rounds to zero")]
+    TooSmallAmount,
+    #[error("Math overflow")]
+    MathOverflow,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SwapQuote {
+    pub zero_for_one: bool,
+    pub is_base_input: bool,
+    /// Input taken by the pool, trade fee included.
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// `amount_0` and `amount_1` of the program's `SwapEvent`.
+    pub amount_0: u64,
+    pub amount_1: u64,
+    /// Trade fee charged on the input, protocol and fund fees included.
+    pub fee_amount: u64,
+    pub protocol_fee: u64,
+    pub fund_fee: u64,
+    /// Pool price, liquidity and tick after the swap.
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick_current: i32,
+    /// Start indices of the tick arrays the swap loads, in the order the
+    /// program expects them.
+    pub tick_arrays: Vec<i32>,
+}
+
+impl SwapQuote {
+    /// Addresses of `tick_arrays`, to pass as the swap's tick array accounts.
+    pub fn tick_array_addresses(&self, pool_id: &Pubkey) -> Vec<Pubkey> {
+        self.tick_arrays
+            .iter()
+            .map(|start| tick_array_address(pool_id, *start))
+            .collect()
+    }
+}
+
+pub fn tick_array_address(pool_id: &Pubkey, start_tick_index: i32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"tick_array", pool_id.as_ref(), &start_tick_index.to_be_bytes()],
+        &RAYDIUM_CLMM_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Swap exactly `amount_in` of token 0 (`zero_for_one`) or token 1.
+pub fn swap_exact_in(
+    pool: &PoolState,
+    amm_config: &AmmConfig,
+    tick_arrays: &[TickArrayState],
+    amount_in: u64,
+    zero_for_one: bool,
+) -> Result<SwapQuote, SwapError> {
+    simulate_swap(pool, amm_config, tick_arrays, amount_in, 0, zero_for_one, true)
+}
+
+/// Swap for exactly `amount_out` of token 1 (`zero_for_one`) or token 0.
+pub fn swap_exact_out(
+    pool: &PoolState,
+    amm_config: &AmmConfig,
+    tick_arrays: &[TickArrayState],
+    amount_out: u64,
+    zero_for_one: bool,
+) -> Result<SwapQuote, SwapError> {
+    simulate_swap(pool, amm_config, tick_arrays, amount_out, 0, zero_for_one, false)
+}
+
+/// The program's `swap_internal`. `amount_specified` is the input when
+/// `is_base_input` and the output otherwise, after any Token-2022 transfer
+/// fee; a zero `sqrt_price_limit_x64` means no limit. `tick_arrays` may hold
+/// any tick arrays of the pool, in any order.
+pub fn simulate_swap(
+    pool: &PoolState,
+    amm_config: &AmmConfig,
+    tick_arrays: &[TickArrayState],
+    amount_specified: u64,
+    sqrt_price_limit_x64: u128,
+    zero_for_one: bool,
+    is_base_input: bool,
+) -> Result<SwapQuote, SwapError> {
+    if amount_specified == 0 {
+        return Err(SwapError::ZeroAmount);
+    }
+    if pool.status & SWAP_DISABLED != 0 {
+        return Err(SwapError::SwapDisabled);
+    }
+    let sqrt_price_limit_x64 = match sqrt_price_limit_x64 {
+        0 if zero_for_one => MIN_SQRT_PRICE_X64 + 1,
+        0 => MAX_SQRT_PRICE_X64 - 1,
+        limit => limit,
+    };
+    let limit_in_range = if zero_for_one {
+        sqrt_price_limit_x64 < pool.sqrt_price_x64 && sqrt_price_limit_x64 > MIN_SQRT_PRICE_X64
+    } else {
+        sqrt_price_limit_x64 > pool.sqrt_price_x64 && sqrt_price_limit_x64 < MAX_SQRT_PRICE_X64
+    };
+    if !limit_in_range {
+        return Err(SwapError::SqrtPriceLimitOverflow);
+    }
+
+    let tick_spacing = pool.tick_spacing;
+    // The first array is the current tick's if it has initialized ticks,
+    // otherwise the next one in the swap direction.
+    let (mut in_current_array, mut array_start) = first_tick_array(pool, zero_for_one)?;
+    let mut array = find_tick_array(tick_arrays, array_start)?;
+    let mut loaded = vec![array_start];
+
+    let mut remaining = amount_specified;
+    let mut calculated: u64 = 0;
+    let mut sqrt_price_x64 = pool.sqrt_price_x64;
+    let mut tick = pool.tick_current;
+    let mut liquidity = pool.liquidity;
+    let (mut fee_amount, mut protocol_fee, mut fund_fee) = (0u64, 0u64, 0u64);
+
+    while remaining != 0 && sqrt_price_x64 != sqrt_price_limit_x64 && tick < MAX_TICK && tick > MIN_TICK {
+        let sqrt_price_start_x64 = sqrt_price_x64;
+        let next_tick = match next_initialized_tick(array, tick, tick_spacing, zero_for_one) {
+            Some(next_tick) => next_tick,
+            None if !in_current_array => {
+                in_current_array = true;
+                first_initialized_tick(array, zero_for_one)?
+            }
+            None => {
+                array_start = next_tick_array(pool, array_start, zero_for_one)?;
+                array = find_tick_array(tick_arrays, array_start)?;
+                loaded.push(array_start);
+                first_initialized_tick(array, zero_for_one)?
+            }
+        };
+
+        let tick_next = next_tick.tick.clamp(MIN_TICK, MAX_TICK);
+        let sqrt_price_next_x64 = get_sqrt_price_at_tick(tick_next)?;
+        let past_limit = if zero_for_one {
+            sqrt_price_next_x64 < sqrt_price_limit_x64
+        } else {
+            sqrt_price_next_x64 > sqrt_price_limit_x64
+        };
+        let target = if past_limit {
+            sqrt_price_limit_x64
+        } else {
+            sqrt_price_next_x64
+        };
+
+        let step = compute_swap_step(
+            sqrt_price_x64,
+            target,
+            liquidity,
+            remaining,
+            amm_config.trade_fee_rate,
+            is_base_input,
+            zero_for_one,
+        )?;
+        sqrt_price_x64 = step.sqrt_price_next_x64;
+
+        let amount_in = step
+            .amount_in
+            .checked_add(step.fee_amount)
+            .ok_or(SwapError::MathOverflow)?;
+        let (spent, received) = if is_base_input {
+            (amount_in, step.amount_out)
+        } else {
+            (step.amount_out, amount_in)
+        };
+        remaining = remaining.checked_sub(spent).ok_or(SwapError::MathOverflow)?;
+        calculated = calculated.checked_add(received).ok_or(SwapError::MathOverflow)?;
+
+        // Protocol and fund fees are shares of the step's trade fee.
+        fee_amount = fee_amount.checked_add(step.fee_amount).ok_or(SwapError::MathOverflow)?;
+        protocol_fee += fee_share(step.fee_amount, amm_config.protocol_fee_rate);
+        fund_fee += fee_share(step.fee_amount, amm_config.fund_fee_rate);
+
+        if sqrt_price_x64 == sqrt_price_next_x64 {
+            let liquidity_net = if zero_for_one {
+                next_tick.liquidity_net.checked_neg().ok_or(SwapError::MathOverflow)?
+            } else {
+                next_tick.liquidity_net
+            };
+            liquidity = liquidity
+                .checked_add_signed(liquidity_net)
+                .ok_or(SwapError::MathOverflow)?;
+            tick = if zero_for_one { tick_next - 1 } else { tick_next };
+        } else if sqrt_price_x64 != sqrt_price_start_x64 {
+            tick = get_tick_at_sqrt_price(sqrt_price_x64)?;
+        }
+    }
+
+    let filled = amount_specified - remaining;
+    let (amount_0, amount_1) = if zero_for_one == is_base_input {
+        (filled, calculated)
+    } else {
+        (calculated, filled)
+    };
+    if amount_0 == 0 || amount_1 == 0 {
+        return Err(SwapError::TooSmallAmount);
+    }
+    let (amount_in, amount_out) = if zero_for_one {
+        (amount_0, amount_1)
+    } else {
+        (amount_1, amount_0)
+    };
+
+    Ok(SwapQuote {
+        zero_for_one,
+        is_base_input,
+        amount_in,
+        amount_out,
+        amount_0,
+        amount_1,
+        fee_amount,
+        protocol_fee,
+        fund_fee,
+        sqrt_price_x64,
+        liquidity,
+        tick_current: tick,
+        tick_arrays: loaded,
+    })
+}
+
+/// Start index of the tick array holding `tick`.
+pub fn tick_array_start_index(tick: i32, tick_spacing: u16) -> i32 {
+    let ticks = ticks_in_array(tick_spacing);
+    tick.div_euclid(ticks) * ticks
+}
+
+/// Q64.64 square root of `1.0001^tick`.
+pub fn get_sqrt_price_at_tick(tick: i32) -> Result<u128, SwapError> {
+    const RATIOS: [u128; 18] = [
+        0xfff97272373d4000,
+        0xfff2e50f5f657000,
+        0xffe5caca7e10f000,
+        0xffcb9843d60f7000,
+        0xff973b41fa98e800,
+        0xff2ea16466c9b000,
+        0xfe5dee046a9a3800,
+        0xfcbe86c7900bb000,
+        0xf987a7253ac65800,
+        0xf3392b0822bb6000,
+        0xe7159475a2caf000,
+        0xd097f3bdfd2f2000,
+        0xa9f746462d9f8000,
+        0x70d869a156f31c00,
+        0x31be135f97ed3200,
+        0x9aa508b5b85a500,
+        0x5d6af8dedc582c,
+        0x2216e584f5fa,
+    ];
+
+    let abs_tick = tick.unsigned_abs();
+    if abs_tick > MAX_TICK as u32 {
+        return Err(SwapError::MathOverflow);
+    }
+    let mut ratio: u128 = if abs_tick & 1 != 0 { 0xfffcb933bd6fb800 } else { 1 << 64 };
+    for (bit, factor) in RATIOS.iter().enumerate() {
+        if abs_tick & (2 << bit) != 0 {
+            ratio = (ratio * factor) >> 64;
+        }
+    }
+    if tick > 0 {
+        ratio = u128::MAX / ratio;
+    }
+    Ok(ratio)
+}
+
+/// Greatest tick whose sqrt price is at most `sqrt_price_x64`.
+pub fn get_tick_at_sqrt_price(sqrt_price_x64: u128) -> Result<i32, SwapError> {
+    // log_sqrt(1.0001)(2) in Q32.32 and the error margins of the 16 bit
+    // log2 approximation, in Q64.64.
+    const LOG_B_2_X32: i128 = 59543866431248;
+    const LOG_B_P_ERR_MARGIN_LOWER_X64: i128 = 184467440737095516;
+    const LOG_B_P_ERR_MARGIN_UPPER_X64: i128 = 15793534762490258745;
+    const BIT_PRECISION: u32 = 16;
+
+    if !(MIN_SQRT_PRICE_X64..MAX_SQRT_PRICE_X64).contains(&sqrt_price_x64) {
+        return Err(SwapError::MathOverflow);
+    }
+    let msb = 127 - sqrt_price_x64.leading_zeros();
+    let log2p_integer_x32 = (msb as i128 - 64) << 32;
+
+    let mut r = if msb >= 64 {
+        sqrt_price_x64 >> (msb - 63)
+    } else {
+        sqrt_price_x64 << (63 - msb)
+    };
+    let mut bit: i128 = 1 << 63;
+    let mut log2p_fraction_x64: i128 = 0;
+    for _ in 0..BIT_PRECISION {
+        r *= r;
+        let more_than_two = (r >> 127) as u32;
+        r >>= 63 + more_than_two;
+        log2p_fraction_x64 += bit * more_than_two as i128;
+        bit >>= 1;
+    }
+
+    let log_sqrt_10001_x64 = (log2p_integer_x32 + (log2p_fraction_x64 >> 32)) * LOG_B_2_X32;
+    let tick_low = ((log_sqrt_10001_x64 - LOG_B_P_ERR_MARGIN_LOWER_X64) >> 64) as i32;
+    let tick_high = ((log_sqrt_10001_x64 + LOG_B_P_ERR_MARGIN_UPPER_X64) >> 64) as i32;
+    if tick_low == tick_high || get_sqrt_price_at_tick(tick_high)? > sqrt_price_x64 {
+        Ok(tick_low)
+    } else {
+        Ok(tick_high)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct SwapStep {
+    sqrt_price_next_x64: u128,
+    amount_in: u64,
+    amount_out: u64,
+    fee_amount: u64,
+}
+
+/// One step of the swap, from `sqrt_price_current_x64` towards
+/// `sqrt_price_target_x64` within constant `liquidity`.
+fn compute_swap_step(
+    sqrt_price_current_x64: u128,
+    sqrt_price_target_x64: u128,
+    liquidity: u128,
+    amount_remaining: u64,
+    fee_rate: u32,
+    is_base_input: bool,
+    zero_for_one: bool,
+) -> Result<SwapStep, SwapError> {
+    let mut step = SwapStep::default();
+    // `None` when the whole range needs more than `u64::MAX`.
+    let amount_in_range = if zero_for_one == is_base_input {
+        get_delta_amount_0(sqrt_price_target_x64, sqrt_price_current_x64, liquidity, is_base_input)
+    } else {
+        get_delta_amount_1(sqrt_price_current_x64, sqrt_price_target_x64, liquidity, is_base_input)
+    };
+
+    if is_base_input {
+        let amount_remaining_less_fee = (amount_remaining as u128
+            * FEE_RATE_DENOMINATOR.saturating_sub(fee_rate) as u128
+            / FEE_RATE_DENOMINATOR as u128) as u64;
+        step.amount_in = amount_in_range.unwrap_or_default();
+        step.sqrt_price_next_x64 = match amount_in_range {
+            Some(amount_in) if amount_remaining_less_fee >= amount_in => sqrt_price_target_x64,
+            _ => next_sqrt_price_from_input(
+                sqrt_price_current_x64,
+                liquidity,
+                amount_remaining_less_fee,
+                zero_for_one,
+            )?,
+        };
+    } else {
+        step.amount_out = amount_in_range.unwrap_or_default();
+        step.sqrt_price_next_x64 = match amount_in_range {
+            Some(amount_out) if amount_remaining >= amount_out => sqrt_price_target_x64,
+            _ => next_sqrt_price_from_output(sqrt_price_current_x64, liquidity, amount_remaining, zero_for_one)?,
+        };
+    }
+
+    // Reaching the target keeps the range amount of the specified side.
+    let reached_target = sqrt_price_target_x64 == step.sqrt_price_next_x64;
+    let (lower, upper) = if zero_for_one {
+        (step.sqrt_price_next_x64, sqrt_price_current_x64)
+    } else {
+        (sqrt_price_current_x64, step.sqrt_price_next_x64)
+    };
+    if !reached_target || !is_base_input {
+        step.amount_in = if zero_for_one {
+            get_delta_amount_0(lower, upper, liquidity, true)
+        } else {
+            get_delta_amount_1(lower, upper, liquidity, true)
+        }
+        .ok_or(SwapError::MathOverflow)?;
+    }
+    if !reached_target || is_base_input {
+        step.amount_out = if zero_for_one {
+            get_delta_amount_1(lower, upper, liquidity, false)
+        } else {
+            get_delta_amount_0(lower, upper, liquidity, false)
+        }
+        .ok_or(SwapError::MathOverflow)?;
+    }
+    if !is_base_input {
+        step.amount_out = step.amount_out.min(amount_remaining);
+    }
+
+    step.fee_amount = if is_base_input && !reached_target {
+        // The input left over once the target can't be reached is all fee.
+        amount_remaining
+            .checked_sub(step.amount_in)
+            .ok_or(SwapError::MathOverflow)?
+    } else {
+        to_u64(mul_div(
+            U512::from(step.amount_in as u128),
+            U512::from(fee_rate as u128),
+            U512::from(FEE_RATE_DENOMINATOR.saturating_sub(fee_rate) as u128),
+            true,
+        ))?
+    };
+    Ok(step)
+}
+
+/// Token 0 between two sqrt prices: `L * (b - a) / (a * b)`.
+fn get_delta_amount_0(sqrt_price_a_x64: u128, sqrt_price_b_x64: u128, liquidity: u128, round_up: bool) -> Option<u64> {
+    let (a, b) = ordered(sqrt_price_a_x64, sqrt_price_b_x64);
+    let numerator = mul_div(
+        U512::from(liquidity) << 64,
+        U512::from(b - a),
+        U512::from(b),
+        round_up,
+    )?;
+    let amount = div(numerator, U512::from(a), round_up)?;
+    u64::try_from(amount).ok()
+}
+
+/// Token 1 between two sqrt prices: `L * (b - a)`.
+fn get_delta_amount_1(sqrt_price_a_x64: u128, sqrt_price_b_x64: u128, liquidity: u128, round_up: bool) -> Option<u64> {
+    let (a, b) = ordered(sqrt_price_a_x64, sqrt_price_b_x64);
+    let amount = mul_div(
+        U512::from(liquidity),
+        U512::from(b - a),
+        U512::one() << 64,
+        round_up,
+    )?;
+    u64::try_from(amount).ok()
+}
+
+fn next_sqrt_price_from_input(
+    sqrt_price_x64: u128,
+    liquidity: u128,
+    amount_in: u64,
+    zero_for_one: bool,
+) -> Result<u128, SwapError> {
+    if zero_for_one {
+        next_sqrt_price_from_amount_0(sqrt_price_x64, liquidity, amount_in, true)
+    } else {
+        next_sqrt_price_from_amount_1(sqrt_price_x64, liquidity, amount_in, true)
+    }
+}
+
+fn next_sqrt_price_from_output(
+    sqrt_price_x64: u128,
+    liquidity: u128,
+    amount_out: u64,
+    zero_for_one: bool,
+) -> Result<u128, SwapError> {
+    if zero_for_one {
+        next_sqrt_price_from_amount_1(sqrt_price_x64, liquidity, amount_out, false)
+    } else {
+        next_sqrt_price_from_amount_0(sqrt_price_x64, liquidity, amount_out, false)
+    }
+}
+
+/// `L * sqrt / (L ± amount * sqrt)`, rounded up.
+fn next_sqrt_price_from_amount_0(
+    sqrt_price_x64: u128,
+    liquidity: u128,
+    amount: u64,
+    add: bool,
+) -> Result<u128, SwapError> {
+    if amount == 0 {
+        return Ok(sqrt_price_x64);
+    }
+    let numerator = U512::from(liquidity) << 64;
+    let product = U512::from(amount as u128) * U512::from(sqrt_price_x64);
+    let denominator = if add {
+        numerator + product
+    } else {
+        numerator.checked_sub(product).ok_or(SwapError::MathOverflow)?
+    };
+    mul_div(numerator, U512::from(sqrt_price_x64), denominator, true)
+        .and_then(|price| u128::try_from(price).ok())
+        .ok_or(SwapError::MathOverflow)
+}
+
+/// `sqrt ± amount / L`, rounded down.
+fn next_sqrt_price_from_amount_1(
+    sqrt_price_x64: u128,
+    liquidity: u128,
+    amount: u64,
+    add: bool,
+) -> Result<u128, SwapError> {
+    if liquidity == 0 {
+        return Err(SwapError::MathOverflow);
+    }
+    let amount_x64 = (amount as u128) << 64;
+    if add {
+        sqrt_price_x64.checked_add(amount_x64 / liquidity)
+    } else {
+        sqrt_price_x64.checked_sub(amount_x64.div_ceil(liquidity))
+    }
+    .ok_or(SwapError::MathOverflow)
+}
+
+fn fee_share(fee_amount: u64, rate: u32) -> u64 {
+    (fee_amount as u128 * rate as u128 / FEE_RATE_DENOMINATOR as u128) as u64
+}
+
+fn ticks_in_array(tick_spacing: u16) -> i32 {
+    TICK_ARRAY_SIZE * tick_spacing as i32
+}
+
+/// Bit of the pool's tick array bitmap for the array at `start`, `None`
+/// outside the range the bitmap covers.
+fn bitmap_bit(start: i32, tick_spacing: u16) -> Option<usize> {
+    let bit = start.div_euclid(ticks_in_array(tick_spacing)) + TICK_ARRAY_BITMAP_SIZE;
+    (0..2 * TICK_ARRAY_BITMAP_SIZE).contains(&bit).then_some(bit as usize)
+}
+
+fn bitmap_is_set(bitmap: &[u64; 16], bit: usize) -> bool {
+    bitmap[bit / 64] >> (bit % 64) & 1 == 1
+}
+
+/// Start of the first tick array the swap loads, and whether it holds the
+/// current tick.
+fn first_tick_array(pool: &PoolState, zero_for_one: bool) -> Result<(bool, i32), SwapError> {
+    let start = tick_array_start_index(pool.tick_current, pool.tick_spacing);
+    let bit = bitmap_bit(start, pool.tick_spacing).ok_or(SwapError::TickArrayBitmapExtensionRequired)?;
+    if bitmap_is_set(&pool.tick_array_bitmap, bit) {
+        return Ok((true, start));
+    }
+    Ok((false, next_tick_array(pool, start, zero_for_one)?))
+}
+
+/// Start of the next initialized tick array after the one at `start`.
+fn next_tick_array(pool: &PoolState, start: i32, zero_for_one: bool) -> Result<i32, SwapError> {
+    let ticks = ticks_in_array(pool.tick_spacing);
+    let next = if zero_for_one { start - ticks } else { start + ticks };
+    let found = bitmap_bit(next, pool.tick_spacing).and_then(|from| {
+        let is_set = |bit: &usize| bitmap_is_set(&pool.tick_array_bitmap, *bit);
+        if zero_for_one {
+            (0..=from).rev().find(is_set)
+        } else {
+            (from..2 * TICK_ARRAY_BITMAP_SIZE as usize).find(is_set)
+        }
+    });
+    if let Some(bit) = found {
+        return Ok((bit as i32 - TICK_ARRAY_BITMAP_SIZE) * ticks);
+    }
+
+    // The search goes on in the bitmap extension account, unless the
+    // pool's own bitmap already reaches the end of the tick range.
+    let boundary = ticks * TICK_ARRAY_BITMAP_SIZE;
+    let beyond = if zero_for_one { -boundary - ticks } else { boundary };
+    if beyond < tick_array_start_index(MIN_TICK, pool.tick_spacing)
+        || beyond > tick_array_start_index(MAX_TICK, pool.tick_spacing)
+    {
+        Err(SwapError::InsufficientLiquidity)
+    } else {
+        Err(SwapError::TickArrayBitmapExtensionRequired)
+    }
+}
+
+fn find_tick_array(tick_arrays: &[TickArrayState], start: i32) -> Result<&TickArrayState, SwapError> {
+    tick_arrays
+        .iter()
+        .find(|array| array.start_tick_index == start)
+        .ok_or(SwapError::MissingTickArray(start))
+}
+
+/// Next initialized tick of `array` from `tick` in the swap direction,
+/// `None` when `tick` is outside the array. Swapping down includes `tick`
+/// itself.
+fn next_initialized_tick(
+    array: &TickArrayState,
+    tick: i32,
+    tick_spacing: u16,
+    zero_for_one: bool,
+) -> Option<&TickState> {
+    if tick_array_start_index(tick, tick_spacing) != array.start_tick_index {
+        return None;
+    }
+    let offset = ((tick - array.start_tick_index) / tick_spacing as i32) as usize;
+    if zero_for_one {
+        array.ticks[..=offset].iter().rev().find(is_initialized)
+    } else {
+        array.ticks[offset + 1..].iter().find(is_initialized)
+    }
+}
+
+fn first_initialized_tick(array: &TickArrayState, zero_for_one: bool) -> Result<&TickState, SwapError> {
+    let tick = if zero_for_one {
+        array.ticks.iter().rev().find(is_initialized)
+    } else {
+        array.ticks.iter().find(is_initialized)
+    };
+    tick.ok_or(SwapError::InvalidTickArray(array.start_tick_index))
+}
+
+fn is_initialized(tick: &&TickState) -> bool {
+    tick.liquidity_gross != 0
+}
+
+fn ordered(a: u128, b: u128) -> (u128, u128) {
+    if a > b {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+fn to_u64(value: Option<U512>) -> Result<u64, SwapError> {
+    value
+        .and_then(|value| u64::try_from(value).ok())
+        .ok_or(SwapError::MathOverflow)
+}
+
+/// `a * b / denominator`, `None` for a zero denominator.
+fn mul_div(a: U512, b: U512, denominator: U512, round_up: bool) -> Option<U512> {
+    div(a * b, denominator, round_up)
+}
+
+fn div(numerator: U512, denominator: U512, round_up: bool) -> Option<U512> {
+    if denominator.is_zero() {
+        return None;
+    }
+    let (quotient, remainder) = numerator.div_mod(denominator);
+    if round_up && !remainder.is_zero() {
+        Some(quotient + 1)
+    } else {
+        Some(quotient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPACING: u16 = 10;
+    /// Liquidity of a wide position over ticks [-1200, 1200) and a narrow
+    /// one over [-300, 300).
+    const WIDE: u128 = 10_000_000_000_000;
+    const NARROW: u128 = 5_000_000_000_000;
+
+    fn tick_array(start: i32, ticks: &[(i32, i128)]) -> TickArrayState {
+        let mut array = TickArrayState {
+            pool_id: Pubkey::default(),
+            start_tick_index: start,
+            ticks: std::array::from_fn(|i| TickState {
+                tick: start + i as i32 * SPACING as i32,
+                ..Default::default()
+            }),
+            initialized_tick_count: ticks.len() as u8,
+            recent_epoch: 0,
+            padding: [0; 107],
+        };
+        for (tick, liquidity_net) in ticks {
+            let state = &mut array.ticks[((tick - start) / SPACING as i32) as usize];
+            state.liquidity_net = *liquidity_net;
+            state.liquidity_gross = liquidity_net.unsigned_abs();
+        }
+        array
+    }
+
+    fn tick_arrays() -> Vec<TickArrayState> {
+        vec![
+            tick_array(-1200, &[(-1200, WIDE as i128)]),
+            tick_array(-600, &[(-300, NARROW as i128)]),
+            tick_array(0, &[(300, -(NARROW as i128))]),
+            tick_array(1200, &[(1200, -(WIDE as i128))]),
+        ]
+    }
+
+    fn pool(tick_arrays: &[TickArrayState]) -> PoolState {
+        let mut pool = PoolState {
+            tick_spacing: SPACING,
+            liquidity: WIDE + NARROW,
+            sqrt_price_x64: 1 << 64,
+            tick_current: 0,
+            ..Default::default()
+        };
+        for array in tick_arrays {
+            let bit = bitmap_bit(array.start_tick_index, SPACING).unwrap();
+            pool.tick_array_bitmap[bit / 64] |= 1 << (bit % 64);
+        }
+        pool
+    }
+
+    fn amm_config() -> AmmConfig {
+        AmmConfig {
+            trade_fee_rate: 2_500,
+            protocol_fee_rate: 120_000,
+            fund_fee_rate: 40_000,
+            tick_spacing: SPACING,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tick_math_round_trips() {
+        assert_eq!(get_sqrt_price_at_tick(0), Ok(1 << 64));
+        assert_eq!(get_sqrt_price_at_tick(MIN_TICK), Ok(MIN_SQRT_PRICE_X64));
+        assert_eq!(get_sqrt_price_at_tick(MAX_TICK), Ok(MAX_SQRT_PRICE_X64));
+        assert_eq!(get_sqrt_price_at_tick(MAX_TICK + 1), Err(SwapError::MathOverflow));
+
+        for tick in [MIN_TICK + 1, -300_001, -1_000, -1, 1, 7, 1_000, 300_001, MAX_TICK - 1] {
+            let sqrt_price_x64 = get_sqrt_price_at_tick(tick).unwrap();
+            assert_eq!(get_tick_at_sqrt_price(sqrt_price_x64), Ok(tick));
+            assert_eq!(get_tick_at_sqrt_price(sqrt_price_x64 - 1), Ok(tick - 1));
+        }
+        assert_eq!(tick_array_start_index(-1, SPACING), -600);
+        assert_eq!(tick_array_start_index(599, SPACING), 0);
+    }
+
+    #[test]
+    fn swap_within_one_tick_range() {
+        let arrays = tick_arrays();
+        let quote = swap_exact_in(&pool(&arrays), &amm_config(), &arrays, 1_000_000, true).unwrap();
+
+        assert_eq!(quote.amount_in, 1_000_000);
+        assert_eq!(quote.amount_out, 997_499);
+        assert_eq!((quote.amount_0, quote.amount_1), (1_000_000, 997_499));
+        assert_eq!(quote.fee_amount, 2_500);
+        assert_eq!(quote.protocol_fee, 300);
+        assert_eq!(quote.fund_fee, 100);
+        assert_eq!(quote.sqrt_price_x64, 18_446_742_847_001_152_291);
+        assert_eq!(quote.liquidity, WIDE + NARROW);
+        assert_eq!(quote.tick_current, -1);
+        // Tick -300 is the step's target, so its array is loaded too.
+        assert_eq!(quote.tick_arrays, vec![0, -600]);
+    }
+
+    #[test]
+    fn crossing_ticks_updates_liquidity_and_loads_arrays() {
+        let arrays = tick_arrays();
+        let pool = pool(&arrays);
+
+        let down = swap_exact_in(&pool, &amm_config(), &arrays, 300_000_000_000, true).unwrap();
+        assert_eq!(down.amount_out, 293_230_914_735);
+        assert_eq!(down.fee_amount, 750_000_001);
+        assert_eq!((down.protocol_fee, down.fund_fee), (89_999_999, 29_999_999));
+        assert_eq!(down.tick_current, -443);
+        assert_eq!(down.liquidity, WIDE);
+        assert_eq!(down.tick_arrays, vec![0, -600, -1200]);
+
+        // Past tick 300 the next initialized tick is 1200; the array at 600
+        // has none and is skipped.
+        let up = swap_exact_in(&pool, &amm_config(), &arrays, 500_000_000_000, false).unwrap();
+        assert_eq!(up.amount_out, 480_443_353_514);
+        assert_eq!(up.tick_current, 828);
+        assert_eq!(up.liquidity, WIDE);
+        assert_eq!(up.tick_arrays, vec![0, 1200]);
+    }
+
+    #[test]
+    fn exact_out_costs_what_exact_in_pays_for() {
+        let arrays = tick_arrays();
+        let pool = pool(&arrays);
+
+        let exact_out = swap_exact_out(&pool, &amm_config(), &arrays, 400_000_000_000, true).unwrap();
+        assert_eq!(exact_out.amount_out, 400_000_000_000);
+        assert_eq!(exact_out.amount_in, 413_113_526_487);
+        assert_eq!(exact_out.fee_amount, 1_032_783_818);
+        assert_eq!(exact_out.liquidity, WIDE);
+        assert_eq!(exact_out.tick_arrays, vec![0, -600, -1200]);
+
+        let exact_in = swap_exact_in(&pool, &amm_config(), &arrays, exact_out.amount_in, true).unwrap();
+        assert_eq!(exact_in.amount_out, exact_out.amount_out);
+    }
+
+    #[test]
+    fn price_limit_stops_the_swap() {
+        let arrays = tick_arrays();
+        let limit = get_sqrt_price_at_tick(-100).unwrap();
+        let quote = simulate_swap(
+            &pool(&arrays),
+            &amm_config(),
+            &arrays,
+            u32::MAX as u64 * 1_000,
+            limit,
+            true,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(quote.sqrt_price_x64, limit);
+        assert_eq!(quote.tick_current, -100);
+        assert_eq!((quote.amount_in, quote.amount_out), (75_372_475_535, 74_809_081_061));
+    }
+
+    #[test]
+    fn reports_what_the_swap_cannot_do() {
+        let arrays = tick_arrays();
+        let pool = pool(&arrays);
+        let config = amm_config();
+
+        assert_eq!(
+            swap_exact_in(&pool, &config, &arrays[2..], 300_000_000_000, true),
+            Err(SwapError::MissingTickArray(-600))
+        );
+        assert_eq!(
+            swap_exact_in(&pool, &config, &arrays, u64::MAX, false),
+            Err(SwapError::TickArrayBitmapExtensionRequired)
+        );
+        assert_eq!(
+            swap_exact_in(&pool, &config, &arrays, 0, true),
+            Err(SwapError::ZeroAmount)
+        );
+        assert_eq!(
+            simulate_swap(&pool, &config, &arrays, 1_000, 2 << 64, true, true),
+            Err(SwapError::SqrtPriceLimitOverflow)
+        );
+
+        let disabled = PoolState {
+            status: SWAP_DISABLED,
+            ..pool
+        };
+        assert_eq!(
+            swap_exact_in(&disabled, &config, &arrays, 1_000, true),
+            Err(SwapError::SwapDisabled)
+        );
+    }
+
+    #[test]
+    fn mul_div_rounds_and_rejects_a_zero_denominator() {
+        let (a, b, c) = (u64::MAX as u128 * 3, u64::MAX as u128 + 5, 7u128 << 70);
+        let floor = mul_div(a.into(), b.into(), c.into(), false).unwrap();
+        let ceil = mul_div(a.into(), b.into(), c.into(), true).unwrap();
+        let expected = (a / 8 * b) / (c / 8);
+        assert_eq!(u128::try_from(floor), Ok(expected));
+        assert_eq!(u128::try_from(ceil), Ok(expected + 1));
+        assert_eq!(mul_div(a.into(), b.into(), U512::zero(), false), None);
+    }
+}
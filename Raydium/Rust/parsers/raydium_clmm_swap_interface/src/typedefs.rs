@@ -1,7 +1,9 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-
+use solana_program::pubkey::Pubkey;
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq,Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeRewardParam {
     /// Reward open time
     pub open_time: u64,
@@ -12,6 +14,7 @@ pub struct InitializeRewardParam {
 }
 
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Observation {
     /// The block timestamp of the observation
     pub block_timestamp: u32,
@@ -22,12 +25,15 @@ pub struct Observation {
 }
 
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionRewardInfo {
     pub growth_inside_last_x64: u128,
     pub reward_amount_owed: u64,
 }
 
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[serde_as]
+#[derive(Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RewardInfo {
     /// Reward state
     pub reward_state: u8,
@@ -38,37 +44,47 @@ pub struct RewardInfo {
     /// Reward last update time
     pub last_update_time: u64,
     /// Q64.64 number indicates how many tokens per second are earned per unit of liquidity.
+    #[serde_as(as = "DisplayFromStr")]
     pub emissions_per_second_x64: u128,
     /// The total amount of reward emissioned
     pub reward_total_emissioned: u64,
     /// The total amount of claimed reward
     pub reward_claimed: u64,
     /// Reward token mint.
-    pub token_mint: [u8; 32],
+    pub token_mint: Pubkey,
     /// Reward vault token account.
-    pub token_vault: [u8; 32],
+    pub token_vault: Pubkey,
     /// The owner that has permission to set reward param
-    pub authority: [u8; 32],
+    pub authority: Pubkey,
     /// Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward emissions were turned on.
+    #[serde_as(as = "DisplayFromStr")]
     pub reward_growth_global_x64: u128,
 }
 
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[serde_as]
+#[derive(Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickState {
     pub tick: i32,
     /// Amount of net liquidity added/subtracted
+    #[serde_as(as = "DisplayFromStr")]
     pub liquidity_net: i128,
     /// Total position liquidity
+    #[serde_as(as = "DisplayFromStr")]
     pub liquidity_gross: u128,
     /// Fee growth outside 0
+    #[serde_as(as = "DisplayFromStr")]
     pub fee_growth_outside_0_x64: u128,
     /// Fee growth outside 1
+    #[serde_as(as = "DisplayFromStr")]
     pub fee_growth_outside_1_x64: u128,
+    #[serde_as(as = "[DisplayFromStr; 3]")]
     pub reward_growths_outside_x64: [u128; 3],
     pub padding: [u32; 13],
 }
 
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PoolStatusBitIndex {
     OpenPositionOrIncreaseLiquidity,
     DecreaseLiquidity,
@@ -78,12 +94,14 @@ pub enum PoolStatusBitIndex {
 }
 
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PoolStatusBitFlag {
     Enable,
     Disable,
 }
 
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RewardState {
     Uninitialized,
     Initialized,
@@ -91,4 +109,4 @@ pub enum RewardState {
     Ended,
 }
 
-pub type TickArryBitmap = [u64; 8];
+pub type TickArrayBitmap = [u64; 8];
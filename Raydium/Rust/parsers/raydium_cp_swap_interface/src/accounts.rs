@@ -14,7 +14,8 @@ pub struct AmmConfig {
     pub create_pool_fee: u64,
     pub protocol_owner: Pubkey,
     pub fund_owner: Pubkey,
-    pub padding: [u64; 16],
+    pub creator_fee_rate: u64,
+    pub padding: [u64; 15],
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct AmmConfigAccount(pub AmmConfig);
@@ -122,7 +123,15 @@ pub struct PoolState {
     pub fund_fees_token0: u64,
     pub fund_fees_token1: u64,
     pub open_time: u64,
-    pub padding: [u64; 32],
+    pub recent_epoch: u64,
+    /// 0: creator fee taken from either token (on the input), 1: only
+    /// token 0, 2: only token 1.
+    pub creator_fee_on: u8,
+    pub enable_creator_fee: bool,
+    pub padding1: [u8; 6],
+    pub creator_fees_token0: u64,
+    pub creator_fees_token1: u64,
+    pub padding: [u64; 28],
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct PoolStateAccount(pub PoolState);
@@ -2,16 +2,18 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 pub const LP_CHANGE_EVENT_EVENT_DISCM: [u8; 8] = [121, 163, 205, 201, 57, 218, 117, 60];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LpChangeEvent {
-    pool_id: Pubkey,
-    lp_amount_before: u64,
-    token0_vault_before: u64,
-    token1_vault_before: u64,
-    token0_amount: u64,
-    token1_amount: u64,
-    token0_transfer_fee: u64,
-    token1_transfer_fee: u64,
-    change_type: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub pool_id: Pubkey,
+    pub lp_amount_before: u64,
+    pub token0_vault_before: u64,
+    pub token1_vault_before: u64,
+    pub token0_amount: u64,
+    pub token1_amount: u64,
+    pub token0_transfer_fee: u64,
+    pub token1_transfer_fee: u64,
+    pub change_type: u8,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct LpChangeEventEvent(pub LpChangeEvent);
@@ -40,15 +42,26 @@ impl LpChangeEventEvent {
 }
 pub const SWAP_EVENT_EVENT_DISCM: [u8; 8] = [64, 198, 205, 232, 38, 8, 113, 226];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwapEvent {
-    pool_id: Pubkey,
-    input_vault_before: u64,
-    output_vault_before: u64,
-    input_amount: u64,
-    output_amount: u64,
-    input_transfer_fee: u64,
-    output_transfer_fee: u64,
-    base_input: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub pool_id: Pubkey,
+    pub input_vault_before: u64,
+    pub output_vault_before: u64,
+    /// Input that reached the vault, after the input transfer fee.
+    pub input_amount: u64,
+    /// Output that left the vault, before the output transfer fee.
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub base_input: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub input_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub output_mint: Pubkey,
+    pub trade_fee: u64,
+    pub creator_fee: u64,
+    pub creator_fee_on_input: bool,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct SwapEventEvent(pub SwapEvent);
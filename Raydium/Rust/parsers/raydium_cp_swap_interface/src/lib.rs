@@ -0,0 +1,29 @@
+solana_program::declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+/// The Anchor IDL of the Raydium CPMM program these bindings were generated from.
+pub const IDL: &str = include_str!("../idls/cpmm_idl.json");
+pub mod accounts;
+pub mod quote;
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
+pub mod events;
+pub use events::*;
+/// Serializes pubkeys as base58 strings instead of byte arrays.
+#[cfg(feature = "serde")]
+pub mod serde_pubkey {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use solana_program::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Pubkey::from_str(&s).map_err(D::Error::custom)
+    }
+}
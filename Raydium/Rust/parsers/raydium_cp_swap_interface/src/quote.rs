@@ -0,0 +1,482 @@
+//! Constant-product quotes for `swap_base_input` and `swap_base_output` over
+//! a decoded `PoolState` and its `AmmConfig`, rounded the way the CPMM
+//! program rounds them.
+
+use crate::accounts::{AmmConfig, PoolState};
+use thiserror::Error;
+
+/// Denominator of the `AmmConfig` fee rates.
+pub const FEE_RATE_DENOMINATOR: u64 = 1_000_000;
+
+/// `PoolState::status` bit that disables swaps.
+const SWAP_DISABLED: u8 = 1 << 2;
+
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+pub enum SwapError {
+    #[error("Swap amount is zero")]
+    ZeroAmount,
+    #[error("Swaps are disabled on this pool")]
+    SwapDisabled,
+    #[error("Not enough liquidity in the output vault")]
+    InsufficientLiquidity,
+    #[error("Input or output amount rounds to zero")]
+    TooSmallAmount,
+    #[error("Math overflow")]
+    MathOverflow,
+}
+
+/// Balances of the pool's token 0 and token 1 vaults.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Reserves {
+    pub token0: u64,
+    pub token1: u64,
+}
+
+impl Reserves {
+    /// Reads both vaults from their SPL Token or Token-2022 account data.
+    pub fn from_vault_accounts(token0_vault: &[u8], token1_vault: &[u8]) -> Option<Self> {
+        Some(Self {
+            token0: token_account_amount(token0_vault)?,
+            token1: token_account_amount(token1_vault)?,
+        })
+    }
+
+    /// The part of the vaults that belongs to liquidity providers: protocol,
+    /// fund and creator fees stay in the vaults until they are collected.
+    pub fn without_fees(&self, pool: &PoolState) -> Option<Self> {
+        let fees0 = pool
+            .protocol_fees_token0
+            .checked_add(pool.fund_fees_token0)?
+            .checked_add(pool.creator_fees_token0)?;
+        let fees1 = pool
+            .protocol_fees_token1
+            .checked_add(pool.fund_fees_token1)?
+            .checked_add(pool.creator_fees_token1)?;
+        Some(Self {
+            token0: self.token0.checked_sub(fees0)?,
+            token1: self.token1.checked_sub(fees1)?,
+        })
+    }
+}
+
+/// `amount` of a token account, stored after the mint and the owner.
+pub fn token_account_amount(data: &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(64..72)?.try_into().ok()?))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub zero_for_one: bool,
+    pub is_base_input: bool,
+    /// Input taken from the user, trade fee and any input creator fee included.
+    pub amount_in: u64,
+    /// Output sent to the user, any output creator fee deducted.
+    pub amount_out: u64,
+    /// Trade fee charged on the input, protocol and fund fees included.
+    pub trade_fee: u64,
+    pub protocol_fee: u64,
+    pub fund_fee: u64,
+    pub creator_fee: u64,
+    /// Whether `creator_fee` is charged on the input or the output token.
+    pub creator_fee_on_input: bool,
+    /// Vault balances without fees after the swap.
+    pub reserves_after: Reserves,
+}
+
+/// Swap exactly `amount_in` of token 0 (`zero_for_one`) or token 1.
+/// `reserves` are the vault balances, fees included.
+pub fn swap_base_input(
+    pool: &PoolState,
+    amm_config: &AmmConfig,
+    reserves: &Reserves,
+    amount_in: u64,
+    zero_for_one: bool,
+) -> Result<SwapQuote, SwapError> {
+    let swap = Swap::new(pool, amm_config, reserves, amount_in, zero_for_one)?;
+    let amount_in = amount_in as u128;
+
+    let trade_fee = trading_fee(amount_in, swap.trade_fee_rate)?;
+    let mut creator_fee = 0;
+    let amount_in_less_fees = if swap.creator_fee_on_input {
+        creator_fee = trading_fee(amount_in, swap.creator_fee_rate)?;
+        amount_in.checked_sub(trade_fee).and_then(|amount| amount.checked_sub(creator_fee))
+    } else {
+        amount_in.checked_sub(trade_fee)
+    }
+    .ok_or(SwapError::MathOverflow)?;
+
+    // Fees can take all of a tiny input into an empty vault.
+    let amount_out_swapped = (amount_in_less_fees * swap.output_vault)
+        .checked_div(swap.input_vault + amount_in_less_fees)
+        .ok_or(SwapError::TooSmallAmount)?;
+    let amount_out = if swap.creator_fee_on_input {
+        amount_out_swapped
+    } else {
+        creator_fee = trading_fee(amount_out_swapped, swap.creator_fee_rate)?;
+        amount_out_swapped - creator_fee
+    };
+    if amount_out == 0 {
+        return Err(SwapError::TooSmallAmount);
+    }
+
+    swap.quote(
+        true,
+        amount_in,
+        amount_out,
+        amount_in_less_fees,
+        amount_out_swapped,
+        trade_fee,
+        creator_fee,
+    )
+}
+
+/// Swap for exactly `amount_out` of token 1 (`zero_for_one`) or token 0.
+/// `reserves` are the vault balances, fees included.
+pub fn swap_base_output(
+    pool: &PoolState,
+    amm_config: &AmmConfig,
+    reserves: &Reserves,
+    amount_out: u64,
+    zero_for_one: bool,
+) -> Result<SwapQuote, SwapError> {
+    let swap = Swap::new(pool, amm_config, reserves, amount_out, zero_for_one)?;
+    let amount_out = amount_out as u128;
+
+    let mut creator_fee = 0;
+    let actual_amount_out = if swap.creator_fee_on_input {
+        amount_out
+    } else {
+        let amount_out_with_fee = pre_fee_amount(amount_out, swap.creator_fee_rate)?;
+        creator_fee = amount_out_with_fee - amount_out;
+        amount_out_with_fee
+    };
+    if actual_amount_out >= swap.output_vault {
+        return Err(SwapError::InsufficientLiquidity);
+    }
+
+    let amount_in_swapped =
+        raydium_ceil_div(swap.input_vault * actual_amount_out, swap.output_vault - actual_amount_out);
+    if amount_in_swapped == 0 {
+        return Err(SwapError::TooSmallAmount);
+    }
+    let (amount_in, trade_fee) = if swap.creator_fee_on_input {
+        let amount_in = pre_fee_amount(amount_in_swapped, swap.trade_fee_rate + swap.creator_fee_rate)?;
+        let total_fee = amount_in - amount_in_swapped;
+        creator_fee = split_creator_fee(total_fee, swap.trade_fee_rate, swap.creator_fee_rate);
+        (amount_in, total_fee - creator_fee)
+    } else {
+        let amount_in = pre_fee_amount(amount_in_swapped, swap.trade_fee_rate)?;
+        (amount_in, amount_in - amount_in_swapped)
+    };
+
+    swap.quote(
+        false,
+        amount_in,
+        amount_out,
+        amount_in_swapped,
+        actual_amount_out,
+        trade_fee,
+        creator_fee,
+    )
+}
+
+/// Creator fee rate that applies to `pool`.
+pub fn creator_fee_rate(pool: &PoolState, amm_config: &AmmConfig) -> u64 {
+    if pool.enable_creator_fee {
+        amm_config.creator_fee_rate
+    } else {
+        0
+    }
+}
+
+/// Whether the creator fee of a swap in this direction is charged on the
+/// input token. Pools that take it from one token only charge it in that
+/// token, on the output when the swap buys it.
+pub fn is_creator_fee_on_input(pool: &PoolState, zero_for_one: bool) -> bool {
+    match pool.creator_fee_on {
+        1 => zero_for_one,
+        2 => !zero_for_one,
+        _ => true,
+    }
+}
+
+/// Vaults, rates and fee side of one swap, oriented input to output.
+struct Swap<'a> {
+    amm_config: &'a AmmConfig,
+    zero_for_one: bool,
+    input_vault: u128,
+    output_vault: u128,
+    trade_fee_rate: u64,
+    creator_fee_rate: u64,
+    creator_fee_on_input: bool,
+}
+
+impl<'a> Swap<'a> {
+    fn new(
+        pool: &PoolState,
+        amm_config: &'a AmmConfig,
+        reserves: &Reserves,
+        amount: u64,
+        zero_for_one: bool,
+    ) -> Result<Self, SwapError> {
+        if amount == 0 {
+            return Err(SwapError::ZeroAmount);
+        }
+        if pool.status & SWAP_DISABLED != 0 {
+            return Err(SwapError::SwapDisabled);
+        }
+        let reserves = reserves.without_fees(pool).ok_or(SwapError::MathOverflow)?;
+        let (input_vault, output_vault) = if zero_for_one {
+            (reserves.token0, reserves.token1)
+        } else {
+            (reserves.token1, reserves.token0)
+        };
+
+        Ok(Self {
+            amm_config,
+            zero_for_one,
+            input_vault: input_vault as u128,
+            output_vault: output_vault as u128,
+            trade_fee_rate: amm_config.trade_fee_rate,
+            creator_fee_rate: creator_fee_rate(pool, amm_config),
+            creator_fee_on_input: is_creator_fee_on_input(pool, zero_for_one),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn quote(
+        &self,
+        is_base_input: bool,
+        amount_in: u128,
+        amount_out: u128,
+        amount_in_swapped: u128,
+        amount_out_swapped: u128,
+        trade_fee: u128,
+        creator_fee: u128,
+    ) -> Result<SwapQuote, SwapError> {
+        let input_vault = to_u64(self.input_vault + amount_in_swapped)?;
+        let output_vault = to_u64(self.output_vault - amount_out_swapped)?;
+        let reserves_after = if self.zero_for_one {
+            Reserves { token0: input_vault, token1: output_vault }
+        } else {
+            Reserves { token0: output_vault, token1: input_vault }
+        };
+
+        Ok(SwapQuote {
+            zero_for_one: self.zero_for_one,
+            is_base_input,
+            amount_in: to_u64(amount_in)?,
+            amount_out: to_u64(amount_out)?,
+            trade_fee: to_u64(trade_fee)?,
+            protocol_fee: to_u64(trade_fee * self.amm_config.protocol_fee_rate as u128 / FEE_RATE_DENOMINATOR as u128)?,
+            fund_fee: to_u64(trade_fee * self.amm_config.fund_fee_rate as u128 / FEE_RATE_DENOMINATOR as u128)?,
+            creator_fee: to_u64(creator_fee)?,
+            creator_fee_on_input: self.creator_fee_on_input,
+            reserves_after,
+        })
+    }
+}
+
+/// Trade and creator fees round up.
+fn trading_fee(amount: u128, rate: u64) -> Result<u128, SwapError> {
+    amount
+        .checked_mul(rate as u128)
+        .map(|fee| fee.div_ceil(FEE_RATE_DENOMINATOR as u128))
+        .ok_or(SwapError::MathOverflow)
+}
+
+/// Amount that leaves `post_fee_amount` once a fee of `rate` is taken.
+fn pre_fee_amount(post_fee_amount: u128, rate: u64) -> Result<u128, SwapError> {
+    if rate == 0 {
+        return Ok(post_fee_amount);
+    }
+    let denominator = FEE_RATE_DENOMINATOR
+        .checked_sub(rate)
+        .filter(|denominator| *denominator > 0)
+        .ok_or(SwapError::MathOverflow)? as u128;
+    post_fee_amount
+        .checked_mul(FEE_RATE_DENOMINATOR as u128)
+        .map(|numerator| numerator.div_ceil(denominator))
+        .ok_or(SwapError::MathOverflow)
+}
+
+/// Creator's share of a fee charged at `trade_fee_rate + creator_fee_rate`.
+fn split_creator_fee(total_fee: u128, trade_fee_rate: u64, creator_fee_rate: u64) -> u128 {
+    match trade_fee_rate + creator_fee_rate {
+        0 => 0,
+        total_rate => total_fee * creator_fee_rate as u128 / total_rate as u128,
+    }
+}
+
+/// The program's `checked_ceil_div`: a quotient below one rounds to the
+/// nearest integer instead of up.
+fn raydium_ceil_div(numerator: u128, denominator: u128) -> u128 {
+    match numerator / denominator {
+        0 if numerator * 2 >= denominator => 1,
+        0 => 0,
+        _ => numerator.div_ceil(denominator),
+    }
+}
+
+fn to_u64(value: u128) -> Result<u64, SwapError> {
+    u64::try_from(value).map_err(|_| SwapError::MathOverflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::pubkey::Pubkey;
+
+    fn amm_config() -> AmmConfig {
+        AmmConfig {
+            bump: 255,
+            disable_create_pool: false,
+            index: 0,
+            trade_fee_rate: 2_500,
+            protocol_fee_rate: 120_000,
+            fund_fee_rate: 40_000,
+            create_pool_fee: 150_000_000,
+            protocol_owner: Pubkey::new_unique(),
+            fund_owner: Pubkey::new_unique(),
+            creator_fee_rate: 1_000,
+            padding: [0; 15],
+        }
+    }
+
+    fn pool(enable_creator_fee: bool, creator_fee_on: u8) -> PoolState {
+        PoolState {
+            amm_config: Pubkey::new_unique(),
+            pool_creator: Pubkey::new_unique(),
+            token0_vault: Pubkey::new_unique(),
+            token1_vault: Pubkey::new_unique(),
+            lp_mint: Pubkey::new_unique(),
+            token0_mint: Pubkey::new_unique(),
+            token1_mint: Pubkey::new_unique(),
+            token0_program: Pubkey::new_unique(),
+            token1_program: Pubkey::new_unique(),
+            observation_key: Pubkey::new_unique(),
+            auth_bump: 255,
+            status: 0,
+            lp_mint_decimals: 9,
+            mint0_decimals: 9,
+            mint1_decimals: 6,
+            lp_supply: 0,
+            protocol_fees_token0: 1_200_000,
+            protocol_fees_token1: 3_400,
+            fund_fees_token0: 400_000,
+            fund_fees_token1: 1_100,
+            open_time: 0,
+            recent_epoch: 0,
+            creator_fee_on,
+            enable_creator_fee,
+            padding1: [0; 6],
+            creator_fees_token0: 400_000,
+            creator_fees_token1: 500,
+            padding: [0; 28],
+        }
+    }
+
+    /// 1 000 SOL against 150 000 USDC once the fees are taken out.
+    const RESERVES: Reserves = Reserves {
+        token0: 1_000_002_000_000,
+        token1: 150_000_005_000,
+    };
+
+    #[test]
+    fn base_input_without_creator_fee() {
+        let quote = swap_base_input(&pool(false, 0), &amm_config(), &RESERVES, 1_000_000_000, true).unwrap();
+
+        assert_eq!(quote.amount_out, 149_475_897);
+        assert_eq!(quote.trade_fee, 2_500_000);
+        assert_eq!(quote.protocol_fee, 300_000);
+        assert_eq!(quote.fund_fee, 100_000);
+        assert_eq!(quote.creator_fee, 0);
+        assert_eq!(
+            quote.reserves_after,
+            Reserves { token0: 1_000_997_500_000, token1: 149_850_524_103 }
+        );
+    }
+
+    #[test]
+    fn base_input_creator_fee_on_input_and_output() {
+        let on_input = swap_base_input(&pool(true, 0), &amm_config(), &RESERVES, 1_000_000_000, true).unwrap();
+        assert!(on_input.creator_fee_on_input);
+        assert_eq!(on_input.creator_fee, 1_000_000);
+        assert_eq!(on_input.amount_out, 149_326_196);
+
+        // Only token 1 pays the creator fee, so buying token 1 pays it on the output.
+        let on_output = swap_base_input(&pool(true, 2), &amm_config(), &RESERVES, 1_000_000_000, true).unwrap();
+        assert!(!on_output.creator_fee_on_input);
+        assert_eq!(on_output.creator_fee, 149_476);
+        assert_eq!(on_output.amount_out, 149_475_897 - 149_476);
+        assert_eq!(on_output.reserves_after.token1, 149_850_524_103);
+    }
+
+    #[test]
+    fn base_output_matches_base_input() {
+        for (enable_creator_fee, creator_fee_on) in [(false, 0), (true, 0), (true, 1), (true, 2)] {
+            let pool = pool(enable_creator_fee, creator_fee_on);
+            for zero_for_one in [true, false] {
+                let amount_out = if zero_for_one { 75_000_000 } else { 500_000_000 };
+                let exact_out = swap_base_output(&pool, &amm_config(), &RESERVES, amount_out, zero_for_one).unwrap();
+                assert_eq!(exact_out.amount_out, amount_out);
+
+                // Exact in rounds the trade and input creator fees up separately,
+                // exact out rounds their sum once, so they can be a unit apart.
+                let exact_in = |amount_in| {
+                    swap_base_input(&pool, &amm_config(), &RESERVES, amount_in, zero_for_one).unwrap().amount_out
+                };
+                assert!(exact_in(exact_out.amount_in + 1) >= amount_out);
+                assert!(exact_in(exact_out.amount_in - 2) < amount_out);
+            }
+        }
+    }
+
+    #[test]
+    fn base_output_splits_input_fees() {
+        let quote = swap_base_output(&pool(true, 0), &amm_config(), &RESERVES, 149_326_196, true).unwrap();
+
+        assert_eq!(quote.amount_in, 999_999_998);
+        assert_eq!(quote.trade_fee, 2_500_000);
+        assert_eq!(quote.creator_fee, 1_000_000);
+        assert_eq!(quote.protocol_fee, 300_000);
+        assert_eq!(quote.fund_fee, 100_000);
+    }
+
+    #[test]
+    fn rejects_what_the_program_rejects() {
+        let config = amm_config();
+        let mut disabled = pool(false, 0);
+        disabled.status = SWAP_DISABLED;
+
+        assert_eq!(swap_base_input(&pool(false, 0), &config, &RESERVES, 0, true), Err(SwapError::ZeroAmount));
+        assert_eq!(swap_base_input(&disabled, &config, &RESERVES, 1_000, true), Err(SwapError::SwapDisabled));
+        assert_eq!(
+            swap_base_output(&pool(false, 0), &config, &RESERVES, 150_000_000_000, true),
+            Err(SwapError::InsufficientLiquidity)
+        );
+        assert_eq!(swap_base_input(&pool(false, 0), &config, &RESERVES, 1, true), Err(SwapError::TooSmallAmount));
+
+        // Only fees left in the input vault, and a unit of input all paid as fee.
+        let drained = Reserves { token0: 2_000_000, ..RESERVES };
+        assert_eq!(swap_base_input(&pool(false, 0), &config, &drained, 1, true), Err(SwapError::TooSmallAmount));
+    }
+
+    #[test]
+    fn rounds_small_quotients_to_nearest() {
+        assert_eq!(raydium_ceil_div(4, 10), 0);
+        assert_eq!(raydium_ceil_div(5, 10), 1);
+        assert_eq!(raydium_ceil_div(11, 10), 2);
+        assert_eq!(pre_fee_amount(997_500, 2_500).unwrap(), 1_000_000);
+        assert_eq!(split_creator_fee(3_500, 2_500, 1_000), 1_000);
+    }
+
+    #[test]
+    fn reads_vault_amounts() {
+        let mut vault = vec![0u8; 165];
+        vault[64..72].copy_from_slice(&42u64.to_le_bytes());
+
+        assert_eq!(Reserves::from_vault_accounts(&vault, &vault), Some(Reserves { token0: 42, token1: 42 }));
+        assert_eq!(token_account_amount(&vault[..70]), None);
+    }
+}
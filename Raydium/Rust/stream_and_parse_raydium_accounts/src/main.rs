@@ -3,7 +3,7 @@ use {
         future::TryFutureExt,
         sink::SinkExt,
         stream::StreamExt,
-    }, log::{error, info}, raydium_amm_interface::{accounts::{AmmInfo, Fees, TargetOrders}, instructions::RaydiumAmmProgramIx},serde::{Deserialize, Serialize}, std::{
+    }, log::{debug, error, info}, raydium_amm_interface::{accounts::{AmmInfo, Fees, TargetOrders}, instructions::RaydiumAmmProgramIx},serde::{Deserialize, Serialize}, std::{
         collections::HashMap, env, sync::Arc, time::Duration
     }, tokio::sync::Mutex, tonic::transport::channel::ClientTlsConfig, yellowstone_grpc_client::{GeyserGrpcClient, Interceptor}, yellowstone_grpc_proto::{
         geyser::SubscribeRequestFilterAccounts,
@@ -12,6 +12,10 @@ use {
         },
     }
 };
+use solana_defi_core::{
+    sink::{sink_from_spec, DEFAULT_BUFFER},
+    EventSink,
+};
 use borsh::BorshDeserialize;
 
 type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
@@ -37,6 +41,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -92,6 +103,7 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -99,6 +111,7 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -115,7 +128,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -131,6 +144,7 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -150,11 +164,11 @@ async fn geyser_subscribe(
                         let lamports = account_data.lamports;
                         let executable = account_data.executable;
 
-                        println!("Account Data: {:#?}", account_data.data);
+                        debug!("Account data: {:?}", account_data.data);
 
                         let discriminator: [u8; 1] = account_data.data[..1].try_into().expect("Failed to extract first byte");
 
-                        println!("Discriminator Received {}: {:#?}", pubkey_str, discriminator);
+                        debug!("Discriminator received {}: {:?}", pubkey_str, discriminator);
 
                 
                         let decoded_account = match decode_account_data(&account_data.data) {
@@ -175,9 +189,9 @@ async fn geyser_subscribe(
                             "decoded_data": decoded_account
                         });
                 
-                        println!("\nAccount Info: {}", account_info);
+                        sink.send(&account_info)?;
                     } else {
-                        println!("Account data is None for slot: {}", slot);
+                        error!("Account data is None for slot: {}", slot);
                     }
 
                 }
@@ -219,7 +233,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
     .map_err(|e| AccountDecodeError {
         message: format!("Failed to deserialize AmmInfoAccount: {}", e),
     })?;
-    println!("\nDecoded Amm Info Structure: {:#?}", data);
     Ok(DecodedAccount::AmmInfo(data))
 
 }
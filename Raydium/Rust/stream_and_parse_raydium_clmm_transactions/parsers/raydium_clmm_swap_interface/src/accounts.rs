@@ -1,85 +0,0 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
-use serde_with::serde_as;
-use serde_with::DisplayFromStr;
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct InitializeRewardParam {
-    pub open_time: u64,
-    pub end_time: u64,
-    pub emissions_per_second_x64: u128,
-}
-
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Observation {
-    pub block_timestamp: u32,
-    pub tick_cumulative: i64,
-    pub padding: [u64; 4],
-}
-
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct PositionRewardInfo {
-    pub growth_inside_last_x64: u128,
-    pub reward_amount_owed: u64,
-}
-
-#[serde_as]
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct RewardInfo {
-    pub reward_state: u8,
-    pub open_time: u64,
-    pub end_time: u64,
-    pub last_update_time: u64,
-    #[serde_as(as = "DisplayFromStr")]
-    pub emissions_per_second_x64: u128,
-    pub reward_total_emissioned: u64,
-    pub reward_claimed: u64,
-    pub token_mint: Pubkey,
-    pub token_vault: Pubkey,
-    pub authority: Pubkey,
-    #[serde_as(as = "DisplayFromStr")]
-    pub reward_growth_global_x64: u128,
-}
-
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct TickState {
-    pub tick: i32,
-    pub liquidity_net: i128,
-    pub liquidity_gross: u128,
-    pub fee_growth_outside_0_x64: u128,
-    pub fee_growth_outside_1_x64: u128,
-    pub reward_growths_outside_x64: [u128; 3],
-    pub padding: [u32; 13],
-}
-
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum PoolStatusBitIndex {
-    OpenPositionOrIncreaseLiquidity,
-    DecreaseLiquidity,
-    CollectFee,
-    CollectReward,
-    Swap,
-}
-
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum PoolStatusBitFlag {
-    Enable,
-    Disable,
-}
-
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum RewardState {
-    Uninitialized,
-    Initialized,
-    Opening,
-    Ended,
-}
-
-pub type TickArrayBitmap = [u64; 8];
@@ -1,6 +1,7 @@
 mod serialization;
 mod instruction_account_mapper;
 mod token_serializable;
+mod swap_check;
 
 use {
     backoff::{future::retry, ExponentialBackoff}, clap::Parser as ClapParser, futures::{
@@ -11,6 +12,11 @@ use {
     serialization::{serialize_pubkey, serialize_option_pubkey},
     instruction_account_mapper::{AccountMetadata, Idl, InstructionAccountMapper},
      log::{error, info},
+    solana_defi_core::{
+        models::serialize::serialization::serialize_token_balances,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        EventSink,
+    },
       serde::Serialize, solana_account_decoder_client_types::token::UiTokenAmount, solana_sdk::{
         hash::Hash, instruction::{AccountMeta, CompiledInstruction, Instruction}, message::{v0::{LoadedAddresses,
              Message, MessageAddressTableLookup},
@@ -24,10 +30,14 @@ use {
         collections::HashMap, env, fs, str::FromStr, sync::Arc, time::{Duration, SystemTime, UNIX_EPOCH}
     }, tokio::sync::Mutex, tonic::transport::channel::ClientTlsConfig,
      raydium_clmm_swap_interface::instructions::RaydiumClmmSwapProgramIx ,
+    swap_check::SwapChecker,
     yellowstone_grpc_client::{GeyserGrpcClient, Interceptor}, yellowstone_grpc_proto::{
-        geyser::SubscribeRequestFilterTransactions,
+        geyser::{SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions},
         prelude::{
-            subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest, SubscribeRequestPing,
+            subscribe_request_filter_accounts_filter::Filter,
+            subscribe_request_filter_accounts_filter_memcmp::Data, subscribe_update::UpdateOneof,
+            CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccountsFilter,
+            SubscribeRequestFilterAccountsFilterMemcmp, SubscribeRequestPing,
         },
     }
 };
@@ -39,10 +49,13 @@ use::solana_sdk::transaction::Result as TransactionResult;
 
 
 type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
+type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
 
 const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
 const RAYDIUM_CLMM_PROGRAM_ID: &str = "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK";
+const TICK_ARRAY_STATE_SIZE: u64 = 10240;
+const AMM_CONFIG_SIZE: u64 = 117;
 
 
 #[derive(Debug, Clone, ClapParser)]
@@ -53,6 +66,23 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long = "check-pool",
+        requires = "rpc_url",
+        help = "CLMM pool whose swaps are replayed through the swap simulator (repeatable)"
+    )]
+    check_pool: Vec<String>,
+
+    #[clap(long, help = "RPC endpoint the checked pools, tick arrays and AMM configs are loaded from")]
+    rpc_url: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -68,6 +98,13 @@ impl Args {
             .map_err(Into::into)
     }
 
+    fn check_pools(&self) -> anyhow::Result<Vec<Pubkey>> {
+        self.check_pool
+            .iter()
+            .map(|pool| Pubkey::from_str(pool).map_err(|e| anyhow::anyhow!("invalid pool {pool}: {e}")))
+            .collect()
+    }
+
     pub fn get_txn_updates(&self) -> anyhow::Result<SubscribeRequest> {
         let mut transactions: TxnFilterMap = HashMap::new();
 
@@ -83,8 +120,55 @@ impl Args {
             },
         );
 
+        // Checked pools, their tick arrays and the AMM configs.
+        let mut accounts: AccountFilterMap = HashMap::new();
+        let pools = self.check_pools()?;
+        if !pools.is_empty() {
+            accounts.insert(
+                "checkedPools".to_owned(),
+                SubscribeRequestFilterAccounts {
+                    account: pools.iter().map(Pubkey::to_string).collect(),
+                    owner: vec![],
+                    nonempty_txn_signature: None,
+                    filters: vec![],
+                },
+            );
+            for pool in &pools {
+                accounts.insert(
+                    format!("tickArrays-{pool}"),
+                    SubscribeRequestFilterAccounts {
+                        account: vec![],
+                        owner: vec![RAYDIUM_CLMM_PROGRAM_ID.to_string()],
+                        nonempty_txn_signature: None,
+                        filters: vec![
+                            SubscribeRequestFilterAccountsFilter {
+                                filter: Some(Filter::Datasize(TICK_ARRAY_STATE_SIZE)),
+                            },
+                            SubscribeRequestFilterAccountsFilter {
+                                filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                                    offset: 8,
+                                    data: Some(Data::Bytes(pool.to_bytes().to_vec())),
+                                })),
+                            },
+                        ],
+                    },
+                );
+            }
+            accounts.insert(
+                "ammConfigs".to_owned(),
+                SubscribeRequestFilterAccounts {
+                    account: vec![],
+                    owner: vec![RAYDIUM_CLMM_PROGRAM_ID.to_string()],
+                    nonempty_txn_signature: None,
+                    filters: vec![SubscribeRequestFilterAccountsFilter {
+                        filter: Some(Filter::Datasize(AMM_CONFIG_SIZE)),
+                    }],
+                },
+            );
+        }
+
         Ok(SubscribeRequest {
-            accounts: HashMap::default(),
+            accounts,
             slots: HashMap::default(),
             transactions,
             transactions_status: HashMap::default(),
@@ -124,13 +208,13 @@ pub struct TransactionWithActions {
     pub actions: Vec<DecodedInstruction>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedTransaction {
     pub signatures: Vec<Signature>,
     pub message: ParsedMessage,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedMessage {
     pub header: MessageHeader,
     pub account_keys: Vec<Pubkey>,
@@ -139,7 +223,7 @@ pub struct ParsedMessage {
     pub address_table_lookups: Vec<MessageAddressTableLookup>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedTransactionStatusMeta {
     pub status: TransactionResult<()>,
     pub fee: u64,
@@ -147,7 +231,9 @@ pub struct ParsedTransactionStatusMeta {
     pub post_balances: Vec<u64>,
     pub inner_instructions: Vec<DecodedInstruction>, 
     pub log_messages: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub pre_token_balances: Option<Vec<TransactionTokenBalance>>,
+    #[serde(serialize_with = "serialize_token_balances")]
     pub post_token_balances: Option<Vec<TransactionTokenBalance>>,
     pub rewards: Option<Rewards>,
     pub loaded_addresses: LoadedAddresses,
@@ -155,14 +241,14 @@ pub struct ParsedTransactionStatusMeta {
     pub compute_units_consumed: Option<u64>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ParsedConfirmedTransaction {
     pub slot: u64,
     pub transaction: ParsedTransaction,
     pub meta: ParsedTransactionStatusMeta,
     pub block_time: Option<i64>,
 }
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ParsedConfirmedTransactionWithStatusMeta {
     pub slot: u64,
     pub transaction: ParsedTransaction,
@@ -179,6 +265,13 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
+    let checker = match &args.rpc_url {
+        Some(rpc_url) if !args.check_pool.is_empty() => Some(SwapChecker::new(rpc_url, &args.check_pools()?)),
+        _ => None,
+    };
+    // Kept across reconnects so pool history and loaded tick arrays survive.
+    let checker = Arc::new(Mutex::new(checker));
     let zero_attempts = Arc::new(Mutex::new(true));
 
     // The default exponential backoff strategy intervals:
@@ -186,6 +279,8 @@ async fn main() -> anyhow::Result<()> {
     // 8.5s, 12.8s, 19.2s, 28.8s, 43.2s, 64.8s, 97s, ... ]
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
+        let checker = Arc::clone(&checker);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -202,7 +297,8 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            let mut checker = checker.lock().await;
+            geyser_subscribe(client, request, checker.as_mut(), &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -236,6 +332,8 @@ fn get_instruction_name_with_typename(instruction: &TokenInstruction) -> String
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    mut checker: Option<&mut SwapChecker>,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -720,8 +818,29 @@ async fn geyser_subscribe(
                         block_time: confirmed_txn_with_meta.block_time,
                     };
 
-                    println!("Decoded Transaction:\n{:#?}", parsed_confirmed_txn_with_meta);
-                    
+                    sink.send(&parsed_confirmed_txn_with_meta)?;
+
+                    if let (Some(checker), TransactionWithStatusMeta::Complete(versioned_tx_with_meta)) =
+                        (checker.as_deref_mut(), &confirmed_txn_with_meta.tx_with_meta)
+                    {
+                        let instructions: Vec<Instruction> = flatten_transaction_response(versioned_tx_with_meta)
+                            .into_iter()
+                            .map(|ix| ix.instruction)
+                            .collect();
+                        let log_messages = versioned_tx_with_meta.meta.log_messages.as_deref().unwrap_or_default();
+                        let checks = checker.check_transaction(signature, &instructions, log_messages).await;
+                        for check in &checks {
+                            sink.send(check)?;
+                        }
+                        if !checks.is_empty() {
+                            info!("swap checks: {} of {} matched", checker.matched(), checker.checked());
+                        }
+                    }
+                    }
+                }
+                Some(UpdateOneof::Account(update)) => {
+                    if let Some(checker) = checker.as_deref_mut() {
+                        checker.on_account_update(update);
                     }
                 }
                 Some(UpdateOneof::Ping(_)) => {
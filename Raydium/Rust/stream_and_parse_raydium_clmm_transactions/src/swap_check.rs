@@ -0,0 +1,414 @@
+//! Replays streamed CLMM swaps through the interface's swap simulator and
+//! compares the result with the `SwapEvent` the program logged.
+
+use {
+    base64::{engine::general_purpose, Engine},
+    borsh::BorshDeserialize,
+    log::{error, warn},
+    raydium_clmm_swap_interface::{
+        accounts::{
+            AmmConfig, AmmConfigAccount, PoolState, PoolStateAccount, TickArrayState, TickArrayStateAccount,
+            AMM_CONFIG_ACCOUNT_DISCM, POOL_STATE_ACCOUNT_DISCM, TICK_ARRAY_STATE_DISCM,
+        },
+        swap::{simulate_swap, tick_array_address, SwapError, SwapQuote, RAYDIUM_CLMM_PROGRAM_ID},
+        SwapEvent, SwapEventEvent, SwapIxArgs,
+    },
+    serde::Serialize,
+    serde_with::{serde_as, DisplayFromStr},
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{commitment_config::CommitmentConfig, instruction::Instruction, pubkey::Pubkey, signature::Signature},
+    std::collections::{HashMap, VecDeque},
+    yellowstone_grpc_proto::prelude::SubscribeUpdateAccount,
+};
+
+/// Anchor discriminators of `swap` and `swap_v2`.
+const SWAP_IX_DISCM: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+const SWAP_V2_IX_DISCM: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+
+/// Accounts before the tick arrays of `swap` and `swap_v2`.
+const SWAP_FIXED_ACCOUNTS: usize = 9;
+const SWAP_V2_FIXED_ACCOUNTS: usize = 13;
+
+/// Pool states kept per pool for transactions that arrive after their
+/// account update.
+const POOL_HISTORY: usize = 64;
+
+/// Tick arrays fetched over RPC for a single swap before giving up.
+const MAX_TICK_ARRAY_LOADS: usize = 16;
+
+/// A `swap` or `swap_v2` instruction of the CLMM program.
+struct SwapInstruction {
+    pool: Pubkey,
+    input_vault: Pubkey,
+    args: SwapIxArgs,
+    /// The tick array accounts (and bitmap extension) passed to the swap.
+    remaining_accounts: Vec<Pubkey>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize)]
+pub struct SwapCheck {
+    #[serde_as(as = "DisplayFromStr")]
+    pub signature: Signature,
+    #[serde_as(as = "DisplayFromStr")]
+    pub pool: Pubkey,
+    pub zero_for_one: bool,
+    pub is_base_input: bool,
+    pub amount_specified: u64,
+    pub event: SwapEvent,
+    pub simulated: Result<SwapQuote, SwapError>,
+    /// Event fields the simulation disagrees with, plus `tick_arrays` when
+    /// the swap did not pass every tick array the simulation walked.
+    pub mismatches: Vec<&'static str>,
+}
+
+impl SwapCheck {
+    pub fn matched(&self) -> bool {
+        self.simulated.is_ok() && self.mismatches.is_empty()
+    }
+}
+
+#[derive(Default)]
+struct WatchedPool {
+    slot: u64,
+    state: Option<PoolState>,
+    /// The state each recent transaction found the pool in, by signature.
+    history: VecDeque<(Signature, PoolState)>,
+    tick_arrays: Vec<TickArrayState>,
+    tick_array_slots: HashMap<i32, u64>,
+}
+
+impl WatchedPool {
+    fn on_state(&mut self, slot: u64, signature: Option<Signature>, state: PoolState) {
+        if slot < self.slot {
+            return;
+        }
+        if let (Some(signature), Some(previous)) = (signature, self.state.take()) {
+            if self.history.len() == POOL_HISTORY {
+                self.history.pop_front();
+            }
+            self.history.push_back((signature, previous));
+        }
+        self.slot = slot;
+        self.state = Some(state);
+    }
+
+    fn on_tick_array(&mut self, slot: u64, tick_array: TickArrayState) {
+        let start = tick_array.start_tick_index;
+        if self.tick_array_slots.get(&start).is_some_and(|&seen| slot < seen) {
+            return;
+        }
+        self.tick_array_slots.insert(start, slot);
+        match self.tick_arrays.iter_mut().find(|array| array.start_tick_index == start) {
+            Some(array) => *array = tick_array,
+            None => self.tick_arrays.push(tick_array),
+        }
+    }
+
+    fn state_before(&self, signature: &Signature) -> Option<PoolState> {
+        self.history
+            .iter()
+            .find(|(seen, _)| seen == signature)
+            .map(|(_, state)| state.clone())
+            .or_else(|| self.state.clone())
+    }
+}
+
+/// Keeps the watched pools, their tick arrays and AMM configs current from
+/// account updates, loading whatever is missing over RPC.
+pub struct SwapChecker {
+    rpc: RpcClient,
+    pools: HashMap<Pubkey, WatchedPool>,
+    amm_configs: HashMap<Pubkey, (u64, AmmConfig)>,
+    checked: u64,
+    matched: u64,
+}
+
+impl SwapChecker {
+    pub fn new(rpc_url: &str, pools: &[Pubkey]) -> Self {
+        Self {
+            rpc: RpcClient::new_with_commitment(rpc_url.to_string(), CommitmentConfig::processed()),
+            pools: pools.iter().map(|pool| (*pool, WatchedPool::default())).collect(),
+            amm_configs: HashMap::new(),
+            checked: 0,
+            matched: 0,
+        }
+    }
+
+    pub fn checked(&self) -> u64 {
+        self.checked
+    }
+
+    pub fn matched(&self) -> u64 {
+        self.matched
+    }
+
+    pub fn on_account_update(&mut self, update: SubscribeUpdateAccount) {
+        let Some(account) = update.account else {
+            return;
+        };
+        let Some(discm) = account.data.get(..8) else {
+            return;
+        };
+        let signature = account
+            .txn_signature
+            .as_deref()
+            .and_then(|signature| Signature::try_from(signature).ok());
+
+        if discm == POOL_STATE_ACCOUNT_DISCM {
+            let Some(pool) = Pubkey::try_from(account.pubkey.as_slice())
+                .ok()
+                .and_then(|pubkey| self.pools.get_mut(&pubkey))
+            else {
+                return;
+            };
+            match PoolStateAccount::deserialize(&account.data) {
+                Ok(state) => pool.on_state(update.slot, signature, state.0),
+                Err(e) => error!("Failed to decode CLMM pool state: {e}"),
+            }
+        } else if discm == TICK_ARRAY_STATE_DISCM {
+            match TickArrayStateAccount::deserialize(&account.data) {
+                Ok(tick_array) => {
+                    if let Some(pool) = self.pools.get_mut(&tick_array.0.pool_id) {
+                        pool.on_tick_array(update.slot, tick_array.0);
+                    }
+                }
+                Err(e) => error!("Failed to decode CLMM tick array: {e}"),
+            }
+        } else if discm == AMM_CONFIG_ACCOUNT_DISCM {
+            let Ok(pubkey) = Pubkey::try_from(account.pubkey.as_slice()) else {
+                return;
+            };
+            match AmmConfigAccount::deserialize(&account.data) {
+                Ok(config) => {
+                    if self.amm_configs.get(&pubkey).is_none_or(|(seen, _)| update.slot >= *seen) {
+                        self.amm_configs.insert(pubkey, (update.slot, config.0));
+                    }
+                }
+                Err(e) => error!("Failed to decode CLMM AMM config: {e}"),
+            }
+        }
+    }
+
+    /// Checks every swap on a watched pool in a transaction, given its
+    /// instructions in execution order and its log messages.
+    pub async fn check_transaction(
+        &mut self,
+        signature: Signature,
+        instructions: &[Instruction],
+        log_messages: &[String],
+    ) -> Vec<SwapCheck> {
+        let swaps: Vec<SwapInstruction> = instructions.iter().filter_map(swap_instruction).collect();
+        if !swaps.iter().any(|swap| self.pools.contains_key(&swap.pool)) {
+            return Vec::new();
+        }
+
+        let events = swap_events(log_messages);
+        if events.len() != swaps.len() || swaps.iter().zip(&events).any(|(swap, event)| swap.pool != event.pool_state) {
+            warn!(
+                "{signature}: {} CLMM swaps but {} swap events, not checked",
+                swaps.len(),
+                events.len()
+            );
+            return Vec::new();
+        }
+
+        // Pools swapped more than once in the transaction continue from the
+        // state the previous event left them in.
+        let mut after: HashMap<Pubkey, PoolState> = HashMap::new();
+        let mut checks = Vec::new();
+        for (swap, event) in swaps.iter().zip(events) {
+            let Some(watched) = self.pools.get(&swap.pool) else {
+                continue;
+            };
+            let pool = match after.remove(&swap.pool).or_else(|| watched.state_before(&signature)) {
+                Some(pool) => pool,
+                None => match self.load_pool(&swap.pool).await {
+                    Ok(pool) => pool,
+                    Err(e) => {
+                        error!("Failed to load CLMM pool {}: {e}", swap.pool);
+                        continue;
+                    }
+                },
+            };
+
+            let mut next = pool.clone();
+            next.sqrt_price_x64 = event.sqrt_price_x64;
+            next.liquidity = event.liquidity;
+            next.tick_current = event.tick;
+            after.insert(swap.pool, next);
+
+            match self.check_swap(signature, &pool, swap, event).await {
+                Ok(check) => {
+                    self.checked += 1;
+                    if check.matched() {
+                        self.matched += 1;
+                    }
+                    checks.push(check);
+                }
+                Err(e) => error!("{signature}: failed to check swap on {}: {e}", swap.pool),
+            }
+        }
+        checks
+    }
+
+    async fn check_swap(
+        &mut self,
+        signature: Signature,
+        pool: &PoolState,
+        swap: &SwapInstruction,
+        event: SwapEvent,
+    ) -> anyhow::Result<SwapCheck> {
+        let amm_config = self.amm_config(&pool.amm_config).await?;
+        let zero_for_one = swap.input_vault == pool.token_vault_0;
+        let is_base_input = swap.args.is_base_input != 0;
+        // The event carries the pool side of the swap, after transfer fees.
+        let amount_specified = if zero_for_one == is_base_input {
+            event.amount_0
+        } else {
+            event.amount_1
+        };
+
+        let mut loads = 0;
+        let simulated = loop {
+            let tick_arrays = &self.pools[&swap.pool].tick_arrays;
+            let result = simulate_swap(
+                pool,
+                &amm_config,
+                tick_arrays,
+                amount_specified,
+                swap.args.sqrt_price_limit_x64,
+                zero_for_one,
+                is_base_input,
+            );
+            match result {
+                Err(SwapError::MissingTickArray(start)) if loads < MAX_TICK_ARRAY_LOADS => {
+                    self.load_tick_array(&swap.pool, start).await?;
+                    loads += 1;
+                }
+                result => break result,
+            }
+        };
+
+        let mut mismatches = Vec::new();
+        if let Ok(quote) = &simulated {
+            if quote.amount_0 != event.amount_0 {
+                mismatches.push("amount_0");
+            }
+            if quote.amount_1 != event.amount_1 {
+                mismatches.push("amount_1");
+            }
+            if quote.sqrt_price_x64 != event.sqrt_price_x64 {
+                mismatches.push("sqrt_price_x64");
+            }
+            if quote.liquidity != event.liquidity {
+                mismatches.push("liquidity");
+            }
+            if quote.tick_current != event.tick {
+                mismatches.push("tick");
+            }
+            let mut passed = swap.remaining_accounts.iter();
+            if !quote
+                .tick_array_addresses(&swap.pool)
+                .iter()
+                .all(|address| passed.any(|account| account == address))
+            {
+                mismatches.push("tick_arrays");
+            }
+        }
+
+        Ok(SwapCheck {
+            signature,
+            pool: swap.pool,
+            zero_for_one,
+            is_base_input,
+            amount_specified,
+            event,
+            simulated,
+            mismatches,
+        })
+    }
+
+    async fn load_pool(&mut self, pool_id: &Pubkey) -> anyhow::Result<PoolState> {
+        let response = self.rpc.get_account_with_commitment(pool_id, self.rpc.commitment()).await?;
+        let account = response.value.ok_or_else(|| anyhow::anyhow!("pool account not found"))?;
+        let state = PoolStateAccount::deserialize(&account.data)?.0;
+        if let Some(pool) = self.pools.get_mut(pool_id) {
+            pool.on_state(response.context.slot, None, state.clone());
+        }
+        Ok(state)
+    }
+
+    async fn amm_config(&mut self, address: &Pubkey) -> anyhow::Result<AmmConfig> {
+        if let Some((_, config)) = self.amm_configs.get(address) {
+            return Ok(config.clone());
+        }
+        let response = self.rpc.get_account_with_commitment(address, self.rpc.commitment()).await?;
+        let account = response.value.ok_or_else(|| anyhow::anyhow!("AMM config {address} not found"))?;
+        let config = AmmConfigAccount::deserialize(&account.data)?.0;
+        self.amm_configs.insert(*address, (response.context.slot, config.clone()));
+        Ok(config)
+    }
+
+    async fn load_tick_array(&mut self, pool_id: &Pubkey, start_tick_index: i32) -> anyhow::Result<()> {
+        let address = tick_array_address(pool_id, start_tick_index);
+        let response = self.rpc.get_account_with_commitment(&address, self.rpc.commitment()).await?;
+        let account = response
+            .value
+            .ok_or_else(|| anyhow::anyhow!("tick array {start_tick_index} ({address}) not found"))?;
+        let tick_array = TickArrayStateAccount::deserialize(&account.data)?.0;
+        if let Some(pool) = self.pools.get_mut(pool_id) {
+            pool.on_tick_array(response.context.slot, tick_array);
+        }
+        Ok(())
+    }
+}
+
+fn swap_instruction(instruction: &Instruction) -> Option<SwapInstruction> {
+    if instruction.program_id != RAYDIUM_CLMM_PROGRAM_ID {
+        return None;
+    }
+    let fixed_accounts = match <[u8; 8]>::try_from(instruction.data.get(..8)?).ok()? {
+        SWAP_IX_DISCM => SWAP_FIXED_ACCOUNTS,
+        SWAP_V2_IX_DISCM => SWAP_V2_FIXED_ACCOUNTS,
+        _ => return None,
+    };
+    let args = SwapIxArgs::deserialize(&mut &instruction.data[8..]).ok()?;
+    let accounts: Vec<Pubkey> = instruction.accounts.iter().map(|meta| meta.pubkey).collect();
+    Some(SwapInstruction {
+        pool: *accounts.get(2)?,
+        input_vault: *accounts.get(5)?,
+        args,
+        // `swap` takes its first tick array as a named account.
+        remaining_accounts: accounts.get(fixed_accounts..)?.to_vec(),
+    })
+}
+
+/// The `SwapEvent`s logged by the CLMM program, following the invoke stack
+/// in the logs so events of other programs are skipped.
+fn swap_events(log_messages: &[String]) -> Vec<SwapEvent> {
+    let clmm_program_id = RAYDIUM_CLMM_PROGRAM_ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+    for log in log_messages {
+        let Some(rest) = log.strip_prefix("Program ") else {
+            continue;
+        };
+        if let Some((program, _)) = rest.split_once(" invoke [") {
+            stack.push(program);
+        } else if rest.ends_with(" success") || rest.contains(" failed: ") {
+            stack.pop();
+        } else if let Some(encoded) = rest.strip_prefix("data: ") {
+            if stack.last() != Some(&clmm_program_id.as_str()) {
+                continue;
+            }
+            let Ok(data) = general_purpose::STANDARD.decode(encoded.trim()) else {
+                continue;
+            };
+            if let Ok(event) = SwapEventEvent::deserialize(&mut data.as_slice()) {
+                events.push(event.0);
+            }
+        }
+    }
+    events
+}
@@ -1,4 +0,0 @@
-solana_program::declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
-pub mod accounts;
-pub mod typedefs;
-pub use accounts::*;
\ No newline at end of file
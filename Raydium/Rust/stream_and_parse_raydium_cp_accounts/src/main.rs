@@ -3,7 +3,7 @@ use {
         future::TryFutureExt,
         sink::SinkExt,
         stream::StreamExt,
-    }, log::{error, info}, raydium_cp_swap_interface::{accounts::{PoolState,
+    }, log::{debug, error, info}, raydium_cp_swap_interface::{accounts::{PoolState,
             AMM_CONFIG_ACCOUNT_DISCM,
             AmmConfig,
             AmmConfigAccount,
@@ -21,6 +21,10 @@ use {
         },
     }
 };
+use solana_defi_core::{
+    sink::{sink_from_spec, DEFAULT_BUFFER},
+    EventSink,
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 
 type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
@@ -48,6 +52,13 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -103,10 +114,12 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
     let zero_attempts = Arc::new(Mutex::new(true));
 
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -123,7 +136,7 @@ async fn main() -> anyhow::Result<()> {
 
             let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            geyser_subscribe(client, request, &sink)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -139,6 +152,7 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
 
@@ -158,11 +172,11 @@ async fn geyser_subscribe(
                         let lamports = account_data.lamports;
                         let executable = account_data.executable;
 
-                      //  println!("Account Data: {:#?}", account_data.data);
+                      //  debug!("Account data: {:?}", account_data.data);
 
                         let discriminator: [u8; 1] = account_data.data[..1].try_into().expect("Failed to extract first byte");
 
-                        println!("Discriminator Received {}: {:#?}", pubkey_str, discriminator);
+                        debug!("Discriminator received {}: {:?}", pubkey_str, discriminator);
 
                 
                         let decoded_account = match decode_account_data(&account_data.data) {
@@ -182,9 +196,9 @@ async fn geyser_subscribe(
                             "decoded_data": decoded_account
                         });
                 
-                        println!("\nAccount Info: {}", account_info);
+                        sink.send(&account_info)?;
                     } else {
-                        println!("Account data is None for slot: {}", slot);
+                        error!("Account data is None for slot: {}", slot);
                     }
 
                 }
@@ -229,7 +243,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize PoolState: {}", e),
                 })?;
-            println!("\nDecoded PoolState Structure: {:#?}", data);
             Ok(DecodedAccount::PoolState(data.0))
         }
         AMM_CONFIG_ACCOUNT_DISCM => {
@@ -237,7 +250,6 @@ pub fn decode_account_data(buf: &[u8]) -> Result<DecodedAccount, AccountDecodeEr
                 .map_err(|e| AccountDecodeError {
                     message: format!("Failed to deserialize AmmConfig: {}", e),
                 })?;
-            println!("\nDecoded AmmConfig Structure: {:#?}", data);
             Ok(DecodedAccount::AmmConfig(data.0))
         }
         _ => Err(AccountDecodeError {
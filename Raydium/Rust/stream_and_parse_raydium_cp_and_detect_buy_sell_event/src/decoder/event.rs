@@ -0,0 +1,44 @@
+use serde::Serialize;
+use raydium_cp_swap_interface::events::{
+    LpChangeEvent, LpChangeEventEvent, LP_CHANGE_EVENT_EVENT_DISCM,
+    SwapEvent, SwapEventEvent, SWAP_EVENT_EVENT_DISCM,
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub enum DecodedEvent {
+    LpChangeEvent(LpChangeEvent),
+    SwapEvent(SwapEvent),
+}
+
+#[derive(Debug)]
+pub struct AccountEventError {
+    pub message: String,
+}
+
+pub fn decode_event_data(buf: &[u8]) -> Result<DecodedEvent, AccountEventError> {
+    if buf.len() < 8 {
+        return Err(AccountEventError {
+            message: "Buffer too short to contain a valid discriminator.".to_string(),
+        });
+    }
+
+    let discriminator: [u8; 8] = buf[..8].try_into().expect("Failed to extract first 8 bytes");
+
+    match discriminator {
+        LP_CHANGE_EVENT_EVENT_DISCM => {
+            let data = LpChangeEventEvent::deserialize(&mut &buf[..]).map_err(|e| AccountEventError {
+                message: format!("Failed to deserialize LpChangeEvent: {}", e),
+            })?;
+            Ok(DecodedEvent::LpChangeEvent(data.0))
+        }
+        SWAP_EVENT_EVENT_DISCM => {
+            let data = SwapEventEvent::deserialize(&mut &buf[..]).map_err(|e| AccountEventError {
+                message: format!("Failed to deserialize SwapEvent: {}", e),
+            })?;
+            Ok(DecodedEvent::SwapEvent(data.0))
+        }
+        _ => Err(AccountEventError {
+            message: "Account discriminator not found.".to_string(),
+        }),
+    }
+}
@@ -0,0 +1,249 @@
+use {
+    backoff::{future::retry, ExponentialBackoff},
+    clap::Parser as ClapParser,
+    serde::Serialize,
+    futures::{
+        future::TryFutureExt,
+        sink::SinkExt,         
+        stream::StreamExt,
+    },
+    log::{error, info, warn},
+    solana_defi_core::{
+        processor::block_time::blocks_meta_filter,
+        schema::TokenAmount,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        EventSink, TransactionProcessor,
+    },
+    std::{collections::HashMap, env, path::Path, sync::Arc, time::{Duration, Instant}},
+    tokio::sync::Mutex,
+    tonic::transport::channel::ClientTlsConfig,
+    yellowstone_grpc_client::{GeyserGrpcClient, Interceptor},
+    yellowstone_grpc_proto::{
+        geyser::SubscribeRequestFilterTransactions,
+        prelude::{subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest, SubscribeRequestPing},
+    },
+};
+
+
+mod decoder;
+mod parser;
+use decoder::RaydiumCpDecoder;
+use parser::parsed_cp_txn;
+
+type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
+
+const RAYDIUM_CP_PROGRAM_ID: &str = "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C";
+/// How often the processing error counts and dropped sink records are logged.
+const STATS_LOG_INTERVAL: Duration = Duration::from_secs(60);
+
+
+#[derive(Debug, Clone, ClapParser)]
+#[clap(author, version, about)]
+struct Args {
+    #[clap(short, long, help = "gRPC endpoint")]
+    endpoint: String,
+
+    #[clap(long, help = "X-Token")]
+    x_token: String,
+
+    #[clap(long, help = "Directory to dump raw updates that fail to process")]
+    dump_failed_updates: Option<String>,
+
+    #[clap(long, help = "Raydium CPMM IDL to use instead of the embedded one")]
+    idl: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
+}
+
+impl Args {
+    async fn connect(&self) -> anyhow::Result<GeyserGrpcClient<impl Interceptor>> {
+        GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
+            .x_token(Some(self.x_token.clone()))?
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(10))
+            .tls_config(ClientTlsConfig::new().with_native_roots())?
+            .max_decoding_message_size(1024 * 1024 * 1024)
+            .connect()
+            .await
+            .map_err(Into::into)
+    }
+
+    pub fn get_txn_updates(&self) -> anyhow::Result<SubscribeRequest> {
+        let mut transactions: TxnFilterMap = HashMap::new();
+
+        transactions.insert(
+            "client".to_owned(),
+            SubscribeRequestFilterTransactions {
+                vote: Some(false),
+                failed: Some(false),
+                account_include: vec![RAYDIUM_CP_PROGRAM_ID.to_string()],
+                account_exclude: vec![],
+                account_required: vec![],
+                signature: None,
+            },
+        );
+
+        Ok(SubscribeRequest {
+            accounts: HashMap::default(),
+            slots: HashMap::default(),
+            transactions,
+            transactions_status: HashMap::default(),
+            blocks: HashMap::default(),
+            blocks_meta: blocks_meta_filter(),
+            entry: HashMap::default(),
+            commitment: Some(CommitmentLevel::Processed as i32),
+            accounts_data_slice: Vec::default(),
+            ping: None,
+            from_slot: None,
+        })
+    }
+}
+
+/// A swap from the user's side: `buy` spends the quote mint on `mint`,
+/// `sell` the reverse.
+#[derive(Clone, Debug, Serialize)]
+struct TransactionEvent {
+    event_type: String,
+    instruction: String,
+    user: Option<String>,
+    pool: String,
+    mint: String,
+    quote_mint: String,
+    input_mint: String,
+    output_mint: String,
+    /// Paid by the user, input transfer fee included.
+    amount_in: TokenAmount,
+    /// Received by the user, output transfer fee deducted.
+    amount_out: TokenAmount,
+    input_transfer_fee: TokenAmount,
+    output_transfer_fee: TokenAmount,
+    /// In the input mint.
+    trade_fee: TokenAmount,
+    /// In the input mint when the pool charges it on the input, else in the output mint.
+    creator_fee: TokenAmount,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env::set_var(
+        env_logger::DEFAULT_FILTER_ENV,
+        env::var_os(env_logger::DEFAULT_FILTER_ENV).unwrap_or_else(|| "info".into()),
+    );
+    env_logger::init();
+
+    let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
+    let decoder = RaydiumCpDecoder::new(args.idl.as_deref().map(Path::new))?;
+    let mut processor = TransactionProcessor::new(decoder)?;
+    if let Some(dir) = &args.dump_failed_updates {
+        processor = processor.dump_failed_updates(dir);
+    }
+    // Kept across reconnects so transactions waiting for their block meta survive them.
+    let processor = Arc::new(Mutex::new(processor));
+    let zero_attempts = Arc::new(Mutex::new(true));
+
+    retry(ExponentialBackoff::default(), move || {
+        let args = args.clone();
+        let sink = Arc::clone(&sink);
+        let processor = Arc::clone(&processor);
+        let zero_attempts = Arc::clone(&zero_attempts);
+
+        async move {
+            let mut zero_attempts = zero_attempts.lock().await;
+            if *zero_attempts {
+                *zero_attempts = false;
+            } else {
+                info!("Retry to connect to the server");
+            }
+            drop(zero_attempts);
+
+            let client = args.connect().await.map_err(backoff::Error::transient)?;
+            info!("Connected");
+
+            let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
+
+            let mut processor = processor.lock().await;
+            geyser_subscribe(client, request, &sink, &mut processor)
+                .await
+                .map_err(backoff::Error::transient)?;
+
+            Ok::<(), backoff::Error<anyhow::Error>>(())
+        }
+        .inspect_err(|error| error!("failed to connect: {error}"))
+    })
+    .await
+    .map_err(Into::into)
+}
+
+async fn geyser_subscribe(
+    mut client: GeyserGrpcClient<impl Interceptor>,
+    request: SubscribeRequest,
+    sink: &Arc<dyn EventSink>,
+    processor: &mut TransactionProcessor<RaydiumCpDecoder>,
+) -> anyhow::Result<()> {
+    let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
+    info!("stream opened");
+
+    let mut last_stats = Instant::now();
+    let mut last_dropped = 0;
+    while let Some(message) = stream.next().await {
+        match message {
+            Ok(msg) => match msg.update_oneof {
+               Some(UpdateOneof::Transaction(update)) => {
+                 match processor.process_transaction_update(update) {
+                  Ok(ready) => {
+                      for cp_txn in ready.into_iter().flat_map(parsed_cp_txn) {
+                          sink.send(&cp_txn)?;
+                      }
+                  }
+                  Err(e) => error!("Failed to process txn (skipping) [{}]: {e}", e.kind()),
+                }
+            } 
+                Some(UpdateOneof::BlockMeta(update)) => {
+                    let ready = processor.process_block_meta_update(update);
+                    for cp_txn in ready.into_iter().flat_map(parsed_cp_txn) {
+                        sink.send(&cp_txn)?;
+                    }
+                }
+                Some(UpdateOneof::Ping(_)) => {
+                    subscribe_tx
+                        .send(SubscribeRequest {
+                            ping: Some(SubscribeRequestPing { id: 1 }),
+                            ..Default::default()
+                        })
+                        .await?;
+                }
+                None => {
+                    error!("update not found in the message");
+                    break;
+                }
+                _ => {}
+            },
+            Err(error) => {
+                error!("error: {error:?}");
+                break;
+            }
+        }
+
+        if last_stats.elapsed() >= STATS_LOG_INTERVAL {
+            if !processor.error_counts().is_empty() {
+                info!("Processing errors so far: {:?}", processor.error_counts());
+            }
+            let dropped = sink.dropped();
+            if dropped != last_dropped {
+                warn!("Sink dropped {} records so far", dropped);
+                last_dropped = dropped;
+            }
+            last_stats = Instant::now();
+        }
+    }
+
+    info!("stream closed");
+    Ok(())
+}
+
@@ -0,0 +1,281 @@
+use {
+    crate::{decoder::event::DecodedEvent, TransactionEvent},
+    raydium_cp_swap_interface::events::SwapEvent,
+    solana_defi_core::{
+        schema::{Record, TokenAmount},
+        types::{DecodedInstruction, ParsedConfirmedTransactionWithStatusMeta},
+    },
+    solana_sdk::pubkey::Pubkey,
+    solana_transaction_status::TransactionTokenBalance,
+};
+
+/// Mints a pair is quoted in, in order of preference: WSOL, USDC, USDT.
+const QUOTE_MINTS: [&str; 3] = [
+    "So11111111111111111111111111111111111111112",
+    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+];
+
+/// Returns one `TransactionEvent` per swap instruction, so routed and
+/// bundled swaps each report their own amounts.
+pub fn parsed_cp_txn(
+    original: ParsedConfirmedTransactionWithStatusMeta<DecodedEvent>,
+) -> Vec<Record<TransactionEvent>> {
+    let meta = &original.meta;
+    let tx = &original.transaction;
+    let token_balances = meta
+        .pre_token_balances
+        .iter()
+        .chain(meta.post_token_balances.iter())
+        .flatten()
+        .collect::<Vec<_>>();
+
+    tx.message
+        .instructions
+        .iter()
+        .chain(meta.inner_instructions.iter())
+        .filter(|instr| instr.name == "SwapBaseInput" || instr.name == "SwapBaseOutput")
+        .filter_map(|instr| parse_swap(instr, &token_balances))
+        .map(|swap| Record::new("raydium_cp_trade", &original, swap))
+        .collect()
+}
+
+fn parse_swap(
+    cp_instruction: &DecodedInstruction<DecodedEvent>,
+    token_balances: &[&TransactionTokenBalance],
+) -> Option<TransactionEvent> {
+    let event = cp_instruction.events.iter().find_map(|event| match event {
+        DecodedEvent::SwapEvent(event) => Some(event),
+        _ => None,
+    })?;
+
+    let input_decimals = mint_decimals(token_balances, &event.input_mint)?;
+    let output_decimals = mint_decimals(token_balances, &event.output_mint)?;
+    let quote_mint = quote_mint(&event.input_mint, &event.output_mint);
+    let (event_type, mint) = if event.input_mint == quote_mint {
+        ("buy", event.output_mint)
+    } else {
+        ("sell", event.input_mint)
+    };
+    let creator_fee_decimals = if event.creator_fee_on_input {
+        input_decimals
+    } else {
+        output_decimals
+    };
+
+    let (amount_in, amount_out) = user_amounts(event)?;
+    let user = cp_instruction
+        .accounts
+        .iter()
+        .find(|acc| acc.name == "payer")
+        .map(|acc| acc.pubkey.to_string());
+
+    Some(TransactionEvent {
+        event_type: event_type.to_string(),
+        instruction: cp_instruction.name.clone(),
+        user,
+        pool: event.pool_id.to_string(),
+        mint: mint.to_string(),
+        quote_mint: quote_mint.to_string(),
+        input_mint: event.input_mint.to_string(),
+        output_mint: event.output_mint.to_string(),
+        amount_in: TokenAmount::new(amount_in, input_decimals),
+        amount_out: TokenAmount::new(amount_out, output_decimals),
+        input_transfer_fee: TokenAmount::new(event.input_transfer_fee, input_decimals),
+        output_transfer_fee: TokenAmount::new(event.output_transfer_fee, output_decimals),
+        trade_fee: TokenAmount::new(event.trade_fee, input_decimals),
+        creator_fee: TokenAmount::new(event.creator_fee, creator_fee_decimals),
+    })
+}
+
+/// What the user paid and received. The event's `input_amount` is what
+/// reached the input vault and its `output_amount` what left the output
+/// vault, so Token-2022 transfer fees are added to the first and taken from
+/// the second.
+fn user_amounts(event: &SwapEvent) -> Option<(u64, u64)> {
+    Some((
+        event.input_amount.checked_add(event.input_transfer_fee)?,
+        event.output_amount.checked_sub(event.output_transfer_fee)?,
+    ))
+}
+
+/// The quote side of a pair: WSOL, USDC or USDT when one of them is
+/// traded, otherwise token 1, the larger mint, as CPMM pools sort theirs.
+fn quote_mint(input_mint: &Pubkey, output_mint: &Pubkey) -> Pubkey {
+    let rank = |mint: &Pubkey| {
+        let mint = mint.to_string();
+        QUOTE_MINTS.iter().position(|quote| *quote == mint)
+    };
+    match (rank(input_mint), rank(output_mint)) {
+        (Some(input), Some(output)) if input <= output => *input_mint,
+        (Some(_), Some(_)) | (None, Some(_)) => *output_mint,
+        (Some(_), None) => *input_mint,
+        (None, None) => *input_mint.max(output_mint),
+    }
+}
+
+fn mint_decimals(token_balances: &[&TransactionTokenBalance], mint: &Pubkey) -> Option<u8> {
+    let mint = mint.to_string();
+    token_balances
+        .iter()
+        .find(|balance| balance.mint == mint)
+        .map(|balance| balance.ui_token_amount.decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_account_decoder_client_types::token::UiTokenAmount,
+        solana_defi_core::{models::mapper::instruction::AccountMetadata, types::InstructionPosition},
+        std::str::FromStr,
+    };
+
+    fn mint(address: &str) -> Pubkey {
+        Pubkey::from_str(address).unwrap()
+    }
+
+    fn wsol() -> Pubkey {
+        mint(QUOTE_MINTS[0])
+    }
+
+    fn usdc() -> Pubkey {
+        mint(QUOTE_MINTS[1])
+    }
+
+    fn swap_event(input_mint: Pubkey, output_mint: Pubkey) -> SwapEvent {
+        SwapEvent {
+            pool_id: Pubkey::new_unique(),
+            input_vault_before: 0,
+            output_vault_before: 0,
+            input_amount: 1_000_000_000,
+            output_amount: 2_000_000,
+            input_transfer_fee: 0,
+            output_transfer_fee: 0,
+            base_input: true,
+            input_mint,
+            output_mint,
+            trade_fee: 2_500_000,
+            creator_fee: 0,
+            creator_fee_on_input: true,
+        }
+    }
+
+    fn swap_instruction(event: SwapEvent, payer: Pubkey) -> DecodedInstruction<DecodedEvent> {
+        DecodedInstruction {
+            name: "SwapBaseInput".to_string(),
+            accounts: vec![AccountMetadata {
+                pubkey: payer,
+                is_writable: true,
+                is_signer: true,
+                declared_writable: Some(true),
+                declared_signer: Some(true),
+                name: "payer".to_string(),
+            }],
+            data: serde_json::Value::Null,
+            events: vec![DecodedEvent::SwapEvent(event)],
+            position: InstructionPosition {
+                outer_index: 0,
+                inner_index: None,
+            },
+            program_id: Pubkey::new_unique(),
+            parent_program_id: None,
+            error: None,
+        }
+    }
+
+    fn token_balance(mint: Pubkey, decimals: u8) -> TransactionTokenBalance {
+        TransactionTokenBalance {
+            account_index: 0,
+            mint: mint.to_string(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: None,
+                decimals,
+                amount: "0".to_string(),
+                ui_amount_string: String::new(),
+            },
+            owner: String::new(),
+            program_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn wsol_quotes_before_usdc() {
+        assert_eq!(quote_mint(&usdc(), &wsol()), wsol());
+        assert_eq!(quote_mint(&wsol(), &usdc()), wsol());
+
+        let token = Pubkey::new_unique();
+        assert_eq!(quote_mint(&token, &usdc()), usdc());
+        assert_eq!(quote_mint(&usdc(), &token), usdc());
+    }
+
+    #[test]
+    fn unquoted_pairs_quote_in_token1() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let token1 = a.max(b);
+        assert_eq!(quote_mint(&a, &b), token1);
+        assert_eq!(quote_mint(&b, &a), token1);
+    }
+
+    #[test]
+    fn spending_the_quote_mint_buys_and_receiving_it_sells() {
+        let (token, payer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let balances = [token_balance(wsol(), 9), token_balance(token, 6)];
+        let balances = balances.iter().collect::<Vec<_>>();
+
+        let buy = parse_swap(&swap_instruction(swap_event(wsol(), token), payer), &balances).unwrap();
+        assert_eq!(buy.event_type, "buy");
+        assert_eq!(buy.mint, token.to_string());
+        assert_eq!(buy.quote_mint, wsol().to_string());
+        assert_eq!(buy.user, Some(payer.to_string()));
+        assert_eq!(buy.amount_in, TokenAmount::new(1_000_000_000, 9));
+        assert_eq!(buy.amount_out, TokenAmount::new(2_000_000, 6));
+        assert_eq!(buy.trade_fee, TokenAmount::new(2_500_000, 9));
+
+        let sell = parse_swap(&swap_instruction(swap_event(token, wsol()), payer), &balances).unwrap();
+        assert_eq!(sell.event_type, "sell");
+        assert_eq!(sell.mint, token.to_string());
+        assert_eq!(sell.quote_mint, wsol().to_string());
+        assert_eq!(sell.amount_in, TokenAmount::new(1_000_000_000, 6));
+    }
+
+    #[test]
+    fn swaps_of_mints_without_token_balances_are_skipped() {
+        let token = Pubkey::new_unique();
+        let balances = [token_balance(wsol(), 9)];
+        let balances = balances.iter().collect::<Vec<_>>();
+
+        let instruction = swap_instruction(swap_event(wsol(), token), Pubkey::new_unique());
+        assert!(parse_swap(&instruction, &balances).is_none());
+    }
+
+    #[test]
+    fn user_amounts_include_both_transfer_fees() {
+        let event = SwapEvent {
+            input_transfer_fee: 1_500,
+            output_transfer_fee: 2_000,
+            ..swap_event(wsol(), Pubkey::new_unique())
+        };
+        // The user sent the input fee on top of what reached the vault, and
+        // the output fee was withheld from what left it.
+        assert_eq!(user_amounts(&event), Some((1_000_001_500, 1_998_000)));
+
+        let token = event.output_mint;
+        let balances = [token_balance(wsol(), 9), token_balance(token, 6)];
+        let balances = balances.iter().collect::<Vec<_>>();
+        let swap = parse_swap(&swap_instruction(event, Pubkey::new_unique()), &balances).unwrap();
+        assert_eq!(swap.amount_in, TokenAmount::new(1_000_001_500, 9));
+        assert_eq!(swap.amount_out, TokenAmount::new(1_998_000, 6));
+        assert_eq!(swap.input_transfer_fee, TokenAmount::new(1_500, 9));
+        assert_eq!(swap.output_transfer_fee, TokenAmount::new(2_000, 6));
+    }
+
+    #[test]
+    fn output_transfer_fees_above_the_output_are_rejected() {
+        let event = SwapEvent {
+            output_transfer_fee: 2_000_001,
+            ..swap_event(wsol(), Pubkey::new_unique())
+        };
+        assert_eq!(user_amounts(&event), None);
+    }
+}
@@ -1,157 +0,0 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
-use crate::*;
-pub const AMM_CONFIG_ACCOUNT_DISCM: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct AmmConfig {
-    pub bump: u8,
-    pub disable_create_pool: bool,
-    pub index: u16,
-    pub trade_fee_rate: u64,
-    pub protocol_fee_rate: u64,
-    pub fund_fee_rate: u64,
-    pub create_pool_fee: u64,
-    pub protocol_owner: Pubkey,
-    pub fund_owner: Pubkey,
-    pub padding: [u64; 16],
-}
-#[derive(Clone, Debug, PartialEq)]
-pub struct AmmConfigAccount(pub AmmConfig);
-impl AmmConfigAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
-        let mut reader = buf;
-        let mut maybe_discm = [0u8; 8];
-        reader.read_exact(&mut maybe_discm)?;
-        if maybe_discm != AMM_CONFIG_ACCOUNT_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        AMM_CONFIG_ACCOUNT_DISCM, maybe_discm
-                    ),
-                ),
-            );
-        }
-        Ok(Self(AmmConfig::deserialize(&mut reader)?))
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&AMM_CONFIG_ACCOUNT_DISCM)?;
-        self.0.serialize(&mut writer)
-    }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
-        let mut data = Vec::new();
-        self.serialize(&mut data)?;
-        Ok(data)
-    }
-}
-pub const OBSERVATION_STATE_ACCOUNT_DISCM: [u8; 8] = [
-    122,
-    174,
-    197,
-    53,
-    129,
-    9,
-    165,
-    132,
-];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ObservationState {
-    pub initialized: bool,
-    pub observation_index: u16,
-    pub pool_id: Pubkey,
-    pub observations: [Observation; 100],
-    pub padding: [u64; 4],
-}
-#[derive(Clone, Debug, PartialEq)]
-pub struct ObservationStateAccount(pub ObservationState);
-impl ObservationStateAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
-        let mut reader = buf;
-        let mut maybe_discm = [0u8; 8];
-        reader.read_exact(&mut maybe_discm)?;
-        if maybe_discm != OBSERVATION_STATE_ACCOUNT_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        OBSERVATION_STATE_ACCOUNT_DISCM, maybe_discm
-                    ),
-                ),
-            );
-        }
-        Ok(Self(ObservationState::deserialize(&mut reader)?))
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&OBSERVATION_STATE_ACCOUNT_DISCM)?;
-        self.0.serialize(&mut writer)
-    }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
-        let mut data = Vec::new();
-        self.serialize(&mut data)?;
-        Ok(data)
-    }
-}
-pub const POOL_STATE_ACCOUNT_DISCM: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct PoolState {
-    pub amm_config: Pubkey,
-    pub pool_creator: Pubkey,
-    pub token0_vault: Pubkey,
-    pub token1_vault: Pubkey,
-    pub lp_mint: Pubkey,
-    pub token0_mint: Pubkey,
-    pub token1_mint: Pubkey,
-    pub token0_program: Pubkey,
-    pub token1_program: Pubkey,
-    pub observation_key: Pubkey,
-    pub auth_bump: u8,
-    pub status: u8,
-    pub lp_mint_decimals: u8,
-    pub mint0_decimals: u8,
-    pub mint1_decimals: u8,
-    pub lp_supply: u64,
-    pub protocol_fees_token0: u64,
-    pub protocol_fees_token1: u64,
-    pub fund_fees_token0: u64,
-    pub fund_fees_token1: u64,
-    pub open_time: u64,
-    pub padding: [u64; 32],
-}
-#[derive(Clone, Debug, PartialEq)]
-pub struct PoolStateAccount(pub PoolState);
-impl PoolStateAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
-        let mut reader = buf;
-        let mut maybe_discm = [0u8; 8];
-        reader.read_exact(&mut maybe_discm)?;
-        if maybe_discm != POOL_STATE_ACCOUNT_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        POOL_STATE_ACCOUNT_DISCM, maybe_discm
-                    ),
-                ),
-            );
-        }
-        Ok(Self(PoolState::deserialize(&mut reader)?))
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&POOL_STATE_ACCOUNT_DISCM)?;
-        self.0.serialize(&mut writer)
-    }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
-        let mut data = Vec::new();
-        self.serialize(&mut data)?;
-        Ok(data)
-    }
-}
@@ -1,9 +0,0 @@
-solana_program::declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
-pub mod typedefs;
-pub use typedefs::*;
-pub mod instructions;
-pub use instructions::*;
-pub mod errors;
-pub use errors::*;
-pub mod events;
-pub use events::*;
@@ -14,6 +14,7 @@ use {
         ConfirmedTransactionWithStatusMeta, TransactionTokenBalance, TransactionWithStatusMeta, VersionedTransactionWithStatusMeta
     },
     solana_sdk::{pubkey::Pubkey, hash::Hash},
+    solana_defi_core::token_registry::TokenRegistry,
 };
 use crate::processor::models::mapper::instruction::Idl;
 use crate::RAYDIUM_LAUNCHPAD_PROGRAM_ID;
@@ -27,10 +28,12 @@ pub struct TransactionProcessor {
     pub token_idl: Idl,
     pub raydium_launchpad_program_id: Pubkey,
     pub token_program_id: Pubkey,
+    /// Symbols and decimals of the mints seen, shared through its cache file.
+    pub tokens: TokenRegistry,
 }
 
 impl TransactionProcessor {
-    pub fn new() -> anyhow::Result<Self> {
+    pub fn new(tokens: TokenRegistry) -> anyhow::Result<Self> {
         let token_idl_json = fs::read_to_string("idls/token_program_idl.json")
             .context("Unable to read Token IDL JSON file")?;
         let raydium_launchpad_idl = fs::read_to_string("idls/raydium_launchpad.json")
@@ -41,11 +44,12 @@ impl TransactionProcessor {
             token_idl: serde_json::from_str(&token_idl_json)?,
             raydium_launchpad_program_id: Pubkey::from_str(RAYDIUM_LAUNCHPAD_PROGRAM_ID)?,
             token_program_id: Pubkey::from_str(TOKEN_PROGRAM_ID)?,
+            tokens,
         })
     }
 
     pub fn process_transaction_update(
-    &self,
+    &mut self,
     update: SubscribeUpdateTransaction,
     ) -> anyhow::Result<Option<ParsedEventTransaction>> {
       let slot = update.slot;
@@ -88,6 +92,7 @@ impl TransactionProcessor {
                 block_time,
             )?;
 
+            self.observe_launchpad_txn(&parsed_txn);
             Ok(self.parsed_raydium_launchpad_txn(parsed_txn))
         } else {
             Ok(None)
@@ -9,6 +9,7 @@ use {
         TransactionEvent,
         TransactionProcessor,
     },
+    solana_defi_core::token_registry::{TokenInfo, TokenProgram},
     solana_sdk::{
         instruction::AccountMeta,
         message::{v0::LoadedAddresses, VersionedMessage},
@@ -60,6 +61,37 @@ impl TransactionProcessor {
         parsed_accounts
     }
 
+    /// Learns the name, symbol and decimals of the mints launched in `txn`,
+    /// and the decimals of every mint in its token balances.
+    pub fn observe_launchpad_txn(&mut self, txn: &ParsedConfirmedTransactionWithStatusMeta) {
+        let meta = &txn.meta;
+        self.tokens.observe_token_balances(meta.pre_token_balances.as_deref().unwrap_or_default());
+        self.tokens.observe_token_balances(meta.post_token_balances.as_deref().unwrap_or_default());
+
+        for instr in txn.transaction.message.instructions.iter().chain(meta.inner_instructions.iter()) {
+            let Some(DecodedEvent::PoolCreateEvent(event)) = &instr.event else {
+                continue;
+            };
+            if !instr.name.starts_with("Initialize") {
+                continue;
+            }
+            let account = |name: &str| instr.accounts.iter().find(|acc| acc.name == name).map(|acc| acc.pubkey);
+            let Some(base_mint) = account("base_mint") else {
+                continue;
+            };
+            let params = &event.base_mint_param;
+            self.tokens.learn(
+                base_mint,
+                TokenInfo {
+                    symbol: Some(params.symbol.clone()).filter(|symbol| !symbol.is_empty()),
+                    name: Some(params.name.clone()).filter(|name| !name.is_empty()),
+                    decimals: Some(params.decimals),
+                    program: account("base_token_program").and_then(|program| TokenProgram::from_program_id(&program)),
+                },
+            );
+        }
+    }
+
     pub fn parsed_raydium_launchpad_txn(
         &self,
         original: ParsedConfirmedTransactionWithStatusMeta,
@@ -80,7 +112,8 @@ impl TransactionProcessor {
                     .accounts
                     .iter()
                     .find(|acc| acc.name == "base_token_mint")
-                    .map(|acc| acc.pubkey.to_string());
+                    .map(|acc| acc.pubkey);
+     let symbol = mint_pubkey.map(|mint| self.tokens.symbol(&mint));
 
      let signer_pubkey = launchpad_instruction
                   .accounts
@@ -105,7 +138,8 @@ impl TransactionProcessor {
     let event = TransactionEvent {
         event_type: trade_direction.to_string(),
         user: signer_pubkey,
-        mint: mint_pubkey,
+        mint: mint_pubkey.map(|mint| mint.to_string()),
+        symbol,
         amount_in: Some(amount_in),
         amount_out: Some(amount_out),
         quote_amount_before: Some(quote_amount_before),
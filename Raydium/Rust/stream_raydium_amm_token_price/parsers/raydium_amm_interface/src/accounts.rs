@@ -79,3 +79,25 @@ pub struct AmmInfo {
     pub client_order_id: u64,
     pub padding: [u64; 2],
 }
+
+/// Size of an `AmmInfo` account. AMM v4 accounts have no discriminator.
+pub const AMM_INFO_ACCOUNT_LEN: usize = 752;
+#[derive(Clone, Debug, PartialEq)]
+pub struct AmmInfoAccount(pub AmmInfo);
+impl AmmInfoAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.len() != AMM_INFO_ACCOUNT_LEN {
+            return Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!(
+                        "account size does not match. Expected: {}. Received: {}",
+                        AMM_INFO_ACCOUNT_LEN, buf.len()
+                    ),
+                ),
+            );
+        }
+        let mut reader = buf;
+        Ok(Self(AmmInfo::deserialize(&mut reader)?))
+    }
+}
@@ -16,13 +16,25 @@ use {
         message::v0::MessageAddressTableLookup,
         transaction_context::TransactionReturnData,
     },
+    solana_defi_core::{
+        metadata_lookup::MetadataLookup,
+        sink::{sink_from_spec, DEFAULT_BUFFER},
+        token_registry::TokenRegistry,
+        EventSink,
+    },
     solana_transaction_status::{TransactionTokenBalance, Rewards},
-    std::{collections::HashMap, env, sync::Arc, time::Duration},
+    std::{
+        collections::HashMap,
+        env,
+        str::FromStr,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
     tokio::sync::Mutex,
     tonic::transport::channel::ClientTlsConfig,
     yellowstone_grpc_client::{GeyserGrpcClient, Interceptor},
     yellowstone_grpc_proto::{
-        geyser::SubscribeRequestFilterTransactions,
+        geyser::{SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions},
         prelude::{subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest, SubscribeRequestPing},
     },
 };
@@ -33,9 +45,15 @@ use processor::TransactionProcessor;
 use processor::types::DecodedInstruction;
 
 type TxnFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
+type AccountFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
 const RAYDIUM_PROGRAM_ID: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
 const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const TOKEN_CACHE_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+/// Pools waiting for their `AmmInfo` are added to the subscription in batches.
+const RESUBSCRIBE_INTERVAL: Duration = Duration::from_secs(5);
+/// A pool that has not swapped again by then stops waiting for its `AmmInfo`.
+const AWAITING_POOL_TIMEOUT: Duration = Duration::from_secs(600);
 
 
 #[derive(Debug, Clone, ClapParser)]
@@ -46,6 +64,19 @@ struct Args {
 
     #[clap(long, help = "X-Token")]
     x_token: String,
+
+    #[clap(long, default_value = "token_registry.json", help = "Token metadata cache, can be shared with other streamers")]
+    token_cache: String,
+
+    #[clap(long, help = "RPC endpoint to look up the metadata of mints not in the token cache")]
+    rpc_url: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 impl Args {
@@ -61,7 +92,8 @@ impl Args {
             .map_err(Into::into)
     }
 
-    pub fn get_txn_updates(&self) -> anyhow::Result<SubscribeRequest> {
+    /// Raydium transactions, and the `AmmInfo` accounts of `pools`.
+    pub fn get_txn_updates(&self, pools: &[Pubkey]) -> anyhow::Result<SubscribeRequest> {
         let mut transactions: TxnFilterMap = HashMap::new();
 
         transactions.insert(
@@ -76,8 +108,24 @@ impl Args {
             },
         );
 
+        // AmmInfo accounts of swapped pools not cached yet, so their swaps resolve
+        // base/quote from the pool's coin and pc mints. An empty `account` list
+        // would match every account.
+        let mut accounts: AccountFilterMap = HashMap::new();
+        if !pools.is_empty() {
+            accounts.insert(
+                "ammInfo".to_owned(),
+                SubscribeRequestFilterAccounts {
+                    account: pools.iter().map(|pool| pool.to_string()).collect(),
+                    owner: vec![],
+                    nonempty_txn_signature: None,
+                    filters: vec![],
+                },
+            );
+        }
+
         Ok(SubscribeRequest {
-            accounts: HashMap::default(),
+            accounts,
             slots: HashMap::default(),
             transactions,
             transactions_status: HashMap::default(),
@@ -156,10 +204,15 @@ async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER)?;
+    // Kept across reconnects so known pools and learned tokens survive them.
+    let processor = Arc::new(Mutex::new(TransactionProcessor::new(TokenRegistry::open(&args.token_cache)?)?));
     let zero_attempts = Arc::new(Mutex::new(true));
 
     retry(ExponentialBackoff::default(), move || {
         let args = args.clone();
+        let sink = Arc::clone(&sink);
+        let processor = Arc::clone(&processor);
         let zero_attempts = Arc::clone(&zero_attempts);
 
         async move {
@@ -174,9 +227,10 @@ async fn main() -> anyhow::Result<()> {
             let client = args.connect().await.map_err(backoff::Error::transient)?;
             info!("Connected");
 
-            let request = args.get_txn_updates().map_err(backoff::Error::Permanent)?;
+            let request = args.get_txn_updates(&[]).map_err(backoff::Error::Permanent)?;
 
-            geyser_subscribe(client, request)
+            let mut processor = processor.lock().await;
+            geyser_subscribe(client, request, &args, &sink, &mut processor)
                 .await
                 .map_err(backoff::Error::transient)?;
 
@@ -191,22 +245,60 @@ async fn main() -> anyhow::Result<()> {
 async fn geyser_subscribe(
     mut client: GeyserGrpcClient<impl Interceptor>,
     request: SubscribeRequest,
+    args: &Args,
+    sink: &Arc<dyn EventSink>,
+    processor: &mut TransactionProcessor,
 ) -> anyhow::Result<()> {
     let (mut subscribe_tx, mut stream) = client.subscribe_with_request(Some(request)).await?;
     info!("stream opened");
 
-    let processor = TransactionProcessor::new()?;
+    let mut lookup = args.rpc_url.clone().map(MetadataLookup::spawn);
+    let mut last_saved = Instant::now();
+    // Swapped pools whose AmmInfo has not arrived yet, with their last swap.
+    let mut awaiting_pools: HashMap<Pubkey, Instant> = HashMap::new();
+    let mut pools_changed = false;
+    let mut last_resubscribe = Instant::now();
 
     while let Some(message) = stream.next().await {
         match message {
             Ok(msg) => match msg.update_oneof {
                Some(UpdateOneof::Transaction(update)) => {
                  match processor.process_transaction_update(update) {
-                  Ok(Some(pumpfun_txn)) => println!("Pump AMM:\n{:#?}", pumpfun_txn),
+                  Ok(Some(raydium_swap)) => {
+                      if let Ok(amm) = Pubkey::from_str(&raydium_swap.pool) {
+                          if !processor.pools.contains_key(&amm)
+                              && awaiting_pools.insert(amm, Instant::now()).is_none()
+                          {
+                              pools_changed = true;
+                          }
+                      }
+                      if let Some(lookup) = &mut lookup {
+                          let mints = [&raydium_swap.base_mint, &raydium_swap.quote_mint]
+                              .into_iter()
+                              .filter_map(|mint| Pubkey::from_str(mint).ok());
+                          lookup.request(&processor.tokens, mints);
+                      }
+                      sink.send(&raydium_swap)?;
+                  }
                   Ok(None) => (),
                   Err(e) => (),
                 }
             } 
+                Some(UpdateOneof::Account(update)) => {
+                    let Some(account) = update.account else {
+                        continue;
+                    };
+                    let Ok(pubkey) = Pubkey::try_from(account.pubkey.as_slice()) else {
+                        continue;
+                    };
+                    if let Err(e) = processor.update_pool(pubkey, &account.data) {
+                        error!("Failed to decode pool: {e}");
+                    }
+                    // Mints and vaults never change, one update is enough.
+                    if awaiting_pools.remove(&pubkey).is_some() {
+                        pools_changed = true;
+                    }
+                }
                 Some(UpdateOneof::Ping(_)) => {
                     subscribe_tx
                         .send(SubscribeRequest {
@@ -226,9 +318,28 @@ async fn geyser_subscribe(
                 break;
             }
         }
+
+        if let Some(lookup) = &mut lookup {
+            lookup.apply(&mut processor.tokens);
+        }
+
+        if pools_changed && last_resubscribe.elapsed() >= RESUBSCRIBE_INTERVAL {
+            awaiting_pools.retain(|_, swapped_at| swapped_at.elapsed() < AWAITING_POOL_TIMEOUT);
+            let pools = awaiting_pools.keys().copied().collect::<Vec<_>>();
+            subscribe_tx.send(args.get_txn_updates(&pools)?).await?;
+            pools_changed = false;
+            last_resubscribe = Instant::now();
+        }
+
+        if last_saved.elapsed() >= TOKEN_CACHE_SAVE_INTERVAL {
+            if let Err(e) = processor.tokens.save() {
+                error!("Failed to save the token cache: {e}");
+            }
+            last_saved = Instant::now();
+        }
     }
 
+    processor.tokens.save()?;
     info!("stream closed");
     Ok(())
 }
-
@@ -8,8 +8,10 @@ use {
     solana_account_decoder_client_types::token::UiTokenAmount, 
     solana_sdk::{instruction::{CompiledInstruction, AccountMeta, Instruction}, signature::Signature},
     std::{
-        fs, str::FromStr, time::{SystemTime, UNIX_EPOCH}
+        collections::{HashMap, VecDeque}, fs, str::FromStr, time::{SystemTime, UNIX_EPOCH}
     },
+    raydium_amm_interface::accounts::AmmInfoAccount,
+    solana_defi_core::token_registry::TokenRegistry,
     solana_transaction_status::{
         ConfirmedTransactionWithStatusMeta, TransactionTokenBalance, TransactionWithStatusMeta, VersionedTransactionWithStatusMeta
     },
@@ -23,15 +25,24 @@ use crate::TOKEN_PROGRAM_ID;
 use spl_token::instruction::TokenInstruction;
 use crate::processor::models::mapper::event;
 
+/// Pools cached before the oldest one is evicted.
+pub const MAX_CACHED_POOLS: usize = 50_000;
+
 pub struct TransactionProcessor {
     pub raydium_idl: Idl,
     pub token_idl: Idl,
     pub RAYDIUM_PROGRAM_ID: Pubkey,
     pub token_program_id: Pubkey,
+    /// Pools seen in `AmmInfo` account updates, by AMM account.
+    pub pools: HashMap<Pubkey, AmmPool>,
+    /// Order `pools` were cached in, oldest first.
+    pool_order: VecDeque<Pubkey>,
+    /// Symbols and decimals of the mints seen, shared through its cache file.
+    pub tokens: TokenRegistry,
 }
 
 impl TransactionProcessor {
-    pub fn new() -> anyhow::Result<Self> {
+    pub fn new(tokens: TokenRegistry) -> anyhow::Result<Self> {
         let token_idl_json = fs::read_to_string("idls/token_program_idl.json")
             .context("Unable to read Token IDL JSON file")?;
         let raydium_idl = fs::read_to_string("idls/raydium_amm.json")
@@ -42,11 +53,30 @@ impl TransactionProcessor {
             token_idl: serde_json::from_str(&token_idl_json)?,
             RAYDIUM_PROGRAM_ID: Pubkey::from_str(RAYDIUM_PROGRAM_ID)?,
             token_program_id: Pubkey::from_str(TOKEN_PROGRAM_ID)?,
+            pools: HashMap::new(),
+            pool_order: VecDeque::new(),
+            tokens,
         })
     }
 
+    /// Caches the mints and vaults of an AMM v4 pool from its `AmmInfo` account data.
+    /// Past `MAX_CACHED_POOLS` the oldest pool is evicted; its swaps fall back to the vault balances.
+    pub fn update_pool(&mut self, amm: Pubkey, data: &[u8]) -> anyhow::Result<()> {
+        let info = AmmInfoAccount::deserialize(data)
+            .with_context(|| format!("invalid AmmInfo account {amm}"))?;
+        if self.pools.insert(amm, info.0.into()).is_none() {
+            self.pool_order.push_back(amm);
+        }
+        while self.pool_order.len() > MAX_CACHED_POOLS {
+            if let Some(oldest) = self.pool_order.pop_front() {
+                self.pools.remove(&oldest);
+            }
+        }
+        Ok(())
+    }
+
     pub fn process_transaction_update(
-     &self,
+     &mut self,
      update: SubscribeUpdateTransaction,
      ) -> anyhow::Result<Option<RaydiumSwapParsed>> {
       let slot = update.slot;
@@ -79,8 +109,14 @@ impl TransactionProcessor {
                 &instructions.inner,
                 &decoded_events,
              )?;
-            let (pre_token_balances, post_token_balances) = match &confirmed_txn.tx_with_meta {
+            let (account_keys, pre_token_balances, post_token_balances) = match &confirmed_txn.tx_with_meta {
                 TransactionWithStatusMeta::Complete(meta) => {
+              let account_keys = self
+                .parse_transaction_accounts(&meta.transaction.message, meta.meta.loaded_addresses.clone())
+                .into_iter()
+                .map(|account| account.pubkey)
+                .collect::<Vec<_>>();
+
               let pre = meta.meta.pre_token_balances
                 .clone()
                 .unwrap_or_default()
@@ -95,9 +131,9 @@ impl TransactionProcessor {
                 .map(Self::convert_token_balance)
                 .collect::<Vec<_>>();
 
-                (pre, post)
+                (account_keys, pre, post)
                 }
-             _ => (vec![], vec![]),
+             _ => (vec![], vec![], vec![]),
              };
 
             // let parsed_txn = Self::build_parsed_transaction(
@@ -107,6 +143,9 @@ impl TransactionProcessor {
             //     decoded_inner,
             //     block_time,
             // )?;
+            self.tokens.observe_token_balances(&pre_token_balances);
+            self.tokens.observe_token_balances(&post_token_balances);
+
            let raydium_swap = self.parse_raydium_swap(
                 &decoded_compiled
                 .iter()
@@ -114,6 +153,7 @@ impl TransactionProcessor {
                 .cloned()
                 .collect::<Vec<_>>(),
             &decoded_events,
+            &account_keys,
             &pre_token_balances,
             &post_token_balances,
             );
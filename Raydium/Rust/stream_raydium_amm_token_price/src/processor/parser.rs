@@ -1,7 +1,7 @@
 use {
     crate::{
         processor::models::mapper::event::DecodedEvent,
-        processor::types::RaydiumSwapParsed,
+        processor::types::{AmmPool, RaydiumSwapParsed},
         ParsedConfirmedTransactionWithStatusMeta,
         ParsedEventTransaction,
         ParsedTransaction,
@@ -15,17 +15,40 @@ use {
     solana_sdk::{
         instruction::AccountMeta,
         message::{v0::LoadedAddresses, VersionedMessage},
+        pubkey::Pubkey,
     },
+    solana_defi_core::price::Price,
+    std::str::FromStr,
     std::vec::Vec,
     std::fmt,
 };
 
+const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+// `SwapDirection` of the AMM program's swap logs
+const DIRECTION_PC_TO_COIN: u64 = 1;
+const DIRECTION_COIN_TO_PC: u64 = 2;
+
 // Implement custom Debug for RaydiumSwapParsed to show formatted values
 impl fmt::Debug for RaydiumSwapParsed {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Pool price in the quote token's unit
+        let pool_price = match self.quote_mint.as_str() {
+            USDC_MINT | USDT_MINT => format!("${:.12}", self.pool_price),
+            _ => format!("{:.12} {}", self.pool_price, self.quote_symbol),
+        };
+        let price_impact = match &self.price_impact {
+            Some(impact) => format!("{impact:.2}"),
+            None => "N/A".to_string(),
+        };
+
         f.debug_struct("RaydiumSwapParsed")
+            .field("pool", &self.pool)
             .field("base_mint", &self.base_mint)
             .field("quote_mint", &self.quote_mint)
+            .field("base_symbol", &self.base_symbol)
+            .field("quote_symbol", &self.quote_symbol)
             .field("base_decimals", &self.base_decimals)
             .field("quote_decimals", &self.quote_decimals)
             .field("amount_in", &self.amount_in)
@@ -36,14 +59,16 @@ impl fmt::Debug for RaydiumSwapParsed {
             .field("pool_quote_reserves", &self.pool_quote_reserves)
             .field("pool_base_reserves_decimal", &self.pool_base_reserves_decimal)
             .field("pool_quote_reserves_decimal", &self.pool_quote_reserves_decimal)
+            .field("base_vault_delta", &self.base_vault_delta)
+            .field("quote_vault_delta", &self.quote_vault_delta)
             .field("base_token_price", &self.base_token_price)
             .field("quote_token_price", &self.quote_token_price)
             .field("pool_base_token_price", &self.pool_base_token_price)
             .field("pool_quote_token_price", &self.pool_quote_token_price)
             .field("swap_type", &self.swap_type)
             .field("direction", &self.direction)
-            .field("pool_price", &self.pool_price)
-            .field("price_impact", &self.price_impact)
+            .field("pool_price", &pool_price)
+            .field("price_impact", &price_impact)
             .finish()
     }
 }
@@ -96,13 +121,10 @@ impl TransactionProcessor {
         &self,
         decoded_instructions: &[DecodedInstruction],
         events: &[DecodedEvent],
+        account_keys: &[Pubkey],
         pre_token_balances: &[TransactionTokenBalance],
         post_token_balances: &[TransactionTokenBalance],
     ) -> Option<RaydiumSwapParsed> {   
-        const SOL_MINT: &str = "So11111111111111111111111111111111111111112"; 
-        const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
-        const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
-
         // Find the swap instruction
         let swap_ix = decoded_instructions.iter().find(|ix| {
             ix.program_id == self.RAYDIUM_PROGRAM_ID &&
@@ -113,7 +135,8 @@ impl TransactionProcessor {
             )
         })?; 
         
-        // Extract event data from the instruction
+        // Extract event data from the instruction. For swapBaseOut the
+        // amount actually taken is `direct_in`, `max_in` is only the limit.
         let (amount_in, amount_out, direction, pool_coin, pool_pc) = match &swap_ix.event {
             Some(DecodedEvent::SwapBaseInLog(data)) => Some((
                 data.amount_in,
@@ -123,7 +146,7 @@ impl TransactionProcessor {
                 data.pool_pc,
             )),
             Some(DecodedEvent::SwapBaseOutLog(data)) => Some((
-                data.max_in,
+                data.direct_in,
                 data.amount_out,
                 data.direction,
                 data.pool_coin,
@@ -132,251 +155,360 @@ impl TransactionProcessor {
             _ => None,
         }?;
 
-        // Collect all token balances
-        let all_balances: Vec<_> = pre_token_balances
-            .iter()
-            .chain(post_token_balances.iter())
-            .cloned()
-            .collect();
-
-        // Get unique mints
-        let unique_mints: Vec<String> = all_balances
-            .iter()
-            .map(|b| b.mint.clone())
-            .collect::<std::collections::HashSet<_>>()
-            .into_iter()
-            .collect();
-
-        if unique_mints.len() < 2 {
-            return None;
-        }
-
-        // Determine quote mint (prioritize SOL, USDC, USDT)
-        let quote_candidates = vec![SOL_MINT, USDC_MINT, USDT_MINT];
-        let quote_mint = unique_mints
-            .iter()
-            .find(|m| quote_candidates.contains(&m.as_str()))
-            .cloned()
-            .unwrap_or(unique_mints[1].clone());
-
-        // Base mint is the other one
-        let base_mint = unique_mints
-            .iter()
-            .find(|m| *m != &quote_mint)
-            .cloned()?;
-
-        // Get balances for base and quote
-        let base_balance = all_balances.iter().find(|b| b.mint == base_mint)?;
-        let quote_balance = all_balances.iter().find(|b| b.mint == quote_mint)?;
-
-        // Get decimals from the actual token balances
-        let base_decimals = base_balance.ui_token_amount.decimals as i32;
-        let quote_decimals = quote_balance.ui_token_amount.decimals as i32;
-
-        // Calculate decimal amounts
-        let amount_in_decimal = amount_in as f64 / 10f64.powi(base_decimals);
-        let amount_out_decimal = amount_out as f64 / 10f64.powi(quote_decimals);
-
-        if amount_out_decimal == 0.0 {
+        // Base and quote are the coin and pc sides of the swapped pool
+        let amm = swap_ix.accounts.get(1)?.pubkey;
+        let pool = match self.pools.get(&amm) {
+            Some(pool) => pool.clone(),
+            None => Self::pool_from_vaults(swap_ix, account_keys, pre_token_balances, post_token_balances)?,
+        };
+        let base_mint = pool.coin_mint.to_string();
+        let quote_mint = pool.pc_mint.to_string();
+        let base_symbol = self.tokens.symbol(&pool.coin_mint);
+        let quote_symbol = self.tokens.symbol(&pool.pc_mint);
+        let base_decimals = pool.coin_decimals;
+        let quote_decimals = pool.pc_decimals;
+
+        let base_vault_delta =
+            Self::vault_delta(&pool.coin_vault, account_keys, pre_token_balances, post_token_balances);
+        let quote_vault_delta =
+            Self::vault_delta(&pool.pc_vault, account_keys, pre_token_balances, post_token_balances);
+
+        if amount_in == 0 || amount_out == 0 {
             return None;
         }
 
-        // Calculate pool reserves in decimal
-        let pool_base_decimal = pool_coin as f64 / 10f64.powi(base_decimals);
-        let pool_quote_decimal = pool_pc as f64 / 10f64.powi(quote_decimals);
-
-        // Calculate swap prices based on direction
-        let (swap_price_quote_per_base, swap_price_base_per_quote) = if direction == 1 {
-            // direction 1: swapping base -> quote (e.g., token -> SOL)
-            let quote_per_base = amount_out_decimal / amount_in_decimal;  // SOL per token
-            let base_per_quote = amount_in_decimal / amount_out_decimal;  // tokens per SOL
-            (quote_per_base, base_per_quote)
-        } else {
-            // direction 2: swapping quote -> base (e.g., SOL -> token)
-            let quote_per_base = amount_in_decimal / amount_out_decimal;  // SOL per token
-            let base_per_quote = amount_out_decimal / amount_in_decimal;  // tokens per SOL
-            (quote_per_base, base_per_quote)
+        // Base is sold for quote when the coin side goes in
+        let coin_in = match direction {
+            DIRECTION_COIN_TO_PC => true,
+            DIRECTION_PC_TO_COIN => false,
+            _ => return None,
         };
-
-        // Calculate pool prices
-        let pool_price_quote_per_base = if pool_base_decimal > 0.0 && pool_quote_decimal > 0.0 {
-            pool_quote_decimal / pool_base_decimal  // SOL per token
+        let (base_amount, quote_amount, in_decimals, out_decimals) = if coin_in {
+            (amount_in, amount_out, base_decimals, quote_decimals)
         } else {
-            0.0
-        };
-        
-        let pool_price_base_per_quote = if pool_price_quote_per_base > 0.0 {
-            pool_base_decimal / pool_quote_decimal  // tokens per SOL
-        } else {
-            0.0
+            (amount_out, amount_in, quote_decimals, base_decimals)
         };
 
-        // Format all prices to NEVER use scientific notation
-        let base_token_price_str = Self::format_decimal(swap_price_quote_per_base);
-        let quote_token_price_str = Self::format_decimal(swap_price_base_per_quote);
-        let pool_base_token_price_str = Self::format_decimal(pool_price_quote_per_base);
-        let pool_quote_token_price_str = Self::format_decimal(pool_price_base_per_quote);
-
-        // Format pool price based on quote mint
-        let pool_price = if quote_mint == SOL_MINT {
-            format!("{} SOL", Self::format_small_price(pool_price_quote_per_base))
-        } else if quote_mint == USDC_MINT || quote_mint == USDT_MINT {
-            format!("${}", Self::format_small_price(pool_price_quote_per_base))
-        } else {
-            format!("{} {}", Self::format_small_price(pool_price_quote_per_base), Self::get_token_symbol(&quote_mint))
-        };
+        // Swap prices from the amounts exchanged
+        let swap_price_quote_per_base =
+            Price::from_amounts(quote_amount, quote_decimals, base_amount, base_decimals)?; // SOL per token
+        let swap_price_base_per_quote = swap_price_quote_per_base.inverse()?; // tokens per SOL
 
-        // Calculate price impact
-        let price_impact = if pool_price_quote_per_base > 0.0 {
-            let impact = ((swap_price_quote_per_base - pool_price_quote_per_base) / pool_price_quote_per_base) * 100.0;
-            format!("{:.2}%", impact)
-        } else {
-            "N/A".to_string()
-        };
+        // Pool prices from the reserves before the swap
+        let pool_price_quote_per_base =
+            Price::from_amounts(pool_pc, quote_decimals, pool_coin, base_decimals).unwrap_or_default();
+        let pool_price_base_per_quote = pool_price_quote_per_base.inverse().unwrap_or_default();
+
+        // Relative to the pool price, `None` for an empty pool
+        let price_impact = swap_price_quote_per_base.percent_change_from(pool_price_quote_per_base);
 
         // Determine source and destination for formatted output
-        let (source_mint, dest_mint, source_amount, dest_amount) = if direction == 1 {
-            (&base_mint, &quote_mint, amount_in_decimal, amount_out_decimal)
+        let (source_symbol, dest_symbol) = if coin_in {
+            (&base_symbol, &quote_symbol)
         } else {
-            (&quote_mint, &base_mint, amount_out_decimal, amount_in_decimal)
+            (&quote_symbol, &base_symbol)
         };
 
         Some(RaydiumSwapParsed {
+            pool: amm.to_string(),
             base_mint: base_mint.clone(),
             quote_mint: quote_mint.clone(),
-            base_decimals,
-            quote_decimals,
+            base_symbol: base_symbol.clone(),
+            quote_symbol: quote_symbol.clone(),
+            base_decimals: base_decimals as i32,
+            quote_decimals: quote_decimals as i32,
 
             amount_in,
             amount_out,
 
             amount_in_formatted: format!(
                 "{} {}",
-                Self::format_number(source_amount),
-                Self::get_token_symbol(source_mint)
+                Price::new(amount_in as u128, in_decimals as u32),
+                source_symbol
             ),
 
             amount_out_formatted: format!(
                 "{} {}",
-                Self::format_number(dest_amount),
-                Self::get_token_symbol(dest_mint)
+                Price::new(amount_out as u128, out_decimals as u32),
+                dest_symbol
             ),
 
             pool_base_reserves: pool_coin,
             pool_quote_reserves: pool_pc,
 
-            pool_base_reserves_decimal: pool_base_decimal,
-            pool_quote_reserves_decimal: pool_quote_decimal,
+            pool_base_reserves_decimal: Price::new(pool_coin as u128, base_decimals as u32),
+            pool_quote_reserves_decimal: Price::new(pool_pc as u128, quote_decimals as u32),
+
+            base_vault_delta,
+            quote_vault_delta,
 
-            // All price fields are now Strings with proper formatting
-            base_token_price: base_token_price_str,
-            quote_token_price: quote_token_price_str,
-            pool_base_token_price: pool_base_token_price_str,
-            pool_quote_token_price: pool_quote_token_price_str,
+            base_token_price: swap_price_quote_per_base,
+            quote_token_price: swap_price_base_per_quote,
+            pool_base_token_price: pool_price_quote_per_base,
+            pool_quote_token_price: pool_price_base_per_quote,
 
             swap_type: swap_ix.name.clone(),
             direction,
 
-            pool_price,
+            pool_price: pool_price_quote_per_base,
             price_impact,
         })
     }
-    
-    // Format decimal numbers to NEVER use scientific notation
-    fn format_decimal(num: f64) -> String {
-        if num == 0.0 {
-            return "0".to_string();
-        }
-        
-        // Handle very small numbers with appropriate precision
-        let abs_num = num.abs();
-        
-        if abs_num < 0.000000001 {
-            // For extremely tiny numbers (like 6.33e-9)
-            format!("{:.12}", num)
-        } else if abs_num < 0.000001 {
-            // For numbers like 0.00000633
-            format!("{:.12}", num)
-        } else if abs_num < 0.0001 {
-            // For numbers like 0.000633
-            format!("{:.10}", num)
-        } else if abs_num < 0.01 {
-            // For numbers like 0.00633
-            format!("{:.8}", num)
-        } else if abs_num < 1.0 {
-            // For numbers like 0.633
-            format!("{:.6}", num)
-        } else if abs_num < 1000.0 {
-            // For numbers like 633.45
-            format!("{:.4}", num)
-        } else if abs_num < 1000000.0 {
-            // For numbers like 63345.67
-            format!("{:.2}", num)
-        } else {
-            // For very large numbers
-            format!("{:.2}", num)
-        }
+
+    /// Pool of a swap whose `AmmInfo` has not been seen yet, read from the
+    /// coin and pc vaults the instruction passes and their token balances.
+    fn pool_from_vaults(
+        swap_ix: &DecodedInstruction,
+        account_keys: &[Pubkey],
+        pre_token_balances: &[TransactionTokenBalance],
+        post_token_balances: &[TransactionTokenBalance],
+    ) -> Option<AmmPool> {
+        // swapBaseIn/swapBaseOut take 18 accounts, or 17 without ammTargetOrders;
+        // the v2 instructions pass the vaults right after ammAuthority.
+        let vault_index = match (swap_ix.name.as_str(), swap_ix.accounts.len()) {
+            ("swapBaseIn2" | "swapBaseOut2", _) => 3,
+            (_, 17) => 4,
+            _ => 5,
+        };
+        let coin_vault = swap_ix.accounts.get(vault_index)?.pubkey;
+        let pc_vault = swap_ix.accounts.get(vault_index + 1)?.pubkey;
+
+        let balance_of = |vault: &Pubkey| {
+            let index = account_keys.iter().position(|key| key == vault)?;
+            pre_token_balances
+                .iter()
+                .chain(post_token_balances.iter())
+                .find(|balance| balance.account_index as usize == index)
+        };
+        let coin = balance_of(&coin_vault)?;
+        let pc = balance_of(&pc_vault)?;
+
+        Some(AmmPool {
+            coin_mint: Pubkey::from_str(&coin.mint).ok()?,
+            pc_mint: Pubkey::from_str(&pc.mint).ok()?,
+            coin_vault,
+            pc_vault,
+            coin_decimals: coin.ui_token_amount.decimals,
+            pc_decimals: pc.ui_token_amount.decimals,
+        })
     }
 
-    // Format small prices with appropriate decimal places (no scientific notation)
-    fn format_small_price(price: f64) -> String {
-        if price == 0.0 {
-            return "0".to_string();
-        }
-        
-        let abs_price = price.abs();
-        
-        if abs_price >= 1.0 {
-            format!("{:.2}", price)
-        } else if abs_price >= 0.01 {
-            format!("{:.4}", price)
-        } else if abs_price >= 0.0001 {
-            format!("{:.6}", price)
-        } else if abs_price >= 0.000001 {
-            format!("{:.8}", price)
-        } else if abs_price >= 0.00000001 {
-            format!("{:.10}", price)
-        } else {
-            // For extremely small numbers, use fixed precision
-            format!("{:.12}", price)
-        }
+    // Change of a vault's token amount between the pre and post balances
+    fn vault_delta(
+        vault: &Pubkey,
+        account_keys: &[Pubkey],
+        pre_token_balances: &[TransactionTokenBalance],
+        post_token_balances: &[TransactionTokenBalance],
+    ) -> i64 {
+        let Some(index) = account_keys.iter().position(|key| key == vault) else {
+            return 0;
+        };
+        let amount = |balances: &[TransactionTokenBalance]| {
+            balances
+                .iter()
+                .find(|balance| balance.account_index as usize == index)
+                .and_then(|balance| balance.ui_token_amount.amount.parse::<i64>().ok())
+                .unwrap_or(0)
+        };
+        amount(post_token_balances) - amount(pre_token_balances)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::processor::models::mapper::instruction::AccountMetadata,
+        raydium_amm_interface::events::{SwapBaseInLog, SwapBaseOutLog},
+        solana_account_decoder_client_types::token::UiTokenAmount,
+        solana_defi_core::token_registry::TokenRegistry,
+    };
+
+    const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+    struct Pool {
+        amm: Pubkey,
+        coin_mint: Pubkey,
+        pc_mint: Pubkey,
+        coin_vault: Pubkey,
+        pc_vault: Pubkey,
     }
 
-    // Format numbers with appropriate decimal places
-    fn format_number(num: f64) -> String {
-        if num == 0.0 {
-            return "0".to_string();
+    impl Pool {
+        fn new() -> Self {
+            Self {
+                amm: Pubkey::new_unique(),
+                coin_mint: Pubkey::new_unique(),
+                pc_mint: Pubkey::from_str(WSOL_MINT).unwrap(),
+                coin_vault: Pubkey::new_unique(),
+                pc_vault: Pubkey::new_unique(),
+            }
         }
-        
-        let abs_num = num.abs();
-        
-        if abs_num >= 1000.0 {
-            format!("{:.2}", num)
-        } else if abs_num >= 1.0 {
-            format!("{:.2}", num)
-        } else if abs_num > 0.0 {
-            // For numbers less than 1, show more decimals
-            if abs_num < 0.000001 {
-                format!("{:.12}", num)
-            } else if abs_num < 0.001 {
-                format!("{:.10}", num)
-            } else {
-                format!("{:.8}", num)
+
+        fn amm_pool(&self) -> AmmPool {
+            AmmPool {
+                coin_mint: self.coin_mint,
+                pc_mint: self.pc_mint,
+                coin_vault: self.coin_vault,
+                pc_vault: self.pc_vault,
+                coin_decimals: 6,
+                pc_decimals: 9,
             }
-        } else {
-            "0".to_string()
         }
+
+        /// Accounts of a swap with the vaults at `vault_index`.
+        fn swap_ix(&self, name: &str, len: usize, vault_index: usize, event: DecodedEvent) -> DecodedInstruction {
+            let mut accounts: Vec<Pubkey> = (0..len).map(|_| Pubkey::new_unique()).collect();
+            accounts[1] = self.amm;
+            accounts[vault_index] = self.coin_vault;
+            accounts[vault_index + 1] = self.pc_vault;
+            DecodedInstruction {
+                name: name.to_string(),
+                accounts: accounts
+                    .into_iter()
+                    .map(|pubkey| AccountMetadata {
+                        pubkey,
+                        is_writable: true,
+                        is_signer: false,
+                        name: String::new(),
+                    })
+                    .collect(),
+                data: serde_json::Value::Null,
+                event: Some(event),
+                program_id: Pubkey::from_str(crate::RAYDIUM_PROGRAM_ID).unwrap(),
+                parent_program_id: None,
+            }
+        }
+    }
+
+    fn processor() -> TransactionProcessor {
+        TransactionProcessor::new(TokenRegistry::default()).unwrap()
     }
 
-    // Get token symbol for known mints
-    fn get_token_symbol(mint: &str) -> String {
-        match mint {
-            "So11111111111111111111111111111111111111112" => "SOL".to_string(),
-            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" => "USDC".to_string(),
-            "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" => "USDT".to_string(),
-            "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263" => "BONK".to_string(),
-            "AZsHEMXd36Bj1EMNXhowJajpUXzrKcK57wW4ZGXVa7yR" => "GUAC".to_string(),
-            _ => format!("{}...{}", &mint[..4], &mint[mint.len()-4..]),
+    fn balance(account_index: usize, mint: &Pubkey, decimals: u8, amount: u64) -> TransactionTokenBalance {
+        TransactionTokenBalance {
+            account_index: account_index as u8,
+            mint: mint.to_string(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: None,
+                decimals,
+                amount: amount.to_string(),
+                ui_amount_string: String::new(),
+            },
+            owner: String::new(),
+            program_id: crate::TOKEN_PROGRAM_ID.to_string(),
         }
     }
-}
\ No newline at end of file
+
+    fn swap_base_in(amount_in: u64, out_amount: u64, direction: u64) -> DecodedEvent {
+        DecodedEvent::SwapBaseInLog(SwapBaseInLog {
+            log_type: 3,
+            amount_in,
+            minimum_out: 0,
+            direction,
+            user_source: amount_in,
+            pool_coin: 1_000_000_000_000,
+            pool_pc: 500_000_000_000,
+            out_amount,
+        })
+    }
+
+    #[test]
+    fn direction_two_sells_the_coin() {
+        let pool = Pool::new();
+        let mut processor = processor();
+        processor.pools.insert(pool.amm, pool.amm_pool());
+        let swap_ix = pool.swap_ix("swapBaseIn", 18, 5, swap_base_in(1_000_000_000, 500_000_000, DIRECTION_COIN_TO_PC));
+
+        let swap = processor.parse_raydium_swap(&[swap_ix], &[], &[], &[], &[]).unwrap();
+        assert_eq!(swap.base_mint, pool.coin_mint.to_string());
+        assert_eq!(swap.base_token_price, Price::new(5, 4));
+        assert_eq!(swap.pool_price, Price::new(5, 4));
+        assert_eq!(swap.amount_in_formatted, format!("1000.000000 {}", swap.base_symbol));
+        assert_eq!(swap.amount_out_formatted, format!("0.500000000 {}", swap.quote_symbol));
+    }
+
+    #[test]
+    fn direction_one_buys_the_coin() {
+        let pool = Pool::new();
+        let mut processor = processor();
+        processor.pools.insert(pool.amm, pool.amm_pool());
+        let event = DecodedEvent::SwapBaseOutLog(SwapBaseOutLog {
+            log_type: 4,
+            max_in: 600_000_000,
+            amount_out: 1_000_000_000,
+            direction: DIRECTION_PC_TO_COIN,
+            user_source: 600_000_000,
+            pool_coin: 1_000_000_000_000,
+            pool_pc: 500_000_000_000,
+            direct_in: 500_000_000,
+        });
+        let swap_ix = pool.swap_ix("swapBaseOut", 18, 5, event);
+
+        let swap = processor.parse_raydium_swap(&[swap_ix], &[], &[], &[], &[]).unwrap();
+        assert_eq!(swap.amount_in, 500_000_000);
+        assert_eq!(swap.base_token_price, Price::new(5, 4));
+        assert_eq!(swap.amount_in_formatted, format!("0.500000000 {}", swap.quote_symbol));
+        assert_eq!(swap.amount_out_formatted, format!("1000.000000 {}", swap.base_symbol));
+    }
+
+    #[test]
+    fn unknown_directions_are_skipped() {
+        let pool = Pool::new();
+        let mut processor = processor();
+        processor.pools.insert(pool.amm, pool.amm_pool());
+        let swap_ix = pool.swap_ix("swapBaseIn", 18, 5, swap_base_in(1_000_000_000, 500_000_000, 0));
+
+        assert!(processor.parse_raydium_swap(&[swap_ix], &[], &[], &[], &[]).is_none());
+    }
+
+    #[test]
+    fn reads_the_pool_from_its_vaults_when_amm_info_is_unknown() {
+        let processor = processor();
+        for (name, len, vault_index) in [("swapBaseIn", 18, 5), ("swapBaseOut", 17, 4), ("swapBaseIn2", 8, 3)] {
+            let pool = Pool::new();
+            let swap_ix = pool.swap_ix(name, len, vault_index, swap_base_in(1_000_000_000, 500_000_000, DIRECTION_COIN_TO_PC));
+            let account_keys: Vec<Pubkey> = swap_ix.accounts.iter().map(|account| account.pubkey).collect();
+            let pre = [
+                balance(vault_index, &pool.coin_mint, 6, 1_000_000_000_000),
+                balance(vault_index + 1, &pool.pc_mint, 9, 500_000_000_000),
+            ];
+            let post = [
+                balance(vault_index, &pool.coin_mint, 6, 1_001_000_000_000),
+                balance(vault_index + 1, &pool.pc_mint, 9, 499_500_000_000),
+            ];
+
+            let swap = processor.parse_raydium_swap(&[swap_ix], &[], &account_keys, &pre, &post).unwrap();
+            assert_eq!(swap.base_mint, pool.coin_mint.to_string(), "{name}");
+            assert_eq!(swap.quote_mint, WSOL_MINT, "{name}");
+            assert_eq!((swap.base_decimals, swap.quote_decimals), (6, 9), "{name}");
+            assert_eq!(swap.base_vault_delta, 1_000_000_000, "{name}");
+            assert_eq!(swap.quote_vault_delta, -500_000_000, "{name}");
+        }
+    }
+
+    #[test]
+    fn token_accounts_of_other_mints_are_ignored() {
+        let pool = Pool::new();
+        let swap_ix = pool.swap_ix("swapBaseIn", 17, 4, swap_base_in(1_000_000_000, 500_000_000, DIRECTION_COIN_TO_PC));
+        // A route through a second pool also moves some USDC.
+        let usdc = Pubkey::from_str(USDC_MINT).unwrap();
+        let mut account_keys = vec![Pubkey::new_unique()];
+        account_keys.extend(swap_ix.accounts.iter().map(|account| account.pubkey));
+        let pre = [
+            balance(0, &usdc, 6, 7_000_000),
+            balance(5, &pool.coin_mint, 6, 1_000_000_000_000),
+            balance(6, &pool.pc_mint, 9, 500_000_000_000),
+        ];
+        let post = [
+            balance(0, &usdc, 6, 2_000_000),
+            balance(5, &pool.coin_mint, 6, 1_001_000_000_000),
+            balance(6, &pool.pc_mint, 9, 499_500_000_000),
+        ];
+
+        let swap = processor().parse_raydium_swap(&[swap_ix], &[], &account_keys, &pre, &post).unwrap();
+        assert_eq!(swap.base_mint, pool.coin_mint.to_string());
+        assert_eq!(swap.quote_mint, WSOL_MINT);
+        assert_eq!(swap.base_vault_delta, 1_000_000_000);
+        assert_eq!(swap.quote_vault_delta, -500_000_000);
+    }
+}
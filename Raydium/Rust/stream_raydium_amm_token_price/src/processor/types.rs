@@ -1,5 +1,7 @@
 use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
 use serde::Serialize;
+use solana_defi_core::price::{Price, PriceChange};
+use raydium_amm_interface::accounts::AmmInfo;
 
 use crate::processor::models::mapper::event::DecodedEvent;
 use crate::processor::models::mapper::instruction::AccountMetadata;
@@ -32,11 +34,38 @@ pub struct ExtractedInstructions {
     pub compiled: Vec<TransactionInstructionWithParent>,
     pub inner: Vec<TransactionInstructionWithParent>,
 }
+/// Mints and vaults of an AMM v4 pool. Base is the coin side, quote the pc side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmmPool {
+    pub coin_mint: Pubkey,
+    pub pc_mint: Pubkey,
+    pub coin_vault: Pubkey,
+    pub pc_vault: Pubkey,
+    pub coin_decimals: u8,
+    pub pc_decimals: u8,
+}
+
+impl From<AmmInfo> for AmmPool {
+    fn from(info: AmmInfo) -> Self {
+        Self {
+            coin_mint: info.coin_mint,
+            pc_mint: info.pc_mint,
+            coin_vault: info.token_coin,
+            pc_vault: info.token_pc,
+            coin_decimals: info.coin_decimals as u8,
+            pc_decimals: info.pc_decimals as u8,
+        }
+    }
+}
+
 #[derive(Clone, Serialize)]
 //#[derive(Debug, Clone)]
 pub struct RaydiumSwapParsed {
+    pub pool: String,
     pub base_mint: String,
     pub quote_mint: String,
+    pub base_symbol: String,
+    pub quote_symbol: String,
     pub base_decimals: i32,
     pub quote_decimals: i32,
     pub amount_in: u64,
@@ -45,14 +74,16 @@ pub struct RaydiumSwapParsed {
     pub amount_out_formatted: String,
     pub pool_base_reserves: u64,
     pub pool_quote_reserves: u64,
-    pub pool_base_reserves_decimal: f64,
-    pub pool_quote_reserves_decimal: f64,
-    pub base_token_price: String,      // SOL per token (formatted, no scientific notation)
-    pub quote_token_price: String,     // tokens per SOL (formatted, no scientific notation)
-    pub pool_base_token_price: String, // SOL per token from pool (formatted, no scientific notation)
-    pub pool_quote_token_price: String, // tokens per SOL from pool (formatted, no scientific notation)
+    pub pool_base_reserves_decimal: Price,
+    pub pool_quote_reserves_decimal: Price,
+    pub base_vault_delta: i64,         // change of the pool's coin vault over the transaction
+    pub quote_vault_delta: i64,        // change of the pool's pc vault over the transaction
+    pub base_token_price: Price,       // quote per base
+    pub quote_token_price: Price,      // base per quote
+    pub pool_base_token_price: Price,  // quote per base from pool reserves
+    pub pool_quote_token_price: Price, // base per quote from pool reserves
     pub swap_type: String,
-    pub direction: u64,
-    pub pool_price: String,
-    pub price_impact: String,
+    pub direction: u64,                // 1 swaps pc -> coin, 2 coin -> pc
+    pub pool_price: Price,
+    pub price_impact: Option<PriceChange>, // percent, swap price against pool price
 }
\ No newline at end of file
@@ -2,9 +2,10 @@ use {
     jito_protos::shredstream::{
         shredstream_proxy_client::ShredstreamProxyClient, SubscribeEntriesRequest,
     },
-    log::error,
+    log::{error, info},
     tonic::{metadata::MetadataValue, transport::Endpoint, Request},
-    std::{fs, str::FromStr, time::Duration},
+    std::{fs, str::FromStr, sync::Arc, time::Duration},
+    solana_defi_core::EventSink,
     solana_entry::entry::Entry,
     serde::Serialize,
     solana_sdk::{
@@ -47,7 +48,7 @@ pub struct CompiledInstruction {
     #[serde(serialize_with = "serialize_option_pubkey")]
     pub parent_program_id: Option<Pubkey>,
 }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct PumpCreatedPool {
      pub mint : String,
      pub pool : String,
@@ -73,8 +74,9 @@ pub async fn connect_and_stream(
     endpoint: &str,
     x_token: Option<&str>,
     pubkey_filter: Option<&str>,
+    sink: &Arc<dyn EventSink>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    println!("Listening to Newly Created Pool on Pumpswap Amm..");
+    info!("Listening to Newly Created Pool on Pumpswap Amm..");
     let endpoint = Endpoint::from_str(endpoint)?
         .keep_alive_while_idle(true)
         .http2_keep_alive_interval(Duration::from_secs(5))
@@ -221,7 +223,9 @@ pub async fn connect_and_stream(
                                 slot: slot_entry.slot,
                             };
                            if let Some(new_pool_txn) = pump_amm_formatter(parsed_tx){;
-                            println!("{:#?}", new_pool_txn);
+                            if let Err(e) = sink.send(&new_pool_txn) {
+                                error!("Failed to send event: {:?}", e);
+                            }
                            }
                         });
                     });
@@ -6,6 +6,7 @@ use {
     std::{env, io, time::Duration},
     tokio::time::sleep,
     processor::core::connect_and_stream,
+    solana_defi_core::sink::{sink_from_spec, DEFAULT_BUFFER},
 };
 
 #[derive(Debug, Clone, Parser)]
@@ -19,6 +20,13 @@ struct Args {
 
     #[clap(long)]
     pubkey: Option<String>,
+
+    #[clap(
+        long,
+        default_value = "jsonl",
+        help = "Output sink: jsonl, file:<dir>[:<max_bytes>], unix:<path> or ws:<addr>"
+    )]
+    sink: String,
 }
 
 #[tokio::main]
@@ -30,17 +38,19 @@ async fn main() -> Result<(), io::Error> {
     env_logger::init();
 
     let args = Args::parse();
+    let sink = sink_from_spec(&args.sink, DEFAULT_BUFFER).map_err(io::Error::other)?;
 
     loop {
         match connect_and_stream(
             &args.shredstream_uri,
             args.x_token.as_deref(),
             args.pubkey.as_deref(),
+            &sink,
         )
         .await
         {
             Ok(()) => {
-                println!("Stream ended gracefully. Reconnecting...");
+                log::info!("Stream ended gracefully. Reconnecting...");
             }
             Err(e) => {
                 eprintln!("Connection or stream error: {e}. Retrying...");
@@ -2,9 +2,10 @@ use {
     jito_protos::shredstream::{
         shredstream_proxy_client::ShredstreamProxyClient, SubscribeEntriesRequest,
     },
-    log::error,
+    log::{error, info},
     tonic::{metadata::MetadataValue, transport::Endpoint, Request},
-    std::{fs, str::FromStr, time::Duration},
+    std::{fs, str::FromStr, sync::Arc, time::Duration},
+    solana_defi_core::EventSink,
     solana_entry::entry::Entry,
     serde::Serialize,
     solana_sdk::{
@@ -65,6 +66,7 @@ pub async fn connect_and_stream(
     endpoint: &str,
     x_token: Option<&str>,
     pubkey_filter: Option<&str>,
+    sink: &Arc<dyn EventSink>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let endpoint = Endpoint::from_str(endpoint)?
         .keep_alive_while_idle(true)
@@ -114,7 +116,7 @@ pub async fn connect_and_stream(
                 };
 
                 if !matching_entries.is_empty() {
-                    println!(
+                    info!(
                         "slot {}, entries: {}, filtered transactions: {}",
                         slot_entry.slot,
                         matching_entries.len(),
@@ -218,7 +220,9 @@ pub async fn connect_and_stream(
                                 slot: slot_entry.slot,
                             };
 
-                            println!("{:#?}", parsed_tx);
+                            if let Err(e) = sink.send(&parsed_tx) {
+                                error!("Failed to send event: {:?}", e);
+                            }
                         });
                     });
                 }
@@ -2,9 +2,10 @@ use {
     jito_protos::shredstream::{
         shredstream_proxy_client::ShredstreamProxyClient, SubscribeEntriesRequest,
     },
-    log::error,
+    log::{error, info},
     tonic::{metadata::MetadataValue, transport::Endpoint, Request},
-    std::{fs, str::FromStr, time::Duration},
+    std::{fs, str::FromStr, sync::Arc, time::Duration},
+    solana_defi_core::EventSink,
     solana_entry::entry::Entry,
     serde::Serialize,
     solana_sdk::{
@@ -37,7 +38,7 @@ pub struct DecodedInstruction {
     #[serde(serialize_with = "serialize_option_pubkey")]
     pub parent_program_id: Option<Pubkey>,
 }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct RLEventType {
      pub token_name : String,
      pub token_symbol : String,
@@ -76,8 +77,9 @@ pub async fn connect_and_stream(
     endpoint: &str,
     x_token: Option<&str>,
     pubkey_filter: Option<&str>,
+    sink: &Arc<dyn EventSink>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    println!("Getting New Minted Tokens on Raydium Launchpad");
+    info!("Getting New Minted Tokens on Raydium Launchpad");
     let endpoint = Endpoint::from_str(endpoint)?
         .keep_alive_while_idle(true)
         .http2_keep_alive_interval(Duration::from_secs(5))
@@ -224,7 +226,9 @@ pub async fn connect_and_stream(
                                 slot: slot_entry.slot,
                             };
                            if let Some(parsed_rl_txn) = rl_transaction_formatter(parsed_tx.clone()){
-                             println!("{:#?}", parsed_rl_txn);
+                             if let Err(e) = sink.send(&parsed_rl_txn) {
+                                 error!("Failed to send event: {:?}", e);
+                             }
                            }
                         });
                     });
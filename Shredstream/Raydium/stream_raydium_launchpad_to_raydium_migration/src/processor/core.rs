@@ -2,9 +2,10 @@ use {
     jito_protos::shredstream::{
         shredstream_proxy_client::ShredstreamProxyClient, SubscribeEntriesRequest,
     },
-    log::error,
+    log::{error, info},
     tonic::{metadata::MetadataValue, transport::Endpoint, Request},
-    std::{fs, str::FromStr, time::Duration},
+    std::{fs, str::FromStr, sync::Arc, time::Duration},
+    solana_defi_core::EventSink,
     solana_entry::entry::Entry,
     serde::Serialize,
     solana_sdk::{
@@ -65,8 +66,9 @@ pub async fn connect_and_stream(
     endpoint: &str,
     x_token: Option<&str>,
     pubkey_filter: Option<&str>,
+    sink: &Arc<dyn EventSink>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    println!("Waiting for Migrated Transactions");
+    info!("Waiting for Migrated Transactions");
     let endpoint = Endpoint::from_str(endpoint)?
         .keep_alive_while_idle(true)
         .http2_keep_alive_interval(Duration::from_secs(5))
@@ -219,7 +221,9 @@ pub async fn connect_and_stream(
                                 slot: slot_entry.slot,
                             };
                            if let Some(migrated_transaction) = rl_formatter(parsed_tx){
-                              println!("{:#?}", migrated_transaction);
+                              if let Err(e) = sink.send(&migrated_transaction) {
+                                  error!("Failed to send event: {:?}", e);
+                              }
                            }
                         });
                     });
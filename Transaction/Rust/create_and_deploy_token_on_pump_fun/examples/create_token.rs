@@ -1,13 +1,13 @@
 use pumpfun_new_mint::{
     PumpFunClient,
     Network,
+    SwapConfig,
     utils::{load_keypair_from_env, lamports_to_sol},
 };
 use solana_sdk::{
     signature::{Keypair, Signer},
 };
 use std::str::FromStr;
-use env_logger;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -41,6 +41,8 @@ async fn main() -> anyhow::Result<()> {
         _ => anyhow::bail!("❌ Invalid network choice"),
     };
 
+    let config = SwapConfig::token_creation(client.network);
+
     let balance = client.check_balance(&wallet.pubkey()).await?;
     println!("💰 Balance: {} SOL", balance);
     
@@ -88,6 +90,7 @@ async fn main() -> anyhow::Result<()> {
                uri,
                false, 
                 false, 
+                &config,
             ).await
         },
         "2" => {
@@ -97,6 +100,7 @@ async fn main() -> anyhow::Result<()> {
                 name,
                 symbol,
                 uri,
+                &config,
             ).await
         },
         _ => anyhow::bail!("❌ Invalid method choice"),
@@ -0,0 +1,21 @@
+use tonic_build::configure;
+
+fn main() {
+    const PROTOC_ENVAR: &str = "PROTOC";
+    if std::env::var(PROTOC_ENVAR).is_err() {
+        #[cfg(not(windows))]
+        std::env::set_var(PROTOC_ENVAR, protobuf_src::protoc());
+    }
+
+    configure()
+        .compile(
+            &[
+                "protos/shared.proto",
+                "protos/packet.proto",
+                "protos/bundle.proto",
+                "protos/searcher.proto",
+            ],
+            &["protos"],
+        )
+        .unwrap();
+}
@@ -0,0 +1,15 @@
+pub mod shared {
+    tonic::include_proto!("shared");
+}
+
+pub mod packet {
+    tonic::include_proto!("packet");
+}
+
+pub mod bundle {
+    tonic::include_proto!("bundle");
+}
+
+pub mod searcher {
+    tonic::include_proto!("searcher");
+}
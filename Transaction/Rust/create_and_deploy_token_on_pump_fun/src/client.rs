@@ -1,18 +1,28 @@
-use solana_client::rpc_client::RpcClient;
+use solana_client::{nonce_utils, rpc_client::RpcClient};
 use solana_sdk::{
     commitment_config::CommitmentConfig,
+    hash::Hash,
+    nonce,
     pubkey::Pubkey,
+    system_instruction,
     signature::{Keypair, Signature, Signer},
     transaction::Transaction,
     compute_budget::ComputeBudgetInstruction,
     message::Message,
 };
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
 use crate::{
     models::*,
     instruction::PumpInstructionBuilder,
+    jito::JitoBundleClient,
+    offline,
     error::{Result, PumpFunError},
     constants::*,
+    quote,
+    state::{BondingCurve, FeeConfig, Global},
     utils::{lamports_to_sol, get_explorer_url, load_rpc_url_with_network},
 };
 use log::info;
@@ -22,12 +32,21 @@ use std::str::FromStr;
 const SPL_TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
 const SPL_ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
 
+/// On-chain state a trade is quoted and built from.
+struct Market {
+    curve: BondingCurve,
+    global: Global,
+    fee_config: Option<FeeConfig>,
+    token_program: Pubkey,
+}
+
 pub struct PumpFunClient {
     pub rpc_client: RpcClient,
     pub instruction_builder: PumpInstructionBuilder,
     pub network: Network,
     pub token_2022_program_id: Pubkey,
     pub associated_token_program_id: Pubkey,
+    pub jito: Option<JitoBundleClient>,
 }
 
 impl PumpFunClient {
@@ -51,9 +70,17 @@ impl PumpFunClient {
             network,
             token_2022_program_id,
             associated_token_program_id,
+            jito: None,
         }
     }
 
+    /// Connects to a Jito block engine. Transactions sent with
+    /// `SwapConfig.use_jito` then go out as bundles.
+    pub async fn with_jito(mut self, block_engine_url: &str, uuid: Option<&str>) -> Result<Self> {
+        self.jito = Some(JitoBundleClient::connect(block_engine_url, uuid).await?);
+        Ok(self)
+    }
+
     pub fn devnet() -> Self {
         println!("🌐 Creating Devnet client");
         let network = Network::Devnet;
@@ -85,6 +112,7 @@ impl PumpFunClient {
         name: String,
         symbol: String,
         uri: String,
+        config: &SwapConfig,
     ) -> Result<Pubkey> {
         println!("\n🪙 Creating token with original create...");
         println!("   Name: {}", name);
@@ -95,27 +123,15 @@ impl PumpFunClient {
         let mint_keypair = Keypair::new();
         println!("   Mint (new keypair): {}", mint_keypair.pubkey());
 
-        let create_ix = self.instruction_builder.build_create_instruction(
-            &creator_keypair.pubkey(),
-            &mint_keypair.pubkey(),
+        let transaction = self.build_create_transaction(
+            creator_keypair,
+            &mint_keypair,
             name,
             symbol,
             uri,
+            config,
         )?;
-
-        let config = SwapConfig {
-            priority_fee_microlamports: Some(5000),
-            compute_unit_limit: Some(400_000),
-            network: self.network,
-            slippage_bps: 0,
-            use_jito: false,
-        };
-
-        let result = self.send_transaction_with_signers(
-            &[creator_keypair, &mint_keypair],
-            vec![create_ix],
-            &config,
-        ).await?;
+        let result = self.send_transaction(&transaction, config).await?;
 
         let mint = mint_keypair.pubkey();
 
@@ -147,6 +163,7 @@ impl PumpFunClient {
         uri: String,
         is_mayhem_mode: bool,
         is_cashback_enabled: bool,
+        config: &SwapConfig,
     ) -> Result<Pubkey> {
         println!("\n🪙 Creating token with create_v2...");
         println!("   Name: {}", name);
@@ -157,29 +174,17 @@ impl PumpFunClient {
         println!("   Mayhem mode: {}", is_mayhem_mode);
         println!("   Cashback enabled: {}", is_cashback_enabled);
 
-        let create_ix = self.instruction_builder.build_create_v2_instruction(
-            &creator_keypair.pubkey(),
-            &mint_keypair.pubkey(),
+        let transaction = self.build_create_v2_transaction(
+            creator_keypair,
+            mint_keypair,
             name,
             symbol,
             uri,
             is_mayhem_mode,
             is_cashback_enabled,
+            config,
         )?;
-
-        let config = SwapConfig {
-            priority_fee_microlamports: Some(5000),
-            compute_unit_limit: Some(400_000),
-            network: self.network,
-            slippage_bps: 0,
-            use_jito: false,
-        };
-
-        let result = self.send_transaction_with_signers(
-            &[creator_keypair, mint_keypair],
-            vec![create_ix],
-            &config,
-        ).await?;
+        let result = self.send_transaction(&transaction, config).await?;
 
         let mint = mint_keypair.pubkey();
 
@@ -200,6 +205,104 @@ impl PumpFunClient {
         Ok(mint)
     }
 
+    /// `create` transaction signed by the creator and the mint. With a
+    /// `durable_nonce` whose authority is another key, that signature is
+    /// still missing; add it with `offline::partial_sign`.
+    pub fn build_create_transaction(
+        &self,
+        creator_keypair: &Keypair,
+        mint_keypair: &Keypair,
+        name: String,
+        symbol: String,
+        uri: String,
+        config: &SwapConfig,
+    ) -> Result<Transaction> {
+        let mut transaction = self.build_unsigned_create_transaction(
+            &creator_keypair.pubkey(),
+            &mint_keypair.pubkey(),
+            name,
+            symbol,
+            uri,
+            config,
+        )?;
+        offline::partial_sign(&mut transaction, &[creator_keypair, mint_keypair])?;
+        Ok(transaction)
+    }
+
+    /// `create_v2` transaction signed by the creator and the mint, see
+    /// `build_create_transaction`.
+    pub fn build_create_v2_transaction(
+        &self,
+        creator_keypair: &Keypair,
+        mint_keypair: &Keypair,
+        name: String,
+        symbol: String,
+        uri: String,
+        is_mayhem_mode: bool,
+        is_cashback_enabled: bool,
+        config: &SwapConfig,
+    ) -> Result<Transaction> {
+        let mut transaction = self.build_unsigned_create_v2_transaction(
+            &creator_keypair.pubkey(),
+            &mint_keypair.pubkey(),
+            name,
+            symbol,
+            uri,
+            is_mayhem_mode,
+            is_cashback_enabled,
+            config,
+        )?;
+        offline::partial_sign(&mut transaction, &[creator_keypair, mint_keypair])?;
+        Ok(transaction)
+    }
+
+    /// Unsigned `create` transaction, to be signed by the creator, the mint
+    /// and the nonce authority wherever their keys are.
+    pub fn build_unsigned_create_transaction(
+        &self,
+        creator: &Pubkey,
+        mint: &Pubkey,
+        name: String,
+        symbol: String,
+        uri: String,
+        config: &SwapConfig,
+    ) -> Result<Transaction> {
+        let create_ix = self.instruction_builder.build_create_instruction(
+            creator,
+            mint,
+            name,
+            symbol,
+            uri,
+        )?;
+
+        self.build_unsigned_transaction(creator, vec![create_ix], config)
+    }
+
+    /// Unsigned `create_v2` transaction, see `build_unsigned_create_transaction`.
+    pub fn build_unsigned_create_v2_transaction(
+        &self,
+        creator: &Pubkey,
+        mint: &Pubkey,
+        name: String,
+        symbol: String,
+        uri: String,
+        is_mayhem_mode: bool,
+        is_cashback_enabled: bool,
+        config: &SwapConfig,
+    ) -> Result<Transaction> {
+        let create_ix = self.instruction_builder.build_create_v2_instruction(
+            creator,
+            mint,
+            name,
+            symbol,
+            uri,
+            is_mayhem_mode,
+            is_cashback_enabled,
+        )?;
+
+        self.build_unsigned_transaction(creator, vec![create_ix], config)
+    }
+
     // ==================== POOL INFO ====================
 
     pub async fn get_pool_info(&self, mint: &Pubkey) -> Result<PumpPoolInfo> {
@@ -210,39 +313,231 @@ impl PumpFunClient {
             &self.instruction_builder.program_id,
         );
 
-        let associated_bonding_curve = get_associated_token_address(&bonding_curve, mint);
-
         let curve_account = self.rpc_client.get_account(&bonding_curve)?;
-
-        let creator_bytes = &curve_account.data[41..73];
-        let creator = Pubkey::try_from(creator_bytes)
-            .map_err(|_| PumpFunError::InvalidAccountData("Failed to parse creator".to_string()))?;
-
-        let complete = curve_account.data[8] != 0;
-        let virtual_token_reserves = u64::from_le_bytes(curve_account.data[9..17].try_into()?);
-        let virtual_sol_reserves = u64::from_le_bytes(curve_account.data[17..25].try_into()?);
-        let token_total_supply = u64::from_le_bytes(curve_account.data[25..33].try_into()?);
+        let curve = BondingCurve::from_account_data(&curve_account.data)?;
+        // `create_v2` curves hold Token-2022 mints, whose token accounts
+        // derive from the mint owner.
+        let token_program = self.rpc_client.get_account(mint)?.owner;
+        let associated_bonding_curve =
+            get_associated_token_address_with_program_id(&bonding_curve, mint, &token_program);
 
         println!("✅ Pool found!");
         println!("   Bonding Curve: {}", bonding_curve);
-        println!("   Creator: {}", creator);
-        println!("   Complete: {}", complete);
-        println!("   Virtual SOL: {} SOL", lamports_to_sol(virtual_sol_reserves));
-        println!("   Virtual Tokens: {} tokens", lamports_to_sol(virtual_token_reserves));
+        println!("   Creator: {}", curve.creator);
+        println!("   Complete: {}", curve.complete);
+        println!("   Virtual SOL: {} SOL", lamports_to_sol(curve.virtual_sol_reserves));
+        println!("   Virtual Tokens: {} tokens", curve.virtual_token_reserves as f64 / 1e6);
 
         Ok(PumpPoolInfo {
             mint: *mint,
             bonding_curve,
             associated_bonding_curve,
-            creator,
-            virtual_token_reserves,
-            virtual_sol_reserves,
-            token_total_supply,
-            complete,
+            creator: curve.creator,
+            virtual_token_reserves: curve.virtual_token_reserves,
+            virtual_sol_reserves: curve.virtual_sol_reserves,
+            token_total_supply: curve.token_total_supply,
+            complete: curve.complete,
             created_at: None,
         })
     }
 
+    // ==================== TRADING ====================
+
+    pub async fn get_bonding_curve(&self, mint: &Pubkey) -> Result<BondingCurve> {
+        let bonding_curve = self.instruction_builder.get_bonding_curve_pda(mint);
+        let account = self.rpc_client.get_account(&bonding_curve)?;
+        BondingCurve::from_account_data(&account.data)
+    }
+
+    pub async fn get_global(&self) -> Result<Global> {
+        let account = self.rpc_client.get_account(&self.instruction_builder.get_global_pda())?;
+        Global::from_account_data(&account.data)
+    }
+
+    /// `None` when the fee program has no config yet, in which case the
+    /// flat fees from `Global` apply.
+    pub async fn get_fee_config(&self) -> Result<Option<FeeConfig>> {
+        let fee_config = self.instruction_builder.get_fee_config_pda();
+        let account = self
+            .rpc_client
+            .get_account_with_commitment(&fee_config, self.rpc_client.commitment())?
+            .value;
+        account
+            .map(|account| FeeConfig::from_account_data(&account.data))
+            .transpose()
+    }
+
+    /// Buys tokens for `sol_amount` lamports, fees included, with
+    /// `buy_exact_sol_in`. The transaction fails if it would get more than
+    /// `config.slippage_bps` fewer tokens than quoted.
+    pub async fn buy(
+        &self,
+        user: &Keypair,
+        mint: &Pubkey,
+        sol_amount: u64,
+        config: &SwapConfig,
+    ) -> Result<TransactionResult> {
+        println!("\n🟢 Buying {} SOL of {}", lamports_to_sol(sol_amount), mint);
+
+        let market = self.load_market(mint).await?;
+        let quote = quote::buy_exact_sol_in_quote(
+            &market.global,
+            market.fee_config.as_ref(),
+            &market.curve,
+            sol_amount,
+            config.slippage_bps,
+        )?;
+
+        println!("   Expected tokens: {}", quote.expected_output);
+        println!("   Min tokens out: {}", quote.min_output);
+        println!("   Fees: {} SOL", lamports_to_sol(quote.fee_amount));
+        println!("   Price impact: {:.2}", quote.price_impact);
+
+        let user_pubkey = user.pubkey();
+        let mut instructions = Vec::new();
+        let associated_user = get_associated_token_address_with_program_id(
+            &user_pubkey,
+            mint,
+            &market.token_program,
+        );
+        if !self.account_exists(&associated_user)? {
+            println!("   Creating token account: {}", associated_user);
+            instructions.push(create_associated_token_account_idempotent(
+                &user_pubkey,
+                &user_pubkey,
+                mint,
+                &market.token_program,
+            ));
+        }
+
+        instructions.push(self.instruction_builder.build_buy_exact_sol_in_instruction(
+            &user_pubkey,
+            mint,
+            &market.token_program,
+            &market.curve.creator,
+            &market.global.fee_recipient_for(market.curve.is_mayhem_mode),
+            sol_amount,
+            quote.min_output,
+            true,
+        )?);
+
+        self.send_transaction_with_signers(&[user], instructions, config).await
+    }
+
+    /// Sells `token_amount` raw tokens. The transaction fails if the output
+    /// drops by more than `config.slippage_bps`.
+    pub async fn sell(
+        &self,
+        user: &Keypair,
+        mint: &Pubkey,
+        token_amount: u64,
+        config: &SwapConfig,
+    ) -> Result<TransactionResult> {
+        println!("\n🔴 Selling {} tokens (raw) of {}", token_amount, mint);
+
+        let market = self.load_market(mint).await?;
+        let instruction = self.build_sell(user, mint, &market, token_amount, config)?;
+
+        self.send_transaction_with_signers(&[user], vec![instruction], config).await
+    }
+
+    /// Sells the whole token balance and closes the token account to
+    /// reclaim its rent.
+    pub async fn sell_all(
+        &self,
+        user: &Keypair,
+        mint: &Pubkey,
+        config: &SwapConfig,
+    ) -> Result<TransactionResult> {
+        println!("\n🔴 Selling all of {}", mint);
+
+        let market = self.load_market(mint).await?;
+        let user_pubkey = user.pubkey();
+        let associated_user = get_associated_token_address_with_program_id(
+            &user_pubkey,
+            mint,
+            &market.token_program,
+        );
+
+        let balance = self.rpc_client.get_token_account_balance(&associated_user)?;
+        let token_amount: u64 = balance.amount.parse().map_err(|_| {
+            PumpFunError::InvalidAccountData(format!("Invalid token balance: {}", balance.amount))
+        })?;
+        if token_amount == 0 {
+            return Err(PumpFunError::Custom(format!("No {} tokens to sell", mint)));
+        }
+        println!("   Balance: {} tokens (raw)", token_amount);
+
+        let instructions = vec![
+            self.build_sell(user, mint, &market, token_amount, config)?,
+            spl_token_2022::instruction::close_account(
+                &market.token_program,
+                &associated_user,
+                &user_pubkey,
+                &user_pubkey,
+                &[],
+            )?,
+        ];
+
+        self.send_transaction_with_signers(&[user], instructions, config).await
+    }
+
+    fn build_sell(
+        &self,
+        user: &Keypair,
+        mint: &Pubkey,
+        market: &Market,
+        token_amount: u64,
+        config: &SwapConfig,
+    ) -> Result<solana_sdk::instruction::Instruction> {
+        let quote = quote::sell_quote(
+            &market.global,
+            market.fee_config.as_ref(),
+            &market.curve,
+            token_amount,
+            config.slippage_bps,
+        )?;
+
+        println!("   Expected SOL: {} SOL", lamports_to_sol(quote.expected_output));
+        println!("   Min SOL output: {} SOL", lamports_to_sol(quote.min_output));
+        println!("   Fees: {} SOL", lamports_to_sol(quote.fee_amount));
+        println!("   Price impact: {:.2}", quote.price_impact);
+
+        self.instruction_builder.build_sell_instruction(
+            &user.pubkey(),
+            mint,
+            &market.token_program,
+            &market.curve.creator,
+            &market.global.fee_recipient_for(market.curve.is_mayhem_mode),
+            token_amount,
+            quote.min_output,
+            market.curve.is_cashback_coin,
+        )
+    }
+
+    async fn load_market(&self, mint: &Pubkey) -> Result<Market> {
+        let curve = self.get_bonding_curve(mint).await?;
+        if curve.complete {
+            return Err(PumpFunError::PoolComplete);
+        }
+
+        Ok(Market {
+            curve,
+            global: self.get_global().await?,
+            fee_config: self.get_fee_config().await?,
+            // `create` mints are SPL Token, `create_v2` mints are Token-2022.
+            token_program: self.rpc_client.get_account(mint)?.owner,
+        })
+    }
+
+    fn account_exists(&self, address: &Pubkey) -> Result<bool> {
+        Ok(self
+            .rpc_client
+            .get_account_with_commitment(address, self.rpc_client.commitment())?
+            .value
+            .is_some())
+    }
+
     // ==================== TRANSACTION HELPERS ====================
 
     async fn send_transaction_with_signers(
@@ -251,12 +546,45 @@ impl PumpFunClient {
         instructions: Vec<solana_sdk::instruction::Instruction>,
         config: &SwapConfig,
     ) -> Result<TransactionResult> {
-        println!("\n   🔍 SENDING TRANSACTION");
+        let transaction = self.build_transaction(signers, instructions, config)?;
+        self.send_transaction(&transaction, config).await
+    }
+
+    /// Builds with `build_unsigned_transaction` and signs with every signer.
+    pub fn build_transaction(
+        &self,
+        signers: &[&Keypair],
+        instructions: Vec<solana_sdk::instruction::Instruction>,
+        config: &SwapConfig,
+    ) -> Result<Transaction> {
+        println!("\n   🔍 BUILDING TRANSACTION");
         println!("   Instructions: {}", instructions.len());
         println!("   Signers: {}", signers.len());
 
+        let mut transaction = self.build_unsigned_transaction(&signers[0].pubkey(), instructions, config)?;
+        offline::partial_sign(&mut transaction, signers)?;
+
+        Ok(transaction)
+    }
+
+    /// Prepends the compute budget instructions and appends the Jito tip
+    /// when `use_jito` is set. With `durable_nonce`, the nonce is advanced
+    /// first and its blockhash is used, otherwise the latest one.
+    ///
+    /// Sign it with `offline::partial_sign`, possibly on other machines.
+    pub fn build_unsigned_transaction(
+        &self,
+        payer: &Pubkey,
+        instructions: Vec<solana_sdk::instruction::Instruction>,
+        config: &SwapConfig,
+    ) -> Result<Transaction> {
         let mut final_instructions = Vec::new();
 
+        // The runtime only recognizes a nonce transaction by its first instruction.
+        if let Some(nonce) = &config.durable_nonce {
+            final_instructions.push(system_instruction::advance_nonce_account(&nonce.account, &nonce.authority));
+        }
+
         if let Some(limit) = config.compute_unit_limit {
             final_instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(limit));
         }
@@ -266,18 +594,54 @@ impl PumpFunClient {
 
         final_instructions.extend(instructions);
 
-        let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
+        if config.use_jito {
+            let jito = self.jito.as_ref().ok_or_else(|| {
+                PumpFunError::Jito("use_jito is set but no block engine is connected".to_string())
+            })?;
+            final_instructions.push(jito.tip_instruction(payer, config.jito_tip_lamports)?);
+        }
 
-        let message = Message::new(&final_instructions, Some(&signers[0].pubkey()));
-        let mut transaction = Transaction::new_unsigned(message);
-        transaction.sign(signers, recent_blockhash);
+        let recent_blockhash = match &config.durable_nonce {
+            Some(nonce) => self.get_nonce_blockhash(nonce)?,
+            None => self.rpc_client.get_latest_blockhash()?,
+        };
+
+        let mut message = Message::new(&final_instructions, Some(payer));
+        message.recent_blockhash = recent_blockhash;
 
-        let simulation = self.rpc_client.simulate_transaction(&transaction)?;
+        Ok(Transaction::new_unsigned(message))
+    }
+
+    /// Simulates, sends and confirms a fully signed transaction.
+    pub async fn send_transaction(
+        &self,
+        transaction: &Transaction,
+        config: &SwapConfig,
+    ) -> Result<TransactionResult> {
+        println!("\n   🔍 SENDING TRANSACTION");
+
+        let missing = offline::missing_signers(transaction);
+        if !missing.is_empty() {
+            let missing = missing.iter().map(|key| key.to_string()).collect::<Vec<_>>();
+            return Err(PumpFunError::TransactionFailed(format!(
+                "Missing signatures from {}",
+                missing.join(", ")
+            )));
+        }
+
+        let simulation = self.rpc_client.simulate_transaction(transaction)?;
         if let Some(err) = simulation.value.err {
             return Err(PumpFunError::TransactionFailed(format!("Simulation failed: {:?}", err)));
         }
 
-        let signature = self.send_with_retry(&transaction).await?;
+        let signature = match (&self.jito, config.use_jito) {
+            (Some(jito), true) => {
+                let uuid = jito.send_bundle(std::slice::from_ref(transaction)).await?;
+                jito.wait_for_bundle(&uuid, Duration::from_secs(30)).await?;
+                transaction.signatures[0]
+            }
+            _ => self.send_with_retry(transaction).await?,
+        };
         self.confirm_transaction(&signature).await?;
 
         let signature_str = signature.to_string();
@@ -293,6 +657,57 @@ impl PumpFunClient {
         })
     }
 
+    // ==================== DURABLE NONCE ====================
+
+    /// Creates a nonce account for `SwapConfig.durable_nonce`, funded for
+    /// rent exemption by `payer`.
+    pub async fn create_nonce_account(
+        &self,
+        payer: &Keypair,
+        nonce_keypair: &Keypair,
+        authority: &Pubkey,
+    ) -> Result<DurableNonce> {
+        println!("\n🔧 Creating nonce account: {}", nonce_keypair.pubkey());
+        let lamports = self.rpc_client.get_minimum_balance_for_rent_exemption(nonce::State::size())?;
+        let instructions = system_instruction::create_nonce_account(
+            &payer.pubkey(),
+            &nonce_keypair.pubkey(),
+            authority,
+            lamports,
+        );
+
+        let config = SwapConfig {
+            network: self.network,
+            ..SwapConfig::default()
+        };
+        self.send_transaction_with_signers(&[payer, nonce_keypair], instructions, &config).await?;
+
+        Ok(DurableNonce {
+            account: nonce_keypair.pubkey(),
+            authority: *authority,
+        })
+    }
+
+    /// The blockhash currently stored in the nonce account.
+    pub fn get_nonce_blockhash(&self, nonce: &DurableNonce) -> Result<Hash> {
+        let account = nonce_utils::get_account_with_commitment(
+            &self.rpc_client,
+            &nonce.account,
+            self.rpc_client.commitment(),
+        )
+        .map_err(|e| PumpFunError::AccountNotFound(format!("Nonce account {}: {}", nonce.account, e)))?;
+        let data = nonce_utils::data_from_account(&account)
+            .map_err(|e| PumpFunError::InvalidAccountData(e.to_string()))?;
+
+        if data.authority != nonce.authority {
+            return Err(PumpFunError::InvalidAccountData(format!(
+                "Nonce authority is {}, not {}",
+                data.authority, nonce.authority
+            )));
+        }
+        Ok(data.blockhash())
+    }
+
     async fn send_with_retry(&self, transaction: &Transaction) -> Result<Signature> {
         let max_retries = 3;
         let mut last_error = None;
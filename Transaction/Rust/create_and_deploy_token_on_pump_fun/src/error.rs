@@ -48,6 +48,9 @@ pub enum PumpFunError {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
     
+    #[error("Jito error: {0}")]
+    Jito(String),
+    
     #[error("Custom error: {0}")]
     Custom(String),
 }
@@ -58,6 +61,18 @@ impl From<ClientError> for PumpFunError {
     }
 }
 
+impl From<tonic::Status> for PumpFunError {
+    fn from(status: tonic::Status) -> Self {
+        PumpFunError::Jito(format!("{}: {}", status.code(), status.message()))
+    }
+}
+
+impl From<tonic::transport::Error> for PumpFunError {
+    fn from(err: tonic::transport::Error) -> Self {
+        PumpFunError::Jito(format!("Transport error: {}", err))
+    }
+}
+
 impl From<ParsePubkeyError> for PumpFunError {
     fn from(err: ParsePubkeyError) -> Self {
         PumpFunError::InvalidPubkey(err.to_string())
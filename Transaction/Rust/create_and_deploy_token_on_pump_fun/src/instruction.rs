@@ -19,9 +19,13 @@ const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb
 const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
 const MPL_TOKEN_METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
 const MAYHEM_PROGRAM_ID: &str = "MAyhSmzXzV1pTf7LsNkrNwkWKTo4ougAJ1PPg47MD4e";
+const FEE_PROGRAM_ID: &str = "pfeeUxB6jkeY1Hxd7CsFCAjcbHA9rWtchMGdZ6VojVZ";
 
 const CREATE_DISCRIMINATOR: [u8; 8] = [24, 30, 200, 40, 5, 28, 7, 119];
 const CREATE_V2_DISCRIMINATOR: [u8; 8] = [0xd6, 0x90, 0x4c, 0xec, 0x5f, 0x8b, 0x31, 0xb4];
+const BUY_DISCRIMINATOR: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+const SELL_DISCRIMINATOR: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+const BUY_EXACT_SOL_IN_DISCRIMINATOR: [u8; 8] = [56, 252, 116, 8, 158, 223, 205, 95];
 
 pub struct PumpInstructionBuilder {
     pub program_id: Pubkey,
@@ -29,6 +33,7 @@ pub struct PumpInstructionBuilder {
     associated_token_program_id: Pubkey,
     mpl_token_metadata_program_id: Pubkey,
     mayhem_program_id: Pubkey,
+    fee_program_id: Pubkey,
 }
 
 impl PumpInstructionBuilder {
@@ -50,11 +55,15 @@ impl PumpInstructionBuilder {
         let mayhem_program_id = Pubkey::from_str(MAYHEM_PROGRAM_ID)
             .expect("Invalid Mayhem program ID");
 
+        let fee_program_id = Pubkey::from_str(FEE_PROGRAM_ID)
+            .expect("Invalid Fee program ID");
+
         println!("   Program ID: {}", program_id);
         println!("   Token 2022 Program ID: {}", token_2022_program_id);
         println!("   Associated Token Program ID: {}", associated_token_program_id);
         println!("   MPL Token Metadata Program ID: {}", mpl_token_metadata_program_id);
         println!("   Mayhem Program ID: {}", mayhem_program_id);
+        println!("   Fee Program ID: {}", fee_program_id);
 
         Self {
             program_id,
@@ -62,6 +71,7 @@ impl PumpInstructionBuilder {
             associated_token_program_id,
             mpl_token_metadata_program_id,
             mayhem_program_id,
+            fee_program_id,
         }
     }
 
@@ -96,6 +106,31 @@ impl PumpInstructionBuilder {
         ).0
     }
 
+    pub fn get_creator_vault_pda(&self, creator: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"creator-vault", creator.as_ref()],
+            &self.program_id,
+        ).0
+    }
+
+    pub fn get_global_volume_accumulator_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"global_volume_accumulator"], &self.program_id).0
+    }
+
+    pub fn get_user_volume_accumulator_pda(&self, user: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"user_volume_accumulator", user.as_ref()],
+            &self.program_id,
+        ).0
+    }
+
+    pub fn get_fee_config_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"fee_config", self.program_id.as_ref()],
+            &self.fee_program_id,
+        ).0
+    }
+
     fn get_global_params_pda(&self) -> Pubkey {
         Pubkey::find_program_address(&[b"global-params"], &self.mayhem_program_id).0
     }
@@ -288,8 +323,162 @@ impl PumpInstructionBuilder {
             data,
         })
     }
+
+    /// `buy`: exactly `amount` tokens for at most `max_sol_cost` lamports.
+    /// `token_program` is the owner of the mint and `fee_recipient` depends
+    /// on whether the curve is in mayhem mode.
+    pub fn build_buy_instruction(
+        &self,
+        user: &Pubkey,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+        creator: &Pubkey,
+        fee_recipient: &Pubkey,
+        amount: u64,
+        max_sol_cost: u64,
+        track_volume: bool,
+    ) -> Result<Instruction> {
+        println!("\n🔧 Building buy instruction...");
+        println!("   Mint: {}", mint);
+        println!("   Amount: {} tokens (raw)", amount);
+        println!("   Max SOL cost: {} lamports", max_sol_cost);
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&BUY_DISCRIMINATOR);
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&max_sol_cost.to_le_bytes());
+        data.push(track_volume as u8);
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: self.buy_accounts(user, mint, token_program, creator, fee_recipient),
+            data,
+        })
+    }
+
+    /// `buy_exact_sol_in`: spends at most `spendable_sol_in` lamports, fees
+    /// included, for at least `min_tokens_out` tokens. Takes the same
+    /// accounts as `buy`.
+    pub fn build_buy_exact_sol_in_instruction(
+        &self,
+        user: &Pubkey,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+        creator: &Pubkey,
+        fee_recipient: &Pubkey,
+        spendable_sol_in: u64,
+        min_tokens_out: u64,
+        track_volume: bool,
+    ) -> Result<Instruction> {
+        println!("\n🔧 Building buy_exact_sol_in instruction...");
+        println!("   Mint: {}", mint);
+        println!("   Spendable SOL: {} lamports", spendable_sol_in);
+        println!("   Min tokens out: {} tokens (raw)", min_tokens_out);
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&BUY_EXACT_SOL_IN_DISCRIMINATOR);
+        data.extend_from_slice(&spendable_sol_in.to_le_bytes());
+        data.extend_from_slice(&min_tokens_out.to_le_bytes());
+        data.push(track_volume as u8);
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: self.buy_accounts(user, mint, token_program, creator, fee_recipient),
+            data,
+        })
+    }
+
+    fn buy_accounts(
+        &self,
+        user: &Pubkey,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+        creator: &Pubkey,
+        fee_recipient: &Pubkey,
+    ) -> Vec<AccountMeta> {
+        let bonding_curve = self.get_bonding_curve_pda(mint);
+        let associated_bonding_curve =
+            get_associated_token_address_with_program_id(&bonding_curve, mint, token_program);
+        let associated_user = get_associated_token_address_with_program_id(user, mint, token_program);
+
+        vec![
+            AccountMeta::new_readonly(self.get_global_pda(), false),
+            AccountMeta::new(*fee_recipient, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(bonding_curve, false),
+            AccountMeta::new(associated_bonding_curve, false),
+            AccountMeta::new(associated_user, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new(self.get_creator_vault_pda(creator), false),
+            AccountMeta::new_readonly(self.get_event_authority_pda(), false),
+            AccountMeta::new_readonly(self.program_id, false),
+            AccountMeta::new_readonly(self.get_global_volume_accumulator_pda(), false),
+            AccountMeta::new(self.get_user_volume_accumulator_pda(user), false),
+            AccountMeta::new_readonly(self.get_fee_config_pda(), false),
+            AccountMeta::new_readonly(self.fee_program_id, false),
+        ]
+    }
+
+    /// `sell`: exactly `amount` tokens for at least `min_sol_output` lamports.
+    /// Cashback coins credit the user volume accumulator, which is passed
+    /// after the declared accounts.
+    pub fn build_sell_instruction(
+        &self,
+        user: &Pubkey,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+        creator: &Pubkey,
+        fee_recipient: &Pubkey,
+        amount: u64,
+        min_sol_output: u64,
+        is_cashback_coin: bool,
+    ) -> Result<Instruction> {
+        println!("\n🔧 Building sell instruction...");
+        println!("   Mint: {}", mint);
+        println!("   Amount: {} tokens (raw)", amount);
+        println!("   Min SOL output: {} lamports", min_sol_output);
+
+        let bonding_curve = self.get_bonding_curve_pda(mint);
+        let associated_bonding_curve =
+            get_associated_token_address_with_program_id(&bonding_curve, mint, token_program);
+        let associated_user = get_associated_token_address_with_program_id(user, mint, token_program);
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&SELL_DISCRIMINATOR);
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&min_sol_output.to_le_bytes());
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.get_global_pda(), false),
+            AccountMeta::new(*fee_recipient, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(bonding_curve, false),
+            AccountMeta::new(associated_bonding_curve, false),
+            AccountMeta::new(associated_user, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(self.get_creator_vault_pda(creator), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(self.get_event_authority_pda(), false),
+            AccountMeta::new_readonly(self.program_id, false),
+            AccountMeta::new_readonly(self.get_fee_config_pda(), false),
+            AccountMeta::new_readonly(self.fee_program_id, false),
+        ];
+        if is_cashback_coin {
+            accounts.push(AccountMeta::new(self.get_user_volume_accumulator_pda(user), false));
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data,
+        })
+    }
 }
 
+
 impl Default for PumpInstructionBuilder {
     fn default() -> Self {
         Self::new(Network::Mainnet)
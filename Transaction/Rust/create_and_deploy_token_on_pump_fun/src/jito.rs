@@ -0,0 +1,294 @@
+use jito_protos::{
+    bundle::{bundle_result, Bundle, BundleResult},
+    packet::{Meta, Packet},
+    searcher::{
+        searcher_service_client::SearcherServiceClient, GetTipAccountsRequest, SendBundleRequest,
+        SubscribeBundleResultsRequest,
+    },
+};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    system_instruction,
+    transaction::Transaction,
+};
+use log::{info, warn};
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tonic::{
+    metadata::{Ascii, MetadataValue},
+    service::{interceptor::InterceptedService, Interceptor},
+    transport::{Channel, ClientTlsConfig, Endpoint},
+    Request, Status, Streaming,
+};
+use crate::error::{PumpFunError, Result};
+
+/// Bundles hold at most five transactions.
+pub const MAX_BUNDLE_TRANSACTIONS: usize = 5;
+
+/// Delay before resubscribing to bundle results after the stream closes,
+/// doubled after each failed attempt up to `MAX_RESUBSCRIBE_DELAY`.
+const RESUBSCRIBE_DELAY: Duration = Duration::from_millis(500);
+const MAX_RESUBSCRIBE_DELAY: Duration = Duration::from_secs(30);
+
+type Searcher = SearcherServiceClient<InterceptedService<Channel, JitoAuth>>;
+
+/// Adds the `x-jito-auth` header when a UUID is configured.
+#[derive(Clone)]
+pub struct JitoAuth {
+    uuid: Option<MetadataValue<Ascii>>,
+}
+
+impl Interceptor for JitoAuth {
+    fn call(&mut self, mut request: Request<()>) -> std::result::Result<Request<()>, Status> {
+        if let Some(uuid) = &self.uuid {
+            request.metadata_mut().insert("x-jito-auth", uuid.clone());
+        }
+        Ok(request)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BundleStatus {
+    /// Forwarded to a Jito validator, not landed yet.
+    Accepted { slot: u64 },
+    Processed { slot: u64 },
+    Finalized,
+    Rejected(String),
+    Dropped(String),
+}
+
+impl BundleStatus {
+    pub fn is_landed(&self) -> bool {
+        matches!(self, BundleStatus::Processed { .. } | BundleStatus::Finalized)
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, BundleStatus::Rejected(_) | BundleStatus::Dropped(_))
+    }
+}
+
+/// Sends bundles to a Jito block engine and keeps the latest result of each.
+pub struct JitoBundleClient {
+    searcher: Searcher,
+    tip_accounts: Vec<Pubkey>,
+    results: Arc<Mutex<HashMap<String, BundleStatus>>>,
+}
+
+impl JitoBundleClient {
+    pub async fn connect(block_engine_url: &str, uuid: Option<&str>) -> Result<Self> {
+        println!("🔧 Connecting to Jito block engine: {}", block_engine_url);
+
+        let mut endpoint = Endpoint::from_shared(block_engine_url.to_string())
+            .map_err(|e| PumpFunError::Jito(e.to_string()))?;
+        if block_engine_url.starts_with("https") {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new())?;
+        }
+        let channel = endpoint.connect().await?;
+
+        let uuid = uuid
+            .map(|uuid| {
+                MetadataValue::from_str(uuid)
+                    .map_err(|_| PumpFunError::Jito("Invalid Jito UUID".to_string()))
+            })
+            .transpose()?;
+        let mut searcher = SearcherServiceClient::with_interceptor(channel, JitoAuth { uuid });
+
+        let tip_accounts = searcher
+            .get_tip_accounts(GetTipAccountsRequest {})
+            .await?
+            .into_inner()
+            .accounts
+            .iter()
+            .map(|account| Pubkey::from_str(account))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        println!("   Tip accounts: {}", tip_accounts.len());
+
+        let client = Self {
+            searcher,
+            tip_accounts,
+            results: Arc::new(Mutex::new(HashMap::new())),
+        };
+        client.track_results().await?;
+        Ok(client)
+    }
+
+    pub fn tip_accounts(&self) -> &[Pubkey] {
+        &self.tip_accounts
+    }
+
+    /// Picks one of the tip accounts. Spreading tips across them avoids
+    /// write-lock contention with other searchers.
+    pub fn choose_tip_account(&self) -> Result<Pubkey> {
+        if self.tip_accounts.is_empty() {
+            return Err(PumpFunError::Jito("Block engine returned no tip accounts".to_string()));
+        }
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos() as usize;
+        Ok(self.tip_accounts[nanos % self.tip_accounts.len()])
+    }
+
+    pub fn tip_instruction(&self, payer: &Pubkey, lamports: u64) -> Result<Instruction> {
+        let tip_account = self.choose_tip_account()?;
+        println!("   Jito tip: {} lamports to {}", lamports, tip_account);
+        Ok(system_instruction::transfer(payer, &tip_account, lamports))
+    }
+
+    /// Sends signed transactions as one bundle and returns its UUID.
+    pub async fn send_bundle(&self, transactions: &[Transaction]) -> Result<String> {
+        if transactions.is_empty() || transactions.len() > MAX_BUNDLE_TRANSACTIONS {
+            return Err(PumpFunError::Jito(format!(
+                "A bundle holds 1 to {} transactions, got {}",
+                MAX_BUNDLE_TRANSACTIONS,
+                transactions.len()
+            )));
+        }
+
+        let packets = transactions
+            .iter()
+            .map(|transaction| {
+                let data = bincode::serialize(transaction)
+                    .map_err(|e| PumpFunError::SerializationError(e.to_string()))?;
+                Ok(Packet {
+                    meta: Some(Meta {
+                        size: data.len() as u64,
+                        ..Default::default()
+                    }),
+                    data,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let uuid = self
+            .searcher
+            .clone()
+            .send_bundle(SendBundleRequest {
+                bundle: Some(Bundle { header: None, packets }),
+            })
+            .await?
+            .into_inner()
+            .uuid;
+
+        println!("   ✅ Bundle sent: {}", uuid);
+        Ok(uuid)
+    }
+
+    pub fn bundle_status(&self, uuid: &str) -> Option<BundleStatus> {
+        self.results.lock().unwrap().get(uuid).cloned()
+    }
+
+    /// Waits until the bundle lands or fails.
+    pub async fn wait_for_bundle(&self, uuid: &str, timeout: Duration) -> Result<BundleStatus> {
+        println!("   Waiting for bundle result...");
+        let start = std::time::Instant::now();
+
+        while start.elapsed() < timeout {
+            match self.bundle_status(uuid) {
+                Some(status) if status.is_landed() => {
+                    println!("   ✅ Bundle landed: {:?}", status);
+                    return Ok(status);
+                }
+                Some(status) if status.is_failed() => {
+                    return Err(PumpFunError::Jito(format!("Bundle {} failed: {:?}", uuid, status)));
+                }
+                _ => tokio::time::sleep(Duration::from_millis(200)).await,
+            }
+        }
+
+        Err(PumpFunError::TransactionTimeout)
+    }
+
+    /// Subscribes to bundle results and records them in the background.
+    /// The first subscription must succeed; after that the stream is
+    /// resubscribed with backoff whenever it ends, until the client is dropped.
+    async fn track_results(&self) -> Result<()> {
+        let mut searcher = self.searcher.clone();
+        let stream = subscribe_bundle_results(&mut searcher).await?;
+        let results = Arc::downgrade(&self.results);
+
+        tokio::spawn(async move {
+            let mut stream = Some(stream);
+            let mut delay = RESUBSCRIBE_DELAY;
+            loop {
+                let Some(current) = stream.as_mut() else {
+                    tokio::time::sleep(delay).await;
+                    if results.strong_count() == 0 {
+                        return;
+                    }
+                    match subscribe_bundle_results(&mut searcher).await {
+                        Ok(resubscribed) => {
+                            info!("Resubscribed to Jito bundle results");
+                            stream = Some(resubscribed);
+                            delay = RESUBSCRIBE_DELAY;
+                        }
+                        Err(e) => {
+                            warn!("Resubscribing to Jito bundle results failed: {}", e);
+                            delay = (delay * 2).min(MAX_RESUBSCRIBE_DELAY);
+                        }
+                    }
+                    continue;
+                };
+
+                match current.message().await {
+                    Ok(Some(result)) => {
+                        if !record_result(&results, result) {
+                            return;
+                        }
+                    }
+                    Ok(None) => {
+                        warn!("Jito bundle result stream ended, resubscribing");
+                        stream = None;
+                    }
+                    Err(e) => {
+                        warn!("Jito bundle result stream closed: {}, resubscribing", e);
+                        stream = None;
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+async fn subscribe_bundle_results(searcher: &mut Searcher) -> Result<Streaming<BundleResult>> {
+    Ok(searcher
+        .subscribe_bundle_results(SubscribeBundleResultsRequest {})
+        .await?
+        .into_inner())
+}
+
+/// Records the result of a bundle. Returns false once the client is gone.
+fn record_result(results: &Weak<Mutex<HashMap<String, BundleStatus>>>, result: BundleResult) -> bool {
+    let Some(results) = results.upgrade() else {
+        return false;
+    };
+    if let Some(status) = bundle_status(&result) {
+        // A bundle forwarded to several validators reports more events
+        // after it has landed.
+        let mut results = results.lock().unwrap();
+        let landed = results
+            .get(&result.bundle_id)
+            .is_some_and(BundleStatus::is_landed);
+        if !landed {
+            results.insert(result.bundle_id, status);
+        }
+    }
+    true
+}
+
+fn bundle_status(result: &BundleResult) -> Option<BundleStatus> {
+    Some(match result.result.as_ref()? {
+        bundle_result::Result::Accepted(accepted) => BundleStatus::Accepted { slot: accepted.slot },
+        bundle_result::Result::Processed(processed) => BundleStatus::Processed { slot: processed.slot },
+        bundle_result::Result::Finalized(_) => BundleStatus::Finalized,
+        bundle_result::Result::Rejected(rejected) => BundleStatus::Rejected(format!("{:?}", rejected.reason)),
+        bundle_result::Result::Dropped(dropped) => BundleStatus::Dropped(format!("{:?}", dropped.reason())),
+    })
+}
@@ -2,11 +2,16 @@
 pub mod client;
 pub mod error;
 pub mod instruction;
+pub mod jito;
 pub mod models;
+pub mod offline;
 pub mod utils;
 pub mod constants;
+pub mod quote;
+pub mod state;
 
 pub use client::PumpFunClient;
 pub use models::*;
 pub use error::*;
-pub use constants::*;
\ No newline at end of file
+pub use constants::*;
+pub use state::{BondingCurve, FeeConfig, Global};
\ No newline at end of file
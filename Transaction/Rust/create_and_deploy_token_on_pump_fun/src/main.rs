@@ -1,144 +1,354 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use pumpfun_new_mint::{
     PumpFunClient,
+    PumpPoolInfo,
     Network,
-    utils::{load_keypair_from_env, lamports_to_sol},
+    SwapConfig,
+    DurableNonce,
+    offline::{missing_signers, serialize_transaction},
+    utils::{load_keypair_from_env, load_rpc_url_with_network, lamports_to_sol, parse_pubkey},
 };
-use solana_sdk::signature::{Keypair, Signer};
-use env_logger;
+use serde::Deserialize;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::path::PathBuf;
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    env_logger::init();
+#[derive(Parser)]
+#[command(name = "pumpfun_new_mint", about = "Create and inspect Pump.fun tokens")]
+struct Cli {
+    #[arg(long, value_enum, default_value = "devnet", global = true)]
+    network: NetworkArg,
 
-    println!("🚀 Pump.fun Token Creator");
-    println!("==========================\n");
+    /// Overrides `RPC_URL` and the network default.
+    #[arg(long, global = true)]
+    rpc_url: Option<String>,
 
-    let wallet = load_keypair_from_env("PRIVATE_KEY")
-        .expect("❌ Please set PRIVATE_KEY in .env file");
+    /// Environment variable holding the base58 wallet key.
+    #[arg(long, default_value = "PRIVATE_KEY", global = true)]
+    keypair_env: String,
 
-    println!("📝 Creator Wallet: {}", wallet.pubkey());
+    #[command(subcommand)]
+    command: Command,
+}
 
-    println!("\nSelect network:");
-    println!("1. Devnet (testing)");
-    println!("2. Mainnet (real)");
-    print!("Choice: ");
-
-    let mut network_choice = String::new();
-    std::io::stdin().read_line(&mut network_choice)?;
-
-    let client = match network_choice.trim() {
-        "1" => {
-            println!("🌐 Using Devnet");
-            PumpFunClient::devnet()
-        },
-        "2" => {
-            println!("🌐 Using Mainnet");
-            PumpFunClient::mainnet()
-        },
-        _ => anyhow::bail!("❌ Invalid network choice"),
-    };
+#[derive(Clone, Copy, ValueEnum)]
+enum NetworkArg {
+    Devnet,
+    Mainnet,
+    Localnet,
+}
 
-    let balance = client.check_balance(&wallet.pubkey()).await?;
-    println!("💰 Balance: {} SOL", balance);
+impl From<NetworkArg> for Network {
+    fn from(network: NetworkArg) -> Self {
+        match network {
+            NetworkArg::Devnet => Network::Devnet,
+            NetworkArg::Mainnet => Network::Mainnet,
+            NetworkArg::Localnet => Network::Localnet,
+        }
+    }
+}
 
-    if balance < 0.1 {
-        anyhow::bail!("❌ Need at least 0.1 SOL for creation");
+#[derive(Subcommand)]
+enum Command {
+    /// Create a token with the original `create` instruction
+    Create(CreateArgs),
+    /// Create a token with `create_v2`
+    CreateV2 {
+        #[command(flatten)]
+        token: CreateArgs,
+        /// Overrides `mayhem_mode` from the spec; `--mayhem false` turns it off.
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        mayhem: Option<bool>,
+        /// Overrides `cashback` from the spec; `--cashback false` turns it off.
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        cashback: Option<bool>,
+    },
+    /// Print the bonding curve of a mint
+    PoolInfo {
+        #[arg(long, value_parser = parse_pubkey)]
+        mint: Pubkey,
+    },
+    /// Print the SOL balance of a wallet, the configured one by default
+    Balance {
+        #[arg(long, value_parser = parse_pubkey)]
+        wallet: Option<Pubkey>,
+    },
+}
+
+#[derive(Args)]
+struct CreateArgs {
+    /// TOML file with `name`, `symbol`, `uri` and, for create-v2,
+    /// `mayhem_mode` and `cashback`. Flags override its values.
+    #[arg(long)]
+    spec: Option<PathBuf>,
+    #[arg(long)]
+    name: Option<String>,
+    #[arg(long)]
+    symbol: Option<String>,
+    #[arg(long)]
+    uri: Option<String>,
+    /// Build and sign the transaction, print it and exit without sending.
+    /// Nothing is sent to the Jito block engine either, so no tip is added.
+    #[arg(long)]
+    dry_run: bool,
+    /// Send the transaction as a bundle through this Jito block engine.
+    #[arg(long)]
+    jito_block_engine: Option<String>,
+    /// Jito auth UUID, sent as the `x-jito-auth` header.
+    #[arg(long, requires = "jito_block_engine")]
+    jito_uuid: Option<String>,
+    /// Tip for the Jito validator, in lamports.
+    #[arg(long, default_value_t = 100_000)]
+    jito_tip: u64,
+    /// Build on this durable nonce account instead of a recent blockhash.
+    #[arg(long, value_parser = parse_pubkey)]
+    nonce_account: Option<Pubkey>,
+    /// Authority of the nonce account, the wallet by default. A transaction
+    /// with another authority needs its signature before it can be sent.
+    #[arg(long, value_parser = parse_pubkey, requires = "nonce_account")]
+    nonce_authority: Option<Pubkey>,
+}
+
+#[derive(Default, Deserialize)]
+struct TokenSpec {
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+    mayhem_mode: Option<bool>,
+    cashback: Option<bool>,
+}
+
+impl TokenSpec {
+    /// `create` has no mayhem mode or cashback, so a spec asking for
+    /// either is a mistake rather than something to ignore.
+    fn ensure_create_v1(&self) -> anyhow::Result<()> {
+        if self.mayhem_mode.is_some() || self.cashback.is_some() {
+            anyhow::bail!("❌ `mayhem_mode` and `cashback` only apply to create-v2, remove them from the spec");
+        }
+        Ok(())
     }
+}
 
-    println!("\nSelect create method:");
-    println!("1. Create_v2");
-    println!("2. Create ");
-    print!("Choice: ");
+struct TokenDetails {
+    name: String,
+    symbol: String,
+    uri: String,
+    mayhem_mode: bool,
+    cashback: bool,
+}
 
-    let mut method_choice = String::new();
-    std::io::stdin().read_line(&mut method_choice)?;
+impl CreateArgs {
+    fn spec(&self) -> anyhow::Result<TokenSpec> {
+        Ok(match &self.spec {
+            Some(path) => toml::from_str(&std::fs::read_to_string(path)?)?,
+            None => TokenSpec::default(),
+        })
+    }
 
-    println!("\n📋 Enter token details:");
+    fn resolve(&self, spec: TokenSpec, mayhem: Option<bool>, cashback: Option<bool>) -> anyhow::Result<TokenDetails> {
+        let required = |flag: &Option<String>, spec: Option<String>, field: &str| {
+            flag.clone()
+                .or(spec)
+                .ok_or_else(|| anyhow::anyhow!("❌ Missing --{} (or `{}` in the spec file)", field, field))
+        };
 
-    print!("Token name: ");
-    let mut name = String::new();
-    std::io::stdin().read_line(&mut name)?;
-    let name = name.trim().to_string();
+        Ok(TokenDetails {
+            name: required(&self.name, spec.name, "name")?,
+            symbol: required(&self.symbol, spec.symbol, "symbol")?,
+            uri: required(&self.uri, spec.uri, "uri")?,
+            mayhem_mode: mayhem.or(spec.mayhem_mode).unwrap_or(false),
+            cashback: cashback.or(spec.cashback).unwrap_or(false),
+        })
+    }
 
-    print!("Token symbol: ");
-    let mut symbol = String::new();
-    std::io::stdin().read_line(&mut symbol)?;
-    let symbol = symbol.trim().to_string();
+    fn swap_config(&self, network: Network, wallet: &Keypair) -> SwapConfig {
+        SwapConfig {
+            use_jito: self.jito_block_engine.is_some() && !self.dry_run,
+            jito_tip_lamports: self.jito_tip,
+            durable_nonce: self.nonce_account.map(|account| DurableNonce {
+                account,
+                authority: self.nonce_authority.unwrap_or_else(|| wallet.pubkey()),
+            }),
+            ..SwapConfig::token_creation(network)
+        }
+    }
 
-    print!("Token URI (metadata): ");
-    let mut uri = String::new();
-    std::io::stdin().read_line(&mut uri)?;
-    let uri = uri.trim().to_string();
+    async fn connect_jito(&self, client: PumpFunClient) -> anyhow::Result<PumpFunClient> {
+        Ok(match &self.jito_block_engine {
+            Some(url) if !self.dry_run => client.with_jito(url, self.jito_uuid.as_deref()).await?,
+            Some(_) => {
+                println!("🧪 Dry run: not connecting to the Jito block engine, the tip is left out");
+                client
+            }
+            None => client,
+        })
+    }
+}
 
-    let result = match method_choice.trim() {
-        "1" => {
-            print!("Mayhem mode? (y/n, default: n): ");
-            let mut mayhem_input = String::new();
-            std::io::stdin().read_line(&mut mayhem_input)?;
-            let is_mayhem_mode = mayhem_input.trim().to_lowercase() == "y";
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::init();
+    let cli = Cli::parse();
+
+    let network = Network::from(cli.network);
+    let rpc_url = cli
+        .rpc_url
+        .clone()
+        .unwrap_or_else(|| load_rpc_url_with_network("RPC_URL", &network));
+    let client = PumpFunClient::new(&rpc_url, network);
 
-            print!("Cashback enabled? (y/n, default: n): ");
-            let mut cashback_input = String::new();
-            std::io::stdin().read_line(&mut cashback_input)?;
-            let is_cashback_enabled = cashback_input.trim().to_lowercase() == "y";
+    match &cli.command {
+        Command::Create(args) => {
+            let spec = args.spec()?;
+            spec.ensure_create_v1()?;
+            let token = args.resolve(spec, None, None)?;
+            let wallet = load_wallet(&cli.keypair_env)?;
+            let config = args.swap_config(network, &wallet);
+            let client = args.connect_jito(client).await?;
+
+            if args.dry_run {
+                let mint = Keypair::new();
+                let transaction = client.build_create_transaction(
+                    &wallet,
+                    &mint,
+                    token.name,
+                    token.symbol,
+                    token.uri,
+                    &config,
+                )?;
+                print_dry_run(&mint, &transaction)?;
+                return Ok(());
+            }
 
-            println!("\n🔑 Generating new mint keypair...");
+            ensure_creation_balance(&client, &wallet).await?;
+            println!("\n📤 Creating token with original create...");
+            let mint_pubkey = client
+                .create_token(&wallet, token.name, token.symbol, token.uri, &config)
+                .await?;
+            print_created(&client, &mint_pubkey).await;
+        }
+        Command::CreateV2 { token: args, mayhem, cashback } => {
+            let token = args.resolve(args.spec()?, *mayhem, *cashback)?;
+            let wallet = load_wallet(&cli.keypair_env)?;
+            let config = args.swap_config(network, &wallet);
+            let client = args.connect_jito(client).await?;
             let mint = Keypair::new();
-            println!("   Mint address: {}", mint.pubkey());
 
+            if args.dry_run {
+                let transaction = client.build_create_v2_transaction(
+                    &wallet,
+                    &mint,
+                    token.name,
+                    token.symbol,
+                    token.uri,
+                    token.mayhem_mode,
+                    token.cashback,
+                    &config,
+                )?;
+                print_dry_run(&mint, &transaction)?;
+                return Ok(());
+            }
+
+            ensure_creation_balance(&client, &wallet).await?;
             println!("\n📤 Creating token with create_v2...");
-            client.create_token_v2(
+            let mint_pubkey = client.create_token_v2(
                 &wallet,
                 &mint,
-                name,
-                symbol,
-                uri,
-                is_mayhem_mode,
-                is_cashback_enabled,
-            ).await
-        },
-        "2" => {
-            println!("\n📤 Creating token with original create...");
-            client.create_token(
-                &wallet,
-                name,
-                symbol,
-                uri,
-            ).await
-        },
-        _ => anyhow::bail!("❌ Invalid method choice"),
-    };
-
-    match result {
-        Ok(mint_pubkey) => {
-            println!("\n✅✅✅ TOKEN CREATED SUCCESSFULLY! ✅✅✅");
-            println!("   Mint: {}", mint_pubkey);
-
-            println!("\n⏳ Fetching pool info to verify (waiting 3 seconds)...");
-            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
-
-            match client.get_pool_info(&mint_pubkey).await {
-                Ok(pool) => {
-                    println!("\n📊 Pool Information:");
-                    println!("   Bonding Curve: {}", pool.bonding_curve);
-                    println!("   Creator: {}", pool.creator);
-                    println!("   Virtual SOL: {} SOL", lamports_to_sol(pool.virtual_sol_reserves));
-                    println!("   Virtual Tokens: {} tokens", lamports_to_sol(pool.virtual_token_reserves));
-                    println!("   Complete: {}", pool.complete);
-                }
-                Err(e) => {
-                    println!("⚠️  Pool not yet available: {}", e);
-                    println!("   Check back in a few seconds on Solscan.");
-                }
-            }
-
-            println!("\n📝 SAVE THIS MINT ADDRESS:");
-            println!("   TEST_TOKEN_MINT={}", mint_pubkey);
+                token.name,
+                token.symbol,
+                token.uri,
+                token.mayhem_mode,
+                token.cashback,
+                &config,
+            ).await?;
+            print_created(&client, &mint_pubkey).await;
         }
-        Err(e) => {
-            println!("\n❌ Failed: {}", e);
+        Command::PoolInfo { mint } => {
+            let pool = client.get_pool_info(mint).await?;
+            print_pool(&pool);
         }
+        Command::Balance { wallet } => {
+            let wallet = match wallet {
+                Some(wallet) => *wallet,
+                None => load_wallet(&cli.keypair_env)?.pubkey(),
+            };
+            let balance = client.check_balance(&wallet).await?;
+            println!("💰 {}: {} SOL", wallet, balance);
+        }
+    }
+
+    Ok(())
+}
+
+fn load_wallet(var_name: &str) -> anyhow::Result<Keypair> {
+    let wallet = load_keypair_from_env(var_name)
+        .ok_or_else(|| anyhow::anyhow!("❌ Please set {} in .env file", var_name))?;
+    println!("📝 Creator Wallet: {}", wallet.pubkey());
+    Ok(wallet)
+}
+
+async fn ensure_creation_balance(client: &PumpFunClient, wallet: &Keypair) -> anyhow::Result<()> {
+    let balance = client.check_balance(&wallet.pubkey()).await?;
+    println!("💰 Balance: {} SOL", balance);
+
+    if balance < 0.1 {
+        anyhow::bail!("❌ Need at least 0.1 SOL for creation");
     }
+    Ok(())
+}
 
+fn print_dry_run(mint: &Keypair, transaction: &Transaction) -> anyhow::Result<()> {
+    let message = &transaction.message;
+
+    println!("\n🧪 DRY RUN (not sent)");
+    println!("   Mint: {}", mint.pubkey());
+    println!("   Signature: {}", transaction.signatures[0]);
+    println!("   Recent blockhash: {}", message.recent_blockhash);
+    for signer in missing_signers(transaction) {
+        println!("   Missing signature: {}", signer);
+    }
+    println!("\n📋 Accounts:");
+    for (index, key) in message.account_keys.iter().enumerate() {
+        println!(
+            "   {:>2} {} {}{}",
+            index,
+            key,
+            if message.is_writable(index) { "[writable]" } else { "[readonly]" },
+            if message.is_signer(index) { "[signer]" } else { "" },
+        );
+    }
+    println!("\n📦 Transaction (base64):");
+    println!("{}", serialize_transaction(transaction)?);
     Ok(())
-}
\ No newline at end of file
+}
+
+async fn print_created(client: &PumpFunClient, mint_pubkey: &Pubkey) {
+    println!("\n✅✅✅ TOKEN CREATED SUCCESSFULLY! ✅✅✅");
+    println!("   Mint: {}", mint_pubkey);
+
+    println!("\n⏳ Fetching pool info to verify (waiting 3 seconds)...");
+    tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+
+    match client.get_pool_info(mint_pubkey).await {
+        Ok(pool) => print_pool(&pool),
+        Err(e) => {
+            println!("⚠️  Pool not yet available: {}", e);
+            println!("   Check back in a few seconds on Solscan.");
+        }
+    }
+
+    println!("\n📝 SAVE THIS MINT ADDRESS:");
+    println!("   TEST_TOKEN_MINT={}", mint_pubkey);
+}
+
+fn print_pool(pool: &PumpPoolInfo) {
+    println!("\n📊 Pool Information:");
+    println!("   Bonding Curve: {}", pool.bonding_curve);
+    println!("   Creator: {}", pool.creator);
+    println!("   Virtual SOL: {} SOL", lamports_to_sol(pool.virtual_sol_reserves));
+    println!("   Virtual Tokens: {} tokens", lamports_to_sol(pool.virtual_token_reserves));
+    println!("   Complete: {}", pool.complete);
+}
@@ -1,6 +1,7 @@
 use solana_sdk::pubkey::Pubkey;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;  // This import is critical
+use solana_defi_price::{Price, PriceChange};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PumpPoolInfo {
@@ -15,24 +16,45 @@ pub struct PumpPoolInfo {
     pub created_at: Option<i64>,
 }
 
+/// Where the fee of a trade goes, in lamports.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FeeBreakdown {
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub cashback: u64,
+}
+
+impl FeeBreakdown {
+    pub fn total(&self) -> u64 {
+        self.protocol_fee + self.creator_fee + self.cashback
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BuyQuote {
     pub input_amount: u64,
+    pub max_input: u64,
     pub expected_output: u64,
     pub min_output: u64,
     pub fee_amount: u64,
-    pub price: f64,
-    pub price_impact: f64,
+    pub fees: FeeBreakdown,
+    /// SOL per whole token.
+    pub price: Price,
+    /// Change of the execution price from the spot price, before fees.
+    pub price_impact: PriceChange,
 }
 
 #[derive(Debug, Clone)]
 pub struct SellQuote {
     pub input_amount: u64,
     pub expected_output: u64,
-    pub max_output: u64,
+    pub min_output: u64,
     pub fee_amount: u64,
-    pub price: f64,
-    pub price_impact: f64,
+    pub fees: FeeBreakdown,
+    /// SOL per whole token.
+    pub price: Price,
+    /// Change of the execution price from the spot price, before fees.
+    pub price_impact: PriceChange,
 }
 
 #[derive(Debug, Clone)]
@@ -41,9 +63,21 @@ pub struct SwapConfig {
     pub priority_fee_microlamports: Option<u64>,
     pub compute_unit_limit: Option<u32>,
     pub use_jito: bool,
+    /// Tip paid to the Jito validator when `use_jito` is set.
+    pub jito_tip_lamports: u64,
+    /// Use this nonce account instead of a recent blockhash.
+    pub durable_nonce: Option<DurableNonce>,
     pub network: Network,
 }
 
+/// A durable nonce account. Transactions built on it stay valid until the
+/// nonce is advanced, instead of expiring with their blockhash.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurableNonce {
+    pub account: Pubkey,
+    pub authority: Pubkey,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Network {
     Devnet,
@@ -58,6 +92,8 @@ impl Default for SwapConfig {
             priority_fee_microlamports: Some(5000),
             compute_unit_limit: Some(200_000),
             use_jito: false,
+            jito_tip_lamports: 100_000,
+            durable_nonce: None,
             network: Network::Mainnet,
         }
     }
@@ -77,6 +113,17 @@ impl SwapConfig {
             ..Default::default()
         }
     }
+
+    /// Compute budget for `create` and `create_v2`, which need more
+    /// compute units than a trade. No slippage applies.
+    pub fn token_creation(network: Network) -> Self {
+        Self {
+            slippage_bps: 0,
+            compute_unit_limit: Some(400_000),
+            network,
+            ..Default::default()
+        }
+    }
     
     pub fn get_program_id(&self) -> Pubkey {
         match self.network {
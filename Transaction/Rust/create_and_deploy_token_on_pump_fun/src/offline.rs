@@ -0,0 +1,102 @@
+//! Moving a transaction between machines: it is built once, signed by each
+//! key holder in turn and submitted once every signature is present.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
+use crate::error::{PumpFunError, Result};
+
+/// Signs with the given keys and keeps the signatures already present.
+pub fn partial_sign(transaction: &mut Transaction, signers: &[&Keypair]) -> Result<()> {
+    let keys = signers.iter().map(|signer| signer.pubkey()).collect::<Vec<_>>();
+    let positions = transaction
+        .get_signing_keypair_positions(&keys)
+        .map_err(|e| PumpFunError::TransactionFailed(e.to_string()))?
+        .into_iter()
+        .zip(&keys)
+        .map(|(position, key)| {
+            position.ok_or_else(|| {
+                PumpFunError::TransactionFailed(format!("{} is not a signer of this transaction", key))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let message_data = transaction.message_data();
+    for (signer, position) in signers.iter().zip(positions) {
+        transaction.signatures[position] = signer.sign_message(&message_data);
+    }
+    Ok(())
+}
+
+/// Signers whose signature is still missing.
+pub fn missing_signers(transaction: &Transaction) -> Vec<Pubkey> {
+    let signer_count = transaction.message.header.num_required_signatures as usize;
+    transaction
+        .message
+        .account_keys
+        .iter()
+        .zip(&transaction.signatures)
+        .take(signer_count)
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(key, _)| *key)
+        .collect()
+}
+
+/// Base64 of the wire format, as accepted by `sendTransaction`.
+pub fn serialize_transaction(transaction: &Transaction) -> Result<String> {
+    let data = bincode::serialize(transaction)
+        .map_err(|e| PumpFunError::SerializationError(e.to_string()))?;
+    Ok(STANDARD.encode(data))
+}
+
+pub fn deserialize_transaction(encoded: &str) -> Result<Transaction> {
+    let data = STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| PumpFunError::SerializationError(format!("Invalid base64: {}", e)))?;
+    bincode::deserialize(&data).map_err(|e| PumpFunError::SerializationError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{hash::Hash, message::Message, system_instruction};
+
+    fn two_signer_transaction(payer: &Keypair, cosigner: &Keypair) -> Transaction {
+        let instructions = [
+            system_instruction::transfer(&payer.pubkey(), &cosigner.pubkey(), 1),
+            system_instruction::transfer(&cosigner.pubkey(), &payer.pubkey(), 1),
+        ];
+        let mut message = Message::new(&instructions, Some(&payer.pubkey()));
+        message.recent_blockhash = Hash::new_unique();
+        Transaction::new_unsigned(message)
+    }
+
+    #[test]
+    fn cosigns_after_a_round_trip() {
+        let payer = Keypair::new();
+        let cosigner = Keypair::new();
+        let mut transaction = two_signer_transaction(&payer, &cosigner);
+
+        partial_sign(&mut transaction, &[&payer]).unwrap();
+        assert_eq!(missing_signers(&transaction), vec![cosigner.pubkey()]);
+
+        let mut transaction = deserialize_transaction(&serialize_transaction(&transaction).unwrap()).unwrap();
+        partial_sign(&mut transaction, &[&cosigner]).unwrap();
+
+        assert!(missing_signers(&transaction).is_empty());
+        transaction.verify().unwrap();
+    }
+
+    #[test]
+    fn rejects_keys_that_are_not_signers() {
+        let payer = Keypair::new();
+        let mut transaction = two_signer_transaction(&payer, &Keypair::new());
+
+        assert!(partial_sign(&mut transaction, &[&Keypair::new()]).is_err());
+        assert_eq!(transaction.signatures, vec![Signature::default(); 2]);
+    }
+}
@@ -0,0 +1,486 @@
+//! Buy and sell quotes on the bonding curve.
+//!
+//! All amounts are computed with the same integer math as the program,
+//! so a quote matches the on-chain result to the lamport.
+//!
+//! Mayhem mode does not change a quote. Mayhem curves trade on their own
+//! reserves and supply with the same fees; only the protocol fee goes to
+//! the reserved fee recipient (`Global::fee_recipient_for`).
+
+use crate::{
+    error::{PumpFunError, Result},
+    models::{BuyQuote, FeeBreakdown, SellQuote},
+    state::{BondingCurve, FeeConfig, Fees, Global},
+};
+use solana_defi_price::{Price, PriceChange};
+
+pub const FEE_DENOMINATOR: u64 = 10_000;
+const SOL_DECIMALS: u8 = 9;
+const TOKEN_DECIMALS: u8 = 6;
+
+/// Fees that apply to a curve.
+///
+/// With a `FeeConfig`, the tier is picked from the curve's market cap.
+/// Without one, the flat fees from `Global` apply.
+pub fn fees_for_curve(global: &Global, fee_config: Option<&FeeConfig>, curve: &BondingCurve) -> Result<Fees> {
+    let tiered = fee_config.filter(|config| !config.fee_tiers.is_empty());
+    let Some(fee_config) = tiered else {
+        return Ok(Fees {
+            lp_fee_bps: 0,
+            protocol_fee_bps: global.fee_basis_points,
+            creator_fee_bps: global.creator_fee_basis_points,
+        });
+    };
+
+    let market_cap = market_cap_lamports(curve)?;
+    let first = &fee_config.fee_tiers[0];
+    if market_cap < first.market_cap_lamports_threshold {
+        return Ok(first.fees);
+    }
+    Ok(fee_config
+        .fee_tiers
+        .iter()
+        .rev()
+        .find(|tier| market_cap >= tier.market_cap_lamports_threshold)
+        .unwrap_or(first)
+        .fees)
+}
+
+/// Market cap of the whole supply at the current virtual reserves.
+pub fn market_cap_lamports(curve: &BondingCurve) -> Result<u128> {
+    if curve.virtual_token_reserves == 0 {
+        return Err(PumpFunError::InsufficientLiquidity);
+    }
+    Ok(curve.virtual_sol_reserves as u128 * curve.token_total_supply as u128
+        / curve.virtual_token_reserves as u128)
+}
+
+/// Quote for `buy`: an exact token amount for at most `max_input` lamports.
+pub fn buy_quote(
+    global: &Global,
+    fee_config: Option<&FeeConfig>,
+    curve: &BondingCurve,
+    token_amount: u64,
+    slippage_bps: u16,
+) -> Result<BuyQuote> {
+    check_tradable(curve, token_amount)?;
+    let fees = fees_for_curve(global, fee_config, curve)?;
+
+    let token_amount = token_amount.min(curve.real_token_reserves);
+    let sol_cost = buy_sol_cost(curve, token_amount)?;
+    let fee = fee_breakdown(curve, &fees, sol_cost)?;
+    let input_amount = sol_cost.checked_add(fee.total()).ok_or(PumpFunError::ArithmeticOverflow)?;
+
+    Ok(BuyQuote {
+        input_amount,
+        max_input: add_slippage(input_amount, slippage_bps)?,
+        expected_output: token_amount,
+        min_output: token_amount,
+        fee_amount: fee.total(),
+        fees: fee,
+        price: price(input_amount, token_amount),
+        price_impact: price_impact(curve, sol_cost, token_amount),
+    })
+}
+
+/// Quote for `buy_exact_sol_in`: spend at most `sol_amount` lamports,
+/// fees included, for as many tokens as that buys.
+pub fn buy_exact_sol_in_quote(
+    global: &Global,
+    fee_config: Option<&FeeConfig>,
+    curve: &BondingCurve,
+    sol_amount: u64,
+    slippage_bps: u16,
+) -> Result<BuyQuote> {
+    check_tradable(curve, sol_amount)?;
+    let fees = fees_for_curve(global, fee_config, curve)?;
+
+    // The program takes the fee on top of the curve cost, so the amount
+    // that reaches the curve is what is left after reserving the fee.
+    let total_fee_bps = total_fee_bps(curve, &fees);
+    let curve_input = mul_div(sol_amount - 1, FEE_DENOMINATOR, FEE_DENOMINATOR + total_fee_bps)?;
+    let token_amount = mul_div(
+        curve_input,
+        curve.virtual_token_reserves,
+        curve.virtual_sol_reserves.checked_add(curve_input).ok_or(PumpFunError::ArithmeticOverflow)?,
+    )?
+    .min(curve.real_token_reserves);
+
+    // Rounding the cost and the fees up can put the total a few lamports
+    // over the budget, so step down to the most tokens it still covers.
+    let token_amount = max_tokens_within(curve, &fees, token_amount, sol_amount)?;
+    if token_amount == 0 {
+        return Err(PumpFunError::InsufficientLiquidity);
+    }
+
+    let sol_cost = buy_sol_cost(curve, token_amount)?;
+    let fee = fee_breakdown(curve, &fees, sol_cost)?;
+    let input_amount = sol_cost.checked_add(fee.total()).ok_or(PumpFunError::ArithmeticOverflow)?;
+
+    Ok(BuyQuote {
+        input_amount,
+        max_input: sol_amount,
+        expected_output: token_amount,
+        min_output: sub_slippage(token_amount, slippage_bps),
+        fee_amount: fee.total(),
+        fees: fee,
+        price: price(input_amount, token_amount),
+        price_impact: price_impact(curve, sol_cost, token_amount),
+    })
+}
+
+/// Quote for `sell`: an exact token amount for at least `min_output` lamports.
+pub fn sell_quote(
+    global: &Global,
+    fee_config: Option<&FeeConfig>,
+    curve: &BondingCurve,
+    token_amount: u64,
+    slippage_bps: u16,
+) -> Result<SellQuote> {
+    check_tradable(curve, token_amount)?;
+    let fees = fees_for_curve(global, fee_config, curve)?;
+
+    let sol_out = mul_div(
+        token_amount,
+        curve.virtual_sol_reserves,
+        curve.virtual_token_reserves.checked_add(token_amount).ok_or(PumpFunError::ArithmeticOverflow)?,
+    )?;
+    if sol_out > curve.real_sol_reserves {
+        return Err(PumpFunError::InsufficientLiquidity);
+    }
+    let fee = fee_breakdown(curve, &fees, sol_out)?;
+    let expected_output = sol_out.saturating_sub(fee.total());
+
+    Ok(SellQuote {
+        input_amount: token_amount,
+        expected_output,
+        min_output: sub_slippage(expected_output, slippage_bps),
+        fee_amount: fee.total(),
+        fees: fee,
+        price: price(expected_output, token_amount),
+        price_impact: price_impact(curve, sol_out, token_amount),
+    })
+}
+
+/// The largest amount up to `token_amount` whose cost, fees included, fits
+/// in `budget`. The cost grows with the amount, so this is a binary search.
+fn max_tokens_within(curve: &BondingCurve, fees: &Fees, token_amount: u64, budget: u64) -> Result<u64> {
+    let (mut fits, mut too_many) = (0, token_amount.checked_add(1).ok_or(PumpFunError::ArithmeticOverflow)?);
+    while too_many - fits > 1 {
+        let mid = fits + (too_many - fits) / 2;
+        if buy_input_amount(curve, fees, mid)? <= budget {
+            fits = mid;
+        } else {
+            too_many = mid;
+        }
+    }
+    Ok(fits)
+}
+
+fn buy_input_amount(curve: &BondingCurve, fees: &Fees, token_amount: u64) -> Result<u64> {
+    let sol_cost = buy_sol_cost(curve, token_amount)?;
+    sol_cost
+        .checked_add(fee_breakdown(curve, fees, sol_cost)?.total())
+        .ok_or(PumpFunError::ArithmeticOverflow)
+}
+
+fn check_tradable(curve: &BondingCurve, amount: u64) -> Result<()> {
+    if curve.complete {
+        return Err(PumpFunError::PoolComplete);
+    }
+    if curve.virtual_token_reserves == 0 || curve.virtual_sol_reserves == 0 {
+        return Err(PumpFunError::InsufficientLiquidity);
+    }
+    if amount == 0 {
+        return Err(PumpFunError::Custom("amount must be greater than zero".to_string()));
+    }
+    Ok(())
+}
+
+/// Lamports the curve charges for `token_amount`, before fees. The program
+/// rounds the division down and adds one lamport.
+fn buy_sol_cost(curve: &BondingCurve, token_amount: u64) -> Result<u64> {
+    let remaining = curve
+        .virtual_token_reserves
+        .checked_sub(token_amount)
+        .filter(|remaining| *remaining > 0)
+        .ok_or(PumpFunError::InsufficientLiquidity)?;
+    mul_div(token_amount, curve.virtual_sol_reserves, remaining)?
+        .checked_add(1)
+        .ok_or(PumpFunError::ArithmeticOverflow)
+}
+
+/// The creator fee only applies once the curve has a creator. On cashback
+/// coins it is paid back to the trader instead of the creator.
+fn fee_breakdown(curve: &BondingCurve, fees: &Fees, amount: u64) -> Result<FeeBreakdown> {
+    let protocol_fee = fee(amount, fees.protocol_fee_bps)?;
+    let creator_share = if curve.has_creator() {
+        fee(amount, fees.creator_fee_bps)?
+    } else {
+        0
+    };
+
+    Ok(if curve.is_cashback_coin {
+        FeeBreakdown { protocol_fee, creator_fee: 0, cashback: creator_share }
+    } else {
+        FeeBreakdown { protocol_fee, creator_fee: creator_share, cashback: 0 }
+    })
+}
+
+fn total_fee_bps(curve: &BondingCurve, fees: &Fees) -> u64 {
+    if curve.has_creator() {
+        fees.protocol_fee_bps + fees.creator_fee_bps
+    } else {
+        fees.protocol_fee_bps
+    }
+}
+
+/// Fees are rounded up.
+fn fee(amount: u64, bps: u64) -> Result<u64> {
+    let fee = (amount as u128 * bps as u128).div_ceil(FEE_DENOMINATOR as u128);
+    u64::try_from(fee).map_err(|_| PumpFunError::ArithmeticOverflow)
+}
+
+fn mul_div(a: u64, b: u64, c: u64) -> Result<u64> {
+    if c == 0 {
+        return Err(PumpFunError::ArithmeticOverflow);
+    }
+    u64::try_from(a as u128 * b as u128 / c as u128).map_err(|_| PumpFunError::ArithmeticOverflow)
+}
+
+fn add_slippage(amount: u64, slippage_bps: u16) -> Result<u64> {
+    mul_div(amount, FEE_DENOMINATOR + slippage_bps as u64, FEE_DENOMINATOR)
+}
+
+fn sub_slippage(amount: u64, slippage_bps: u16) -> u64 {
+    let bps = FEE_DENOMINATOR.saturating_sub(slippage_bps as u64);
+    (amount as u128 * bps as u128 / FEE_DENOMINATOR as u128) as u64
+}
+
+/// SOL per whole token, zero when no tokens change hands.
+fn price(lamports: u64, token_amount: u64) -> Price {
+    Price::from_amounts(lamports, SOL_DECIMALS, token_amount, TOKEN_DECIMALS).unwrap_or_default()
+}
+
+/// Change of the execution price, before fees, from the spot price:
+/// positive for buys and negative for sells.
+fn price_impact(curve: &BondingCurve, lamports: u64, token_amount: u64) -> PriceChange {
+    let spot = price(curve.virtual_sol_reserves, curve.virtual_token_reserves);
+    price(lamports, token_amount)
+        .percent_change_from(spot)
+        .unwrap_or(PriceChange { negative: false, percent: Price::ZERO })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::FeeTier;
+    use solana_sdk::pubkey::Pubkey;
+
+    fn global() -> Global {
+        Global {
+            initialized: true,
+            authority: Pubkey::default(),
+            fee_recipient: Pubkey::new_unique(),
+            initial_virtual_token_reserves: 1_073_000_000_000_000,
+            initial_virtual_sol_reserves: 30_000_000_000,
+            initial_real_token_reserves: 793_100_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+            fee_basis_points: 95,
+            withdraw_authority: Pubkey::default(),
+            enable_migrate: true,
+            pool_migration_fee: 0,
+            creator_fee_basis_points: 5,
+            fee_recipients: [Pubkey::default(); 7],
+            set_creator_authority: Pubkey::default(),
+            admin_set_creator_authority: Pubkey::default(),
+            create_v2_enabled: true,
+            whitelist_pda: Pubkey::default(),
+            reserved_fee_recipient: Pubkey::new_unique(),
+            mayhem_mode_enabled: true,
+            reserved_fee_recipients: [Pubkey::default(); 7],
+            is_cashback_enabled: true,
+        }
+    }
+
+    fn new_curve() -> BondingCurve {
+        BondingCurve {
+            virtual_token_reserves: 1_073_000_000_000_000,
+            virtual_sol_reserves: 30_000_000_000,
+            real_token_reserves: 793_100_000_000_000,
+            real_sol_reserves: 0,
+            token_total_supply: 1_000_000_000_000_000,
+            complete: false,
+            creator: Pubkey::new_unique(),
+            is_mayhem_mode: false,
+            is_cashback_coin: false,
+        }
+    }
+
+    #[test]
+    fn buy_charges_curve_cost_plus_rounded_up_fees() {
+        let quote = buy_quote(&global(), None, &new_curve(), 1_000_000_000_000, 100).unwrap();
+
+        // 1e12 * 30e9 / (1.073e15 - 1e12) rounds down to 27_985_074, plus one lamport.
+        let sol_cost = 27_985_075;
+        assert_eq!(quote.fees.protocol_fee, 265_859);
+        assert_eq!(quote.fees.creator_fee, 13_993);
+        assert_eq!(quote.input_amount, sol_cost + 265_859 + 13_993);
+        assert_eq!(quote.max_input, quote.input_amount * 10_100 / 10_000);
+        assert_eq!(quote.expected_output, 1_000_000_000_000);
+    }
+
+    #[test]
+    fn buy_exact_sol_in_stays_within_spendable_sol() {
+        let quote = buy_exact_sol_in_quote(&global(), None, &new_curve(), 1_000_000_000, 500).unwrap();
+
+        assert!(quote.input_amount <= 1_000_000_000);
+        assert_eq!(quote.max_input, 1_000_000_000);
+        assert_eq!(quote.min_output, quote.expected_output * 9_500 / 10_000);
+        assert!(!quote.price_impact.negative);
+        assert!(!quote.price_impact.percent.is_zero());
+    }
+
+    #[test]
+    fn buy_exact_sol_in_never_spends_more_than_the_budget() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut curves = Vec::new();
+        for sold in [0, 200_000_000_000_000, 600_000_000_000_000, 793_000_000_000_000] {
+            let mut curve = new_curve();
+            curve.virtual_sol_reserves = mul_div(
+                curve.virtual_sol_reserves,
+                curve.virtual_token_reserves,
+                curve.virtual_token_reserves - sold,
+            )
+            .unwrap();
+            curve.virtual_token_reserves -= sold;
+            curve.real_token_reserves -= sold;
+            curves.push(BondingCurve { creator: Pubkey::default(), ..curve.clone() });
+            curves.push(BondingCurve { is_cashback_coin: true, ..curve.clone() });
+            curves.push(curve);
+        }
+
+        for curve in &curves {
+            let fees = fees_for_curve(&global(), None, curve).unwrap();
+            for _ in 0..250 {
+                let sol_amount = rng.gen_range(1_000..100_000_000_000);
+                let quote = buy_exact_sol_in_quote(&global(), None, curve, sol_amount, 0).unwrap();
+
+                assert!(quote.input_amount <= sol_amount, "{sol_amount}: {quote:?}");
+                assert_eq!(quote.input_amount, buy_input_amount(curve, &fees, quote.expected_output).unwrap());
+                // Only rounding is left over, unless the curve sold out.
+                if quote.expected_output < curve.real_token_reserves {
+                    assert!(sol_amount - quote.input_amount <= sol_amount / 1_000 + 2, "{sol_amount}: {quote:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn steps_down_to_the_tokens_the_budget_covers() {
+        let curve = new_curve();
+        let fees = fees_for_curve(&global(), None, &curve).unwrap();
+        let cost = buy_input_amount(&curve, &fees, 1_000_000_000_000).unwrap();
+
+        assert_eq!(max_tokens_within(&curve, &fees, 1_000_000_000_000, cost).unwrap(), 1_000_000_000_000);
+        let fewer = max_tokens_within(&curve, &fees, 1_000_000_000_000, cost - 1).unwrap();
+        assert!(fewer < 1_000_000_000_000);
+        assert!(buy_input_amount(&curve, &fees, fewer).unwrap() < cost);
+        assert!(buy_input_amount(&curve, &fees, fewer + 1).unwrap() >= cost);
+    }
+
+    #[test]
+    fn mayhem_mode_does_not_change_quotes() {
+        let mut mayhem = new_curve();
+        mayhem.is_mayhem_mode = true;
+        mayhem.real_sol_reserves = 1_000_000_000;
+        let regular = BondingCurve { is_mayhem_mode: false, ..mayhem.clone() };
+
+        let buy = |curve: &BondingCurve| buy_quote(&global(), None, curve, 1_000_000_000_000, 100).unwrap();
+        let sell = |curve: &BondingCurve| sell_quote(&global(), None, curve, 1_000_000_000_000, 100).unwrap();
+        assert_eq!(format!("{:?}", buy(&mayhem)), format!("{:?}", buy(&regular)));
+        assert_eq!(format!("{:?}", sell(&mayhem)), format!("{:?}", sell(&regular)));
+    }
+
+    #[test]
+    fn price_is_sol_per_whole_token() {
+        let quote = buy_quote(&global(), None, &new_curve(), 1_000_000_000_000, 0).unwrap();
+
+        // 28_264_927 lamports for 1_000_000 tokens.
+        assert_eq!(quote.input_amount, 28_264_927);
+        assert_eq!(quote.price.to_string(), "0.000000028264927");
+    }
+
+    #[test]
+    fn sell_returns_curve_output_minus_fees() {
+        let mut curve = new_curve();
+        curve.real_sol_reserves = 1_000_000_000;
+
+        let quote = sell_quote(&global(), None, &curve, 1_000_000_000_000, 0).unwrap();
+
+        // 1e12 * 30e9 / (1.073e15 + 1e12) rounds down to 27_932_960.
+        assert_eq!(quote.fees.protocol_fee, 265_364);
+        assert_eq!(quote.fees.creator_fee, 13_967);
+        assert_eq!(quote.expected_output, 27_932_960 - 265_364 - 13_967);
+        assert_eq!(quote.min_output, quote.expected_output);
+        assert!(quote.price_impact.negative);
+    }
+
+    #[test]
+    fn cashback_coins_pay_the_creator_share_to_the_trader() {
+        let mut curve = new_curve();
+        curve.is_cashback_coin = true;
+
+        let quote = buy_quote(&global(), None, &curve, 1_000_000_000_000, 0).unwrap();
+
+        assert_eq!(quote.fees.creator_fee, 0);
+        assert_eq!(quote.fees.cashback, 13_993);
+    }
+
+    #[test]
+    fn curves_without_creator_pay_no_creator_fee() {
+        let mut curve = new_curve();
+        curve.creator = Pubkey::default();
+
+        let quote = buy_quote(&global(), None, &curve, 1_000_000_000_000, 0).unwrap();
+
+        assert_eq!(quote.fees.creator_fee, 0);
+        assert_eq!(quote.fee_amount, quote.fees.protocol_fee);
+    }
+
+    #[test]
+    fn fee_tier_follows_market_cap() {
+        let tier = |threshold, protocol_fee_bps| FeeTier {
+            market_cap_lamports_threshold: threshold,
+            fees: Fees { lp_fee_bps: 0, protocol_fee_bps, creator_fee_bps: 30 },
+        };
+        let fee_config = FeeConfig {
+            bump: 0,
+            admin: Pubkey::default(),
+            flat_fees: Fees::default(),
+            fee_tiers: vec![tier(0, 95), tier(50_000_000_000, 50), tier(500_000_000_000, 20)],
+        };
+
+        // A fresh curve is worth about 28 SOL.
+        let fees = fees_for_curve(&global(), Some(&fee_config), &new_curve()).unwrap();
+        assert_eq!(fees.protocol_fee_bps, 95);
+
+        let mut curve = new_curve();
+        curve.virtual_sol_reserves = 60_000_000_000;
+        let fees = fees_for_curve(&global(), Some(&fee_config), &curve).unwrap();
+        assert_eq!(fees.protocol_fee_bps, 50);
+    }
+
+    #[test]
+    fn complete_curves_cannot_be_quoted() {
+        let mut curve = new_curve();
+        curve.complete = true;
+
+        assert!(matches!(
+            buy_quote(&global(), None, &curve, 1, 0),
+            Err(PumpFunError::PoolComplete)
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+use solana_sdk::pubkey::Pubkey;
+use crate::error::{PumpFunError, Result};
+
+const DISCRIMINATOR_LEN: usize = 8;
+
+/// Reads little-endian fields in order from an Anchor account.
+struct AccountReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> AccountReader<'a> {
+    fn new(data: &'a [u8], account: &str) -> Result<Self> {
+        if data.len() < DISCRIMINATOR_LEN {
+            return Err(PumpFunError::InvalidAccountData(format!(
+                "{} account is only {} bytes",
+                account,
+                data.len()
+            )));
+        }
+        Ok(Self { data, offset: DISCRIMINATOR_LEN })
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + len)
+            .ok_or_else(|| {
+                PumpFunError::InvalidAccountData(format!(
+                    "account data ends at {} bytes, expected at least {}",
+                    self.data.len(),
+                    self.offset + len
+                ))
+            })?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn has_remaining(&self) -> bool {
+        self.offset < self.data.len()
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn bool(&mut self) -> Result<bool> {
+        Ok(self.u8()? != 0)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into()?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into()?))
+    }
+
+    fn u128(&mut self) -> Result<u128> {
+        Ok(u128::from_le_bytes(self.bytes(16)?.try_into()?))
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey> {
+        Ok(Pubkey::try_from(self.bytes(32)?)?)
+    }
+
+    fn pubkeys<const N: usize>(&mut self) -> Result<[Pubkey; N]> {
+        let mut keys = [Pubkey::default(); N];
+        for key in keys.iter_mut() {
+            *key = self.pubkey()?;
+        }
+        Ok(keys)
+    }
+
+    /// Fields appended by later program upgrades are missing from
+    /// accounts created before them.
+    fn optional_bool(&mut self) -> Result<bool> {
+        if self.has_remaining() { self.bool() } else { Ok(false) }
+    }
+
+    fn optional_pubkey(&mut self) -> Result<Pubkey> {
+        if self.has_remaining() { self.pubkey() } else { Ok(Pubkey::default()) }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BondingCurve {
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    pub complete: bool,
+    pub creator: Pubkey,
+    pub is_mayhem_mode: bool,
+    pub is_cashback_coin: bool,
+}
+
+impl BondingCurve {
+    pub fn from_account_data(data: &[u8]) -> Result<Self> {
+        let mut reader = AccountReader::new(data, "BondingCurve")?;
+        Ok(Self {
+            virtual_token_reserves: reader.u64()?,
+            virtual_sol_reserves: reader.u64()?,
+            real_token_reserves: reader.u64()?,
+            real_sol_reserves: reader.u64()?,
+            token_total_supply: reader.u64()?,
+            complete: reader.bool()?,
+            creator: reader.optional_pubkey()?,
+            is_mayhem_mode: reader.optional_bool()?,
+            is_cashback_coin: reader.optional_bool()?,
+        })
+    }
+
+    /// Curves created before creator fees were introduced have no creator.
+    pub fn has_creator(&self) -> bool {
+        self.creator != Pubkey::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Global {
+    pub initialized: bool,
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub fee_basis_points: u64,
+    pub withdraw_authority: Pubkey,
+    pub enable_migrate: bool,
+    pub pool_migration_fee: u64,
+    pub creator_fee_basis_points: u64,
+    pub fee_recipients: [Pubkey; 7],
+    pub set_creator_authority: Pubkey,
+    pub admin_set_creator_authority: Pubkey,
+    pub create_v2_enabled: bool,
+    pub whitelist_pda: Pubkey,
+    pub reserved_fee_recipient: Pubkey,
+    pub mayhem_mode_enabled: bool,
+    pub reserved_fee_recipients: [Pubkey; 7],
+    pub is_cashback_enabled: bool,
+}
+
+impl Global {
+    pub fn from_account_data(data: &[u8]) -> Result<Self> {
+        let mut reader = AccountReader::new(data, "Global")?;
+        Ok(Self {
+            initialized: reader.bool()?,
+            authority: reader.pubkey()?,
+            fee_recipient: reader.pubkey()?,
+            initial_virtual_token_reserves: reader.u64()?,
+            initial_virtual_sol_reserves: reader.u64()?,
+            initial_real_token_reserves: reader.u64()?,
+            token_total_supply: reader.u64()?,
+            fee_basis_points: reader.u64()?,
+            withdraw_authority: reader.pubkey()?,
+            enable_migrate: reader.bool()?,
+            pool_migration_fee: reader.u64()?,
+            creator_fee_basis_points: reader.u64()?,
+            fee_recipients: reader.pubkeys()?,
+            set_creator_authority: reader.pubkey()?,
+            admin_set_creator_authority: reader.pubkey()?,
+            create_v2_enabled: reader.optional_bool()?,
+            whitelist_pda: reader.optional_pubkey()?,
+            reserved_fee_recipient: reader.optional_pubkey()?,
+            mayhem_mode_enabled: reader.optional_bool()?,
+            reserved_fee_recipients: if reader.has_remaining() {
+                reader.pubkeys()?
+            } else {
+                [Pubkey::default(); 7]
+            },
+            is_cashback_enabled: reader.optional_bool()?,
+        })
+    }
+
+    /// Mayhem-mode curves pay the protocol fee to the reserved fee
+    /// recipients instead of the regular ones.
+    pub fn fee_recipient_for(&self, is_mayhem_mode: bool) -> Pubkey {
+        if is_mayhem_mode {
+            self.reserved_fee_recipient
+        } else {
+            self.fee_recipient
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Fees {
+    pub lp_fee_bps: u64,
+    pub protocol_fee_bps: u64,
+    pub creator_fee_bps: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeTier {
+    pub market_cap_lamports_threshold: u128,
+    pub fees: Fees,
+}
+
+/// The `FeeConfig` account of the fee program, holding market cap based fee tiers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeConfig {
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub flat_fees: Fees,
+    pub fee_tiers: Vec<FeeTier>,
+}
+
+impl FeeConfig {
+    pub fn from_account_data(data: &[u8]) -> Result<Self> {
+        let mut reader = AccountReader::new(data, "FeeConfig")?;
+        let bump = reader.u8()?;
+        let admin = reader.pubkey()?;
+        let flat_fees = read_fees(&mut reader)?;
+
+        // The count comes from the account, so the reads below bound the
+        // vector rather than the count itself.
+        let tier_count = reader.u32()?;
+        let mut fee_tiers = Vec::new();
+        for _ in 0..tier_count {
+            fee_tiers.push(FeeTier {
+                market_cap_lamports_threshold: reader.u128()?,
+                fees: read_fees(&mut reader)?,
+            });
+        }
+
+        Ok(Self { bump, admin, flat_fees, fee_tiers })
+    }
+}
+
+fn read_fees(reader: &mut AccountReader) -> Result<Fees> {
+    Ok(Fees {
+        lp_fee_bps: reader.u64()?,
+        protocol_fee_bps: reader.u64()?,
+        creator_fee_bps: reader.u64()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_config_data(tier_count: u32, tiers: &[(u128, [u64; 3])]) -> Vec<u8> {
+        let mut data = vec![0; DISCRIMINATOR_LEN];
+        data.push(255);
+        data.extend(Pubkey::new_unique().to_bytes());
+        data.extend([20u64, 5, 5].iter().flat_map(|bps| bps.to_le_bytes()));
+        data.extend(tier_count.to_le_bytes());
+        for (threshold, fees) in tiers {
+            data.extend(threshold.to_le_bytes());
+            data.extend(fees.iter().flat_map(|bps| bps.to_le_bytes()));
+        }
+        data
+    }
+
+    #[test]
+    fn reads_fee_tiers() {
+        let data = fee_config_data(2, &[(0, [20, 5, 95]), (1_000_000, [10, 5, 30])]);
+        let config = FeeConfig::from_account_data(&data).unwrap();
+        assert_eq!(config.flat_fees, Fees { lp_fee_bps: 20, protocol_fee_bps: 5, creator_fee_bps: 5 });
+        assert_eq!(config.fee_tiers.len(), 2);
+        assert_eq!(config.fee_tiers[1].market_cap_lamports_threshold, 1_000_000);
+        assert_eq!(config.fee_tiers[1].fees.creator_fee_bps, 30);
+    }
+
+    #[test]
+    fn rejects_a_tier_count_past_the_end_of_the_data() {
+        let data = fee_config_data(u32::MAX, &[(0, [20, 5, 95])]);
+        assert!(matches!(
+            FeeConfig::from_account_data(&data),
+            Err(PumpFunError::InvalidAccountData(_))
+        ));
+    }
+}
@@ -0,0 +1,211 @@
+use jito_protos::{
+    bundle::{bundle_result, BundleResult, Processed},
+    searcher::{
+        searcher_service_server::{SearcherService, SearcherServiceServer},
+        ConnectedLeadersRegionedRequest, ConnectedLeadersRegionedResponse, ConnectedLeadersRequest,
+        ConnectedLeadersResponse, GetRegionsRequest, GetRegionsResponse, GetTipAccountsRequest,
+        GetTipAccountsResponse, NextScheduledLeaderRequest, NextScheduledLeaderResponse,
+        SendBundleRequest, SendBundleResponse, SubscribeBundleResultsRequest,
+    },
+};
+use pumpfun_new_mint::jito::{BundleStatus, JitoBundleClient};
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::{ReceiverStream, TcpListenerStream};
+use tonic::{transport::Server, Request, Response, Status};
+
+/// Stands in for the block engine: accepts every bundle and reports it
+/// processed to all result subscribers.
+#[derive(Default)]
+struct StandInSearcher {
+    tip_accounts: Vec<String>,
+    bundles: Arc<Mutex<Vec<SendBundleRequest>>>,
+    subscribers: Arc<Mutex<Vec<mpsc::Sender<Result<BundleResult, Status>>>>>,
+}
+
+#[tonic::async_trait]
+impl SearcherService for StandInSearcher {
+    type SubscribeBundleResultsStream = ReceiverStream<Result<BundleResult, Status>>;
+
+    async fn subscribe_bundle_results(
+        &self,
+        _request: Request<SubscribeBundleResultsRequest>,
+    ) -> Result<Response<Self::SubscribeBundleResultsStream>, Status> {
+        let (sender, receiver) = mpsc::channel(16);
+        self.subscribers.lock().unwrap().push(sender);
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn send_bundle(
+        &self,
+        request: Request<SendBundleRequest>,
+    ) -> Result<Response<SendBundleResponse>, Status> {
+        let uuid = {
+            let mut bundles = self.bundles.lock().unwrap();
+            bundles.push(request.into_inner());
+            format!("bundle-{}", bundles.len())
+        };
+
+        let result = BundleResult {
+            bundle_id: uuid.clone(),
+            result: Some(bundle_result::Result::Processed(Processed {
+                validator_identity: "validator".to_string(),
+                slot: 42,
+                bundle_index: 0,
+            })),
+        };
+        let subscribers = self.subscribers.lock().unwrap().clone();
+        for subscriber in subscribers {
+            let _ = subscriber.send(Ok(result.clone())).await;
+        }
+
+        Ok(Response::new(SendBundleResponse { uuid }))
+    }
+
+    async fn get_next_scheduled_leader(
+        &self,
+        _request: Request<NextScheduledLeaderRequest>,
+    ) -> Result<Response<NextScheduledLeaderResponse>, Status> {
+        Err(Status::unimplemented("not needed"))
+    }
+
+    async fn get_connected_leaders(
+        &self,
+        _request: Request<ConnectedLeadersRequest>,
+    ) -> Result<Response<ConnectedLeadersResponse>, Status> {
+        Err(Status::unimplemented("not needed"))
+    }
+
+    async fn get_connected_leaders_regioned(
+        &self,
+        _request: Request<ConnectedLeadersRegionedRequest>,
+    ) -> Result<Response<ConnectedLeadersRegionedResponse>, Status> {
+        Err(Status::unimplemented("not needed"))
+    }
+
+    async fn get_tip_accounts(
+        &self,
+        _request: Request<GetTipAccountsRequest>,
+    ) -> Result<Response<GetTipAccountsResponse>, Status> {
+        Ok(Response::new(GetTipAccountsResponse {
+            accounts: self.tip_accounts.clone(),
+        }))
+    }
+
+    async fn get_regions(
+        &self,
+        _request: Request<GetRegionsRequest>,
+    ) -> Result<Response<GetRegionsResponse>, Status> {
+        Err(Status::unimplemented("not needed"))
+    }
+}
+
+async fn start_searcher(searcher: StandInSearcher) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(
+        Server::builder()
+            .add_service(SearcherServiceServer::new(searcher))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+
+    format!("http://{}", address)
+}
+
+#[tokio::test]
+async fn sends_tipped_bundle_and_tracks_its_result() {
+    let tip_account = Pubkey::new_unique();
+    let bundles = Arc::new(Mutex::new(Vec::new()));
+    let url = start_searcher(StandInSearcher {
+        tip_accounts: vec![tip_account.to_string()],
+        bundles: Arc::clone(&bundles),
+        ..Default::default()
+    })
+    .await;
+
+    let client = JitoBundleClient::connect(&url, Some("test-uuid")).await.unwrap();
+    assert_eq!(client.tip_accounts(), &[tip_account]);
+
+    let payer = Keypair::new();
+    let tip = client.tip_instruction(&payer.pubkey(), 10_000).unwrap();
+    assert_eq!(tip, system_instruction::transfer(&payer.pubkey(), &tip_account, 10_000));
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[tip],
+        Some(&payer.pubkey()),
+        &[&payer],
+        Hash::default(),
+    );
+    let uuid = client.send_bundle(&[transaction.clone()]).await.unwrap();
+
+    let status = client.wait_for_bundle(&uuid, Duration::from_secs(5)).await.unwrap();
+    assert_eq!(status, BundleStatus::Processed { slot: 42 });
+
+    let bundles = bundles.lock().unwrap();
+    let packets = &bundles[0].bundle.as_ref().unwrap().packets;
+    assert_eq!(packets.len(), 1);
+    assert_eq!(packets[0].data, bincode::serialize(&transaction).unwrap());
+}
+
+#[tokio::test]
+async fn rejects_bundles_over_five_transactions() {
+    let url = start_searcher(StandInSearcher {
+        tip_accounts: vec![Pubkey::new_unique().to_string()],
+        ..Default::default()
+    })
+    .await;
+    let client = JitoBundleClient::connect(&url, None).await.unwrap();
+
+    let payer = Keypair::new();
+    let transaction = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        Hash::default(),
+    );
+
+    assert!(client.send_bundle(&vec![transaction; 6]).await.is_err());
+}
+
+#[tokio::test]
+async fn resubscribes_to_bundle_results_after_the_stream_ends() {
+    let subscribers = Arc::new(Mutex::new(Vec::new()));
+    let url = start_searcher(StandInSearcher {
+        tip_accounts: vec![Pubkey::new_unique().to_string()],
+        subscribers: Arc::clone(&subscribers),
+        ..Default::default()
+    })
+    .await;
+    let client = JitoBundleClient::connect(&url, None).await.unwrap();
+
+    // Dropping the senders ends the result stream.
+    subscribers.lock().unwrap().clear();
+    let start = std::time::Instant::now();
+    while subscribers.lock().unwrap().is_empty() {
+        assert!(start.elapsed() < Duration::from_secs(5), "never resubscribed");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    let payer = Keypair::new();
+    let transaction = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        Hash::default(),
+    );
+    let uuid = client.send_bundle(&[transaction]).await.unwrap();
+
+    let status = client.wait_for_bundle(&uuid, Duration::from_secs(5)).await.unwrap();
+    assert_eq!(status, BundleStatus::Processed { slot: 42 });
+}
@@ -33,7 +33,7 @@ pub struct ExtractedInstructions {
     pub inner: Vec<TransactionInstructionWithParent>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TransactionEvent {
     pub event_type: Option<String>,
     pub user: Option<String>,
@@ -52,14 +52,14 @@ pub struct TransactionEvent {
     pub behavior: Option<TradeBehavior>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PriceInfo {
     pub sol_per_token: Option<f64>,
     pub token_per_sol: Option<f64>,
     pub market_price: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PoolState {
     pub virtual_sol: u64,
     pub virtual_token: u64,
@@ -67,19 +67,19 @@ pub struct PoolState {
     pub real_token: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FeeInfo {
     pub protocol: FeePart,
     pub creator: FeePart,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FeePart {
     pub amount: u64,
     pub bps: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TradeBehavior {
     pub is_buy: bool,
     pub is_sell: bool,
@@ -0,0 +1,30 @@
+use {
+    crate::models::mapper::instruction::AccountMetadata,
+    serde::Serialize,
+    solana_sdk::{instruction::AccountMeta, pubkey::Pubkey},
+    std::fmt::Debug,
+};
+
+/// The program-specific part of the pipeline.
+///
+/// `TransactionProcessor` owns the gRPC conversion, account resolution and
+/// instruction flattening; an implementation of this trait tells it which
+/// program to look for and how to decode that program's instructions and
+/// `Program data:` events.
+pub trait ProgramDecoder {
+    type Event: Debug + Clone + Serialize + PartialEq;
+
+    fn program_id(&self) -> Pubkey;
+
+    /// Decodes raw instruction data into the instruction name and its args.
+    fn decode_instruction(&self, data: &[u8]) -> anyhow::Result<(String, serde_json::Value)>;
+
+    /// Decodes the bytes of a single `Program data:` log line.
+    fn decode_event(&self, data: &[u8]) -> anyhow::Result<Self::Event>;
+
+    fn map_accounts(
+        &self,
+        accounts: &[AccountMeta],
+        instruction_name: &str,
+    ) -> anyhow::Result<Vec<AccountMetadata>>;
+}
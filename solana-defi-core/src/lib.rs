@@ -0,0 +1,13 @@
+pub mod decoder;
+#[cfg(feature = "rpc")]
+pub mod metadata_lookup;
+pub mod models;
+pub use solana_defi_price as price;
+pub mod processor;
+pub mod schema;
+pub mod sink;
+pub mod token_registry;
+
+pub use decoder::ProgramDecoder;
+pub use processor::{types, ProcessorError, TransactionProcessor};
+pub use sink::EventSink;
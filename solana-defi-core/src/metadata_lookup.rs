@@ -0,0 +1,91 @@
+//! Looks up the Metaplex metadata and mint accounts of mints the stream has
+//! not described yet, so a [`TokenRegistry`] shared by several streamers is
+//! filled from the stream and the RPC alike.
+
+use {
+    crate::token_registry::{metadata_address, TokenRegistry},
+    log::error,
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{account::Account, pubkey::Pubkey},
+    std::collections::HashSet,
+    tokio::sync::mpsc,
+};
+
+/// Mint batches waiting for an RPC lookup; a batch that does not fit is
+/// dropped and retried on the mint's next request.
+pub const METADATA_LOOKUP_QUEUE: usize = 64;
+
+pub struct MetadataLookup {
+    requests: mpsc::Sender<Vec<Pubkey>>,
+    results: mpsc::Receiver<Vec<(Pubkey, Account)>>,
+    /// Mints queued for or already looked up, found or not.
+    looked_up: HashSet<Pubkey>,
+}
+
+impl MetadataLookup {
+    /// Looks up batches of mints on a separate task so a slow RPC never
+    /// stalls the stream. The task ends once the lookup is dropped.
+    pub fn spawn(rpc_url: String) -> Self {
+        let rpc = RpcClient::new(rpc_url);
+        let (request_tx, mut request_rx) = mpsc::channel::<Vec<Pubkey>>(METADATA_LOOKUP_QUEUE);
+        let (result_tx, result_rx) = mpsc::channel(METADATA_LOOKUP_QUEUE);
+        tokio::spawn(async move {
+            while let Some(mints) = request_rx.recv().await {
+                match look_up_metadata(&rpc, &mints).await {
+                    Ok(accounts) => {
+                        if result_tx.send(accounts).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => error!("Failed to look up token metadata: {e}"),
+                }
+            }
+        });
+        Self {
+            requests: request_tx,
+            results: result_rx,
+            looked_up: HashSet::new(),
+        }
+    }
+
+    /// Queues the mints of `mints` that `tokens` has no metadata for and
+    /// that were not looked up before.
+    pub fn request(&mut self, tokens: &TokenRegistry, mints: impl IntoIterator<Item = Pubkey>) {
+        let mints = mints
+            .into_iter()
+            .filter(|mint| tokens.needs_metadata(mint) && self.looked_up.insert(*mint))
+            .collect::<Vec<_>>();
+        if mints.is_empty() {
+            return;
+        }
+        if let Err(mpsc::error::TrySendError::Full(mints)) = self.requests.try_send(mints) {
+            for mint in mints {
+                self.looked_up.remove(&mint);
+            }
+        }
+    }
+
+    /// Hands the accounts looked up so far to `tokens`. Looked up mints are
+    /// labelled from their next event on.
+    pub fn apply(&mut self, tokens: &mut TokenRegistry) {
+        while let Ok(accounts) = self.results.try_recv() {
+            for (key, account) in accounts {
+                tokens.observe_account(&key, &account.owner, &account.data);
+            }
+        }
+    }
+}
+
+/// Reads the Metaplex metadata and mint accounts of `mints`.
+async fn look_up_metadata(rpc: &RpcClient, mints: &[Pubkey]) -> anyhow::Result<Vec<(Pubkey, Account)>> {
+    let keys = mints
+        .iter()
+        .flat_map(|mint| [metadata_address(mint), *mint])
+        .collect::<Vec<_>>();
+    let accounts = rpc.get_multiple_accounts(&keys).await?;
+    Ok(keys
+        .into_iter()
+        .zip(accounts)
+        .filter_map(|(key, account)| Some((key, account?)))
+        .collect())
+}
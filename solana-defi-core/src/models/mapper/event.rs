@@ -0,0 +1,33 @@
+use base64::engine::general_purpose;
+use base64::Engine;
+
+pub fn decode_log_data(base64_string: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    general_purpose::STANDARD.decode(base64_string)
+}
+
+pub fn extract_all_log_messages(logs: &[String]) -> Vec<String> {
+    logs.iter()
+        .filter_map(|message| {
+            message
+                .strip_prefix("Program data: ")
+                .map(|s| s.trim().to_string())
+        })
+        .collect()
+}
+
+/// Parses `Program <id> invoke [<depth>]` into the program id and its depth.
+pub fn parse_invoke_log(log: &str) -> Option<(&str, usize)> {
+    let (program_id, depth) = log.strip_prefix("Program ")?.split_once(" invoke [")?;
+    let depth = depth.strip_suffix(']')?.parse().ok()?;
+    Some((program_id, depth))
+}
+
+/// Matches the `Program <id> success` / `Program <id> failed: ...` line closing an invoke.
+pub fn is_invoke_result_log(log: &str) -> bool {
+    match log.strip_prefix("Program ").and_then(|rest| rest.split_once(' ')) {
+        Some((program_id, result)) => {
+            !program_id.ends_with(':') && (result == "success" || result.starts_with("failed"))
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,508 @@
+use crate::models::serialize::serialization::serialize_pubkey;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs, path::Path};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_sdk::instruction::AccountMeta;
+
+#[derive(Debug, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    /// Anchor 0.30+ IDLs list the 8-byte discriminator, older ones leave it out.
+    #[serde(default)]
+    pub discriminator: Vec<u8>,
+    pub accounts: Vec<IdlAccountItem>,
+}
+
+/// An instruction account, or a named group of accounts (Anchor composite accounts).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum IdlAccountItem {
+    Group(IdlAccountGroup),
+    Account(IdlAccount),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlAccountGroup {
+    pub name: String,
+    pub accounts: Vec<IdlAccountItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlAccount {
+    pub name: String,
+    #[serde(default, alias = "isMut", alias = "is_writable")]
+    pub writable: bool,
+    #[serde(default, alias = "isSigner", alias = "is_signer")]
+    pub signer: bool,
+    #[serde(default, alias = "isOptional")]
+    pub optional: bool,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub pda: Option<IdlPda>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlPda {
+    pub seeds: Vec<IdlSeed>,
+    #[serde(default)]
+    pub program: Option<IdlSeed>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IdlSeed {
+    Const { value: Vec<u8> },
+    Arg { path: String },
+    Account {
+        path: String,
+        #[serde(default)]
+        account: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Idl {
+    pub instructions: Vec<IdlInstruction>,
+    /// Names for accounts passed after the ones the IDL declares, per instruction.
+    #[serde(skip)]
+    pub remaining_accounts: HashMap<String, Vec<String>>,
+}
+
+impl Idl {
+    /// Parses the IDL embedded at build time, or the file at `override_path` when given.
+    pub fn load(embedded: &str, override_path: Option<&Path>) -> anyhow::Result<Self> {
+        let json = match override_path {
+            Some(path) => fs::read_to_string(path)
+                .with_context(|| format!("Unable to read IDL file {}", path.display()))?,
+            None => embedded.to_string(),
+        };
+        serde_json::from_str(&json).context("Unable to parse IDL JSON")
+    }
+
+    /// Names the remaining accounts of `instruction` in the order they are passed.
+    pub fn with_remaining_accounts(mut self, instruction: &str, names: &[&str]) -> Self {
+        self.remaining_accounts.insert(
+            instruction.to_string(),
+            names.iter().map(|name| name.to_string()).collect(),
+        );
+        self
+    }
+
+    /// Finds the instruction whose discriminator prefixes `data`.
+    pub fn instruction_by_discriminator(&self, data: &[u8]) -> Option<&IdlInstruction> {
+        self.instructions
+            .iter()
+            .find(|ix| !ix.discriminator.is_empty() && data.starts_with(&ix.discriminator))
+    }
+}
+
+impl IdlInstruction {
+    /// Accounts in the order the instruction expects them, with groups expanded.
+    pub fn flat_accounts(&self) -> Vec<&IdlAccount> {
+        fn flatten<'a>(items: &'a [IdlAccountItem], out: &mut Vec<&'a IdlAccount>) {
+            for item in items {
+                match item {
+                    IdlAccountItem::Group(group) => flatten(&group.accounts, out),
+                    IdlAccountItem::Account(account) => out.push(account),
+                }
+            }
+        }
+
+        let mut accounts = Vec::new();
+        flatten(&self.accounts, &mut accounts);
+        accounts
+    }
+}
+
+/// `is_writable`/`is_signer` are the privileges the account has in the message,
+/// `declared_*` what the IDL declares (`None` for remaining accounts).
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct AccountMetadata {
+    #[serde(serialize_with = "serialize_pubkey")]
+    pub pubkey: Pubkey,
+    pub is_writable: bool,
+    pub is_signer: bool,
+    pub declared_writable: Option<bool>,
+    pub declared_signer: Option<bool>,
+    pub name: String,
+}
+
+impl AccountMetadata {
+    /// True when the IDL asks for a privilege the account does not have,
+    /// usually a sign that the program reordered its accounts.
+    pub fn privilege_mismatch(&self) -> bool {
+        (self.declared_writable == Some(true) && !self.is_writable)
+            || (self.declared_signer == Some(true) && !self.is_signer)
+    }
+}
+
+fn to_camel_case(s: &str) -> String {
+    s.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first_char) => first_char.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            result.push('_');
+        }
+        result.push(c.to_lowercase().next().unwrap());
+    }
+    result
+}
+fn to_anchor_snake_case(s: &str) -> String {
+    let mut out = String::new();
+    let mut prev_is_lower = false;
+
+    for c in s.chars() {
+        if c.is_uppercase() {
+            if prev_is_lower {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+            prev_is_lower = false;
+        } else {
+            out.push(c);
+            prev_is_lower = c.is_ascii_lowercase();
+        }
+    }
+
+    out
+}
+
+
+pub trait InstructionAccountMapper<'info> {
+    /// Names `accounts` after the IDL entry of `instruction_name`. Fails with
+    /// `InvalidArgument` when the IDL has no such instruction and with
+    /// `NotEnoughAccountKeys` when fewer accounts are passed than it requires.
+    fn map_accounts<'me>(
+        &self,
+        accounts: &[AccountMeta],
+        instruction_name: &str,
+    ) -> Result<Vec<AccountMetadata>, ProgramError>;
+}
+
+impl<'info> InstructionAccountMapper<'info> for Idl {
+    fn map_accounts<'me>(
+        &self,
+        accounts: &[AccountMeta],
+        instruction_name: &str,
+    ) -> Result<Vec<AccountMetadata>, ProgramError> {
+        let normalized_name = instruction_name.to_string();
+        let instruction = self
+            .instructions
+            .iter()
+            .find(|ix| {
+                ix.name == normalized_name || 
+                ix.name.to_lowercase() == normalized_name.to_lowercase() ||
+                to_camel_case(&ix.name) == normalized_name ||
+                to_snake_case(&normalized_name) == ix.name ||
+                to_anchor_snake_case(&ix.name) == to_anchor_snake_case(instruction_name)
+            })
+            .ok_or(ProgramError::InvalidArgument)?;
+        let idl_accounts = instruction.flat_accounts();
+        let required_accounts = idl_accounts.iter().filter(|account| !account.optional).count();
+        if accounts.len() < required_accounts {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let remaining_names = self.remaining_accounts.get(&instruction.name);
+
+        let mut account_metadata: Vec<AccountMetadata> = accounts
+            .iter()
+            .zip(idl_accounts.iter())
+            .map(|(account, account_info)| AccountMetadata {
+                pubkey: account.pubkey,
+                is_writable: account.is_writable,
+                is_signer: account.is_signer,
+                declared_writable: Some(account_info.writable),
+                declared_signer: Some(account_info.signer),
+                name: account_info.name.clone(),
+            })
+            .collect();
+
+        for (i, (metadata, account_info)) in account_metadata.iter().zip(idl_accounts.iter()).enumerate() {
+            // Omitted optional accounts are replaced by the read-only program id.
+            if metadata.privilege_mismatch() && !account_info.optional {
+                log::warn!(
+                    "{}: account {} ({}) {} is declared writable={} signer={} but is writable={} signer={}",
+                    instruction.name,
+                    i,
+                    metadata.name,
+                    metadata.pubkey,
+                    account_info.writable,
+                    account_info.signer,
+                    metadata.is_writable,
+                    metadata.is_signer,
+                );
+            }
+        }
+
+        for (i, account) in accounts.iter().enumerate().skip(idl_accounts.len()) {
+            let remaining_index = i - idl_accounts.len();
+            let name = remaining_names
+                .and_then(|names| names.get(remaining_index))
+                .cloned()
+                .unwrap_or_else(|| format!("Remaining accounts {}", remaining_index + 1));
+
+            account_metadata.push(AccountMetadata {
+                pubkey: account.pubkey,
+                is_writable: account.is_writable,
+                is_signer: account.is_signer,
+                declared_writable: None,
+                declared_signer: None,
+                name,
+            });
+        }
+
+        Ok(account_metadata)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trimmed from the Anchor 0.30 layout of the Pump IDL, with a composite
+    /// account group and an `arg` seed added.
+    const ANCHOR_030_IDL: &str = r#"{
+        "address": "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+        "metadata": { "name": "pump", "version": "0.1.0", "spec": "0.1.0" },
+        "instructions": [
+            {
+                "name": "buy",
+                "discriminator": [102, 6, 61, 18, 1, 218, 235, 234],
+                "accounts": [
+                    { "name": "global", "pda": { "seeds": [{ "kind": "const", "value": [103, 108, 111, 98, 97, 108] }] } },
+                    { "name": "fee_recipient", "writable": true },
+                    { "name": "mint" },
+                    {
+                        "name": "bonding_curve",
+                        "writable": true,
+                        "pda": {
+                            "seeds": [
+                                { "kind": "const", "value": [98, 111, 110, 100, 105, 110, 103, 45, 99, 117, 114, 118, 101] },
+                                { "kind": "account", "path": "mint" }
+                            ]
+                        }
+                    },
+                    {
+                        "name": "creator_vault",
+                        "writable": true,
+                        "pda": {
+                            "seeds": [{ "kind": "account", "path": "bonding_curve.creator", "account": "BondingCurve" }],
+                            "program": { "kind": "const", "value": [1, 2, 3] }
+                        }
+                    },
+                    { "name": "user", "writable": true, "signer": true },
+                    { "name": "system_program", "address": "11111111111111111111111111111111" }
+                ],
+                "args": [{ "name": "amount", "type": "u64" }]
+            },
+            {
+                "name": "claim",
+                "discriminator": [62, 198, 214, 193, 213, 159, 108, 210],
+                "accounts": [
+                    {
+                        "name": "vault",
+                        "accounts": [
+                            { "name": "vault_authority" },
+                            {
+                                "name": "vault",
+                                "writable": true,
+                                "pda": { "seeds": [{ "kind": "arg", "path": "index" }] }
+                            }
+                        ]
+                    },
+                    { "name": "referrer", "optional": true }
+                ],
+                "args": []
+            }
+        ]
+    }"#;
+
+    const LEGACY_IDL: &str = r#"{
+        "version": "3.3.0",
+        "name": "spl_token",
+        "instructions": [
+            {
+                "name": "transfer",
+                "accounts": [
+                    { "name": "source", "isMut": true, "isSigner": false },
+                    { "name": "destination", "isMut": true, "isSigner": false },
+                    { "name": "authority", "isMut": false, "isSigner": true }
+                ],
+                "args": [{ "name": "amount", "type": "u64" }]
+            }
+        ]
+    }"#;
+
+    fn flat_names(ix: &IdlInstruction) -> Vec<&str> {
+        ix.flat_accounts().iter().map(|account| account.name.as_str()).collect()
+    }
+
+    #[test]
+    fn parses_the_anchor_030_layout() {
+        let idl = Idl::load(ANCHOR_030_IDL, None).unwrap();
+        let buy = &idl.instructions[0];
+        assert_eq!(buy.discriminator, [102, 6, 61, 18, 1, 218, 235, 234]);
+
+        let accounts = buy.flat_accounts();
+        let user = accounts.iter().find(|account| account.name == "user").unwrap();
+        assert!(user.writable && user.signer);
+        assert!(!accounts[0].writable && !accounts[0].signer);
+        assert_eq!(accounts[6].address.as_deref(), Some("11111111111111111111111111111111"));
+
+        let seeds = &accounts[3].pda.as_ref().unwrap().seeds;
+        assert!(matches!(&seeds[0], IdlSeed::Const { value } if value == b"bonding-curve"));
+        assert!(matches!(&seeds[1], IdlSeed::Account { path, account: None } if path == "mint"));
+        let creator_vault = accounts[4].pda.as_ref().unwrap();
+        assert!(matches!(
+            &creator_vault.seeds[0],
+            IdlSeed::Account { account: Some(account), .. } if account == "BondingCurve"
+        ));
+        assert!(matches!(&creator_vault.program, Some(IdlSeed::Const { value }) if value == &[1, 2, 3]));
+    }
+
+    #[test]
+    fn expands_account_groups_in_order() {
+        let idl = Idl::load(ANCHOR_030_IDL, None).unwrap();
+        let claim = &idl.instructions[1];
+        assert!(matches!(&claim.accounts[0], IdlAccountItem::Group(group) if group.name == "vault"));
+        assert_eq!(flat_names(claim), ["vault_authority", "vault", "referrer"]);
+
+        let accounts = claim.flat_accounts();
+        assert!(matches!(&accounts[1].pda.as_ref().unwrap().seeds[0], IdlSeed::Arg { path } if path == "index"));
+        assert!(accounts[2].optional);
+    }
+
+    #[test]
+    fn parses_the_legacy_layout() {
+        let idl = Idl::load(LEGACY_IDL, None).unwrap();
+        let transfer = &idl.instructions[0];
+        assert!(transfer.discriminator.is_empty());
+        let accounts = transfer.flat_accounts();
+        assert!(accounts[0].writable && !accounts[0].signer);
+        assert!(!accounts[2].writable && accounts[2].signer);
+    }
+
+    #[test]
+    fn finds_instructions_by_discriminator() {
+        let idl = Idl::load(ANCHOR_030_IDL, None).unwrap();
+        let mut data = vec![102, 6, 61, 18, 1, 218, 235, 234];
+        data.extend(1_000u64.to_le_bytes());
+        assert_eq!(idl.instruction_by_discriminator(&data).unwrap().name, "buy");
+        assert!(idl.instruction_by_discriminator(&[1, 2, 3]).is_none());
+
+        // Legacy instructions have no discriminator and never match.
+        let legacy = Idl::load(LEGACY_IDL, None).unwrap();
+        assert!(legacy.instruction_by_discriminator(&[3, 0, 0]).is_none());
+    }
+
+    #[test]
+    fn loads_the_override_file_instead_of_the_embedded_idl() {
+        let path = std::env::temp_dir().join(format!("idl_{}.json", Pubkey::new_unique()));
+        fs::write(&path, LEGACY_IDL).unwrap();
+        let idl = Idl::load(ANCHOR_030_IDL, Some(&path)).unwrap();
+        assert_eq!(idl.instructions[0].name, "transfer");
+        fs::remove_file(&path).unwrap();
+
+        assert!(Idl::load(ANCHOR_030_IDL, Some(&path)).is_err());
+        assert!(Idl::load("{}", None).is_err());
+    }
+
+    fn meta(writable: bool, signer: bool) -> AccountMeta {
+        AccountMeta { pubkey: Pubkey::new_unique(), is_signer: signer, is_writable: writable }
+    }
+
+    #[test]
+    fn keeps_actual_and_declared_privileges() {
+        let idl = Idl::load(LEGACY_IDL, None).unwrap();
+        let accounts = [meta(true, false), meta(false, false), meta(false, true)];
+
+        let mapped = idl.map_accounts(&accounts, "transfer").unwrap();
+        let privileges: Vec<_> = mapped
+            .iter()
+            .map(|account| (account.is_writable, account.declared_writable, account.is_signer, account.declared_signer))
+            .collect();
+        assert_eq!(
+            privileges,
+            [
+                (true, Some(true), false, Some(false)),
+                (false, Some(true), false, Some(false)),
+                (false, Some(false), true, Some(true)),
+            ],
+        );
+        assert_eq!(mapped[1].pubkey, accounts[1].pubkey);
+        let mismatches: Vec<_> = mapped.iter().map(AccountMetadata::privilege_mismatch).collect();
+        assert_eq!(mismatches, [false, true, false]);
+    }
+
+    #[test]
+    fn extra_privileges_are_not_a_mismatch() {
+        let idl = Idl::load(LEGACY_IDL, None).unwrap();
+        let accounts = [meta(true, true), meta(true, true), meta(true, true)];
+        let mapped = idl.map_accounts(&accounts, "transfer").unwrap();
+        assert!(mapped.iter().all(|account| !account.privilege_mismatch()));
+    }
+
+    #[test]
+    fn names_remaining_accounts() {
+        let idl = Idl::load(LEGACY_IDL, None)
+            .unwrap()
+            .with_remaining_accounts("transfer", &["multisig_signer"]);
+        let accounts = [meta(true, false), meta(true, false), meta(false, false), meta(false, true), meta(false, true)];
+
+        let mapped = idl.map_accounts(&accounts, "transfer").unwrap();
+        let names: Vec<_> = mapped.iter().map(|account| account.name.as_str()).collect();
+        assert_eq!(names, ["source", "destination", "authority", "multisig_signer", "Remaining accounts 2"]);
+        assert_eq!(mapped[3].declared_writable, None);
+        assert_eq!(mapped[4].declared_signer, None);
+        assert!(!mapped[4].privilege_mismatch());
+    }
+
+    #[test]
+    fn unnamed_remaining_accounts_are_numbered() {
+        let idl = Idl::load(LEGACY_IDL, None).unwrap();
+        let accounts = [meta(true, false), meta(true, false), meta(false, true), meta(false, false)];
+        let mapped = idl.map_accounts(&accounts, "transfer").unwrap();
+        assert_eq!(mapped[3].name, "Remaining accounts 1");
+    }
+
+    #[test]
+    fn matches_instruction_names_across_cases() {
+        let idl = Idl::load(ANCHOR_030_IDL, None).unwrap();
+        let accounts: Vec<_> = (0..7).map(|_| meta(true, true)).collect();
+        for name in ["buy", "Buy", "BUY"] {
+            assert_eq!(idl.map_accounts(&accounts, name).unwrap()[1].name, "fee_recipient");
+        }
+
+        let legacy = Idl::load(LEGACY_IDL, None).unwrap();
+        assert_eq!(
+            legacy.map_accounts(&accounts[..3], "Transfer").unwrap()[0].name,
+            "source",
+        );
+        assert_eq!(legacy.map_accounts(&accounts, "burn"), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn rejects_fewer_accounts_than_required() {
+        let idl = Idl::load(ANCHOR_030_IDL, None).unwrap();
+        let accounts = [meta(false, false), meta(true, false)];
+        assert_eq!(idl.map_accounts(&accounts, "buy"), Err(ProgramError::NotEnoughAccountKeys));
+
+        // `referrer` is optional, so `claim` may leave it out.
+        let mapped = idl.map_accounts(&accounts, "claim").unwrap();
+        let names: Vec<_> = mapped.iter().map(|account| account.name.as_str()).collect();
+        assert_eq!(names, ["vault_authority", "vault"]);
+    }
+}
@@ -0,0 +1,40 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::{TransactionTokenBalance, UiTransactionTokenBalance};
+
+pub fn serialize_pubkey<S>(value: &Pubkey, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
+pub fn serialize_option_pubkey<S>(value: &Option<Pubkey>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value {
+        Some(pubkey) => serializer.serialize_str(&pubkey.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+// TransactionTokenBalance has no Serialize impl, its RPC form does.
+pub fn serialize_token_balances<S>(
+    value: &Option<Vec<TransactionTokenBalance>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::Serialize;
+    value
+        .as_ref()
+        .map(|balances| {
+            balances
+                .iter()
+                .cloned()
+                .map(UiTransactionTokenBalance::from)
+                .collect::<Vec<_>>()
+        })
+        .serialize(serializer)
+}
@@ -0,0 +1,393 @@
+
+use solana_program::{pubkey::Pubkey, program_option::COption};
+use spl_token::instruction::{AuthorityType, TokenInstruction};
+use spl_token_2022::{
+    extension::{
+        confidential_transfer::instruction::ConfidentialTransferInstruction,
+        confidential_transfer_fee::instruction::ConfidentialTransferFeeInstruction,
+        cpi_guard::instruction::CpiGuardInstruction,
+        default_account_state::instruction::DefaultAccountStateInstruction,
+        group_member_pointer::instruction::GroupMemberPointerInstruction,
+        group_pointer::instruction::GroupPointerInstruction,
+        interest_bearing_mint::instruction::InterestBearingMintInstruction,
+        memo_transfer::instruction::RequiredMemoTransfersInstruction,
+        metadata_pointer::instruction::MetadataPointerInstruction,
+        transfer_fee::instruction::TransferFeeInstruction,
+        transfer_hook::instruction::TransferHookInstruction,
+    },
+    instruction::TokenInstruction as Token2022Instruction,
+};
+use serde::{Serialize, Deserialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+// Helper function to convert COption<Pubkey> to Option<Pubkey>
+fn convert_coption<T>(coption: COption<T>) -> Option<T> {
+    match coption {
+        COption::Some(value) => Some(value),
+        COption::None => None,
+    }
+}
+
+fn convert_set_authority(
+    authority_type: AuthorityType,
+    new_authority: COption<Pubkey>,
+) -> SerializableSetAuthority {
+    SerializableSetAuthority {
+        authority_type: match authority_type {
+            AuthorityType::MintTokens => "MintTokens".to_string(),
+            AuthorityType::FreezeAccount => "FreezeAccount".to_string(),
+            AuthorityType::AccountOwner => "AccountOwner".to_string(),
+            AuthorityType::CloseAccount => "CloseAccount".to_string(),
+        },
+        new_authority: new_authority.into(), // Convert COption<Pubkey> to Option<Pubkey>
+    }
+}
+
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct SerializableInitializeMint {
+    decimals: u8,
+    #[serde_as(as = "DisplayFromStr")]
+    mint_authority: Pubkey,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    freeze_authority: Option<Pubkey>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct SerializableInitializeAccount3 {
+    #[serde_as(as = "DisplayFromStr")]
+    owner: Pubkey,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct SerializableSetAuthority {
+    authority_type: String,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    new_authority: Option<Pubkey>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct SerializableTransfer {
+    amount: u64,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct SerializableInitializeTransferFeeConfig {
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    transfer_fee_config_authority: Option<Pubkey>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    withdraw_withheld_authority: Option<Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub enum SerializableTokenInstruction {
+    InitializeMint(SerializableInitializeMint),
+    InitializeAccount,
+    InitializeMultisig { m: u8 },
+    Transfer(SerializableTransfer),
+    Approve { amount: u64 },
+    Revoke,
+    SetAuthority(SerializableSetAuthority),
+    MintTo { amount: u64 },
+    Burn { amount: u64 },
+    CloseAccount,
+    FreezeAccount,
+    ThawAccount,
+    TransferChecked { amount: u64, decimals: u8 },
+    ApproveChecked { amount: u64, decimals: u8 },
+    MintToChecked { amount: u64, decimals: u8 },
+    BurnChecked { amount: u64, decimals: u8 },
+    InitializeAccount2 { #[serde_as(as = "DisplayFromStr")] owner: Pubkey },
+    SyncNative,
+    InitializeAccount3(SerializableInitializeAccount3),
+    InitializeMultisig2 { m: u8 },
+    InitializeMint2(SerializableInitializeMint),
+    GetAccountDataSize,
+    InitializeImmutableOwner,
+    AmountToUiAmount { amount: u64 },
+    UiAmountToAmount { ui_amount: String },
+    // Token-2022 only
+    InitializeMintCloseAuthority {
+        #[serde_as(as = "Option<DisplayFromStr>")]
+        close_authority: Option<Pubkey>,
+    },
+    InitializeTransferFeeConfig(SerializableInitializeTransferFeeConfig),
+    TransferCheckedWithFee { amount: u64, decimals: u8, fee: u64 },
+    WithdrawWithheldTokensFromMint,
+    WithdrawWithheldTokensFromAccounts { num_token_accounts: u8 },
+    HarvestWithheldTokensToMint,
+    SetTransferFee { transfer_fee_basis_points: u16, maximum_fee: u64 },
+    Reallocate { extension_types: Vec<String> },
+    CreateNativeMint,
+    InitializeNonTransferableMint,
+    InitializePermanentDelegate { #[serde_as(as = "DisplayFromStr")] delegate: Pubkey },
+    WithdrawExcessLamports,
+    Extension { extension: String, instruction: String },
+}
+
+
+// Convert TokenInstruction to SerializableTokenInstruction
+pub fn convert_to_serializable(ix: TokenInstruction) -> SerializableTokenInstruction {
+    match ix {
+        TokenInstruction::InitializeMint { decimals, mint_authority, freeze_authority } => {
+            SerializableTokenInstruction::InitializeMint(SerializableInitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority: freeze_authority.into(), // Convert COption to Option
+            })
+        }
+        TokenInstruction::InitializeAccount => SerializableTokenInstruction::InitializeAccount,
+        TokenInstruction::InitializeMultisig { m } => SerializableTokenInstruction::InitializeMultisig { m },
+        TokenInstruction::Transfer { amount } => SerializableTokenInstruction::Transfer(SerializableTransfer { amount }),
+        TokenInstruction::Approve { amount } => SerializableTokenInstruction::Approve { amount },
+        TokenInstruction::Revoke => SerializableTokenInstruction::Revoke,
+        TokenInstruction::SetAuthority { authority_type, new_authority } => {
+            SerializableTokenInstruction::SetAuthority(convert_set_authority(authority_type, new_authority))
+        }
+        TokenInstruction::MintTo { amount } => SerializableTokenInstruction::MintTo { amount },
+        TokenInstruction::Burn { amount } => SerializableTokenInstruction::Burn { amount },
+        TokenInstruction::CloseAccount => SerializableTokenInstruction::CloseAccount,
+        TokenInstruction::FreezeAccount => SerializableTokenInstruction::FreezeAccount,
+        TokenInstruction::ThawAccount => SerializableTokenInstruction::ThawAccount,
+        TokenInstruction::TransferChecked { amount, decimals } => {
+            SerializableTokenInstruction::TransferChecked { amount, decimals }
+        }
+        TokenInstruction::ApproveChecked { amount, decimals } => {
+            SerializableTokenInstruction::ApproveChecked { amount, decimals }
+        }
+        TokenInstruction::MintToChecked { amount, decimals } => {
+            SerializableTokenInstruction::MintToChecked { amount, decimals }
+        }
+        TokenInstruction::BurnChecked { amount, decimals } => {
+            SerializableTokenInstruction::BurnChecked { amount, decimals }
+        }
+        TokenInstruction::InitializeAccount2 { owner } => {
+            SerializableTokenInstruction::InitializeAccount2 { owner }
+        }
+        TokenInstruction::SyncNative => SerializableTokenInstruction::SyncNative,
+        TokenInstruction::InitializeAccount3 { owner } => {
+            SerializableTokenInstruction::InitializeAccount3(SerializableInitializeAccount3 { owner })
+        }
+        TokenInstruction::InitializeMultisig2 { m } => {
+            SerializableTokenInstruction::InitializeMultisig2 { m }
+        }
+        TokenInstruction::InitializeMint2 { decimals, mint_authority, freeze_authority } => {
+            SerializableTokenInstruction::InitializeMint2(SerializableInitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority: freeze_authority.into(),
+            })
+        }
+        TokenInstruction::GetAccountDataSize => SerializableTokenInstruction::GetAccountDataSize,
+        TokenInstruction::InitializeImmutableOwner => SerializableTokenInstruction::InitializeImmutableOwner,
+        TokenInstruction::AmountToUiAmount { amount } => SerializableTokenInstruction::AmountToUiAmount { amount },
+        TokenInstruction::UiAmountToAmount { ui_amount } => {
+            SerializableTokenInstruction::UiAmountToAmount { ui_amount: ui_amount.to_string() }
+        }
+    }
+}
+
+// Convert a Token-2022 instruction to the same SerializableTokenInstruction.
+// `data` is the raw instruction data, needed to unpack extension instructions.
+pub fn convert_token_2022_to_serializable(
+    ix: Token2022Instruction,
+    data: &[u8],
+) -> SerializableTokenInstruction {
+    match ix {
+        Token2022Instruction::InitializeMint { decimals, mint_authority, freeze_authority } => {
+            SerializableTokenInstruction::InitializeMint(SerializableInitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority: freeze_authority.into(),
+            })
+        }
+        Token2022Instruction::InitializeAccount => SerializableTokenInstruction::InitializeAccount,
+        Token2022Instruction::InitializeMultisig { m } => SerializableTokenInstruction::InitializeMultisig { m },
+        #[allow(deprecated)]
+        Token2022Instruction::Transfer { amount } => SerializableTokenInstruction::Transfer(SerializableTransfer { amount }),
+        Token2022Instruction::Approve { amount } => SerializableTokenInstruction::Approve { amount },
+        Token2022Instruction::Revoke => SerializableTokenInstruction::Revoke,
+        Token2022Instruction::SetAuthority { authority_type, new_authority } => {
+            SerializableTokenInstruction::SetAuthority(SerializableSetAuthority {
+                authority_type: format!("{:?}", authority_type),
+                new_authority: new_authority.into(),
+            })
+        }
+        Token2022Instruction::MintTo { amount } => SerializableTokenInstruction::MintTo { amount },
+        Token2022Instruction::Burn { amount } => SerializableTokenInstruction::Burn { amount },
+        Token2022Instruction::CloseAccount => SerializableTokenInstruction::CloseAccount,
+        Token2022Instruction::FreezeAccount => SerializableTokenInstruction::FreezeAccount,
+        Token2022Instruction::ThawAccount => SerializableTokenInstruction::ThawAccount,
+        Token2022Instruction::TransferChecked { amount, decimals } => {
+            SerializableTokenInstruction::TransferChecked { amount, decimals }
+        }
+        Token2022Instruction::ApproveChecked { amount, decimals } => {
+            SerializableTokenInstruction::ApproveChecked { amount, decimals }
+        }
+        Token2022Instruction::MintToChecked { amount, decimals } => {
+            SerializableTokenInstruction::MintToChecked { amount, decimals }
+        }
+        Token2022Instruction::BurnChecked { amount, decimals } => {
+            SerializableTokenInstruction::BurnChecked { amount, decimals }
+        }
+        Token2022Instruction::InitializeAccount2 { owner } => {
+            SerializableTokenInstruction::InitializeAccount2 { owner }
+        }
+        Token2022Instruction::SyncNative => SerializableTokenInstruction::SyncNative,
+        Token2022Instruction::InitializeAccount3 { owner } => {
+            SerializableTokenInstruction::InitializeAccount3(SerializableInitializeAccount3 { owner })
+        }
+        Token2022Instruction::InitializeMultisig2 { m } => {
+            SerializableTokenInstruction::InitializeMultisig2 { m }
+        }
+        Token2022Instruction::InitializeMint2 { decimals, mint_authority, freeze_authority } => {
+            SerializableTokenInstruction::InitializeMint2(SerializableInitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority: freeze_authority.into(),
+            })
+        }
+        Token2022Instruction::GetAccountDataSize { .. } => SerializableTokenInstruction::GetAccountDataSize,
+        Token2022Instruction::InitializeImmutableOwner => SerializableTokenInstruction::InitializeImmutableOwner,
+        Token2022Instruction::AmountToUiAmount { amount } => SerializableTokenInstruction::AmountToUiAmount { amount },
+        Token2022Instruction::UiAmountToAmount { ui_amount } => {
+            SerializableTokenInstruction::UiAmountToAmount { ui_amount: ui_amount.to_string() }
+        }
+        Token2022Instruction::InitializeMintCloseAuthority { close_authority } => {
+            SerializableTokenInstruction::InitializeMintCloseAuthority {
+                close_authority: convert_coption(close_authority),
+            }
+        }
+        Token2022Instruction::TransferFeeExtension => convert_transfer_fee(data),
+        Token2022Instruction::Reallocate { extension_types } => SerializableTokenInstruction::Reallocate {
+            extension_types: extension_types.iter().map(|t| format!("{:?}", t)).collect(),
+        },
+        Token2022Instruction::CreateNativeMint => SerializableTokenInstruction::CreateNativeMint,
+        Token2022Instruction::InitializeNonTransferableMint => {
+            SerializableTokenInstruction::InitializeNonTransferableMint
+        }
+        Token2022Instruction::InitializePermanentDelegate { delegate } => {
+            SerializableTokenInstruction::InitializePermanentDelegate { delegate }
+        }
+        Token2022Instruction::WithdrawExcessLamports => SerializableTokenInstruction::WithdrawExcessLamports,
+        other => SerializableTokenInstruction::Extension {
+            extension: format!("{:?}", other),
+            instruction: extension_instruction_type(&other, data).unwrap_or_default(),
+        },
+    }
+}
+
+fn convert_transfer_fee(data: &[u8]) -> SerializableTokenInstruction {
+    match TransferFeeInstruction::unpack(&data[1..]) {
+        Ok(TransferFeeInstruction::InitializeTransferFeeConfig {
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+        }) => SerializableTokenInstruction::InitializeTransferFeeConfig(
+            SerializableInitializeTransferFeeConfig {
+                transfer_fee_config_authority: convert_coption(transfer_fee_config_authority),
+                withdraw_withheld_authority: convert_coption(withdraw_withheld_authority),
+                transfer_fee_basis_points,
+                maximum_fee,
+            },
+        ),
+        Ok(TransferFeeInstruction::TransferCheckedWithFee { amount, decimals, fee }) => {
+            SerializableTokenInstruction::TransferCheckedWithFee { amount, decimals, fee }
+        }
+        Ok(TransferFeeInstruction::WithdrawWithheldTokensFromMint) => {
+            SerializableTokenInstruction::WithdrawWithheldTokensFromMint
+        }
+        Ok(TransferFeeInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts }) => {
+            SerializableTokenInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts }
+        }
+        Ok(TransferFeeInstruction::HarvestWithheldTokensToMint) => {
+            SerializableTokenInstruction::HarvestWithheldTokensToMint
+        }
+        Ok(TransferFeeInstruction::SetTransferFee { transfer_fee_basis_points, maximum_fee }) => {
+            SerializableTokenInstruction::SetTransferFee { transfer_fee_basis_points, maximum_fee }
+        }
+        Err(_) => SerializableTokenInstruction::Extension {
+            extension: "TransferFeeExtension".to_string(),
+            instruction: String::new(),
+        },
+    }
+}
+
+// Extension instructions carry their own instruction type in the second byte.
+fn extension_instruction_type(ix: &Token2022Instruction, data: &[u8]) -> Option<String> {
+    let ix_type = *data.get(1)?;
+    let name = match ix {
+        Token2022Instruction::TransferFeeExtension => {
+            format!("{:?}", TransferFeeInstruction::unpack(&data[1..]).ok()?)
+        }
+        Token2022Instruction::ConfidentialTransferExtension => {
+            format!("{:?}", ConfidentialTransferInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::DefaultAccountStateExtension => {
+            format!("{:?}", DefaultAccountStateInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::MemoTransferExtension => {
+            format!("{:?}", RequiredMemoTransfersInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::InterestBearingMintExtension => {
+            format!("{:?}", InterestBearingMintInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::CpiGuardExtension => {
+            format!("{:?}", CpiGuardInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::TransferHookExtension => {
+            format!("{:?}", TransferHookInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::ConfidentialTransferFeeExtension => {
+            format!("{:?}", ConfidentialTransferFeeInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::MetadataPointerExtension => {
+            format!("{:?}", MetadataPointerInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::GroupPointerExtension => {
+            format!("{:?}", GroupPointerInstruction::try_from(ix_type).ok()?)
+        }
+        Token2022Instruction::GroupMemberPointerExtension => {
+            format!("{:?}", GroupMemberPointerInstruction::try_from(ix_type).ok()?)
+        }
+        _ => return None,
+    };
+    // Drop any payload from the debug output, e.g. `TransferCheckedWithFee { .. }`.
+    Some(name.split([' ', '(']).next().unwrap_or_default().to_string())
+}
+
+/// Instruction name used to look up the Token-2022 IDL accounts, e.g.
+/// `transferCheckedWithFee` or `initializeMetadataPointer`.
+pub fn token_2022_instruction_name(ix: &Token2022Instruction, data: &[u8]) -> String {
+    let top_level = format!("{:?}", ix);
+    let top_level = top_level.split([' ', '(']).next().unwrap_or_default();
+
+    let Some(extension) = top_level.strip_suffix("Extension") else {
+        return lower_first(top_level);
+    };
+    match (ix, extension_instruction_type(ix, data)) {
+        (Token2022Instruction::TransferFeeExtension, Some(instruction)) => lower_first(&instruction),
+        (Token2022Instruction::MemoTransferExtension, Some(instruction)) => {
+            format!("{}RequiredMemoTransfers", lower_first(&instruction))
+        }
+        (_, Some(instruction)) => format!("{}{}", lower_first(&instruction), extension),
+        (_, None) => lower_first(top_level),
+    }
+}
+
+fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first_char) => first_char.to_lowercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,202 @@
+use {
+    super::types::ParsedConfirmedTransactionWithStatusMeta,
+    std::collections::{BTreeMap, HashMap},
+    yellowstone_grpc_proto::geyser::SubscribeRequestFilterBlocksMeta,
+};
+
+/// How many slots of block times are kept around.
+const MAX_CACHED_SLOTS: usize = 1024;
+
+/// How many slots a transaction waits for its block meta before it is
+/// released without a block time (skipped or forked slots never get one).
+const MAX_PENDING_SLOTS: u64 = 32;
+
+/// How many transactions may wait for their block meta at once; past that
+/// new ones are handed out without a block time.
+const MAX_PENDING_TRANSACTIONS: usize = 10_000;
+
+/// The `blocks_meta` filter the processor needs next to the transaction filter.
+pub fn blocks_meta_filter() -> HashMap<String, SubscribeRequestFilterBlocksMeta> {
+    HashMap::from([("client".to_owned(), SubscribeRequestFilterBlocksMeta {})])
+}
+
+pub struct BlockTimeCache<E> {
+    block_times: BTreeMap<u64, i64>,
+    pending: BTreeMap<u64, Vec<ParsedConfirmedTransactionWithStatusMeta<E>>>,
+    pending_count: usize,
+}
+
+impl<E> Default for BlockTimeCache<E> {
+    fn default() -> Self {
+        Self {
+            block_times: BTreeMap::new(),
+            pending: BTreeMap::new(),
+            pending_count: 0,
+        }
+    }
+}
+
+impl<E> BlockTimeCache<E> {
+    pub fn block_time(&self, slot: u64) -> Option<i64> {
+        self.block_times.get(&slot).copied()
+    }
+
+    /// Holds a transaction back until the block meta of its slot arrives.
+    /// Hands it straight back when no block meta came in the last
+    /// `MAX_PENDING_SLOTS` slots (no `blocks_meta` subscription, or the
+    /// stream of them stopped) or when too many transactions already wait.
+    pub fn defer(
+        &mut self,
+        txn: ParsedConfirmedTransactionWithStatusMeta<E>,
+    ) -> Option<ParsedConfirmedTransactionWithStatusMeta<E>> {
+        let block_metas_arriving = self
+            .block_times
+            .last_key_value()
+            .is_some_and(|(slot, _)| slot + MAX_PENDING_SLOTS >= txn.slot);
+        if !block_metas_arriving || self.pending_count >= MAX_PENDING_TRANSACTIONS {
+            return Some(txn);
+        }
+
+        self.pending_count += 1;
+        self.pending.entry(txn.slot).or_default().push(txn);
+        None
+    }
+
+    /// Records the block time of a slot and returns the transactions that
+    /// were waiting for it, plus any that have waited too long.
+    pub fn insert(
+        &mut self,
+        slot: u64,
+        block_time: i64,
+    ) -> Vec<ParsedConfirmedTransactionWithStatusMeta<E>> {
+        self.block_times.insert(slot, block_time);
+        while self.block_times.len() > MAX_CACHED_SLOTS {
+            self.block_times.pop_first();
+        }
+
+        let mut ready: Vec<_> = self
+            .pending
+            .remove(&slot)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mut txn| {
+                txn.block_time = Some(block_time);
+                txn
+            })
+            .collect();
+        self.pending_count -= ready.len();
+
+        ready.extend(self.flush(slot));
+        ready
+    }
+
+    /// Releases, without a block time, the transactions that have waited
+    /// more than `MAX_PENDING_SLOTS` slots behind `slot`. Called for every
+    /// transaction too, so they come out even after block metas stop.
+    pub fn flush(&mut self, slot: u64) -> Vec<ParsedConfirmedTransactionWithStatusMeta<E>> {
+        let waiting = self.pending.split_off(&slot.saturating_sub(MAX_PENDING_SLOTS));
+        let expired = std::mem::replace(&mut self.pending, waiting);
+
+        let ready: Vec<_> = expired.into_values().flatten().collect();
+        self.pending_count -= ready.len();
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::processor::test_utils::*,
+    };
+
+    const BLOCK_TIME: i64 = 1_700_000_000;
+
+    /// A parsed transaction of `slot`, still without a block time.
+    fn txn(slot: u64) -> ParsedConfirmedTransactionWithStatusMeta<TradeEvent> {
+        let keys = Keys::new();
+        let update = transaction_update(slot, message(&keys, vec![]), meta(vec![], &[]));
+        processor().process_transaction_update(update).unwrap().pop().unwrap()
+    }
+
+    #[test]
+    fn holds_transactions_until_their_block_meta() {
+        let mut cache = BlockTimeCache::default();
+        assert!(cache.insert(9, BLOCK_TIME).is_empty());
+        assert!(cache.defer(txn(10)).is_none());
+        assert!(cache.insert(11, BLOCK_TIME + 1).is_empty());
+
+        let ready = cache.insert(10, BLOCK_TIME);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].block_time, Some(BLOCK_TIME));
+        assert_eq!(cache.pending_count, 0);
+    }
+
+    #[test]
+    fn passes_transactions_through_without_block_metas() {
+        let mut cache = BlockTimeCache::default();
+        let passed = cache.defer(txn(10)).unwrap();
+        assert_eq!(passed.block_time, None);
+        assert!(cache.pending.is_empty());
+    }
+
+    #[test]
+    fn passes_transactions_through_once_block_metas_stop() {
+        let mut cache = BlockTimeCache::default();
+        cache.insert(10, BLOCK_TIME);
+        assert!(cache.defer(txn(10 + MAX_PENDING_SLOTS)).is_none());
+        assert!(cache.defer(txn(11 + MAX_PENDING_SLOTS)).is_some());
+    }
+
+    #[test]
+    fn releases_transactions_whose_block_meta_never_comes() {
+        let mut cache = BlockTimeCache::default();
+        cache.insert(9, BLOCK_TIME);
+        assert!(cache.defer(txn(10)).is_none());
+        assert!(cache.insert(10 + MAX_PENDING_SLOTS, BLOCK_TIME).is_empty());
+
+        let ready = cache.insert(11 + MAX_PENDING_SLOTS, BLOCK_TIME);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].slot, 10);
+        assert_eq!(ready[0].block_time, None);
+        assert!(cache.pending.is_empty());
+    }
+
+    #[test]
+    fn releases_waiting_transactions_once_later_slots_arrive() {
+        let mut cache = BlockTimeCache::default();
+        cache.insert(9, BLOCK_TIME);
+        assert!(cache.defer(txn(10)).is_none());
+        assert!(cache.flush(10 + MAX_PENDING_SLOTS).is_empty());
+
+        let ready = cache.flush(11 + MAX_PENDING_SLOTS);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].block_time, None);
+        assert_eq!(cache.pending_count, 0);
+    }
+
+    #[test]
+    fn caps_the_number_of_waiting_transactions() {
+        let mut cache = BlockTimeCache::default();
+        cache.insert(9, BLOCK_TIME);
+        let waiting = txn(10);
+        for _ in 0..MAX_PENDING_TRANSACTIONS {
+            assert!(cache.defer(waiting.clone()).is_none());
+        }
+        assert!(cache.defer(waiting).is_some());
+
+        assert_eq!(cache.insert(10, BLOCK_TIME).len(), MAX_PENDING_TRANSACTIONS);
+        assert_eq!(cache.pending_count, 0);
+    }
+
+    #[test]
+    fn keeps_a_bounded_number_of_block_times() {
+        let mut cache = BlockTimeCache::<TradeEvent>::default();
+        for slot in 0..MAX_CACHED_SLOTS as u64 + 10 {
+            cache.insert(slot, BLOCK_TIME);
+        }
+        assert_eq!(cache.block_times.len(), MAX_CACHED_SLOTS);
+        assert_eq!(cache.block_time(0), None);
+        assert_eq!(cache.block_time(MAX_CACHED_SLOTS as u64 + 9), Some(BLOCK_TIME));
+    }
+}
@@ -0,0 +1,452 @@
+use {
+    anyhow::Context,
+    solana_account_decoder_client_types::token::UiTokenAmount,
+    solana_sdk::{
+        instruction::CompiledInstruction,
+        message::{
+            legacy::Message as LegacyMessage,
+            v0::{LoadedAddresses, Message, MessageAddressTableLookup},
+            MessageHeader, VersionedMessage,
+        },
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::VersionedTransaction,
+        transaction_context::TransactionReturnData,
+    },
+    solana_transaction_status::{
+        ConfirmedTransactionWithStatusMeta, InnerInstruction, InnerInstructions, Reward,
+        RewardType, TransactionStatusMeta, TransactionTokenBalance,
+        TransactionWithStatusMeta, VersionedTransactionWithStatusMeta,
+    },
+    super::{
+        core::TransactionProcessor,
+        types::{
+            DecodedInstruction, ParsedConfirmedTransactionWithStatusMeta, ParsedMessage,
+            ParsedTransaction, ParsedTransactionStatusMeta,
+        },
+    },
+    crate::decoder::ProgramDecoder,
+    yellowstone_grpc_proto::convert_from,
+};
+
+impl<D: ProgramDecoder> TransactionProcessor<D> {
+    pub fn build_confirmed_transaction(
+        slot: u64,
+        signatures: Vec<Signature>,
+        raw_message: yellowstone_grpc_proto::prelude::Message,
+        meta: yellowstone_grpc_proto::prelude::TransactionStatusMeta,
+        block_time: Option<i64>,
+    ) -> anyhow::Result<ConfirmedTransactionWithStatusMeta> {
+        Ok(ConfirmedTransactionWithStatusMeta {
+            slot,
+            tx_with_meta: TransactionWithStatusMeta::Complete(VersionedTransactionWithStatusMeta {
+                transaction: VersionedTransaction {
+                    signatures,
+                    message: Self::build_message(raw_message)?,
+                },
+                meta: TransactionStatusMeta {
+                    status: match convert_from::create_tx_error(meta.err.as_ref())
+                        .map_err(|e| anyhow::anyhow!("Failed to decode transaction error: {e}"))?
+                    {
+                        Some(err) => Err(err),
+                        None => Ok(()),
+                    },
+                    fee: meta.fee,
+                    pre_balances: meta.pre_balances.clone(),
+                    post_balances: meta.post_balances.clone(),
+                    inner_instructions: Some(
+                        meta.inner_instructions
+                            .iter()
+                            .map(|f| InnerInstructions {
+                                index: f.index as u8,
+                                instructions: f.instructions
+                                    .iter()
+                                    .map(|v| InnerInstruction {
+                                        instruction: CompiledInstruction {
+                                            program_id_index: v.program_id_index as u8,
+                                            accounts: v.accounts.clone(),
+                                            data: v.data.clone(),
+                                        },
+                                        stack_height: v.stack_height,
+                                    })
+                                    .collect(),
+                            })
+                            .collect(),
+                    ),
+                    log_messages: Some(meta.log_messages.iter().cloned().collect()),
+                    pre_token_balances: Some(
+                        meta.pre_token_balances
+                            .into_iter()
+                            .map(|tb| TransactionTokenBalance {
+                                account_index: tb.account_index as u8,
+                                mint: tb.mint.clone(),
+                                ui_token_amount: UiTokenAmount {
+                                    ui_amount: {
+                                        let ui_token_amount = tb.ui_token_amount.clone().unwrap_or_default();
+                                        if ui_token_amount.ui_amount == 0.0 {
+                                            None
+                                        } else {
+                                            Some(ui_token_amount.ui_amount)
+                                        }
+                                    },
+                                    decimals: tb.ui_token_amount.clone().unwrap_or_default().decimals as u8,
+                                    amount: tb.ui_token_amount.clone().unwrap_or_default().amount,
+                                    ui_amount_string: tb.ui_token_amount.clone().unwrap_or_default().ui_amount_string,
+                                },
+                                owner: tb.owner.clone(),
+                                program_id: tb.program_id.clone(),
+                            })
+                            .collect(),
+                    ),
+                    post_token_balances: Some(
+                        meta.post_token_balances
+                            .iter()
+                            .map(|tb| TransactionTokenBalance {
+                                account_index: tb.account_index as u8,
+                                mint: tb.mint.clone(),
+                                ui_token_amount: UiTokenAmount {
+                                    ui_amount: {
+                                        let ui_token_amount = tb.ui_token_amount.clone().unwrap_or_default();
+                                        if ui_token_amount.ui_amount == 0.0 {
+                                            None
+                                        } else {
+                                            Some(ui_token_amount.ui_amount)
+                                        }
+                                    },
+                                    decimals: tb.ui_token_amount.clone().unwrap_or_default().decimals as u8,
+                                    amount: tb.ui_token_amount.clone().unwrap_or_default().amount,
+                                    ui_amount_string: tb.ui_token_amount.clone().unwrap_or_default().ui_amount_string,
+                                },
+                                owner: tb.owner.clone(),
+                                program_id: tb.program_id.clone(),
+                            })
+                            .collect(),
+                    ),
+                    rewards: Some(
+                        meta.rewards
+                            .iter()
+                            .map(|r| Reward {
+                                pubkey: r.pubkey.clone(),
+                                lamports: r.lamports,
+                                post_balance: r.post_balance,
+                                reward_type: match r.reward_type {
+                                    1 => Some(RewardType::Fee),
+                                    2 => Some(RewardType::Rent),
+                                    3 => Some(RewardType::Staking),
+                                    4 => Some(RewardType::Voting),
+                                    _ => None,
+                                },
+                                // Sent as a decimal string, empty for non-voting rewards.
+                                commission: r.commission.parse::<u8>().ok(),
+                            })
+                            .collect::<Vec<_>>(),
+                    ),
+                    loaded_addresses: LoadedAddresses {
+                        writable: meta
+                            .loaded_writable_addresses
+                            .iter()
+                            .map(|addr| {
+                                addr.clone()
+                                    .try_into()
+                                    .map(Pubkey::new_from_array)
+                                    .map_err(|e| anyhow::anyhow!("Failed to convert writable address: {:?}", e))
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()?,
+                        readonly: meta
+                            .loaded_readonly_addresses
+                            .iter()
+                            .map(|addr| {
+                                addr.clone()
+                                    .try_into()
+                                    .map(Pubkey::new_from_array)
+                                    .map_err(|e| anyhow::anyhow!("Failed to convert readonly address: {:?}", e))
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()?,
+                    },
+                    return_data: meta.return_data.as_ref().map(|return_data| -> anyhow::Result<TransactionReturnData> {
+                        Ok(TransactionReturnData {
+                            program_id: return_data
+                                .program_id
+                                .clone()
+                                .try_into()
+                                .map(Pubkey::new_from_array)
+                                .map_err(|e| anyhow::anyhow!("Failed to convert return_data program_id: {:?}", e))?,
+                            data: return_data.data.clone(),
+                        })
+                    }).transpose()?,
+                    compute_units_consumed: meta.compute_units_consumed,
+                },
+            }),
+            block_time,
+        })
+    }
+    /// Rebuilds the message as legacy or v0 according to the `versioned` flag
+    /// of the gRPC message.
+    pub fn build_message(
+        raw_message: yellowstone_grpc_proto::prelude::Message,
+    ) -> anyhow::Result<VersionedMessage> {
+        let header = raw_message.header.context("header empty")?;
+        let header = MessageHeader {
+            num_required_signatures: header.num_required_signatures as u8,
+            num_readonly_signed_accounts: header.num_readonly_signed_accounts as u8,
+            num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts as u8,
+        };
+        let account_keys = raw_message
+            .account_keys
+            .iter()
+            .map(|k| {
+                k.clone()
+                    .try_into()
+                    .map(Pubkey::new_from_array)
+                    .map_err(|e| anyhow::anyhow!("Failed to convert account key: {:?}", e))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let recent_blockhash = Self::parse_blockhash(&raw_message.recent_blockhash)?;
+        let instructions = raw_message
+            .instructions
+            .iter()
+            .map(|ix| CompiledInstruction {
+                program_id_index: ix.program_id_index as u8,
+                accounts: ix.accounts.clone(),
+                data: ix.data.clone(),
+            })
+            .collect();
+
+        if !raw_message.versioned {
+            return Ok(VersionedMessage::Legacy(LegacyMessage {
+                header,
+                account_keys,
+                recent_blockhash,
+                instructions,
+            }));
+        }
+
+        Ok(VersionedMessage::V0(Message {
+            header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+            address_table_lookups: raw_message
+                .address_table_lookups
+                .iter()
+                .map(|l| {
+                    let account_key = l.account_key
+                        .clone()
+                        .try_into()
+                        .map(Pubkey::new_from_array)
+                        .map_err(|e| anyhow::anyhow!("Failed to convert address_table_lookup account_key: {:?}", e))?;
+
+                    Ok(MessageAddressTableLookup {
+                        account_key,
+                        writable_indexes: l.writable_indexes.clone(),
+                        readonly_indexes: l.readonly_indexes.clone(),
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        }))
+    }
+
+    pub fn build_parsed_transaction(
+        slot: u64,
+        confirmed_txn: &ConfirmedTransactionWithStatusMeta,
+        decoded_compiled: Vec<DecodedInstruction<D::Event>>,
+        decoded_inner: Vec<DecodedInstruction<D::Event>>,
+        received_at: i64,
+    ) -> anyhow::Result<ParsedConfirmedTransactionWithStatusMeta<D::Event>> {
+        match &confirmed_txn.tx_with_meta {
+            TransactionWithStatusMeta::Complete(versioned_tx_with_meta) => {
+                Ok(ParsedConfirmedTransactionWithStatusMeta {
+                    slot,
+                    transaction: ParsedTransaction {
+                        signatures: versioned_tx_with_meta.transaction.signatures.clone(),
+                        message: match &versioned_tx_with_meta.transaction.message {
+                            VersionedMessage::V0(msg) => ParsedMessage {
+                                versioned: true,
+                                header: msg.header.clone(),
+                                account_keys: msg.account_keys.clone(),
+                                recent_blockhash: msg.recent_blockhash.clone(),
+                                instructions: decoded_compiled,
+                                address_table_lookups: msg.address_table_lookups.clone(),
+                            },
+                            VersionedMessage::Legacy(msg) => ParsedMessage {
+                                versioned: false,
+                                header: msg.header.clone(),
+                                account_keys: msg.account_keys.clone(),
+                                recent_blockhash: msg.recent_blockhash.clone(),
+                                instructions: decoded_compiled,
+                                address_table_lookups: vec![],
+                            },
+                        },
+                    },
+                    meta: ParsedTransactionStatusMeta {
+                        status: versioned_tx_with_meta.meta.status.clone(),
+                        fee: versioned_tx_with_meta.meta.fee,
+                        pre_balances: versioned_tx_with_meta.meta.pre_balances.clone(),
+                        post_balances: versioned_tx_with_meta.meta.post_balances.clone(),
+                        inner_instructions: decoded_inner,
+                        log_messages: versioned_tx_with_meta.meta.log_messages.clone(),
+                        pre_token_balances: versioned_tx_with_meta.meta.pre_token_balances.clone(),
+                        post_token_balances: versioned_tx_with_meta.meta.post_token_balances.clone(),
+                        rewards: versioned_tx_with_meta.meta.rewards.clone(),
+                        loaded_addresses: versioned_tx_with_meta.meta.loaded_addresses.clone(),
+                        return_data: versioned_tx_with_meta.meta.return_data.clone(),
+                        compute_units_consumed: versioned_tx_with_meta.meta.compute_units_consumed,
+                    },
+                    block_time: confirmed_txn.block_time,
+                    received_at,
+                })
+            }
+            _ => anyhow::bail!("Expected Complete variant"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::super::test_utils::*,
+        super::*,
+        solana_sdk::{
+            instruction::InstructionError, message::VersionedMessage,
+            transaction::TransactionError,
+        },
+        yellowstone_grpc_proto::prelude::{
+            MessageAddressTableLookup as RawLookup, Reward as RawReward,
+            TransactionError as RawTransactionError,
+        },
+    };
+
+    type Processor = TransactionProcessor<TestDecoder>;
+
+    fn signature(byte: u8) -> Signature {
+        Signature::from([byte; 64])
+    }
+
+    #[test]
+    fn keeps_legacy_messages_legacy() {
+        let keys = Keys::new();
+        let mut raw_message = message(&keys, vec![compiled(Keys::PROGRAM, &[Keys::USER, Keys::POOL], instruction_data(BUY, 1))]);
+        raw_message.versioned = false;
+
+        let confirmed =
+            Processor::build_confirmed_transaction(10, vec![signature(1)], raw_message, meta(vec![], &[]), None).unwrap();
+        let TransactionWithStatusMeta::Complete(txn) = &confirmed.tx_with_meta else {
+            panic!("expected a complete transaction");
+        };
+        let VersionedMessage::Legacy(legacy) = &txn.transaction.message else {
+            panic!("expected a legacy message");
+        };
+        assert_eq!(legacy.account_keys[0], keys.user);
+        assert_eq!(legacy.header.num_readonly_unsigned_accounts, 3);
+
+        let parsed = Processor::build_parsed_transaction(10, &confirmed, vec![], vec![], 0).unwrap();
+        assert!(!parsed.transaction.message.versioned);
+        assert!(parsed.transaction.message.address_table_lookups.is_empty());
+    }
+
+    #[test]
+    fn keeps_v0_lookups_and_loaded_addresses() {
+        let keys = Keys::new();
+        let (table, loaded) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut raw_message = message(&keys, vec![]);
+        raw_message.address_table_lookups = vec![RawLookup {
+            account_key: table.to_bytes().to_vec(),
+            writable_indexes: vec![3],
+            readonly_indexes: vec![],
+        }];
+        let mut raw_meta = meta(vec![], &[]);
+        raw_meta.loaded_writable_addresses = vec![loaded.to_bytes().to_vec()];
+
+        let confirmed =
+            Processor::build_confirmed_transaction(10, vec![signature(1)], raw_message, raw_meta, None).unwrap();
+        let parsed = Processor::build_parsed_transaction(10, &confirmed, vec![], vec![], 0).unwrap();
+        assert!(parsed.transaction.message.versioned);
+        assert_eq!(parsed.transaction.message.address_table_lookups[0].account_key, table);
+        assert_eq!(parsed.meta.loaded_addresses.writable, vec![loaded]);
+    }
+
+    #[test]
+    fn keeps_every_signature_in_order() {
+        let keys = Keys::new();
+        let signatures = vec![signature(1), signature(2), signature(3)];
+        let mut raw_message = message(&keys, vec![]);
+        raw_message.header.as_mut().unwrap().num_required_signatures = 3;
+
+        let confirmed =
+            Processor::build_confirmed_transaction(10, signatures.clone(), raw_message, meta(vec![], &[]), None)
+                .unwrap();
+        let parsed = Processor::build_parsed_transaction(10, &confirmed, vec![], vec![], 0).unwrap();
+        assert_eq!(parsed.transaction.signatures, signatures);
+    }
+
+    #[test]
+    fn takes_every_signature_from_the_update() {
+        let keys = Keys::new();
+        let mut update = transaction_update(10, message(&keys, vec![]), meta(vec![], &[]));
+        let txn = update.transaction.as_mut().unwrap().transaction.as_mut().unwrap();
+        txn.signatures = vec![vec![1; 64], vec![2; 64]];
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        assert_eq!(parsed.transaction.signatures, vec![signature(1), signature(2)]);
+    }
+
+    #[test]
+    fn rejects_short_signatures() {
+        let keys = Keys::new();
+        let mut update = transaction_update(10, message(&keys, vec![]), meta(vec![], &[]));
+        let txn = update.transaction.as_mut().unwrap().transaction.as_mut().unwrap();
+        txn.signatures = vec![vec![1; 64], vec![2; 10]];
+
+        let err = processor_at(10).process_transaction_update(update).unwrap_err();
+        assert_eq!(err.kind(), "bad_signature_length");
+    }
+
+    fn build_meta(raw_meta: yellowstone_grpc_proto::prelude::TransactionStatusMeta) -> TransactionStatusMeta {
+        let keys = Keys::new();
+        let confirmed =
+            Processor::build_confirmed_transaction(10, vec![signature(1)], message(&keys, vec![]), raw_meta, None)
+                .unwrap();
+        match confirmed.tx_with_meta {
+            TransactionWithStatusMeta::Complete(txn) => txn.meta,
+            TransactionWithStatusMeta::MissingMetadata(_) => panic!("expected a complete transaction"),
+        }
+    }
+
+    #[test]
+    fn maps_the_transaction_error_into_the_status() {
+        assert_eq!(build_meta(meta(vec![], &[])).status, Ok(()));
+
+        let err = TransactionError::InstructionError(1, InstructionError::Custom(6001));
+        let mut raw_meta = meta(vec![], &[]);
+        raw_meta.err = Some(RawTransactionError { err: bincode::serialize(&err).unwrap() });
+        assert_eq!(build_meta(raw_meta).status, Err(err));
+
+        let mut raw_meta = meta(vec![], &[]);
+        raw_meta.err = Some(RawTransactionError { err: vec![0xff] });
+        let keys = Keys::new();
+        assert!(
+            Processor::build_confirmed_transaction(10, vec![signature(1)], message(&keys, vec![]), raw_meta, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parses_reward_commission_and_type() {
+        let reward = |reward_type, commission: &str| RawReward {
+            pubkey: Pubkey::new_unique().to_string(),
+            lamports: 10,
+            post_balance: 20,
+            reward_type,
+            commission: commission.to_string(),
+        };
+        let mut raw_meta = meta(vec![], &[]);
+        raw_meta.rewards = vec![reward(1, ""), reward(4, "10"), reward(0, "x")];
+
+        let rewards = build_meta(raw_meta).rewards.unwrap();
+        let parsed: Vec<_> = rewards.iter().map(|r| (r.reward_type, r.commission)).collect();
+        assert_eq!(
+            parsed,
+            [(Some(RewardType::Fee), None), (Some(RewardType::Voting), Some(10)), (None, None)],
+        );
+    }
+}
@@ -0,0 +1,478 @@
+use {
+    anyhow::Context,
+    super::types::*,
+    yellowstone_grpc_proto::prelude::{
+        SubscribeUpdateBlockMeta, SubscribeUpdateTransaction,
+    },
+    solana_account_decoder_client_types::token::UiTokenAmount,
+    solana_sdk::{instruction::{CompiledInstruction, AccountMeta, Instruction}, signature::Signature},
+    std::{
+        collections::HashMap, fs, path::{Path, PathBuf}, time::{SystemTime, UNIX_EPOCH}
+    },
+    solana_transaction_status::{
+        ConfirmedTransactionWithStatusMeta, TransactionTokenBalance, TransactionWithStatusMeta, VersionedTransactionWithStatusMeta
+    },
+    solana_sdk::hash::Hash,
+};
+use super::block_time::BlockTimeCache;
+use super::error::ProcessorError;
+use crate::decoder::ProgramDecoder;
+use crate::models::mapper::instruction::Idl;
+use spl_token::instruction::TokenInstruction;
+
+const TOKEN_PROGRAM_IDL: &str = include_str!("../../idls/token_program_idl.json");
+const TOKEN_2022_PROGRAM_IDL: &str = include_str!("../../idls/token_2022_program_idl.json");
+
+pub struct TransactionProcessor<D: ProgramDecoder> {
+    pub decoder: D,
+    pub token_idl: Idl,
+    pub token_2022_idl: Idl,
+    block_times: BlockTimeCache<D::Event>,
+    error_counts: HashMap<&'static str, u64>,
+    dump_dir: Option<PathBuf>,
+}
+
+impl<D: ProgramDecoder> TransactionProcessor<D> {
+    pub fn new(decoder: D) -> anyhow::Result<Self> {
+        Ok(Self {
+            decoder,
+            token_idl: Idl::load(TOKEN_PROGRAM_IDL, None)?,
+            token_2022_idl: Idl::load(TOKEN_2022_PROGRAM_IDL, None)?,
+            block_times: BlockTimeCache::default(),
+            error_counts: HashMap::new(),
+            dump_dir: None,
+        })
+    }
+
+    /// Replaces the embedded Token Program IDL with the one at `path`.
+    pub fn with_token_idl(mut self, path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        self.token_idl = Idl::load(TOKEN_PROGRAM_IDL, Some(path.as_ref()))?;
+        Ok(self)
+    }
+
+    /// Replaces the embedded Token-2022 IDL with the one at `path`.
+    pub fn with_token_2022_idl(mut self, path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        self.token_2022_idl = Idl::load(TOKEN_2022_PROGRAM_IDL, Some(path.as_ref()))?;
+        Ok(self)
+    }
+
+    /// Writes the raw update to `dir` whenever it fails to process.
+    pub fn dump_failed_updates(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dump_dir = Some(dir.into());
+        self
+    }
+
+    /// Number of failed updates and instructions so far, keyed by
+    /// `ProcessorError::kind`.
+    pub fn error_counts(&self) -> &HashMap<&'static str, u64> {
+        &self.error_counts
+    }
+
+    /// Parses a transaction update and returns the transactions that are
+    /// ready: this one once the block time of its slot is known, plus any
+    /// that waited too long for theirs. A transaction waiting for its block
+    /// time is handed out later by `process_block_meta_update` or a later
+    /// call. Without block metas, transactions are returned straight away
+    /// with no block time.
+    pub fn process_transaction_update(
+        &mut self,
+        update: SubscribeUpdateTransaction,
+    ) -> Result<Vec<ParsedConfirmedTransactionWithStatusMeta<D::Event>>, ProcessorError> {
+        let raw_update = self.dump_dir.as_ref().map(|_| update.clone());
+
+        // Instructions that did not decode; the transaction itself still goes out.
+        let mut failures = Vec::new();
+        let result = self
+            .process_transaction(update, &mut failures)
+            .map_err(ProcessorError::from);
+
+        let errors: Vec<&ProcessorError> = result.as_ref().err().into_iter().chain(&failures).collect();
+        for err in &errors {
+            *self.error_counts.entry(err.kind()).or_default() += 1;
+        }
+        if let Some(raw_update) = raw_update.filter(|_| !errors.is_empty()) {
+            self.dump_update(&raw_update, &errors);
+        }
+        result
+    }
+
+    fn dump_update(&self, update: &SubscribeUpdateTransaction, errors: &[&ProcessorError]) {
+        let Some(dir) = &self.dump_dir else {
+            return;
+        };
+        let signature = update
+            .transaction
+            .as_ref()
+            .and_then(|txn| Self::parse_signature(&txn.signature).ok())
+            .map(|signature| signature.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let path = dir.join(format!("{}-{}.txt", update.slot, signature));
+
+        let errors: String = errors.iter().map(|err| format!("error: {err}\n")).collect();
+        let written = fs::create_dir_all(dir)
+            .and_then(|_| fs::write(&path, format!("{errors}\n{update:#?}\n")));
+        if let Err(e) = written {
+            log::error!("Failed to dump update to {}: {e}", path.display());
+        }
+    }
+
+    fn process_transaction(
+        &mut self,
+        update: SubscribeUpdateTransaction,
+        failures: &mut Vec<ProcessorError>,
+    ) -> anyhow::Result<Vec<ParsedConfirmedTransactionWithStatusMeta<D::Event>>> {
+        let slot = update.slot;
+        let received_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)?
+            .as_millis() as i64;
+        let block_time = self.block_times.block_time(slot);
+
+        if let Some(txn_info) = update.transaction {
+            let transaction = txn_info.transaction.ok_or(ProcessorError::MissingMeta("transaction"))?;
+            let mut signatures = transaction
+                .signatures
+                .iter()
+                .map(|signature| Self::parse_signature(signature))
+                .collect::<Result<Vec<_>, _>>()?;
+            if signatures.is_empty() {
+                signatures.push(Self::parse_signature(&txn_info.signature)?);
+            }
+
+            let raw_message = transaction.message.ok_or(ProcessorError::MissingMeta("message"))?;
+            let meta = txn_info.meta.ok_or(ProcessorError::MissingMeta("meta"))?;
+
+            let confirmed_txn = Self::build_confirmed_transaction(
+                slot,
+                signatures,
+                raw_message,
+                meta,
+                block_time,
+            )?;
+            let instructions = self.extract_all_instructions(&confirmed_txn)?;
+            let mut decoded_events = self.extract_decoded_events(&confirmed_txn, &instructions.inner);
+
+            let (decoded_compiled, decoded_inner) = self.decode_instructions(
+                &instructions.compiled,
+                &instructions.inner,
+                &mut decoded_events,
+                failures,
+            );
+
+            let parsed_txn = Self::build_parsed_transaction(
+                slot,
+                &confirmed_txn,
+                decoded_compiled,
+                decoded_inner,
+                received_at,
+            )?;
+
+            let mut ready = self.block_times.flush(slot);
+            if parsed_txn.block_time.is_none() {
+                ready.extend(self.block_times.defer(parsed_txn));
+            } else {
+                ready.push(parsed_txn);
+            }
+            Ok(ready)
+        } else {
+            Ok(self.block_times.flush(slot))
+        }
+    }
+
+    /// Records the block time of a slot and returns the transactions that were
+    /// waiting for it.
+    pub fn process_block_meta_update(
+        &mut self,
+        update: SubscribeUpdateBlockMeta,
+    ) -> Vec<ParsedConfirmedTransactionWithStatusMeta<D::Event>> {
+        match update.block_time {
+            Some(block_time) => self.block_times.insert(update.slot, block_time.timestamp),
+            None => vec![],
+        }
+    }
+
+    pub fn parse_signature(signature: &[u8]) -> Result<Signature, ProcessorError> {
+        let raw_signature_array: [u8; 64] = signature
+            .try_into()
+            .map_err(|_| ProcessorError::BadSignatureLength(signature.len()))?;
+        Ok(Signature::from(raw_signature_array))
+    }
+
+    pub fn parse_blockhash(blockhash: &[u8]) -> anyhow::Result<Hash> {
+        Ok(Hash::new_from_array(
+            blockhash.try_into().context("Failed to convert blockhash to [u8; 32]")?,
+        ))
+    }
+
+    pub fn extract_all_instructions(
+        &self,
+        confirmed_txn: &ConfirmedTransactionWithStatusMeta,
+    ) -> anyhow::Result<ExtractedInstructions> {
+        match &confirmed_txn.tx_with_meta {
+            TransactionWithStatusMeta::Complete(versioned_tx_with_meta) => {
+                Ok(ExtractedInstructions {
+                    compiled: self.flatten_compiled_instructions(versioned_tx_with_meta)?,
+                    inner: self.flatten_inner_instructions(versioned_tx_with_meta)?,
+                })
+            }
+            TransactionWithStatusMeta::MissingMetadata(_) => Ok(ExtractedInstructions {
+                compiled: vec![],
+                inner: vec![],
+            }),
+        }
+    }
+
+    pub fn get_instruction_name_with_typename(&self, instruction: &TokenInstruction) -> String {
+        let debug_string = format!("{:?}", instruction);
+        if let Some(first_brace) = debug_string.find(" {") {
+            let name = &debug_string[..first_brace];
+            self.to_camel_case(name)
+        } else {
+            self.to_camel_case(&debug_string)
+        }
+    }
+
+    pub fn flatten_compiled_instructions(
+        &self,
+        transaction_with_meta: &VersionedTransactionWithStatusMeta,
+    ) -> Result<Vec<TransactionInstructionWithParent>, ProcessorError> {
+        let mut compiled_result = Vec::new();
+        let transaction = &transaction_with_meta.transaction;
+        let ci_ixs = transaction.message.instructions();
+        let parsed_accounts = self.parse_transaction_accounts(
+            &transaction.message,
+            transaction_with_meta.meta.loaded_addresses.clone(),
+        );
+
+        for (index, ci_ix) in ci_ixs.iter().enumerate() {
+            compiled_result.push(TransactionInstructionWithParent {
+                instruction: self.compiled_instruction_to_instruction(ci_ix, &parsed_accounts)?,
+                parent_program_id: None,
+                position: InstructionPosition { outer_index: index, inner_index: None },
+                stack_height: Some(1),
+            });
+        }
+
+        Ok(compiled_result)
+    }
+
+    pub fn flatten_inner_instructions(
+        &self,
+        transaction_with_meta: &VersionedTransactionWithStatusMeta,
+    ) -> Result<Vec<TransactionInstructionWithParent>, ProcessorError> {
+        let mut inner_result = Vec::new();
+        let transaction = &transaction_with_meta.transaction;
+        let ci_ixs = transaction.message.instructions();
+        let parsed_accounts = self.parse_transaction_accounts(
+            &transaction.message,
+            transaction_with_meta.meta.loaded_addresses.clone(),
+        );
+
+        if let Some(inner_ixs) = &transaction_with_meta.meta.inner_instructions {
+            let mut ordered_cii = inner_ixs.clone();
+            ordered_cii.sort_by(|a, b| a.index.cmp(&b.index));
+
+            for cii in ordered_cii {
+                let parent_ix = ci_ixs.get(cii.index as usize).ok_or_else(|| {
+                    ProcessorError::Malformed(format!(
+                        "inner instructions reference outer instruction {} of {}",
+                        cii.index,
+                        ci_ixs.len()
+                    ))
+                })?;
+                let parent_program_id =
+                    Self::resolve_account(&parsed_accounts, parent_ix.program_id_index as usize)?.pubkey;
+
+                for (inner_index, cii_entry) in cii.instructions.into_iter().enumerate() {
+                    let ix = CompiledInstruction {
+                        program_id_index: cii_entry.instruction.program_id_index,
+                        accounts: cii_entry.instruction.accounts.clone(),
+                        data: cii_entry.instruction.data.clone(),
+                    };
+                    inner_result.push(TransactionInstructionWithParent {
+                        instruction: self.compiled_instruction_to_instruction(&ix, &parsed_accounts)?,
+                        parent_program_id: Some(parent_program_id),
+                        position: InstructionPosition {
+                            outer_index: cii.index as usize,
+                            inner_index: Some(inner_index),
+                        },
+                        stack_height: cii_entry.stack_height,
+                    });
+                }
+            }
+        }
+
+        Ok(inner_result)
+    }
+
+    fn compiled_instruction_to_instruction(
+        &self,
+        ci: &CompiledInstruction,
+        parsed_accounts: &[AccountMeta],
+    ) -> Result<Instruction, ProcessorError> {
+        let program_id = Self::resolve_account(parsed_accounts, ci.program_id_index as usize)?.pubkey;
+        let accounts = ci
+            .accounts
+            .iter()
+            .map(|&index| Self::resolve_account(parsed_accounts, index as usize).cloned())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Instruction {
+            program_id,
+            accounts,
+            data: ci.data.clone(),
+        })
+    }
+
+    fn resolve_account(
+        parsed_accounts: &[AccountMeta],
+        index: usize,
+    ) -> Result<&AccountMeta, ProcessorError> {
+        parsed_accounts.get(index).ok_or(ProcessorError::UnresolvedAltIndex {
+            index,
+            accounts: parsed_accounts.len(),
+        })
+    }
+
+    pub fn convert_token_balance(
+        tb: TransactionTokenBalance,
+    ) -> TransactionTokenBalance {
+        let ui_token_amount = tb.ui_token_amount.clone();
+        TransactionTokenBalance {
+            account_index: tb.account_index,
+            mint: tb.mint.clone(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: if ui_token_amount.ui_amount == Some(0.0) {
+                    None
+                } else {
+                    ui_token_amount.ui_amount
+                },
+                decimals: ui_token_amount.decimals,
+                amount: ui_token_amount.amount,
+                ui_amount_string: ui_token_amount.ui_amount_string,
+            },
+            owner: tb.owner.clone(),
+            program_id: tb.program_id.clone(),
+        }
+    }
+
+    fn to_camel_case(&self, name: &str) -> String {
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first_char) => first_char.to_lowercase().collect::<String>() + chars.as_str(),
+            None => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::super::test_utils::*,
+        spl_token::instruction::TokenInstruction,
+    };
+
+    fn transfer_data(amount: u64) -> Vec<u8> {
+        TokenInstruction::Transfer { amount }.pack()
+    }
+
+    #[test]
+    fn round_trips_a_buy_with_its_event_and_token_transfer() {
+        let keys = Keys::new();
+        let logs = [
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            format!("Program {} invoke [2]", spl_token::ID),
+            format!("Program {} success", spl_token::ID),
+            program_data_log(42),
+            format!("Program {PROGRAM_ID} success"),
+        ];
+        let update = transaction_update(
+            10,
+            message(&keys, vec![compiled(Keys::PROGRAM, &[Keys::USER, Keys::POOL], instruction_data(BUY, 42))]),
+            meta(
+                vec![(0, vec![inner(
+                    Keys::TOKEN_PROGRAM,
+                    &[Keys::SOURCE, Keys::DESTINATION, Keys::USER],
+                    transfer_data(42),
+                    2,
+                )])],
+                &logs,
+            ),
+        );
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        assert_eq!(parsed.slot, 10);
+        assert_eq!(parsed.block_time, Some(1_700_000_000));
+        assert_eq!(parsed.transaction.signatures.len(), 1);
+
+        let [buy] = parsed.transaction.message.instructions.as_slice() else {
+            panic!("expected one top-level instruction");
+        };
+        assert_eq!(buy.name, "buy");
+        assert_eq!(buy.data, serde_json::json!({ "amount": 42 }));
+        assert_eq!(buy.events, vec![TradeEvent { amount: 42 }]);
+        assert_eq!(buy.program_id, PROGRAM_ID);
+        let names: Vec<_> = buy.accounts.iter().map(|account| account.name.as_str()).collect();
+        assert_eq!(names, ["user", "pool"]);
+        assert_eq!(buy.accounts[0].pubkey, keys.user);
+        assert_eq!(buy.accounts[1].pubkey, keys.pool);
+
+        let [transfer] = parsed.meta.inner_instructions.as_slice() else {
+            panic!("expected one inner instruction");
+        };
+        assert_eq!(transfer.name, "transfer");
+        assert_eq!(transfer.parent_program_id, Some(PROGRAM_ID));
+        assert_eq!(transfer.position.outer_index, 0);
+        assert_eq!(transfer.position.inner_index, Some(0));
+        assert_eq!(transfer.accounts[0].pubkey, keys.source);
+        assert_eq!(transfer.accounts[1].pubkey, keys.destination);
+    }
+
+    #[test]
+    fn skips_instructions_of_other_programs() {
+        let keys = Keys::new();
+        let update = transaction_update(
+            10,
+            message(&keys, vec![
+                compiled(Keys::POOL, &[Keys::USER], vec![1, 2, 3]),
+                compiled(Keys::PROGRAM, &[Keys::USER, Keys::POOL], instruction_data(SELL, 7)),
+            ]),
+            meta(vec![], &[]),
+        );
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        let names: Vec<_> = parsed
+            .transaction
+            .message
+            .instructions
+            .iter()
+            .map(|ix| (ix.name.as_str(), ix.position.outer_index))
+            .collect();
+        assert_eq!(names, [("sell", 1)]);
+    }
+
+    #[test]
+    fn hands_out_waiting_transactions_with_later_transactions() {
+        let keys = Keys::new();
+        let update = |slot| transaction_update(slot, message(&keys, vec![]), meta(vec![], &[]));
+
+        let mut processor = processor_at(10);
+        assert!(processor.process_transaction_update(update(11)).unwrap().is_empty());
+        assert!(processor.process_transaction_update(update(42)).unwrap().is_empty());
+
+        let ready = processor.process_transaction_update(update(44)).unwrap();
+        let slots: Vec<_> = ready.iter().map(|txn| (txn.slot, txn.block_time)).collect();
+        assert_eq!(slots, [(11, None), (44, None)]);
+    }
+
+    #[test]
+    fn counts_failed_updates_by_kind() {
+        let keys = Keys::new();
+        let mut update = transaction_update(10, message(&keys, vec![]), meta(vec![], &[]));
+        update.transaction.as_mut().unwrap().meta = None;
+
+        let mut processor = processor_at(10);
+        assert!(processor.process_transaction_update(update).is_err());
+        assert_eq!(processor.error_counts().get("missing_meta"), Some(&1));
+    }
+
+}
@@ -0,0 +1,399 @@
+use super::core::TransactionProcessor;
+use super::error::ProcessorError;
+use super::events::{is_event_cpi, EventsByInstruction};
+use super::types::*;
+
+use crate::decoder::ProgramDecoder;
+use crate::models::mapper::instruction::InstructionAccountMapper;
+use crate::models::mapper::instruction::AccountMetadata;
+use crate::models::serialize::token_serializable::{
+    convert_to_serializable, convert_token_2022_to_serializable, token_2022_instruction_name,
+};
+use solana_program::program_error::ProgramError;
+use spl_token::instruction::TokenInstruction;
+use spl_token_2022::instruction::TokenInstruction as Token2022Instruction;
+
+/// Name of an instruction whose data did not decode.
+pub const UNKNOWN_INSTRUCTION: &str = "unknown";
+
+impl<D: ProgramDecoder> TransactionProcessor<D> {
+    /// Decodes the instructions of the program and of both token programs.
+    /// An instruction that fails to decode is kept with its `error` set, so
+    /// the rest of the transaction is not lost with it; the error itself is
+    /// pushed to `failures`.
+    pub fn decode_instructions(
+        &self,
+        compiled_instructions: &[TransactionInstructionWithParent],
+        inner_instructions: &[TransactionInstructionWithParent],
+        decoded_events: &mut EventsByInstruction<D::Event>,
+        failures: &mut Vec<ProcessorError>,
+    ) -> (Vec<DecodedInstruction<D::Event>>, Vec<DecodedInstruction<D::Event>>) {
+        let mut decode_all = |instructions: &[TransactionInstructionWithParent]| {
+            instructions
+                .iter()
+                .filter_map(|instruction| {
+                    self.decode_single_instruction(instruction, decoded_events, failures)
+                        .unwrap_or_else(|err| {
+                            let err = ProcessorError::from(err);
+                            Some(failed_instruction(instruction, UNKNOWN_INSTRUCTION, err, vec![], failures))
+                        })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let decoded_compiled = decode_all(compiled_instructions);
+        let decoded_inner = decode_all(inner_instructions);
+        (decoded_compiled, decoded_inner)
+    }
+
+    pub fn decode_single_instruction(
+        &self,
+        instruction: &TransactionInstructionWithParent,
+        decoded_events: &mut EventsByInstruction<D::Event>,
+        failures: &mut Vec<ProcessorError>,
+    ) -> anyhow::Result<Option<DecodedInstruction<D::Event>>> {
+        if instruction.instruction.program_id == self.decoder.program_id() {
+            if is_event_cpi(&instruction.instruction.data) {
+                // Already attached to the emitting instruction by `extract_decoded_events`.
+                return Ok(None);
+            }
+            self.decode_program_instruction(instruction, decoded_events, failures)
+        } else if instruction.instruction.program_id == spl_token::ID {
+            self.decode_token_instruction(instruction, failures)
+        } else if instruction.instruction.program_id == spl_token_2022::ID {
+            self.decode_token_2022_instruction(instruction, failures)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn decode_program_instruction(
+        &self,
+        instruction: &TransactionInstructionWithParent,
+        decoded_events: &mut EventsByInstruction<D::Event>,
+        failures: &mut Vec<ProcessorError>,
+    ) -> anyhow::Result<Option<DecodedInstruction<D::Event>>> {
+        let events = decoded_events
+            .remove(&instruction.position)
+            .unwrap_or_default();
+
+        let Ok((ix_name, data)) = self.decoder.decode_instruction(&instruction.instruction.data) else {
+            let err = unknown_discriminator(instruction);
+            return Ok(Some(failed_instruction(instruction, UNKNOWN_INSTRUCTION, err, events, failures)));
+        };
+
+        let (mapped_accounts, error) =
+            match self.decoder.map_accounts(&instruction.instruction.accounts, &ix_name) {
+                Ok(accounts) => (accounts, None),
+                Err(e) => {
+                    let err = account_mapping_error(instruction, &ix_name, e.downcast_ref());
+                    (numbered_accounts(instruction), Some(record_failure(err, failures)))
+                }
+            };
+
+        Ok(Some(DecodedInstruction {
+            name: ix_name,
+            accounts: mapped_accounts,
+            data,
+            events,
+            position: instruction.position,
+            program_id: instruction.instruction.program_id,
+            parent_program_id: instruction.parent_program_id,
+            error,
+        }))
+    }
+
+    pub fn decode_token_instruction(
+        &self,
+        instruction: &TransactionInstructionWithParent,
+        failures: &mut Vec<ProcessorError>,
+    ) -> anyhow::Result<Option<DecodedInstruction<D::Event>>> {
+        let Ok(decoded_ix) = TokenInstruction::unpack(&instruction.instruction.data) else {
+            let err = unknown_discriminator(instruction);
+            return Ok(Some(failed_instruction(instruction, UNKNOWN_INSTRUCTION, err, vec![], failures)));
+        };
+        let ix_name = self.get_instruction_name_with_typename(&decoded_ix);
+        let serializable_ix = convert_to_serializable(decoded_ix);
+
+        let (mapped_accounts, error) =
+            match self.token_idl.map_accounts(&instruction.instruction.accounts, &ix_name) {
+                Ok(accounts) => (accounts, None),
+                Err(e) => {
+                    let err = account_mapping_error(instruction, &ix_name, Some(&e));
+                    (numbered_accounts(instruction), Some(record_failure(err, failures)))
+                }
+            };
+
+        let data = serde_json::to_value(serializable_ix)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize token ix data: {:?}", e))?;
+
+        Ok(Some(DecodedInstruction {
+            name: ix_name,
+            accounts: mapped_accounts,
+            data,
+            events: vec![],
+            position: instruction.position,
+            program_id: instruction.instruction.program_id,
+            parent_program_id: instruction.parent_program_id,
+            error,
+        }))
+    }
+
+    pub fn decode_token_2022_instruction(
+        &self,
+        instruction: &TransactionInstructionWithParent,
+        failures: &mut Vec<ProcessorError>,
+    ) -> anyhow::Result<Option<DecodedInstruction<D::Event>>> {
+        let data = &instruction.instruction.data;
+        let Ok(decoded_ix) = Token2022Instruction::unpack(data) else {
+            let err = unknown_discriminator(instruction);
+            return Ok(Some(failed_instruction(instruction, UNKNOWN_INSTRUCTION, err, vec![], failures)));
+        };
+        let ix_name = token_2022_instruction_name(&decoded_ix, data);
+        let serializable_ix = convert_token_2022_to_serializable(decoded_ix, data);
+
+        // Confidential transfer instructions have no entry in the IDL.
+        let (mapped_accounts, error) =
+            match self.token_2022_idl.map_accounts(&instruction.instruction.accounts, &ix_name) {
+                Ok(accounts) => (accounts, None),
+                Err(e) => {
+                    let err = account_mapping_error(instruction, &ix_name, Some(&e));
+                    (numbered_accounts(instruction), Some(record_failure(err, failures)))
+                }
+            };
+
+        let data = serde_json::to_value(serializable_ix)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize token-2022 ix data: {:?}", e))?;
+
+        Ok(Some(DecodedInstruction {
+            name: ix_name,
+            accounts: mapped_accounts,
+            data,
+            events: vec![],
+            position: instruction.position,
+            program_id: instruction.instruction.program_id,
+            parent_program_id: instruction.parent_program_id,
+            error,
+        }))
+    }
+}
+
+/// An instruction that could not be decoded, with its raw accounts.
+fn failed_instruction<E>(
+    instruction: &TransactionInstructionWithParent,
+    name: &str,
+    err: ProcessorError,
+    events: Vec<E>,
+    failures: &mut Vec<ProcessorError>,
+) -> DecodedInstruction<E> {
+    log::debug!("Failed to decode instruction at {:?}: {err}", instruction.position);
+    let error = record_failure(err, failures);
+    DecodedInstruction {
+        name: name.to_string(),
+        accounts: numbered_accounts(instruction),
+        data: serde_json::Value::Null,
+        events,
+        position: instruction.position,
+        program_id: instruction.instruction.program_id,
+        parent_program_id: instruction.parent_program_id,
+        error: Some(error),
+    }
+}
+
+/// Keeps `err` for the error counters and returns the message stored on the instruction.
+fn record_failure(err: ProcessorError, failures: &mut Vec<ProcessorError>) -> String {
+    let message = err.to_string();
+    failures.push(err);
+    message
+}
+
+/// The accounts of an instruction the IDL cannot name, as `Account 1`, `Account 2`, ...
+fn numbered_accounts(instruction: &TransactionInstructionWithParent) -> Vec<AccountMetadata> {
+    instruction
+        .instruction
+        .accounts
+        .iter()
+        .enumerate()
+        .map(|(i, account)| AccountMetadata {
+            pubkey: account.pubkey,
+            is_writable: account.is_writable,
+            is_signer: account.is_signer,
+            declared_writable: None,
+            declared_signer: None,
+            name: format!("Account {}", i + 1),
+        })
+        .collect()
+}
+
+fn unknown_discriminator(instruction: &TransactionInstructionWithParent) -> ProcessorError {
+    let data = &instruction.instruction.data;
+    ProcessorError::UnknownDiscriminator {
+        program_id: instruction.instruction.program_id,
+        discriminator: data[..data.len().min(8)].to_vec(),
+    }
+}
+
+/// `Idl::map_accounts` fails with `NotEnoughAccountKeys` when the instruction
+/// has fewer accounts than the IDL declares, and with `InvalidArgument` when
+/// the IDL has no such instruction.
+fn account_mapping_error(
+    instruction: &TransactionInstructionWithParent,
+    name: &str,
+    err: Option<&ProgramError>,
+) -> ProcessorError {
+    match err {
+        Some(ProgramError::NotEnoughAccountKeys) => ProcessorError::NotEnoughAccounts {
+            instruction: name.to_string(),
+            accounts: instruction.instruction.accounts.len(),
+        },
+        _ => ProcessorError::InstructionNotInIdl {
+            instruction: name.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::super::test_utils::*,
+        crate::processor::types::DecodedInstruction,
+        solana_sdk::pubkey::Pubkey,
+        spl_token_2022::extension::{memo_transfer, transfer_fee},
+    };
+
+    const TRANSFER_ACCOUNTS: [u8; 4] = [Keys::SOURCE, Keys::POOL, Keys::DESTINATION, Keys::USER];
+
+    fn decode_token_2022(data: Vec<u8>, accounts: &[u8]) -> DecodedInstruction<TradeEvent> {
+        let keys = Keys::new();
+        let update = transaction_update(
+            10,
+            message(&keys, vec![compiled(Keys::TOKEN_2022_PROGRAM, accounts, data)]),
+            meta(vec![], &[]),
+        );
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        let [decoded] = <[_; 1]>::try_from(parsed.transaction.message.instructions).unwrap();
+        decoded
+    }
+
+    fn account_names(instruction: &DecodedInstruction<TradeEvent>) -> Vec<&str> {
+        instruction.accounts.iter().map(|account| account.name.as_str()).collect()
+    }
+
+    #[test]
+    fn decodes_token_2022_transfer_checked() {
+        let (any, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let data = spl_token_2022::instruction::transfer_checked(
+            &spl_token_2022::ID, &any, &any, &any, &owner, &[], 1_500, 6,
+        )
+        .unwrap()
+        .data;
+
+        let decoded = decode_token_2022(data, &TRANSFER_ACCOUNTS);
+        assert_eq!(decoded.name, "transferChecked");
+        assert_eq!(decoded.program_id, spl_token_2022::ID);
+        assert_eq!(account_names(&decoded), ["source", "mint", "destination", "authority"]);
+        assert_eq!(decoded.data["TransferChecked"]["amount"], 1_500);
+        assert_eq!(decoded.data["TransferChecked"]["decimals"], 6);
+    }
+
+    #[test]
+    fn names_extension_instructions_after_the_idl() {
+        let (any, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let data = transfer_fee::instruction::transfer_checked_with_fee(
+            &spl_token_2022::ID, &any, &any, &any, &owner, &[], 1_000, 6, 10,
+        )
+        .unwrap()
+        .data;
+        let decoded = decode_token_2022(data, &TRANSFER_ACCOUNTS);
+        assert_eq!(decoded.name, "transferCheckedWithFee");
+        assert_eq!(account_names(&decoded), ["source", "mint", "destination", "authority"]);
+
+        let data = memo_transfer::instruction::enable_required_transfer_memos(
+            &spl_token_2022::ID, &any, &owner, &[],
+        )
+        .unwrap()
+        .data;
+        let decoded = decode_token_2022(data, &[Keys::SOURCE, Keys::USER]);
+        assert_eq!(decoded.name, "enableRequiredMemoTransfers");
+        assert_eq!(account_names(&decoded), ["account", "owner"]);
+    }
+
+    #[test]
+    fn numbers_accounts_of_instructions_missing_from_the_idl() {
+        // ConfidentialTransferExtension / ConfigureAccount has no IDL entry.
+        let decoded = decode_token_2022(vec![27, 2], &[Keys::SOURCE, Keys::POOL, Keys::USER]);
+        assert_eq!(decoded.name, "configureAccountConfidentialTransfer");
+        assert_eq!(account_names(&decoded), ["Account 1", "Account 2", "Account 3"]);
+        assert_eq!(decoded.accounts[0].declared_writable, None);
+        assert!(decoded.error.unwrap().contains("not in the IDL"));
+    }
+
+    #[test]
+    fn keeps_the_rest_of_the_transaction_when_an_instruction_fails() {
+        let keys = Keys::new();
+        let (any, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let transfer = spl_token::instruction::transfer(&spl_token::ID, &any, &any, &owner, &[], 5).unwrap().data;
+        let update = transaction_update(
+            10,
+            message(&keys, vec![
+                compiled(Keys::PROGRAM, &[Keys::USER, Keys::POOL], vec![42]),
+                compiled(Keys::PROGRAM, &[Keys::USER], instruction_data(SELL, 3)),
+                compiled(Keys::TOKEN_PROGRAM, &[Keys::SOURCE], transfer),
+                compiled(Keys::TOKEN_PROGRAM, &[Keys::SOURCE], vec![0xff]),
+                compiled(Keys::PROGRAM, &[Keys::USER, Keys::POOL], instruction_data(BUY, 4)),
+            ]),
+            meta(vec![], &[]),
+        );
+
+        let mut processor = processor_at(10);
+        let parsed = processor.process_transaction_update(update).unwrap().pop().unwrap();
+        let decoded: Vec<_> = parsed
+            .transaction
+            .message
+            .instructions
+            .iter()
+            .map(|ix| (ix.name.as_str(), ix.error.clone()))
+            .collect();
+        assert_eq!(
+            decoded,
+            [
+                ("unknown", Some(format!("Unknown discriminator [42] for program {PROGRAM_ID}"))),
+                ("sell", Some("Instruction sell has 1 accounts, fewer than its IDL declares".to_string())),
+                ("transfer", Some("Instruction transfer has 1 accounts, fewer than its IDL declares".to_string())),
+                ("unknown", Some(format!("Unknown discriminator [255] for program {}", spl_token::ID))),
+                ("buy", None),
+            ],
+        );
+        let [unknown, sell, ..] = parsed.transaction.message.instructions.as_slice() else {
+            unreachable!();
+        };
+        assert_eq!(unknown.data, serde_json::Value::Null);
+        assert_eq!(account_names(unknown), ["Account 1", "Account 2"]);
+        assert_eq!(sell.data, serde_json::json!({ "amount": 3 }));
+        assert_eq!(account_names(sell), ["Account 1"]);
+        assert_eq!(processor.error_counts().get("unknown_discriminator"), Some(&2));
+        assert_eq!(processor.error_counts().get("not_enough_accounts"), Some(&2));
+    }
+
+    #[test]
+    fn undecodable_instructions_keep_their_events() {
+        let keys = Keys::new();
+        let logs = [
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            program_data_log(9),
+            format!("Program {PROGRAM_ID} success"),
+        ];
+        let update = transaction_update(
+            10,
+            message(&keys, vec![compiled(Keys::PROGRAM, &[Keys::USER, Keys::POOL], vec![42])]),
+            meta(vec![], &logs),
+        );
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        let [unknown] = parsed.transaction.message.instructions.as_slice() else {
+            panic!("expected one instruction");
+        };
+        assert_eq!(unknown.name, "unknown");
+        assert_eq!(unknown.events, vec![TradeEvent { amount: 9 }]);
+    }
+}
@@ -0,0 +1,50 @@
+use solana_sdk::pubkey::Pubkey;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProcessorError {
+    #[error("Missing {0} in transaction update")]
+    MissingMeta(&'static str),
+
+    #[error("Signature must be exactly 64 bytes, got {0}")]
+    BadSignatureLength(usize),
+
+    #[error("Account index {index} out of range for {accounts} resolved accounts, lookup table addresses missing?")]
+    UnresolvedAltIndex { index: usize, accounts: usize },
+
+    #[error("Unknown discriminator {discriminator:?} for program {program_id}")]
+    UnknownDiscriminator { program_id: Pubkey, discriminator: Vec<u8> },
+
+    #[error("Instruction {instruction} is not in the IDL")]
+    InstructionNotInIdl { instruction: String },
+
+    #[error("Instruction {instruction} has {accounts} accounts, fewer than its IDL declares")]
+    NotEnoughAccounts { instruction: String, accounts: usize },
+
+    #[error("Malformed update: {0}")]
+    Malformed(String),
+}
+
+impl ProcessorError {
+    /// Short name used as the key of the per-kind error counters.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ProcessorError::MissingMeta(_) => "missing_meta",
+            ProcessorError::BadSignatureLength(_) => "bad_signature_length",
+            ProcessorError::UnresolvedAltIndex { .. } => "unresolved_alt_index",
+            ProcessorError::UnknownDiscriminator { .. } => "unknown_discriminator",
+            ProcessorError::InstructionNotInIdl { .. } => "instruction_not_in_idl",
+            ProcessorError::NotEnoughAccounts { .. } => "not_enough_accounts",
+            ProcessorError::Malformed(_) => "malformed",
+        }
+    }
+}
+
+impl From<anyhow::Error> for ProcessorError {
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast::<ProcessorError>() {
+            Ok(err) => err,
+            Err(err) => ProcessorError::Malformed(format!("{err:#}")),
+        }
+    }
+}
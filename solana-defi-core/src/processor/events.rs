@@ -0,0 +1,272 @@
+use {
+    super::{core::TransactionProcessor, types::*},
+    crate::{decoder::ProgramDecoder, models::mapper::event},
+    solana_transaction_status::{ConfirmedTransactionWithStatusMeta, TransactionWithStatusMeta},
+    std::collections::HashMap,
+};
+
+/// Prefix Anchor puts in front of the self-CPI data written by `emit_cpi!`.
+pub const EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+pub type EventsByInstruction<E> = HashMap<InstructionPosition, Vec<E>>;
+
+impl<D: ProgramDecoder> TransactionProcessor<D> {
+    /// Decodes every event of the transaction, from `Program data:` logs and
+    /// from `emit_cpi!` inner instructions, keyed by the instruction that emitted it.
+    pub fn extract_decoded_events(
+        &self,
+        confirmed_txn: &ConfirmedTransactionWithStatusMeta,
+        inner_instructions: &[TransactionInstructionWithParent],
+    ) -> EventsByInstruction<D::Event> {
+        let log_events = match &confirmed_txn.tx_with_meta {
+            TransactionWithStatusMeta::Complete(versioned_meta) => versioned_meta
+                .meta
+                .log_messages
+                .as_deref()
+                .map(|logs| self.log_events(logs))
+                .unwrap_or_default(),
+            TransactionWithStatusMeta::MissingMetadata(_) => vec![],
+        };
+
+        let mut events = EventsByInstruction::new();
+        for (position, decoded) in log_events.into_iter().chain(self.cpi_events(inner_instructions)) {
+            // Programs that both log and self-CPI the same event would otherwise show it twice.
+            let emitted = events.entry(position).or_default();
+            if !emitted.contains(&decoded) {
+                emitted.push(decoded);
+            }
+        }
+
+        events
+    }
+
+    /// Replays the invoke/success nesting of the logs to find which
+    /// instruction was executing when each `Program data:` line was written.
+    pub fn log_events(&self, logs: &[String]) -> Vec<(InstructionPosition, D::Event)> {
+        let program_id = self.decoder.program_id().to_string();
+        let mut events = Vec::new();
+        let mut stack: Vec<(InstructionPosition, &str)> = Vec::new();
+        let mut outer_index: Option<usize> = None;
+        let mut next_inner_index = 0;
+
+        for log in logs {
+            if let Some(data) = log.strip_prefix("Program data: ") {
+                let Some((position, invoked_program)) = stack.last() else {
+                    continue;
+                };
+                if *invoked_program != program_id {
+                    continue;
+                }
+                if let Ok(decoded_bytes) = event::decode_log_data(data.trim()) {
+                    if let Ok(decoded) = self.decoder.decode_event(&decoded_bytes) {
+                        events.push((*position, decoded));
+                    }
+                }
+            } else if let Some((invoked_program, depth)) = event::parse_invoke_log(log) {
+                let position = if depth == 1 {
+                    let index = outer_index.map_or(0, |index| index + 1);
+                    outer_index = Some(index);
+                    next_inner_index = 0;
+                    InstructionPosition { outer_index: index, inner_index: None }
+                } else {
+                    let Some(index) = outer_index else {
+                        break;
+                    };
+                    next_inner_index += 1;
+                    InstructionPosition { outer_index: index, inner_index: Some(next_inner_index - 1) }
+                };
+                stack.push((position, invoked_program));
+            } else if event::is_invoke_result_log(log) {
+                stack.pop();
+            } else if log == "Log truncated" {
+                break;
+            }
+        }
+
+        events
+    }
+
+    /// Decodes the `emit_cpi!` self-invocations and attributes each event to
+    /// the instruction one stack level above the self-CPI. Payloads that do
+    /// not decode are skipped, as undecodable `Program data:` lines are.
+    pub fn cpi_events(
+        &self,
+        inner_instructions: &[TransactionInstructionWithParent],
+    ) -> Vec<(InstructionPosition, D::Event)> {
+        let program_id = self.decoder.program_id();
+
+        inner_instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, ix)| ix.instruction.program_id == program_id)
+            .filter_map(|(index, ix)| {
+                let payload = ix.instruction.data.strip_prefix(&EVENT_IX_TAG[..])?;
+                let decoded = self.decoder.decode_event(payload).ok()?;
+                Some((Self::invoking_position(inner_instructions, index), decoded))
+            })
+            .collect()
+    }
+
+    fn invoking_position(
+        inner_instructions: &[TransactionInstructionWithParent],
+        index: usize,
+    ) -> InstructionPosition {
+        let ix = &inner_instructions[index];
+        let outer = InstructionPosition {
+            outer_index: ix.position.outer_index,
+            inner_index: None,
+        };
+
+        // Height 2 is a direct CPI from the top-level instruction; a missing
+        // height leaves the top-level instruction as the best guess.
+        let Some(height) = ix.stack_height.filter(|height| *height > 2) else {
+            return outer;
+        };
+
+        inner_instructions[..index]
+            .iter()
+            .rev()
+            .take_while(|prev| prev.position.outer_index == ix.position.outer_index)
+            .find(|prev| prev.stack_height == Some(height - 1))
+            .map_or(outer, |prev| prev.position)
+    }
+}
+
+pub fn is_event_cpi(data: &[u8]) -> bool {
+    data.starts_with(&EVENT_IX_TAG)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{super::test_utils::*, EVENT_IX_TAG},
+        crate::processor::types::DecodedInstruction,
+    };
+
+    fn events_by_position(instructions: &[DecodedInstruction<TradeEvent>]) -> Vec<(usize, Option<usize>, Vec<u64>)> {
+        instructions
+            .iter()
+            .map(|ix| {
+                let amounts = ix.events.iter().map(|event| event.amount).collect();
+                (ix.position.outer_index, ix.position.inner_index, amounts)
+            })
+            .collect()
+    }
+
+    fn buy(amount: u64) -> yellowstone_grpc_proto::prelude::CompiledInstruction {
+        compiled(Keys::PROGRAM, &[Keys::USER, Keys::POOL], instruction_data(BUY, amount))
+    }
+
+    #[test]
+    fn logged_events_go_to_their_own_buy_in_a_bundle() {
+        let keys = Keys::new();
+        let logs = [
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            program_data_log(1),
+            format!("Program {PROGRAM_ID} success"),
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            program_data_log(2),
+            format!("Program {PROGRAM_ID} success"),
+        ];
+        let update = transaction_update(10, message(&keys, vec![buy(1), buy(2)]), meta(vec![], &logs));
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        assert_eq!(
+            events_by_position(&parsed.transaction.message.instructions),
+            [(0, None, vec![1]), (1, None, vec![2])],
+        );
+    }
+
+    #[test]
+    fn cpi_events_go_to_their_own_buy_in_a_bundle() {
+        let keys = Keys::new();
+        let self_cpi = |amount| inner(Keys::PROGRAM, &[], event_cpi_data(amount), 2);
+        let update = transaction_update(
+            10,
+            message(&keys, vec![buy(1), buy(2)]),
+            meta(vec![(0, vec![self_cpi(1)]), (1, vec![self_cpi(2)])], &[]),
+        );
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        assert_eq!(
+            events_by_position(&parsed.transaction.message.instructions),
+            [(0, None, vec![1]), (1, None, vec![2])],
+        );
+        assert!(parsed.meta.inner_instructions.is_empty());
+    }
+
+    #[test]
+    fn routed_buys_keep_their_own_events() {
+        let keys = Keys::new();
+        let router = keys.pool;
+        let routed_buy = |amount| inner(Keys::PROGRAM, &[Keys::USER, Keys::POOL], instruction_data(BUY, amount), 2);
+        let self_cpi = |amount| inner(Keys::PROGRAM, &[], event_cpi_data(amount), 3);
+        let logs = [
+            format!("Program {router} invoke [1]"),
+            format!("Program {PROGRAM_ID} invoke [2]"),
+            format!("Program {PROGRAM_ID} invoke [3]"),
+            format!("Program {PROGRAM_ID} success"),
+            format!("Program {PROGRAM_ID} success"),
+            format!("Program {PROGRAM_ID} invoke [2]"),
+            format!("Program {PROGRAM_ID} invoke [3]"),
+            format!("Program {PROGRAM_ID} success"),
+            format!("Program {PROGRAM_ID} success"),
+            format!("Program {router} success"),
+        ];
+        let update = transaction_update(
+            10,
+            message(&keys, vec![compiled(Keys::POOL, &[Keys::USER], vec![])]),
+            meta(vec![(0, vec![routed_buy(1), self_cpi(1), routed_buy(2), self_cpi(2)])], &logs),
+        );
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        assert_eq!(
+            events_by_position(&parsed.meta.inner_instructions),
+            [(0, Some(0), vec![1]), (0, Some(2), vec![2])],
+        );
+    }
+
+    #[test]
+    fn undecodable_events_are_skipped_on_both_paths() {
+        let keys = Keys::new();
+        let garbage_log = format!("Program data: {}", "AAAA");
+        let mut garbage_cpi = EVENT_IX_TAG.to_vec();
+        garbage_cpi.extend([0, 1, 2]);
+        let logs = [
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            garbage_log,
+            program_data_log(1),
+            format!("Program {PROGRAM_ID} invoke [2]"),
+            format!("Program {PROGRAM_ID} success"),
+            format!("Program {PROGRAM_ID} success"),
+        ];
+        let update = transaction_update(
+            10,
+            message(&keys, vec![buy(1)]),
+            meta(vec![(0, vec![inner(Keys::PROGRAM, &[], garbage_cpi, 2)])], &logs),
+        );
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        assert_eq!(events_by_position(&parsed.transaction.message.instructions), [(0, None, vec![1])]);
+    }
+
+    #[test]
+    fn events_both_logged_and_self_invoked_are_kept_once() {
+        let keys = Keys::new();
+        let logs = [
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            program_data_log(1),
+            format!("Program {PROGRAM_ID} invoke [2]"),
+            format!("Program {PROGRAM_ID} success"),
+            format!("Program {PROGRAM_ID} success"),
+        ];
+        let update = transaction_update(
+            10,
+            message(&keys, vec![buy(1)]),
+            meta(vec![(0, vec![inner(Keys::PROGRAM, &[], event_cpi_data(1), 2)])], &logs),
+        );
+
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+        assert_eq!(events_by_position(&parsed.transaction.message.instructions), [(0, None, vec![1])]);
+    }
+}
@@ -0,0 +1,13 @@
+pub mod block_time;
+mod core;
+mod decoder;
+pub mod error;
+pub mod events;
+mod builder;
+mod parser;
+pub mod types;
+#[cfg(test)]
+pub(crate) mod test_utils;
+
+pub use core::TransactionProcessor;
+pub use error::ProcessorError;
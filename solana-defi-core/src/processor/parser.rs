@@ -0,0 +1,47 @@
+use {
+    super::core::TransactionProcessor,
+    crate::decoder::ProgramDecoder,
+    solana_sdk::{
+        instruction::AccountMeta,
+        message::{v0::LoadedAddresses, VersionedMessage},
+    },
+    std::vec::Vec,
+};
+
+impl<D: ProgramDecoder> TransactionProcessor<D> {
+    pub fn parse_transaction_accounts(
+        &self,
+        message: &VersionedMessage,
+        loaded_addresses: LoadedAddresses,
+    ) -> Vec<AccountMeta> {
+        // `is_maybe_writable` applies the header ranges plus the runtime rule
+        // that demotes invoked programs to read-only, for legacy and v0 alike.
+        let mut parsed_accounts: Vec<AccountMeta> = message
+            .static_account_keys()
+            .iter()
+            .enumerate()
+            .map(|(index, pubkey)| AccountMeta {
+                pubkey: *pubkey,
+                is_signer: message.is_signer(index),
+                is_writable: message.is_maybe_writable(index, None),
+            })
+            .collect();
+
+        // Legacy messages cannot reference lookup tables.
+        if let VersionedMessage::V0(_) = message {
+            parsed_accounts.extend(loaded_addresses.writable.into_iter().map(|pubkey| AccountMeta {
+                pubkey,
+                is_signer: false,
+                is_writable: true,
+            }));
+
+            parsed_accounts.extend(loaded_addresses.readonly.into_iter().map(|pubkey| AccountMeta {
+                pubkey,
+                is_signer: false,
+                is_writable: false,
+            }));
+        }
+
+        parsed_accounts
+    }
+}
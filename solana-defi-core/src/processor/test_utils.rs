@@ -0,0 +1,245 @@
+//! Fixtures shared by the processor tests: a toy program with a `buy` and a
+//! `sell` instruction and one event, and helpers that assemble the gRPC
+//! updates the processor consumes.
+
+use {
+    super::{core::TransactionProcessor, events::EVENT_IX_TAG},
+    crate::{
+        decoder::ProgramDecoder,
+        models::mapper::instruction::{AccountMetadata, Idl, InstructionAccountMapper},
+    },
+    base64::{engine::general_purpose, Engine},
+    serde::Serialize,
+    solana_sdk::{instruction::AccountMeta, pubkey::Pubkey},
+    yellowstone_grpc_proto::prelude::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Message, MessageHeader,
+        SubscribeUpdateBlockMeta, SubscribeUpdateTransaction, SubscribeUpdateTransactionInfo,
+        Transaction, TransactionStatusMeta, UnixTimestamp,
+    },
+};
+
+pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+pub const BUY: u8 = 0;
+pub const SELL: u8 = 1;
+pub const TRADE_EVENT: u8 = 0xee;
+
+const TEST_IDL: &str = r#"{
+    "instructions": [
+        {
+            "name": "buy",
+            "discriminator": [0],
+            "accounts": [
+                { "name": "user", "writable": true, "signer": true },
+                { "name": "pool", "writable": true }
+            ]
+        },
+        {
+            "name": "sell",
+            "discriminator": [1],
+            "accounts": [
+                { "name": "user", "writable": true, "signer": true },
+                { "name": "pool", "writable": true }
+            ]
+        }
+    ]
+}"#;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TradeEvent {
+    pub amount: u64,
+}
+
+pub struct TestDecoder {
+    idl: Idl,
+}
+
+impl ProgramDecoder for TestDecoder {
+    type Event = TradeEvent;
+
+    fn program_id(&self) -> Pubkey {
+        PROGRAM_ID
+    }
+
+    fn decode_instruction(&self, data: &[u8]) -> anyhow::Result<(String, serde_json::Value)> {
+        let name = match data.first() {
+            Some(&BUY) => "buy",
+            Some(&SELL) => "sell",
+            _ => anyhow::bail!("unknown instruction"),
+        };
+        let amount = read_amount(&data[1..])?;
+        Ok((name.to_string(), serde_json::json!({ "amount": amount })))
+    }
+
+    fn decode_event(&self, data: &[u8]) -> anyhow::Result<TradeEvent> {
+        match data.split_first() {
+            Some((&TRADE_EVENT, rest)) => Ok(TradeEvent { amount: read_amount(rest)? }),
+            _ => anyhow::bail!("unknown event"),
+        }
+    }
+
+    fn map_accounts(
+        &self,
+        accounts: &[AccountMeta],
+        instruction_name: &str,
+    ) -> anyhow::Result<Vec<AccountMetadata>> {
+        Ok(self.idl.map_accounts(accounts, instruction_name)?)
+    }
+}
+
+fn read_amount(data: &[u8]) -> anyhow::Result<u64> {
+    let bytes = data.get(..8).ok_or_else(|| anyhow::anyhow!("amount out of bounds"))?;
+    Ok(u64::from_le_bytes(bytes.try_into()?))
+}
+
+pub fn processor() -> TransactionProcessor<TestDecoder> {
+    let idl = Idl::load(TEST_IDL, None).unwrap();
+    TransactionProcessor::new(TestDecoder { idl }).unwrap()
+}
+
+pub fn instruction_data(kind: u8, amount: u64) -> Vec<u8> {
+    let mut data = vec![kind];
+    data.extend(amount.to_le_bytes());
+    data
+}
+
+pub fn event_data(amount: u64) -> Vec<u8> {
+    instruction_data(TRADE_EVENT, amount)
+}
+
+/// `emit_cpi!` payload: the event tag followed by the event bytes.
+pub fn event_cpi_data(amount: u64) -> Vec<u8> {
+    let mut data = EVENT_IX_TAG.to_vec();
+    data.extend(event_data(amount));
+    data
+}
+
+pub fn program_data_log(amount: u64) -> String {
+    format!("Program data: {}", general_purpose::STANDARD.encode(event_data(amount)))
+}
+
+/// Account keys of every test message: the fee payer, the pool, two token
+/// accounts, then the read-only program ids.
+pub struct Keys {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+}
+
+impl Keys {
+    pub const USER: u8 = 0;
+    pub const POOL: u8 = 1;
+    pub const SOURCE: u8 = 2;
+    pub const DESTINATION: u8 = 3;
+    pub const PROGRAM: u8 = 4;
+    pub const TOKEN_PROGRAM: u8 = 5;
+    pub const TOKEN_2022_PROGRAM: u8 = 6;
+
+    pub fn new() -> Self {
+        Self {
+            user: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            source: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<Vec<u8>> {
+        [
+            self.user,
+            self.pool,
+            self.source,
+            self.destination,
+            PROGRAM_ID,
+            spl_token::ID,
+            spl_token_2022::ID,
+        ]
+        .iter()
+        .map(|key| key.to_bytes().to_vec())
+        .collect()
+    }
+}
+
+pub fn compiled(program_id_index: u8, accounts: &[u8], data: Vec<u8>) -> CompiledInstruction {
+    CompiledInstruction {
+        program_id_index: program_id_index as u32,
+        accounts: accounts.to_vec(),
+        data,
+    }
+}
+
+pub fn inner(
+    program_id_index: u8,
+    accounts: &[u8],
+    data: Vec<u8>,
+    stack_height: u32,
+) -> InnerInstruction {
+    InnerInstruction {
+        program_id_index: program_id_index as u32,
+        accounts: accounts.to_vec(),
+        data,
+        stack_height: Some(stack_height),
+    }
+}
+
+pub fn message(keys: &Keys, instructions: Vec<CompiledInstruction>) -> Message {
+    Message {
+        header: Some(MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 3,
+        }),
+        account_keys: keys.to_bytes(),
+        recent_blockhash: vec![9; 32],
+        instructions,
+        versioned: true,
+        address_table_lookups: vec![],
+    }
+}
+
+pub fn meta(inner_instructions: Vec<(u32, Vec<InnerInstruction>)>, logs: &[String]) -> TransactionStatusMeta {
+    TransactionStatusMeta {
+        fee: 5000,
+        pre_balances: vec![0; 7],
+        post_balances: vec![0; 7],
+        inner_instructions: inner_instructions
+            .into_iter()
+            .map(|(index, instructions)| InnerInstructions { index, instructions })
+            .collect(),
+        log_messages: logs.to_vec(),
+        ..Default::default()
+    }
+}
+
+pub fn transaction_update(slot: u64, message: Message, meta: TransactionStatusMeta) -> SubscribeUpdateTransaction {
+    let signature = vec![1; 64];
+    SubscribeUpdateTransaction {
+        transaction: Some(SubscribeUpdateTransactionInfo {
+            signature: signature.clone(),
+            transaction: Some(Transaction {
+                signatures: vec![signature],
+                message: Some(message),
+            }),
+            meta: Some(meta),
+            ..Default::default()
+        }),
+        slot,
+    }
+}
+
+pub fn block_meta_update(slot: u64, block_time: i64) -> SubscribeUpdateBlockMeta {
+    SubscribeUpdateBlockMeta {
+        slot,
+        block_time: Some(UnixTimestamp { timestamp: block_time }),
+        ..Default::default()
+    }
+}
+
+/// A processor that already knows the block time of `slot`, so updates of
+/// that slot are returned straight away.
+pub fn processor_at(slot: u64) -> TransactionProcessor<TestDecoder> {
+    let mut processor = processor();
+    assert!(processor.process_block_meta_update(block_meta_update(slot, 1_700_000_000)).is_empty());
+    processor
+}
@@ -0,0 +1,111 @@
+use {
+    serde::Serialize,
+    solana_sdk::{
+        hash::Hash,
+        instruction::Instruction,
+        message::{
+            v0::{LoadedAddresses, MessageAddressTableLookup},
+            MessageHeader,
+        },
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::Result as TransactionResult,
+        transaction_context::TransactionReturnData,
+    },
+    solana_transaction_status::{Rewards, TransactionTokenBalance},
+};
+
+use crate::models::mapper::instruction::AccountMetadata;
+use crate::models::serialize::serialization::{
+    serialize_option_pubkey,
+    serialize_pubkey,
+    serialize_token_balances,
+};
+
+/// Where an instruction sits in a transaction: the index of the top-level
+/// instruction and, for CPIs, the index inside its inner instruction list.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+pub struct InstructionPosition {
+    pub outer_index: usize,
+    pub inner_index: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DecodedInstruction<E> {
+    pub name: String,
+    pub accounts: Vec<AccountMetadata>,
+    pub data: serde_json::Value,
+    pub events: Vec<E>,
+    pub position: InstructionPosition,
+
+    #[serde(serialize_with = "serialize_pubkey")]
+    pub program_id: Pubkey,
+
+    #[serde(serialize_with = "serialize_option_pubkey")]
+    pub parent_program_id: Option<Pubkey>,
+
+    /// Why the instruction was only partly decoded. Its `name` is then
+    /// `unknown` when the data did not decode, and its accounts are numbered
+    /// when the IDL could not name them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct TransactionInstructionWithParent {
+    pub instruction: Instruction,
+    pub parent_program_id: Option<Pubkey>,
+    pub position: InstructionPosition,
+    pub stack_height: Option<u32>,
+}
+
+pub struct ExtractedInstructions {
+    pub compiled: Vec<TransactionInstructionWithParent>,
+    pub inner: Vec<TransactionInstructionWithParent>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ParsedConfirmedTransactionWithStatusMeta<E> {
+    pub slot: u64,
+    pub transaction: ParsedTransaction<E>,
+    pub meta: ParsedTransactionStatusMeta<E>,
+    /// Block time from the slot's `blocks_meta` update, `None` if it never arrived.
+    pub block_time: Option<i64>,
+    /// Local unix time in milliseconds at which the update was received.
+    pub received_at: i64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ParsedTransaction<E> {
+    pub signatures: Vec<Signature>,
+    pub message: ParsedMessage<E>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ParsedMessage<E> {
+    /// `false` for legacy messages, `true` for v0.
+    pub versioned: bool,
+    pub header: MessageHeader,
+    pub account_keys: Vec<Pubkey>,
+    pub recent_blockhash: Hash,
+    pub instructions: Vec<DecodedInstruction<E>>,
+    pub address_table_lookups: Vec<MessageAddressTableLookup>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ParsedTransactionStatusMeta<E> {
+    pub status: TransactionResult<()>,
+    pub fee: u64,
+    pub pre_balances: Vec<u64>,
+    pub post_balances: Vec<u64>,
+    pub inner_instructions: Vec<DecodedInstruction<E>>,
+    pub log_messages: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_token_balances")]
+    pub pre_token_balances: Option<Vec<TransactionTokenBalance>>,
+    #[serde(serialize_with = "serialize_token_balances")]
+    pub post_token_balances: Option<Vec<TransactionTokenBalance>>,
+    pub rewards: Option<Rewards>,
+    pub loaded_addresses: LoadedAddresses,
+    pub return_data: Option<TransactionReturnData>,
+    pub compute_units_consumed: Option<u64>,
+}
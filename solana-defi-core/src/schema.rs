@@ -0,0 +1,352 @@
+//! Output schema shared by every streamer. Records are wrapped in a
+//! [`Record`] carrying `schema_version`; bump [`SCHEMA_VERSION`] on any change
+//! that removes or renames a field.
+//!
+//! Conventions:
+//! - pubkeys, signatures and blockhashes are base58 strings
+//! - amounts are [`TokenAmount`]: the raw integer amount, its decimals and an
+//!   optional UI amount that is for display only
+
+use {
+    crate::processor::types::{DecodedInstruction, ParsedConfirmedTransactionWithStatusMeta},
+    serde::Serialize,
+    solana_transaction_status::TransactionTokenBalance,
+    std::{
+        collections::BTreeMap,
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub const SOL_DECIMALS: u8 = 9;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct TokenAmount {
+    pub amount: u64,
+    pub decimals: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_amount: Option<f64>,
+}
+
+impl TokenAmount {
+    /// Raw amount without a UI amount.
+    pub fn raw(amount: u64, decimals: u8) -> Self {
+        Self { amount, decimals, ui_amount: None }
+    }
+
+    /// Raw amount with the UI amount filled in.
+    pub fn new(amount: u64, decimals: u8) -> Self {
+        Self {
+            amount,
+            decimals,
+            ui_amount: Some(amount as f64 / 10f64.powi(decimals as i32)),
+        }
+    }
+
+    pub fn lamports(amount: u64) -> Self {
+        Self::new(amount, SOL_DECIMALS)
+    }
+}
+
+/// Envelope around every record a streamer emits.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record<T> {
+    pub schema_version: u32,
+    /// What `data` holds, e.g. `transaction` or `pump_trade`.
+    pub kind: &'static str,
+    pub slot: u64,
+    pub signature: String,
+    pub block_time: Option<i64>,
+    /// Local unix time in milliseconds at which the update was received.
+    pub received_at: i64,
+    pub data: T,
+}
+
+impl<T> Record<T> {
+    pub fn new<E>(
+        kind: &'static str,
+        txn: &ParsedConfirmedTransactionWithStatusMeta<E>,
+        data: T,
+    ) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            kind,
+            slot: txn.slot,
+            signature: txn
+                .transaction
+                .signatures
+                .first()
+                .map(|signature| signature.to_string())
+                .unwrap_or_default(),
+            block_time: txn.block_time,
+            received_at: txn.received_at,
+            data,
+        }
+    }
+
+    /// Record for output derived from an account update, which has no
+    /// signature and no block time.
+    pub fn account_update(kind: &'static str, slot: u64, data: T) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            kind,
+            slot,
+            signature: String::new(),
+            block_time: None,
+            received_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis() as i64)
+                .unwrap_or_default(),
+            data,
+        }
+    }
+
+    pub fn with_data<U>(self, data: U) -> Record<U> {
+        Record {
+            schema_version: self.schema_version,
+            kind: self.kind,
+            slot: self.slot,
+            signature: self.signature,
+            block_time: self.block_time,
+            received_at: self.received_at,
+            data,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionData<E> {
+    pub signatures: Vec<String>,
+    pub versioned: bool,
+    pub recent_blockhash: String,
+    pub success: bool,
+    pub error: Option<String>,
+    pub fee: TokenAmount,
+    pub compute_units_consumed: Option<u64>,
+    /// Static keys followed by the writable and read-only lookup table addresses.
+    pub account_keys: Vec<String>,
+    pub instructions: Vec<DecodedInstruction<E>>,
+    pub inner_instructions: Vec<DecodedInstruction<E>>,
+    pub sol_balances: Vec<BalanceChange>,
+    pub token_balances: Vec<TokenBalanceChange>,
+    pub log_messages: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceChange {
+    pub account: String,
+    pub pre: TokenAmount,
+    pub post: TokenAmount,
+}
+
+/// `pre`/`post` are `None` when the token account was created or closed.
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenBalanceChange {
+    pub account: String,
+    pub mint: String,
+    pub owner: Option<String>,
+    pub pre: Option<TokenAmount>,
+    pub post: Option<TokenAmount>,
+}
+
+impl<E> Record<TransactionData<E>> {
+    pub fn transaction(txn: ParsedConfirmedTransactionWithStatusMeta<E>) -> Self {
+        let message = &txn.transaction.message;
+        let meta = &txn.meta;
+
+        let account_keys: Vec<String> = message
+            .account_keys
+            .iter()
+            .chain(&meta.loaded_addresses.writable)
+            .chain(&meta.loaded_addresses.readonly)
+            .map(|pubkey| pubkey.to_string())
+            .collect();
+
+        let sol_balances = meta
+            .pre_balances
+            .iter()
+            .zip(&meta.post_balances)
+            .enumerate()
+            .filter_map(|(index, (pre, post))| {
+                Some(BalanceChange {
+                    account: account_keys.get(index)?.clone(),
+                    pre: TokenAmount::lamports(*pre),
+                    post: TokenAmount::lamports(*post),
+                })
+            })
+            .collect();
+
+        let token_balances = token_balance_changes(
+            &account_keys,
+            meta.pre_token_balances.as_deref().unwrap_or_default(),
+            meta.post_token_balances.as_deref().unwrap_or_default(),
+        );
+
+        let signatures = txn.transaction.signatures.iter().map(|s| s.to_string()).collect();
+        let versioned = message.versioned;
+        let recent_blockhash = message.recent_blockhash.to_string();
+        let success = meta.status.is_ok();
+        let error = meta.status.as_ref().err().map(|e| e.to_string());
+        let fee = TokenAmount::lamports(meta.fee);
+        let compute_units_consumed = meta.compute_units_consumed;
+        let log_messages = meta.log_messages.clone().unwrap_or_default();
+
+        let envelope = Record::new("transaction", &txn, ());
+        envelope.with_data(TransactionData {
+            signatures,
+            versioned,
+            recent_blockhash,
+            success,
+            error,
+            fee,
+            compute_units_consumed,
+            account_keys,
+            instructions: txn.transaction.message.instructions,
+            inner_instructions: txn.meta.inner_instructions,
+            sol_balances,
+            token_balances,
+            log_messages,
+        })
+    }
+}
+
+fn token_balance_changes(
+    account_keys: &[String],
+    pre: &[TransactionTokenBalance],
+    post: &[TransactionTokenBalance],
+) -> Vec<TokenBalanceChange> {
+    let mut changes: BTreeMap<u8, TokenBalanceChange> = BTreeMap::new();
+    for (balance, is_pre) in pre.iter().map(|b| (b, true)).chain(post.iter().map(|b| (b, false))) {
+        let Some(account) = account_keys.get(balance.account_index as usize) else {
+            continue;
+        };
+        let amount = balance
+            .ui_token_amount
+            .amount
+            .parse()
+            .ok()
+            .map(|amount| TokenAmount::new(amount, balance.ui_token_amount.decimals));
+
+        let change = changes.entry(balance.account_index).or_insert_with(|| TokenBalanceChange {
+            account: account.clone(),
+            mint: balance.mint.clone(),
+            owner: (!balance.owner.is_empty()).then(|| balance.owner.clone()),
+            pre: None,
+            post: None,
+        });
+        if is_pre {
+            change.pre = amount;
+        } else {
+            change.post = amount;
+        }
+    }
+    changes.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::processor::test_utils::*,
+        serde_json::json,
+        solana_sdk::{hash::Hash, signature::Signature},
+        yellowstone_grpc_proto::prelude::{TokenBalance, UiTokenAmount},
+    };
+
+    fn token_balance(keys: &Keys, account_index: u8, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index: account_index as u32,
+            mint: keys.pool.to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                ui_amount: amount as f64 / 1e6,
+                decimals: 6,
+                amount: amount.to_string(),
+                ui_amount_string: (amount as f64 / 1e6).to_string(),
+            }),
+            owner: keys.user.to_string(),
+            program_id: spl_token::ID.to_string(),
+        }
+    }
+
+    #[test]
+    fn transaction_record_has_base58_keys_and_raw_amounts() {
+        let keys = Keys::new();
+        let mut meta = meta(vec![], &[]);
+        meta.pre_balances[0] = 2_000_000_000;
+        meta.post_balances[0] = 1_999_995_000;
+        // The destination token account is created by the transaction.
+        meta.pre_token_balances = vec![token_balance(&keys, Keys::SOURCE, 1_000_000)];
+        meta.post_token_balances = vec![
+            token_balance(&keys, Keys::SOURCE, 400_000),
+            token_balance(&keys, Keys::DESTINATION, 600_000),
+        ];
+        let update = transaction_update(
+            10,
+            message(&keys, vec![compiled(Keys::PROGRAM, &[Keys::USER, Keys::POOL], instruction_data(BUY, 42))]),
+            meta,
+        );
+        let parsed = processor_at(10).process_transaction_update(update).unwrap().pop().unwrap();
+
+        let record = serde_json::to_value(Record::transaction(parsed)).unwrap();
+        assert_eq!(record["schema_version"], json!(SCHEMA_VERSION));
+        assert_eq!(record["kind"], "transaction");
+        assert_eq!(record["slot"], 10);
+        assert_eq!(record["block_time"], 1_700_000_000);
+        let signature = Signature::from([1; 64]).to_string();
+        assert_eq!(record["signature"], json!(signature));
+
+        let data = &record["data"];
+        assert_eq!(data["signatures"], json!([signature]));
+        assert_eq!(data["recent_blockhash"], json!(Hash::new_from_array([9; 32]).to_string()));
+        assert_eq!(data["account_keys"][0], json!(keys.user.to_string()));
+        assert_eq!(data["account_keys"][4], json!(PROGRAM_ID.to_string()));
+        assert_eq!(data["instructions"][0]["program_id"], json!(PROGRAM_ID.to_string()));
+        assert_eq!(data["instructions"][0]["accounts"][1]["pubkey"], json!(keys.pool.to_string()));
+        assert_eq!(data["fee"], json!({ "amount": 5000, "decimals": 9, "ui_amount": 0.000005 }));
+        assert_eq!(
+            data["sol_balances"][0],
+            json!({
+                "account": keys.user.to_string(),
+                "pre": { "amount": 2_000_000_000u64, "decimals": 9, "ui_amount": 2.0 },
+                "post": { "amount": 1_999_995_000u64, "decimals": 9, "ui_amount": 1.999995 },
+            })
+        );
+        assert_eq!(
+            data["token_balances"],
+            json!([
+                {
+                    "account": keys.source.to_string(),
+                    "mint": keys.pool.to_string(),
+                    "owner": keys.user.to_string(),
+                    "pre": { "amount": 1_000_000, "decimals": 6, "ui_amount": 1.0 },
+                    "post": { "amount": 400_000, "decimals": 6, "ui_amount": 0.4 },
+                },
+                {
+                    "account": keys.destination.to_string(),
+                    "mint": keys.pool.to_string(),
+                    "owner": keys.user.to_string(),
+                    "pre": null,
+                    "post": { "amount": 600_000, "decimals": 6, "ui_amount": 0.6 },
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn raw_amount_leaves_out_the_ui_amount() {
+        let amount = serde_json::to_value(TokenAmount::raw(u64::MAX, 6)).unwrap();
+        assert_eq!(amount, json!({ "amount": u64::MAX, "decimals": 6 }));
+    }
+
+    #[test]
+    fn account_update_record_is_versioned_without_a_signature() {
+        let record = serde_json::to_value(Record::account_update("pool", 7, json!({}))).unwrap();
+        assert_eq!(record["schema_version"], json!(SCHEMA_VERSION));
+        assert_eq!(record["kind"], "pool");
+        assert_eq!(record["slot"], 7);
+        assert_eq!(record["signature"], "");
+        assert_eq!(record["block_time"], json!(null));
+        assert!(record["received_at"].as_i64().unwrap() > 0);
+    }
+}
@@ -0,0 +1,91 @@
+use {
+    super::{EventSink, SinkBuffer},
+    log::error,
+    std::{
+        path::{Path, PathBuf},
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    tokio::{
+        fs::{self, File},
+        io::AsyncWriteExt,
+        sync::mpsc,
+    },
+};
+
+pub const DEFAULT_MAX_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Appends JSON lines to `<dir>/events-<unix_ms>-<n>.jsonl`, starting a new
+/// file once the current one reaches `max_bytes`.
+pub struct RotatingFileSink {
+    buffer: SinkBuffer,
+}
+
+impl RotatingFileSink {
+    pub fn new(dir: impl AsRef<Path>, max_bytes: u64, capacity: usize) -> anyhow::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+
+        let (buffer, rx) = SinkBuffer::new(capacity);
+        tokio::spawn(async move {
+            if let Err(e) = write_lines(dir, max_bytes, rx).await {
+                error!("Rotating file sink stopped: {e}");
+            }
+        });
+        Ok(Self { buffer })
+    }
+}
+
+/// Writes queued lines until the sink is dropped. Files are flushed whenever
+/// the queue runs empty and before they are rotated, so nothing written is
+/// left in the file buffer when the sink goes away.
+async fn write_lines(dir: PathBuf, max_bytes: u64, mut rx: mpsc::Receiver<String>) -> std::io::Result<()> {
+    let mut current: Option<(File, u64)> = None;
+    let mut files = 0u64;
+    while let Some(line) = rx.recv().await {
+        let mut next = Some(line);
+        while let Some(mut line) = next {
+            line.push('\n');
+            if let Some((mut full, _)) = current.take_if(|(_, written)| *written >= max_bytes) {
+                full.flush().await?;
+            }
+            let (file, written) = match current.as_mut() {
+                Some(open) => open,
+                None => {
+                    files += 1;
+                    current.insert((open_next(&dir, files).await?, 0))
+                }
+            };
+            file.write_all(line.as_bytes()).await?;
+            *written += line.len() as u64;
+            next = rx.try_recv().ok();
+        }
+
+        if let Some((file, _)) = current.as_mut() {
+            file.flush().await?;
+        }
+    }
+    Ok(())
+}
+
+/// `n` keeps files rotated within the same millisecond apart.
+async fn open_next(dir: &Path, n: u64) -> std::io::Result<File> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(format!("events-{now}-{n}.jsonl")))
+        .await
+}
+
+impl EventSink for RotatingFileSink {
+    fn send_line(&self, line: String) -> bool {
+        self.buffer.push(line)
+    }
+
+    fn dropped(&self) -> u64 {
+        self.buffer.dropped()
+    }
+}
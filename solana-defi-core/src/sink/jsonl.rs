@@ -0,0 +1,38 @@
+use {
+    super::{EventSink, SinkBuffer},
+    log::error,
+    tokio::io::{self, AsyncWriteExt},
+};
+
+/// Writes one JSON document per line to stdout.
+pub struct JsonLinesSink {
+    buffer: SinkBuffer,
+}
+
+impl JsonLinesSink {
+    pub fn new(capacity: usize) -> Self {
+        let (buffer, mut rx) = SinkBuffer::new(capacity);
+        tokio::spawn(async move {
+            let mut stdout = io::stdout();
+            while let Some(mut line) = rx.recv().await {
+                line.push('\n');
+                if let Err(e) = stdout.write_all(line.as_bytes()).await {
+                    error!("JSON Lines sink stopped: {e}");
+                    break;
+                }
+                let _ = stdout.flush().await;
+            }
+        });
+        Self { buffer }
+    }
+}
+
+impl EventSink for JsonLinesSink {
+    fn send_line(&self, line: String) -> bool {
+        self.buffer.push(line)
+    }
+
+    fn dropped(&self) -> u64 {
+        self.buffer.dropped()
+    }
+}
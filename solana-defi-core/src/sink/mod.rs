@@ -0,0 +1,243 @@
+mod file;
+mod jsonl;
+mod unix;
+mod websocket;
+
+pub use {
+    file::RotatingFileSink, jsonl::JsonLinesSink, unix::UnixSocketSink,
+    websocket::WebSocketSink,
+};
+
+use {
+    serde::Serialize,
+    std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    tokio::sync::{broadcast, mpsc},
+};
+
+/// Records queued per sink before new ones are dropped.
+pub const DEFAULT_BUFFER: usize = 4096;
+
+/// Destination for the records a streamer produces. `send` never waits: when
+/// the buffer is full the record is dropped and counted, so a slow consumer
+/// cannot stall the gRPC stream.
+pub trait EventSink: Send + Sync {
+    /// Queues one JSON line. Returns `false` if it was dropped.
+    fn send_line(&self, line: String) -> bool;
+
+    /// Number of records dropped because the buffer was full.
+    fn dropped(&self) -> u64;
+
+    fn send<T: Serialize>(&self, record: &T) -> anyhow::Result<bool>
+    where
+        Self: Sized,
+    {
+        Ok(self.send_line(serde_json::to_string(record)?))
+    }
+}
+
+impl EventSink for Arc<dyn EventSink> {
+    fn send_line(&self, line: String) -> bool {
+        (**self).send_line(line)
+    }
+
+    fn dropped(&self) -> u64 {
+        (**self).dropped()
+    }
+}
+
+/// Builds a sink from its CLI spec:
+/// `jsonl`, `file:<dir>[:<max_bytes>]`, `unix:<path>` or `ws:<addr>`.
+/// Must be called from within a Tokio runtime.
+pub fn sink_from_spec(spec: &str, buffer: usize) -> anyhow::Result<Arc<dyn EventSink>> {
+    let (kind, target) = spec.split_once(':').unwrap_or((spec, ""));
+    Ok(match kind {
+        "jsonl" | "stdout" => Arc::new(JsonLinesSink::new(buffer)),
+        "file" => {
+            let (dir, max_bytes) = match target.rsplit_once(':') {
+                Some((dir, max_bytes)) => (dir, max_bytes.parse()?),
+                None => (target, file::DEFAULT_MAX_BYTES),
+            };
+            Arc::new(RotatingFileSink::new(dir, max_bytes, buffer)?)
+        }
+        "unix" => Arc::new(UnixSocketSink::bind(target, buffer)?),
+        "ws" => Arc::new(WebSocketSink::bind(target, buffer)?),
+        _ => anyhow::bail!("Unknown sink `{spec}`, expected jsonl, file:<dir>, unix:<path> or ws:<addr>"),
+    })
+}
+
+/// Bounded queue between the caller and the task that writes the records.
+struct SinkBuffer {
+    tx: mpsc::Sender<String>,
+    dropped: AtomicU64,
+}
+
+impl SinkBuffer {
+    fn new(capacity: usize) -> (Self, mpsc::Receiver<String>) {
+        let (tx, rx) = mpsc::channel(capacity);
+        (Self { tx, dropped: AtomicU64::new(0) }, rx)
+    }
+
+    fn push(&self, line: String) -> bool {
+        let queued = self.tx.try_send(line).is_ok();
+        if !queued {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        queued
+    }
+
+    fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Forwards queued lines to every connected client. Clients that fall
+/// more than `capacity` lines behind skip ahead instead of holding the others up.
+fn fan_out(mut rx: mpsc::Receiver<String>, capacity: usize) -> broadcast::Sender<Arc<str>> {
+    let (tx, _) = broadcast::channel(capacity);
+    let clients = tx.clone();
+    tokio::spawn(async move {
+        while let Some(line) = rx.recv().await {
+            // No subscribers is not an error, the line is just not delivered.
+            let _ = clients.send(Arc::from(line));
+        }
+    });
+    tx
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::time::Duration,
+        tokio::{
+            io::{AsyncBufReadExt, BufReader},
+            net::UnixStream,
+            time::{sleep, timeout},
+        },
+    };
+
+    /// A sink whose queue is only drained by the test.
+    struct TestSink(SinkBuffer);
+
+    impl EventSink for TestSink {
+        fn send_line(&self, line: String) -> bool {
+            self.0.push(line)
+        }
+
+        fn dropped(&self) -> u64 {
+            self.0.dropped()
+        }
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("sink_{name}_{}_{nanos}", std::process::id()))
+    }
+
+    #[test]
+    fn full_buffer_drops_and_counts_instead_of_blocking() {
+        let (buffer, mut rx) = SinkBuffer::new(2);
+        assert!(buffer.push("a".to_string()));
+        assert!(buffer.push("b".to_string()));
+        assert!(!buffer.push("c".to_string()));
+        assert!(!buffer.push("d".to_string()));
+        assert_eq!(buffer.dropped(), 2);
+
+        assert_eq!(rx.try_recv().unwrap(), "a");
+        assert_eq!(rx.try_recv().unwrap(), "b");
+        assert!(rx.try_recv().is_err());
+
+        // Draining makes room again.
+        assert!(buffer.push("e".to_string()));
+        assert_eq!(buffer.dropped(), 2);
+    }
+
+    #[test]
+    fn send_writes_one_json_line_per_record() {
+        let (buffer, mut rx) = SinkBuffer::new(4);
+        let sink = TestSink(buffer);
+        assert!(sink.send(&serde_json::json!({ "slot": 1, "kind": "pump_trade" })).unwrap());
+
+        let line = rx.try_recv().unwrap();
+        assert!(!line.contains('\n'));
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&line).unwrap()["slot"], 1);
+    }
+
+    #[test]
+    fn rejects_unknown_specs() {
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        let _guard = runtime.enter();
+        assert!(sink_from_spec("kafka:localhost", DEFAULT_BUFFER).is_err());
+        assert!(sink_from_spec("file:/tmp/x:not_a_number", DEFAULT_BUFFER).is_err());
+    }
+
+    #[tokio::test]
+    async fn lagging_clients_skip_ahead() {
+        let (buffer, rx) = SinkBuffer::new(16);
+        let lines = fan_out(rx, 2);
+        let mut client = lines.subscribe();
+        for line in ["1", "2", "3", "4"] {
+            assert!(buffer.push(line.to_string()));
+        }
+        sleep(Duration::from_millis(50)).await;
+
+        assert!(matches!(client.recv().await, Err(broadcast::error::RecvError::Lagged(2))));
+        assert_eq!(&*client.recv().await.unwrap(), "3");
+        assert_eq!(&*client.recv().await.unwrap(), "4");
+    }
+
+    #[tokio::test]
+    async fn rotating_file_sink_starts_new_files() {
+        let dir = temp_path("file");
+        let sink = RotatingFileSink::new(&dir, 10, 16).unwrap();
+        for line in ["0123456789", "abc", "def"] {
+            assert!(sink.send_line(line.to_string()));
+        }
+        sleep(Duration::from_millis(100)).await;
+
+        let mut contents = Vec::new();
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            contents.push(std::fs::read_to_string(entry.unwrap().path()).unwrap());
+        }
+        contents.sort();
+        assert_eq!(contents, ["0123456789\n", "abc\ndef\n"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn rotating_file_sink_flushes_everything_once_dropped() {
+        let dir = temp_path("file_drop");
+        let sink = RotatingFileSink::new(&dir, file::DEFAULT_MAX_BYTES, 1024).unwrap();
+        let lines: Vec<String> = (0..500).map(|n| format!("line {n}")).collect();
+        for line in &lines {
+            assert!(sink.send_line(line.clone()));
+        }
+        drop(sink);
+        sleep(Duration::from_millis(200)).await;
+
+        let [entry] = std::fs::read_dir(&dir).unwrap().collect::<Vec<_>>().try_into().unwrap();
+        let contents = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+        assert_eq!(contents, lines.join("\n") + "\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn unix_socket_sink_streams_lines_to_clients() {
+        let path = temp_path("unix.sock");
+        let sink = UnixSocketSink::bind(&path, 16).unwrap();
+        let client = UnixStream::connect(&path).await.unwrap();
+        sleep(Duration::from_millis(50)).await;
+
+        assert!(sink.send(&serde_json::json!({ "n": 1 })).unwrap());
+        let mut lines = BufReader::new(client).lines();
+        let line = timeout(Duration::from_secs(5), lines.next_line()).await.unwrap().unwrap();
+        assert_eq!(line.as_deref(), Some(r#"{"n":1}"#));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,71 @@
+use {
+    super::{fan_out, EventSink, SinkBuffer},
+    log::{error, info, warn},
+    std::path::Path,
+    tokio::{
+        io::AsyncWriteExt,
+        net::UnixListener,
+        sync::broadcast::error::RecvError,
+    },
+};
+
+/// Listens on a Unix domain socket and writes every JSON line to all
+/// connected clients.
+pub struct UnixSocketSink {
+    buffer: SinkBuffer,
+}
+
+impl UnixSocketSink {
+    pub fn bind(path: impl AsRef<Path>, capacity: usize) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        // A socket file left behind by a previous run would make bind fail.
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        let listener = UnixListener::bind(path)?;
+
+        let (buffer, rx) = SinkBuffer::new(capacity);
+        let lines = fan_out(rx, capacity);
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = match listener.accept().await {
+                    Ok(client) => client,
+                    Err(e) => {
+                        error!("Unix socket sink stopped accepting: {e}");
+                        break;
+                    }
+                };
+                info!("Unix socket sink client connected");
+                let mut client_lines = lines.subscribe();
+                tokio::spawn(async move {
+                    loop {
+                        let line = match client_lines.recv().await {
+                            Ok(line) => line,
+                            Err(RecvError::Lagged(skipped)) => {
+                                warn!("Unix socket sink client lagged, skipped {skipped} lines");
+                                continue;
+                            }
+                            Err(RecvError::Closed) => break,
+                        };
+                        if stream.write_all(line.as_bytes()).await.is_err()
+                            || stream.write_all(b"\n").await.is_err()
+                        {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+        Ok(Self { buffer })
+    }
+}
+
+impl EventSink for UnixSocketSink {
+    fn send_line(&self, line: String) -> bool {
+        self.buffer.push(line)
+    }
+
+    fn dropped(&self) -> u64 {
+        self.buffer.dropped()
+    }
+}
@@ -0,0 +1,70 @@
+use {
+    super::{fan_out, EventSink, SinkBuffer},
+    futures_util::SinkExt,
+    log::{error, info, warn},
+    tokio::{net::TcpListener, sync::broadcast::error::RecvError},
+    tokio_tungstenite::{accept_async, tungstenite::Message},
+};
+
+/// Serves a local WebSocket endpoint that broadcasts every JSON line as a
+/// text message to all connected clients.
+pub struct WebSocketSink {
+    buffer: SinkBuffer,
+}
+
+impl WebSocketSink {
+    pub fn bind(addr: &str, capacity: usize) -> anyhow::Result<Self> {
+        let listener = std::net::TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(listener)?;
+
+        let (buffer, rx) = SinkBuffer::new(capacity);
+        let lines = fan_out(rx, capacity);
+        tokio::spawn(async move {
+            loop {
+                let (stream, peer) = match listener.accept().await {
+                    Ok(client) => client,
+                    Err(e) => {
+                        error!("WebSocket sink stopped accepting: {e}");
+                        break;
+                    }
+                };
+                let mut client_lines = lines.subscribe();
+                tokio::spawn(async move {
+                    let mut ws = match accept_async(stream).await {
+                        Ok(ws) => ws,
+                        Err(e) => {
+                            warn!("WebSocket handshake with {peer} failed: {e}");
+                            return;
+                        }
+                    };
+                    info!("WebSocket sink client {peer} connected");
+                    loop {
+                        let line = match client_lines.recv().await {
+                            Ok(line) => line,
+                            Err(RecvError::Lagged(skipped)) => {
+                                warn!("WebSocket client {peer} lagged, skipped {skipped} lines");
+                                continue;
+                            }
+                            Err(RecvError::Closed) => break,
+                        };
+                        if ws.send(Message::Text(line.to_string())).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+        Ok(Self { buffer })
+    }
+}
+
+impl EventSink for WebSocketSink {
+    fn send_line(&self, line: String) -> bool {
+        self.buffer.push(line)
+    }
+
+    fn dropped(&self) -> u64 {
+        self.buffer.dropped()
+    }
+}
@@ -0,0 +1,367 @@
+//! Token metadata learned from the stream: Metaplex metadata accounts,
+//! Token-2022 metadata extensions, token balances and the create events of
+//! launchpads. Kept in a JSON cache file that several streamers can share.
+
+use {
+    serde::{Deserialize, Serialize},
+    solana_sdk::{pubkey, pubkey::Pubkey},
+    solana_transaction_status::TransactionTokenBalance,
+    spl_token_2022::{
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint,
+    },
+    spl_token_metadata_interface::state::TokenMetadata,
+    std::{
+        collections::{BTreeMap, HashMap},
+        fs,
+        path::{Path, PathBuf},
+        str::FromStr,
+    },
+};
+
+pub const METAPLEX_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// `Key::MetadataV1` of the Metaplex token metadata program.
+const METADATA_V1_KEY: u8 = 4;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TokenProgram {
+    SplToken,
+    Token2022,
+}
+
+impl TokenProgram {
+    pub fn from_program_id(program_id: &Pubkey) -> Option<Self> {
+        if *program_id == spl_token::ID {
+            Some(TokenProgram::SplToken)
+        } else if *program_id == spl_token_2022::ID {
+            Some(TokenProgram::Token2022)
+        } else {
+            None
+        }
+    }
+}
+
+/// What is known about a mint. Fields stay `None` until a source provides them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TokenInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program: Option<TokenProgram>,
+}
+
+impl TokenInfo {
+    /// Takes every field `other` has. Returns whether anything changed.
+    pub fn merge(&mut self, other: TokenInfo) -> bool {
+        let before = self.clone();
+        self.symbol = other.symbol.or(self.symbol.take());
+        self.name = other.name.or(self.name.take());
+        self.decimals = other.decimals.or(self.decimals);
+        self.program = other.program.or(self.program);
+        *self != before
+    }
+
+    /// Fills in only the fields still missing.
+    fn fill_from(&mut self, other: &TokenInfo) {
+        self.symbol = self.symbol.take().or_else(|| other.symbol.clone());
+        self.name = self.name.take().or_else(|| other.name.clone());
+        self.decimals = self.decimals.or(other.decimals);
+        self.program = self.program.or(other.program);
+    }
+}
+
+/// Metaplex metadata PDA of `mint`.
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", METAPLEX_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &METAPLEX_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Mint, name and symbol of a Metaplex `MetadataV1` account.
+pub fn parse_metaplex_metadata(data: &[u8]) -> Option<(Pubkey, TokenInfo)> {
+    if data.first() != Some(&METADATA_V1_KEY) {
+        return None;
+    }
+    // key, update_authority, mint, then borsh strings padded with NULs.
+    let mint = Pubkey::try_from(data.get(33..65)?).ok()?;
+    let mut rest = data.get(65..)?;
+    let name = read_string(&mut rest)?;
+    let symbol = read_string(&mut rest)?;
+
+    Some((
+        mint,
+        TokenInfo {
+            symbol: non_empty(symbol),
+            name: non_empty(name),
+            ..Default::default()
+        },
+    ))
+}
+
+/// Decimals and program of a mint account, plus the name and symbol of its
+/// Token-2022 metadata extension if it has one.
+pub fn parse_mint_account(owner: &Pubkey, data: &[u8]) -> Option<TokenInfo> {
+    let program = TokenProgram::from_program_id(owner)?;
+    let mint = StateWithExtensions::<Mint>::unpack(data).ok()?;
+    let mut info = TokenInfo {
+        decimals: Some(mint.base.decimals),
+        program: Some(program),
+        ..Default::default()
+    };
+    if let Ok(metadata) = mint.get_variable_len_extension::<TokenMetadata>() {
+        info.symbol = non_empty(metadata.symbol);
+        info.name = non_empty(metadata.name);
+    }
+    Some(info)
+}
+
+/// Mints and what is known about them, optionally backed by a cache file.
+pub struct TokenRegistry {
+    tokens: HashMap<Pubkey, TokenInfo>,
+    path: Option<PathBuf>,
+    dirty: bool,
+}
+
+impl Default for TokenRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            tokens: HashMap::new(),
+            path: None,
+            dirty: false,
+        };
+        for (mint, symbol, name, decimals) in WELL_KNOWN_TOKENS {
+            registry.tokens.insert(
+                Pubkey::from_str(mint).expect("valid well-known mint"),
+                TokenInfo {
+                    symbol: Some(symbol.to_string()),
+                    name: Some(name.to_string()),
+                    decimals: Some(*decimals),
+                    program: Some(TokenProgram::SplToken),
+                },
+            );
+        }
+        registry
+    }
+}
+
+impl TokenRegistry {
+    /// Registry backed by `path`, loaded from it when it exists.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut registry = Self::default();
+        for (mint, info) in read_cache(&path)? {
+            registry.tokens.entry(mint).or_default().merge(info);
+        }
+        registry.path = Some(path);
+        Ok(registry)
+    }
+
+    pub fn get(&self, mint: &Pubkey) -> Option<&TokenInfo> {
+        self.tokens.get(mint)
+    }
+
+    /// Symbol of `mint`, or its shortened address when the symbol is unknown.
+    pub fn symbol(&self, mint: &Pubkey) -> String {
+        match self.get(mint).and_then(|info| info.symbol.as_ref()) {
+            Some(symbol) => symbol.clone(),
+            None => {
+                let mint = mint.to_string();
+                format!("{}...{}", &mint[..4], &mint[mint.len() - 4..])
+            }
+        }
+    }
+
+    /// Whether a name or symbol source for `mint` is still worth watching.
+    pub fn needs_metadata(&self, mint: &Pubkey) -> bool {
+        !self.get(mint).is_some_and(|info| info.symbol.is_some())
+    }
+
+    /// Records what a source says about `mint`. Returns whether anything changed.
+    pub fn learn(&mut self, mint: Pubkey, info: TokenInfo) -> bool {
+        let changed = self.tokens.entry(mint).or_default().merge(info);
+        self.dirty |= changed;
+        changed
+    }
+
+    /// Learns from a Metaplex metadata account or a mint account; other
+    /// accounts are ignored.
+    pub fn observe_account(&mut self, pubkey: &Pubkey, owner: &Pubkey, data: &[u8]) -> bool {
+        if *owner == METAPLEX_METADATA_PROGRAM_ID {
+            return match parse_metaplex_metadata(data) {
+                Some((mint, info)) => self.learn(mint, info),
+                None => false,
+            };
+        }
+        match parse_mint_account(owner, data) {
+            Some(info) => self.learn(*pubkey, info),
+            None => false,
+        }
+    }
+
+    /// Learns decimals and token programs from a transaction's token balances.
+    pub fn observe_token_balances(&mut self, balances: &[TransactionTokenBalance]) -> bool {
+        let mut changed = false;
+        for balance in balances {
+            let Ok(mint) = Pubkey::from_str(&balance.mint) else {
+                continue;
+            };
+            let program = Pubkey::from_str(&balance.program_id)
+                .ok()
+                .and_then(|program_id| TokenProgram::from_program_id(&program_id));
+            changed |= self.learn(
+                mint,
+                TokenInfo {
+                    decimals: Some(balance.ui_token_amount.decimals),
+                    program,
+                    ..Default::default()
+                },
+            );
+        }
+        changed
+    }
+
+    /// Writes the registry to its cache file if it changed. Entries another
+    /// process added to the file in the meantime are kept.
+    pub fn save(&mut self) -> anyhow::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if !self.dirty {
+            return Ok(());
+        }
+
+        for (mint, info) in read_cache(path)? {
+            self.tokens.entry(mint).or_default().fill_from(&info);
+        }
+        let cache: BTreeMap<String, &TokenInfo> =
+            self.tokens.iter().map(|(mint, info)| (mint.to_string(), info)).collect();
+
+        // Written next to the cache and renamed over it, so readers never see
+        // a partial file.
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(&cache)?)?;
+        fs::rename(&tmp, path)?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+const WELL_KNOWN_TOKENS: &[(&str, &str, &str, u8)] = &[
+    ("So11111111111111111111111111111111111111112", "SOL", "Wrapped SOL", 9),
+    ("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "USDC", "USD Coin", 6),
+    ("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", "USDT", "USDT", 6),
+    ("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", "BONK", "Bonk", 5),
+    ("AZsHEMXd36Bj1EMNXhowJajpUXzrKcK57wW4ZGXVa7yR", "GUAC", "Guacamole", 5),
+];
+
+fn read_cache(path: &Path) -> anyhow::Result<Vec<(Pubkey, TokenInfo)>> {
+    let contents = match fs::read(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let cache: BTreeMap<String, TokenInfo> = serde_json::from_slice(&contents)?;
+    Ok(cache
+        .into_iter()
+        .filter_map(|(mint, info)| Some((Pubkey::from_str(&mint).ok()?, info)))
+        .collect())
+}
+
+fn read_string(data: &mut &[u8]) -> Option<String> {
+    let len = u32::from_le_bytes(data.get(..4)?.try_into().ok()?) as usize;
+    let bytes = data.get(4..4 + len)?;
+    *data = &data[4 + len..];
+    Some(String::from_utf8_lossy(bytes).trim_end_matches('\0').to_string())
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn borsh_string(value: &str, padded_len: usize) -> Vec<u8> {
+        let mut bytes = (padded_len as u32).to_le_bytes().to_vec();
+        bytes.extend(value.as_bytes());
+        bytes.resize(4 + padded_len, 0);
+        bytes
+    }
+
+    fn metadata_account(mint: &Pubkey, name: &str, symbol: &str) -> Vec<u8> {
+        let mut data = vec![METADATA_V1_KEY];
+        data.extend(Pubkey::new_unique().to_bytes());
+        data.extend(mint.to_bytes());
+        data.extend(borsh_string(name, 32));
+        data.extend(borsh_string(symbol, 10));
+        data.extend(borsh_string("https://example.com", 200));
+        data
+    }
+
+    #[test]
+    fn learns_from_metaplex_metadata() {
+        let mint = Pubkey::new_unique();
+        let mut registry = TokenRegistry::default();
+        assert!(registry.needs_metadata(&mint));
+
+        let data = metadata_account(&mint, "Dog Wif Hat", "WIF");
+        assert!(registry.observe_account(&metadata_address(&mint), &METAPLEX_METADATA_PROGRAM_ID, &data));
+        assert_eq!(registry.symbol(&mint), "WIF");
+        assert_eq!(registry.get(&mint).unwrap().name.as_deref(), Some("Dog Wif Hat"));
+        assert!(!registry.needs_metadata(&mint));
+    }
+
+    #[test]
+    fn unknown_symbol_is_shortened() {
+        let registry = TokenRegistry::default();
+        let mint = Pubkey::from_str("7GCihgDB8fe6KNjn2MYtkzZcRjQy3t9GHdC8uHYmW2hr").unwrap();
+        assert_eq!(registry.symbol(&mint), "7GCi...W2hr");
+        let sol = Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap();
+        assert_eq!(registry.symbol(&sol), "SOL");
+    }
+
+    #[test]
+    fn merge_keeps_known_fields() {
+        let mut info = TokenInfo {
+            symbol: Some("PUMP".to_string()),
+            ..Default::default()
+        };
+        assert!(info.merge(TokenInfo {
+            decimals: Some(6),
+            program: Some(TokenProgram::Token2022),
+            ..Default::default()
+        }));
+        assert_eq!(info.symbol.as_deref(), Some("PUMP"));
+        assert_eq!(info.decimals, Some(6));
+        assert!(!info.merge(TokenInfo::default()));
+    }
+
+    #[test]
+    fn cache_round_trip_keeps_other_writers_entries() {
+        let path = std::env::temp_dir().join(format!("token_registry_{}.json", Pubkey::new_unique()));
+        let (ours, theirs) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let mut other = TokenRegistry::open(&path).unwrap();
+        other.learn(theirs, TokenInfo { symbol: Some("THEM".to_string()), ..Default::default() });
+        let mut registry = TokenRegistry::open(&path).unwrap();
+        other.save().unwrap();
+
+        registry.learn(ours, TokenInfo { symbol: Some("US".to_string()), ..Default::default() });
+        registry.save().unwrap();
+
+        let reloaded = TokenRegistry::open(&path).unwrap();
+        assert_eq!(reloaded.symbol(&ours), "US");
+        assert_eq!(reloaded.symbol(&theirs), "THEM");
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,350 @@
+//! Fixed-point prices. A [`Price`] is a `u128` mantissa and a decimal scale,
+//! built from raw integer amounts without going through `f64`. Prices are
+//! only turned into strings when serialized or formatted.
+
+use {
+    serde::{Serialize, Serializer},
+    std::{cmp::Ordering, fmt, ops::Mul},
+};
+
+/// Significant digits kept when a division does not terminate.
+pub const PRICE_DIGITS: u32 = 30;
+
+/// `mantissa / 10^scale`.
+#[derive(Clone, Copy, Default)]
+pub struct Price {
+    mantissa: u128,
+    scale: u32,
+}
+
+impl Price {
+    pub const ZERO: Price = Price { mantissa: 0, scale: 0 };
+
+    pub fn new(mantissa: u128, scale: u32) -> Self {
+        Self { mantissa, scale }
+    }
+
+    pub fn mantissa(&self) -> u128 {
+        self.mantissa
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// `numerator / denominator`, exact when the division terminates within
+    /// [`PRICE_DIGITS`] significant digits and truncated otherwise. `None`
+    /// when `denominator` is zero.
+    pub fn from_ratio(numerator: u128, denominator: u128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let mut mantissa = numerator / denominator;
+        let mut remainder = numerator % denominator;
+        let mut scale = 0;
+        while remainder != 0 && digits(mantissa) < PRICE_DIGITS {
+            let Some(next) = mantissa.checked_mul(10) else {
+                break;
+            };
+            // Keep `remainder * 10` in range for denominators near `u128::MAX`.
+            let (digit, rest) = match remainder.checked_mul(10) {
+                Some(shifted) => (shifted / denominator, shifted % denominator),
+                None => long_step(remainder, denominator),
+            };
+            mantissa = next + digit;
+            remainder = rest;
+            scale += 1;
+        }
+        Some(Self { mantissa, scale }.normalized())
+    }
+
+    /// Price of one whole base token in quote tokens, from raw amounts
+    /// (`quote_amount` of the quote token per `base_amount` of the base token).
+    pub fn from_amounts(quote_amount: u64, quote_decimals: u8, base_amount: u64, base_decimals: u8) -> Option<Self> {
+        Self::from_ratio(quote_amount as u128, base_amount as u128)
+            .map(|price| price.shift(base_decimals as i32 - quote_decimals as i32))
+    }
+
+    /// Price of token A in token B from a Q64.64 square root price of raw
+    /// amounts, as used by concentrated liquidity and Meteora pools.
+    pub fn from_sqrt_price_x64(sqrt_price_x64: u128, decimals_a: u8, decimals_b: u8) -> Self {
+        let sqrt_price = Self::from_ratio(sqrt_price_x64, 1 << 64).unwrap_or_default();
+        (sqrt_price * sqrt_price).shift(decimals_a as i32 - decimals_b as i32)
+    }
+
+    /// `self * 10^exponent`.
+    pub fn shift(self, exponent: i32) -> Self {
+        if exponent <= 0 {
+            return Self {
+                mantissa: self.mantissa,
+                scale: self.scale + exponent.unsigned_abs(),
+            }
+            .normalized();
+        }
+        let mut price = self;
+        for _ in 0..exponent {
+            if price.scale > 0 {
+                price.scale -= 1;
+            } else {
+                price.mantissa = price.mantissa.saturating_mul(10);
+            }
+        }
+        price
+    }
+
+    /// `1 / self`, `None` for a zero price.
+    pub fn inverse(self) -> Option<Self> {
+        // 1 / (m / 10^s) = 10^s / m; the scale is shifted back in afterwards
+        // so `10^s` never has to be built.
+        Self::from_ratio(1, self.mantissa).map(|price| price.shift(self.scale as i32))
+    }
+
+    /// `self / other`, `None` when `other` is zero.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let (a, b, _) = align(self, other);
+        Self::from_ratio(a, b)
+    }
+
+    /// Signed change from `reference` in percent, `None` when `reference` is zero.
+    pub fn percent_change_from(self, reference: Self) -> Option<PriceChange> {
+        let (current, reference_mantissa, _) = align(self, reference);
+        let negative = current < reference_mantissa;
+        let difference = current.abs_diff(reference_mantissa);
+        let percent = Self::from_ratio(difference, reference_mantissa)?.shift(2);
+        Some(PriceChange {
+            negative: negative && !percent.is_zero(),
+            percent,
+        })
+    }
+
+    /// Drops the least significant fractional digit; `self.scale` must be non-zero.
+    fn drop_digit(self) -> Self {
+        Self {
+            mantissa: self.mantissa / 10,
+            scale: self.scale - 1,
+        }
+    }
+
+    /// Strips trailing zeros and fractional digits past what a `u128` can carry.
+    fn normalized(mut self) -> Self {
+        while self.scale > 0 && self.mantissa % 10 == 0 {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+        while self.scale > 0 && digits(self.mantissa) > PRICE_DIGITS {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+        if self.mantissa == 0 {
+            self.scale = 0;
+        }
+        self
+    }
+}
+
+/// Product, dropping the least significant fractional digits of the operands
+/// until the mantissas multiply without overflow. Saturates when both are
+/// integers.
+impl Mul for Price {
+    type Output = Price;
+
+    fn mul(self, other: Self) -> Self {
+        let (mut a, mut b) = (self, other);
+        loop {
+            if let Some(mantissa) = a.mantissa.checked_mul(b.mantissa) {
+                return Self {
+                    mantissa,
+                    scale: a.scale + b.scale,
+                }
+                .normalized();
+            }
+            match (a.scale, b.scale) {
+                (0, 0) => return Self::new(u128::MAX, 0),
+                (_, 0) => a = a.drop_digit(),
+                (0, _) => b = b.drop_digit(),
+                _ if a.mantissa >= b.mantissa => a = a.drop_digit(),
+                _ => b = b.drop_digit(),
+            }
+        }
+    }
+}
+
+impl PartialEq for Price {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b, _) = align(*self, *other);
+        a.cmp(&b)
+    }
+}
+
+/// Exact decimal rendering. A precision (`{:.8}`) truncates to that many
+/// fractional digits; trailing zeros are never printed otherwise.
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.to_string();
+        let scale = self.scale as usize;
+        let (integer, fraction) = if digits.len() > scale {
+            let (integer, fraction) = digits.split_at(digits.len() - scale);
+            (integer.to_string(), fraction.to_string())
+        } else {
+            ("0".to_string(), format!("{digits:0>scale$}"))
+        };
+
+        let fraction = match f.precision() {
+            Some(precision) => format!("{:0<precision$}", &fraction[..fraction.len().min(precision)]),
+            None => fraction,
+        };
+        if fraction.is_empty() {
+            f.write_str(&integer)
+        } else {
+            write!(f, "{integer}.{fraction}")
+        }
+    }
+}
+
+impl fmt::Debug for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Serialized as its decimal string so no precision is lost in JSON.
+impl Serialize for Price {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// A signed percentage, e.g. a price impact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceChange {
+    pub negative: bool,
+    pub percent: Price,
+}
+
+impl fmt::Display for PriceChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        fmt::Display::fmt(&self.percent, f)?;
+        f.write_str("%")
+    }
+}
+
+impl Serialize for PriceChange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+fn digits(value: u128) -> u32 {
+    value.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// `remainder * 10` divided by `denominator` when the product overflows,
+/// as ten additions reduced modulo `denominator`.
+fn long_step(remainder: u128, denominator: u128) -> (u128, u128) {
+    let (mut digit, mut rest) = (0, 0);
+    for _ in 0..10 {
+        if rest >= denominator - remainder {
+            rest -= denominator - remainder;
+            digit += 1;
+        } else {
+            rest += remainder;
+        }
+    }
+    (digit, rest)
+}
+
+/// Mantissas of `a` and `b` at a common scale, losing digits of the more
+/// precise one only when the other would overflow.
+fn align(a: Price, b: Price) -> (u128, u128, u32) {
+    let (mut a, mut b) = (a, b);
+    while a.scale != b.scale {
+        let (low, high) = if a.scale < b.scale { (&mut a, &mut b) } else { (&mut b, &mut a) };
+        match low.mantissa.checked_mul(10) {
+            Some(mantissa) => {
+                low.mantissa = mantissa;
+                low.scale += 1;
+            }
+            None => *high = high.drop_digit(),
+        }
+    }
+    (a.mantissa, b.mantissa, a.scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meme_token_price_keeps_its_digits() {
+        // 30 SOL against 1.07 billion 6-decimal tokens.
+        let price = Price::from_amounts(30_000_000_000, 9, 1_073_000_000_000_000, 6).unwrap();
+        assert_eq!(price.to_string(), "0.0000000279589934762348555452003727865");
+        assert_eq!(format!("{price:.12}"), "0.000000027958");
+    }
+
+    #[test]
+    fn terminating_ratio_is_exact() {
+        let price = Price::from_ratio(1, 8).unwrap();
+        assert_eq!(price, Price::new(125, 3));
+        assert_eq!(price.to_string(), "0.125");
+        assert_eq!(Price::from_ratio(10, 0), None);
+    }
+
+    #[test]
+    fn shift_and_inverse() {
+        let price = Price::new(25, 1).shift(3);
+        assert_eq!(price.to_string(), "2500");
+        assert_eq!(price.inverse().unwrap().to_string(), "0.0004");
+        assert_eq!(Price::ZERO.inverse(), None);
+    }
+
+    #[test]
+    fn sqrt_price_x64_squares() {
+        // sqrt(4) in Q64.64 with a 9 vs 6 decimal pair.
+        let price = Price::from_sqrt_price_x64(2 << 64, 9, 6);
+        assert_eq!(price.to_string(), "4000");
+    }
+
+    #[test]
+    fn percent_change_is_signed() {
+        let pool = Price::new(100, 0);
+        let up = Price::new(1015, 1).percent_change_from(pool).unwrap();
+        assert_eq!(up.to_string(), "1.5%");
+        let down = Price::new(98, 0).percent_change_from(pool).unwrap();
+        assert_eq!(down.to_string(), "-2%");
+        assert_eq!(format!("{down:.2}"), "-2.00%");
+    }
+
+    #[test]
+    fn ordering_ignores_scale() {
+        assert_eq!(Price::new(5, 1), Price::new(50, 2));
+        assert!(Price::new(1, 9) < Price::new(1, 8));
+    }
+
+    #[test]
+    fn ratio_with_huge_denominator() {
+        let price = Price::from_ratio(u128::MAX / 3, u128::MAX).unwrap();
+        assert_eq!(format!("{price:.6}"), "0.333333");
+    }
+}